@@ -307,6 +307,14 @@ pub struct RunCmd {
 	/// Will use the specified relay chain chainspec.
 	#[arg(long, conflicts_with_all = ["relay_chain_rpc_urls", "collator"])]
 	pub relay_chain_light_client: bool,
+
+	/// Write a snapshot of every collation produced by this collator to the given directory.
+	///
+	/// A snapshot can be replayed through the checks relay chain validators perform with the
+	/// `dry-run-collation` subcommand of the polkadot binary. Not supported with the relay chain
+	/// light client, which only runs full-nodes.
+	#[arg(long, value_name = "PATH", conflicts_with = "relay_chain_light_client")]
+	pub collation_snapshot_dir: Option<PathBuf>,
 }
 
 impl RunCmd {
@@ -329,7 +337,10 @@ impl RunCmd {
 				_ => RelayChainMode::Embedded,
 			};
 
-		CollatorOptions { relay_chain_mode }
+		CollatorOptions {
+			relay_chain_mode,
+			collation_snapshot_dir: self.collation_snapshot_dir.clone(),
+		}
 	}
 }
 
@@ -349,6 +360,8 @@ pub enum RelayChainMode {
 pub struct CollatorOptions {
 	/// How this collator retrieves relay chain information
 	pub relay_chain_mode: RelayChainMode,
+	/// Directory to write a snapshot of every produced collation to, if any.
+	pub collation_snapshot_dir: Option<PathBuf>,
 }
 
 /// A non-redundant version of the `RunCmd` that sets the `validator` field when the
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::{pin::Pin, sync::Arc, time::Duration};
+use std::{path::PathBuf, pin::Pin, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use cumulus_primitives_core::{
@@ -296,6 +296,7 @@ fn build_polkadot_full_node(
 	parachain_config: &Configuration,
 	telemetry_worker_handle: Option<TelemetryWorkerHandle>,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	collation_snapshot_dir: Option<PathBuf>,
 ) -> Result<(NewFull, Option<CollatorPair>), polkadot_service::Error> {
 	let (is_parachain_node, maybe_collator_key) = if parachain_config.role.is_authority() {
 		let collator_key = CollatorPair::generate().0;
@@ -327,6 +328,7 @@ fn build_polkadot_full_node(
 			execute_workers_max_num: None,
 			prepare_workers_hard_max_num: None,
 			prepare_workers_soft_max_num: None,
+			collation_snapshot_dir,
 		},
 	)?;
 
@@ -340,6 +342,7 @@ pub fn build_inprocess_relay_chain(
 	telemetry_worker_handle: Option<TelemetryWorkerHandle>,
 	task_manager: &mut TaskManager,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	collation_snapshot_dir: Option<PathBuf>,
 ) -> RelayChainResult<(Arc<(dyn RelayChainInterface + 'static)>, Option<CollatorPair>)> {
 	// This is essentially a hack, but we want to ensure that we send the correct node version
 	// to the telemetry.
@@ -351,6 +354,7 @@ pub fn build_inprocess_relay_chain(
 		parachain_config,
 		telemetry_worker_handle,
 		hwbench,
+		collation_snapshot_dir,
 	)
 	.map_err(|e| RelayChainError::Application(Box::new(e) as Box<_>))?;
 
@@ -41,7 +41,7 @@ use sc_service::{config::PrometheusConfig, Configuration, TaskManager};
 use sp_runtime::{app_crypto::Pair, traits::Block as BlockT};
 
 use futures::{FutureExt, StreamExt};
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 mod blockchain_rpc_client;
 mod collator_overseer;
@@ -94,6 +94,7 @@ async fn build_interface(
 	polkadot_config: Configuration,
 	task_manager: &mut TaskManager,
 	client: RelayChainRpcClient,
+	collation_snapshot_dir: Option<PathBuf>,
 ) -> RelayChainResult<(Arc<(dyn RelayChainInterface + 'static)>, Option<CollatorPair>)> {
 	let collator_pair = CollatorPair::generate().0;
 	let collator_node = match polkadot_config.network.network_backend {
@@ -102,6 +103,7 @@ async fn build_interface(
 				polkadot_config,
 				collator_pair.clone(),
 				Arc::new(BlockChainRpcClient::new(client.clone())),
+				collation_snapshot_dir,
 			)
 			.await?,
 		sc_network::config::NetworkBackendType::Litep2p =>
@@ -109,6 +111,7 @@ async fn build_interface(
 				polkadot_config,
 				collator_pair.clone(),
 				Arc::new(BlockChainRpcClient::new(client.clone())),
+				collation_snapshot_dir,
 			)
 			.await?,
 	};
@@ -123,6 +126,7 @@ pub async fn build_minimal_relay_chain_node_with_rpc(
 	polkadot_config: Configuration,
 	task_manager: &mut TaskManager,
 	relay_chain_url: Vec<Url>,
+	collation_snapshot_dir: Option<PathBuf>,
 ) -> RelayChainResult<(Arc<(dyn RelayChainInterface + 'static)>, Option<CollatorPair>)> {
 	let client = cumulus_relay_chain_rpc_interface::create_client_and_start_worker(
 		relay_chain_url,
@@ -130,7 +134,7 @@ pub async fn build_minimal_relay_chain_node_with_rpc(
 	)
 	.await?;
 
-	build_interface(polkadot_config, task_manager, client).await
+	build_interface(polkadot_config, task_manager, client, collation_snapshot_dir).await
 }
 
 pub async fn build_minimal_relay_chain_node_light_client(
@@ -155,7 +159,7 @@ pub async fn build_minimal_relay_chain_node_light_client(
 	)
 	.await?;
 
-	build_interface(polkadot_config, task_manager, client).await
+	build_interface(polkadot_config, task_manager, client, None).await
 }
 
 /// Builds a minimal relay chain node. Chain data is fetched
@@ -174,6 +178,7 @@ async fn new_minimal_relay_chain<Block: BlockT, Network: NetworkBackend<RelayBlo
 	config: Configuration,
 	collator_pair: CollatorPair,
 	relay_chain_rpc_client: Arc<BlockChainRpcClient>,
+	collation_snapshot_dir: Option<PathBuf>,
 ) -> Result<NewMinimalNode, RelayChainError> {
 	let role = config.role.clone();
 	let mut net_config =
@@ -252,6 +257,7 @@ async fn new_minimal_relay_chain<Block: BlockT, Network: NetworkBackend<RelayBlo
 		req_protocol_names: request_protocol_names,
 		peerset_protocol_names,
 		notification_services,
+		collation_snapshot_dir,
 	};
 
 	let overseer_handle =
@@ -24,7 +24,7 @@ use cumulus_client_network::{AssumeSybilResistance, RequireSecondedInBlockAnnoun
 use cumulus_client_pov_recovery::{PoVRecovery, RecoveryDelayRange, RecoveryHandle};
 use cumulus_primitives_core::{CollectCollationInfo, ParaId};
 use cumulus_relay_chain_inprocess_interface::build_inprocess_relay_chain;
-use cumulus_relay_chain_interface::{RelayChainInterface, RelayChainResult};
+use cumulus_relay_chain_interface::{RelayChainError, RelayChainInterface, RelayChainResult};
 use cumulus_relay_chain_minimal_node::{
 	build_minimal_relay_chain_node_light_client, build_minimal_relay_chain_node_with_rpc,
 };
@@ -369,16 +369,26 @@ pub async fn build_relay_chain_interface(
 			telemetry_worker_handle,
 			task_manager,
 			hwbench,
+			collator_options.collation_snapshot_dir,
 		),
 		cumulus_client_cli::RelayChainMode::ExternalRpc(rpc_target_urls) =>
 			build_minimal_relay_chain_node_with_rpc(
 				relay_chain_config,
 				task_manager,
 				rpc_target_urls,
+				collator_options.collation_snapshot_dir,
 			)
 			.await,
-		cumulus_client_cli::RelayChainMode::LightClient =>
-			build_minimal_relay_chain_node_light_client(relay_chain_config, task_manager).await,
+		cumulus_client_cli::RelayChainMode::LightClient => {
+			if collator_options.collation_snapshot_dir.is_some() {
+				return Err(RelayChainError::GenericError(
+					"Collation snapshots are not supported with the relay chain light client"
+						.into(),
+				))
+			}
+
+			build_minimal_relay_chain_node_light_client(relay_chain_config, task_manager).await
+		},
 	}
 }
 
@@ -0,0 +1,147 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking for the `cumulus-pallet-hrmp-channels`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+use sp_runtime::traits::BlockNumberProvider;
+
+/// The `n`-th parachain other than this one.
+fn other_para<T: Config>(n: u32) -> ParaId {
+	ParaId::from(u32::from(T::SelfParaId::get()) + 1 + n)
+}
+
+/// `MaxChannels` channels in `state` since the first relay chain block, without deposits.
+fn channels<T: Config>(state: ChannelState) -> ChannelsOf<T> {
+	let mut channels = ChannelsOf::<T>::new();
+	for n in 0..T::MaxChannels::get() {
+		let channel =
+			Channel { state, max_capacity: 8, max_message_size: 1024, deposit: 0, since: 0 };
+		channels.try_insert(other_para::<T>(n), channel).unwrap();
+	}
+	channels
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn open_channels(n: Linear<1, { T::MaxChannels::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ChannelManager::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let recipients: BoundedVec<_, _> =
+			(0..n).map(other_para::<T>).collect::<Vec<_>>().try_into().unwrap();
+		InboundChannels::<T>::put(channels::<T>(ChannelState::Open));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, recipients);
+
+		assert_eq!(OutboundChannels::<T>::get().len() as u32, n);
+		assert_last_event::<T>(
+			Event::OpenChannelRequested { recipient: other_para::<T>(n - 1) }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_channel() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ChannelManager::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		OutboundChannels::<T>::put(channels::<T>(ChannelState::Open));
+		InboundChannels::<T>::put(channels::<T>(ChannelState::Requested));
+		let sender = other_para::<T>(0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, sender);
+
+		assert_last_event::<T>(Event::OpenChannelAccepted { sender }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close_channel() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ChannelManager::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		OutboundChannels::<T>::put(channels::<T>(ChannelState::Open));
+		let para = other_para::<T>(0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, para, ChannelDirection::Outbound);
+
+		assert_last_event::<T>(
+			Event::ChannelClosing { para, direction: ChannelDirection::Outbound }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_inbound_policy() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ChannelManager::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let paras = (0..T::MaxChannels::get()).map(other_para::<T>).collect::<Vec<_>>();
+		let policy = InboundPolicy::AcceptFrom(paras.try_into().unwrap());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, policy.clone());
+
+		assert_eq!(Policy::<T>::get(), policy);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_open_request() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ChannelManager::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		OutboundChannels::<T>::put(channels::<T>(ChannelState::Requested));
+		let para = other_para::<T>(0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, para, ChannelDirection::Outbound);
+
+		assert_last_event::<T>(
+			Event::OpenRequestCanceled { para, direction: ChannelDirection::Outbound }.into(),
+		);
+		Ok(())
+	}
+
+	/// All requests in both directions expired, the outbound ones are canceled on the relay
+	/// chain.
+	#[benchmark]
+	fn sync_channels() {
+		OutboundChannels::<T>::put(channels::<T>(ChannelState::Requested));
+		InboundChannels::<T>::put(channels::<T>(ChannelState::Requested));
+		T::RelayBlockNumberProvider::set_block_number(T::RequestTtl::get());
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+		}
+
+		assert!(OutboundChannels::<T>::get().is_empty());
+		assert!(InboundChannels::<T>::get().is_empty());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
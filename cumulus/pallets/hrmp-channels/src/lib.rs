@@ -0,0 +1,711 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Manages the HRMP channels of a parachain from the parachain side.
+//!
+//! Opening an HRMP channel takes a request of the sender and an acceptance of the recipient, both
+//! made on the relay chain, which reserves a deposit from the sovereign account of each of the two
+//! parachains there. This pallet makes these calls by sending them to the relay chain in a
+//! `Transact`:
+//!
+//! - [`Pallet::open_channels`] requests channels to a list of target parachains.
+//! - [`Pallet::accept_channel`] accepts the request of another parachain. Requests are accepted
+//!   automatically if the [`InboundPolicy`] set by governance allows it.
+//! - [`Pallet::cancel_open_request`] cancels a request that was not accepted yet, in either
+//!   direction.
+//! - [`Pallet::close_channel`] closes a channel in either direction.
+//!
+//! The fees for executing these calls are withdrawn from the sovereign account of the parachain on
+//! the relay chain, which also has to hold the deposits. The pallet does not request or accept
+//! channels for which the deposits would exceed [`Config::MaxTotalDeposit`].
+//!
+//! The relay chain notifies the parachain of requests to open channels to it, of the acceptance of
+//! its own requests and of channels being closed with the `HrmpNewChannelOpenRequest`,
+//! `HrmpChannelAccepted` and `HrmpChannelClosing` instructions. The pallet handles these when set
+//! as the respective handlers in the XCM executor config of the runtime, whose barrier has to let
+//! them through. Channels being opened and closed are then picked up from the relay chain state
+//! reported to the parachain, see [`Config::ChannelList`].
+//!
+//! A request that is not accepted, or an accepted request for which no channel is opened, within
+//! [`Config::RequestTtl`] blocks of the relay chain expires. The pallet forgets it, so that it no
+//! longer takes up a slot of [`Config::MaxChannels`] or counts towards [`Config::MaxTotalDeposit`],
+//! and cancels it on the relay chain if this parachain made it and it was not accepted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use cumulus_primitives_core::{
+	relay_chain::{BlockNumber as RelayBlockNumber, HrmpChannelId},
+	AbridgedHrmpChannel, IsSystem, ListHrmpChannels, ParaId,
+};
+use frame_support::{
+	pallet_prelude::*, traits::EnsureOrigin, BoundedBTreeMap, CloneNoBound, DefaultNoBound,
+	EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::BlockNumberProvider, RuntimeDebug};
+use sp_std::{prelude::*, vec};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{
+	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+};
+
+const LOG_TARGET: &str = "runtime::hrmp-channels";
+
+/// The direction of an HRMP channel, seen from this parachain.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ChannelDirection {
+	/// The channel goes from this parachain to another one.
+	Outbound,
+	/// The channel goes from another parachain to this one.
+	Inbound,
+}
+
+/// The state of an HRMP channel of this parachain.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ChannelState {
+	/// The sender requested to open the channel, the recipient did not accept yet.
+	Requested,
+	/// The recipient accepted the request, the relay chain did not open the channel yet.
+	Accepted,
+	/// The channel is open.
+	Open,
+	/// Either side requested to close the channel, the relay chain did not close it yet.
+	Closing,
+}
+
+/// An HRMP channel of this parachain.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Channel {
+	/// The state of the channel.
+	pub state: ChannelState,
+	/// The maximum number of messages that can be pending in the channel at once.
+	pub max_capacity: u32,
+	/// The maximum size of a message sent over the channel.
+	pub max_message_size: u32,
+	/// The deposit this parachain has reserved for the channel on the relay chain.
+	pub deposit: u128,
+	/// The block of the relay chain at which the channel entered its state.
+	pub since: RelayBlockNumber,
+}
+
+/// Which requests to open a channel to this parachain are accepted automatically.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DefaultNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(MaxParas))]
+pub enum InboundPolicy<MaxParas: Get<u32>> {
+	/// No request is accepted automatically, they wait for [`Pallet::accept_channel`].
+	#[default]
+	Manual,
+	/// The requests of all parachains are accepted.
+	AcceptAll,
+	/// The requests of system parachains are accepted, the others wait.
+	AcceptSystem,
+	/// The requests of the listed parachains are accepted, the others wait.
+	AcceptFrom(BoundedVec<ParaId, MaxParas>),
+}
+
+impl<MaxParas: Get<u32>> InboundPolicy<MaxParas> {
+	/// Whether a request of `sender` is accepted automatically.
+	pub fn accepts(&self, sender: ParaId) -> bool {
+		match self {
+			Self::Manual => false,
+			Self::AcceptAll => true,
+			Self::AcceptSystem => sender.is_system(),
+			Self::AcceptFrom(paras) => paras.contains(&sender),
+		}
+	}
+}
+
+/// The channels of this parachain in one direction, by the parachain on the other side.
+pub type ChannelsOf<T> = BoundedBTreeMap<ParaId, Channel, <T as Config>::MaxChannels>;
+
+/// The calls of the HRMP pallet of the relay chain this pallet makes.
+#[derive(Encode)]
+enum HrmpCall {
+	#[codec(index = 0)]
+	InitOpenChannel {
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	},
+	#[codec(index = 1)]
+	AcceptOpenChannel { sender: ParaId },
+	#[codec(index = 2)]
+	CloseChannel { channel_id: HrmpChannelId },
+	#[codec(index = 6)]
+	CancelOpenRequest { channel_id: HrmpChannelId, open_requests: u32 },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type of the runtime.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin allowed to open, accept and close channels and to set the
+		/// [`InboundPolicy`].
+		type ChannelManager: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The open channels as last reported by the relay chain, usually `ParachainSystem`.
+		type ChannelList: ListHrmpChannels;
+
+		/// Sends the calls to the relay chain.
+		type XcmSender: SendXcm;
+
+		/// The id of this parachain.
+		type SelfParaId: Get<ParaId>;
+
+		/// The block number of the relay chain, usually `RelaychainDataProvider`.
+		type RelayBlockNumberProvider: BlockNumberProvider<BlockNumber = RelayBlockNumber>;
+
+		/// The index of the HRMP pallet in the runtime of the relay chain.
+		#[pallet::constant]
+		type RelayHrmpPalletIndex: Get<u8>;
+
+		/// The amount of the relay chain token withdrawn from the sovereign account of this
+		/// parachain on the relay chain to pay for executing a call there. The rest is deposited
+		/// back.
+		#[pallet::constant]
+		type RelayCallFee: Get<u128>;
+
+		/// The weight a call may take on the relay chain.
+		#[pallet::constant]
+		type RelayCallWeight: Get<Weight>;
+
+		/// The deposit the relay chain reserves for requesting to open a channel.
+		#[pallet::constant]
+		type SenderDeposit: Get<u128>;
+
+		/// The deposit the relay chain reserves for accepting to open a channel.
+		#[pallet::constant]
+		type RecipientDeposit: Get<u128>;
+
+		/// The maximum of the deposits reserved on the relay chain for all channels at once.
+		#[pallet::constant]
+		type MaxTotalDeposit: Get<u128>;
+
+		/// The capacity proposed for outbound channels.
+		#[pallet::constant]
+		type ProposedMaxCapacity: Get<u32>;
+
+		/// The maximum message size proposed for outbound channels.
+		#[pallet::constant]
+		type ProposedMaxMessageSize: Get<u32>;
+
+		/// The maximum number of channels in each direction.
+		#[pallet::constant]
+		type MaxChannels: Get<u32>;
+
+		/// The number of relay chain blocks after which a request that was not accepted, or an
+		/// accepted request for which no channel was opened, expires.
+		///
+		/// The relay chain opens accepted channels at the start of its next session, so this
+		/// should be longer than a session.
+		#[pallet::constant]
+		type RequestTtl: Get<RelayBlockNumber>;
+
+		/// An upper bound of the number of requests to open a channel pending on the relay chain,
+		/// which is the witness of canceling one. The relay chain rejects the cancellation if
+		/// there are more.
+		#[pallet::constant]
+		type MaxRelayOpenRequests: Get<u32>;
+
+		/// Weight info for this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The channels from this parachain to others.
+	#[pallet::storage]
+	pub type OutboundChannels<T: Config> = StorageValue<_, ChannelsOf<T>, ValueQuery>;
+
+	/// The channels from other parachains to this one.
+	#[pallet::storage]
+	pub type InboundChannels<T: Config> = StorageValue<_, ChannelsOf<T>, ValueQuery>;
+
+	/// Which requests to open a channel to this parachain are accepted automatically.
+	#[pallet::storage]
+	pub type Policy<T: Config> = StorageValue<_, InboundPolicy<T::MaxChannels>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// This parachain requested to open a channel to `recipient`.
+		OpenChannelRequested { recipient: ParaId },
+		/// `sender` requested to open a channel to this parachain.
+		OpenChannelRequestReceived { sender: ParaId, max_capacity: u32, max_message_size: u32 },
+		/// This parachain accepted to open a channel from `sender`.
+		OpenChannelAccepted { sender: ParaId },
+		/// `recipient` accepted to open the channel requested by this parachain.
+		OpenChannelAcceptedByRecipient { recipient: ParaId },
+		/// The request to open a channel with `para` was canceled.
+		OpenRequestCanceled { para: ParaId, direction: ChannelDirection },
+		/// The request to open a channel with `para` expired.
+		OpenRequestExpired { para: ParaId, direction: ChannelDirection },
+		/// The relay chain opened a channel with `para`.
+		ChannelOpened { para: ParaId, direction: ChannelDirection },
+		/// Either side requested to close a channel with `para`.
+		ChannelClosing { para: ParaId, direction: ChannelDirection },
+		/// The relay chain closed a channel with `para`.
+		ChannelClosed { para: ParaId, direction: ChannelDirection },
+		/// The policy for accepting requests to open channels was changed.
+		InboundPolicySet,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There already is a channel with the parachain in this direction.
+		ChannelExists,
+		/// A parachain cannot open a channel to itself.
+		OwnParachain,
+		/// The maximum number of channels in this direction is reached.
+		TooManyChannels,
+		/// There is no request to open a channel with the parachain that was not accepted yet.
+		NoOpenRequest,
+		/// The channel is not open.
+		NotOpen,
+		/// The deposits for the channels would exceed [`Config::MaxTotalDeposit`].
+		DepositLimitExceeded,
+		/// The call could not be sent to the relay chain.
+		SendFailure,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// The relay chain state is the one of the previous block, this block's inherent did
+			// not set it yet. Channels are opened and closed at session boundaries of the relay
+			// chain, picking them up a block later does not make a difference.
+			let now = T::RelayBlockNumberProvider::current_block_number();
+			Self::sync_channels(ChannelDirection::Outbound, T::ChannelList::egress_channels(), now);
+			Self::sync_channels(ChannelDirection::Inbound, T::ChannelList::ingress_channels(), now);
+			T::WeightInfo::sync_channels()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request to open a channel to each of `recipients`, with the proposed capacity and
+		/// message size of the config.
+		///
+		/// The request to one of them can only be made if there is no channel to it yet.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::open_channels(recipients.len() as u32))]
+		pub fn open_channels(
+			origin: OriginFor<T>,
+			recipients: BoundedVec<ParaId, T::MaxChannels>,
+		) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			let mut channels = OutboundChannels::<T>::get();
+			let mut total_deposit = Self::total_deposit();
+			let now = T::RelayBlockNumberProvider::current_block_number();
+			for recipient in recipients {
+				ensure!(recipient != T::SelfParaId::get(), Error::<T>::OwnParachain);
+				ensure!(!channels.contains_key(&recipient), Error::<T>::ChannelExists);
+				total_deposit = total_deposit.saturating_add(T::SenderDeposit::get());
+				ensure!(
+					total_deposit <= T::MaxTotalDeposit::get(),
+					Error::<T>::DepositLimitExceeded
+				);
+
+				let channel = Channel {
+					state: ChannelState::Requested,
+					max_capacity: T::ProposedMaxCapacity::get(),
+					max_message_size: T::ProposedMaxMessageSize::get(),
+					deposit: T::SenderDeposit::get(),
+					since: now,
+				};
+				channels
+					.try_insert(recipient, channel)
+					.map_err(|_| Error::<T>::TooManyChannels)?;
+				Self::send_to_relay(HrmpCall::InitOpenChannel {
+					recipient,
+					proposed_max_capacity: T::ProposedMaxCapacity::get(),
+					proposed_max_message_size: T::ProposedMaxMessageSize::get(),
+				})?;
+				Self::deposit_event(Event::OpenChannelRequested { recipient });
+			}
+			OutboundChannels::<T>::put(channels);
+
+			Ok(())
+		}
+
+		/// Accept the request of `sender` to open a channel to this parachain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::accept_channel())]
+		pub fn accept_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+			Self::do_accept_channel(sender)
+		}
+
+		/// Close the channel with `para` in `direction`.
+		///
+		/// The relay chain closes it at the start of its next session.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::close_channel())]
+		pub fn close_channel(
+			origin: OriginFor<T>,
+			para: ParaId,
+			direction: ChannelDirection,
+		) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			let mut channels = Self::channels(direction);
+			let channel = channels.get_mut(&para).ok_or(Error::<T>::NotOpen)?;
+			ensure!(channel.state == ChannelState::Open, Error::<T>::NotOpen);
+
+			Self::send_to_relay(HrmpCall::CloseChannel {
+				channel_id: Self::channel_id(para, direction),
+			})?;
+			channel.state = ChannelState::Closing;
+			channel.since = T::RelayBlockNumberProvider::current_block_number();
+			Self::put_channels(direction, channels);
+			Self::deposit_event(Event::ChannelClosing { para, direction });
+
+			Ok(())
+		}
+
+		/// Set which requests to open a channel to this parachain are accepted automatically.
+		///
+		/// Requests received before are not affected.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_inbound_policy())]
+		pub fn set_inbound_policy(
+			origin: OriginFor<T>,
+			policy: InboundPolicy<T::MaxChannels>,
+		) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+			Policy::<T>::put(policy);
+			Self::deposit_event(Event::InboundPolicySet);
+			Ok(())
+		}
+
+		/// Cancel the request to open a channel with `para` in `direction` that was not accepted
+		/// yet.
+		///
+		/// Canceling an outbound request returns its deposit on the relay chain, canceling an
+		/// inbound one declines it.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::cancel_open_request())]
+		pub fn cancel_open_request(
+			origin: OriginFor<T>,
+			para: ParaId,
+			direction: ChannelDirection,
+		) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			let mut channels = Self::channels(direction);
+			ensure!(
+				channels
+					.get(&para)
+					.map_or(false, |channel| channel.state == ChannelState::Requested),
+				Error::<T>::NoOpenRequest
+			);
+
+			Self::send_to_relay(HrmpCall::CancelOpenRequest {
+				channel_id: Self::channel_id(para, direction),
+				open_requests: T::MaxRelayOpenRequests::get(),
+			})?;
+			channels.remove(&para);
+			Self::put_channels(direction, channels);
+			Self::deposit_event(Event::OpenRequestCanceled { para, direction });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The deposits reserved on the relay chain for the channels in both directions.
+	pub fn total_deposit() -> u128 {
+		OutboundChannels::<T>::get()
+			.values()
+			.chain(InboundChannels::<T>::get().values())
+			.fold(0u128, |total, channel| total.saturating_add(channel.deposit))
+	}
+
+	fn channels(direction: ChannelDirection) -> ChannelsOf<T> {
+		match direction {
+			ChannelDirection::Outbound => OutboundChannels::<T>::get(),
+			ChannelDirection::Inbound => InboundChannels::<T>::get(),
+		}
+	}
+
+	fn put_channels(direction: ChannelDirection, channels: ChannelsOf<T>) {
+		match direction {
+			ChannelDirection::Outbound => OutboundChannels::<T>::put(channels),
+			ChannelDirection::Inbound => InboundChannels::<T>::put(channels),
+		}
+	}
+
+	fn channel_id(para: ParaId, direction: ChannelDirection) -> HrmpChannelId {
+		match direction {
+			ChannelDirection::Outbound =>
+				HrmpChannelId { sender: T::SelfParaId::get(), recipient: para },
+			ChannelDirection::Inbound =>
+				HrmpChannelId { sender: para, recipient: T::SelfParaId::get() },
+		}
+	}
+
+	fn do_accept_channel(sender: ParaId) -> DispatchResult {
+		let mut channels = InboundChannels::<T>::get();
+		let channel = channels.get_mut(&sender).ok_or(Error::<T>::NoOpenRequest)?;
+		ensure!(channel.state == ChannelState::Requested, Error::<T>::NoOpenRequest);
+		ensure!(
+			Self::total_deposit().saturating_add(T::RecipientDeposit::get()) <=
+				T::MaxTotalDeposit::get(),
+			Error::<T>::DepositLimitExceeded
+		);
+
+		Self::send_to_relay(HrmpCall::AcceptOpenChannel { sender })?;
+		channel.state = ChannelState::Accepted;
+		channel.deposit = T::RecipientDeposit::get();
+		channel.since = T::RelayBlockNumberProvider::current_block_number();
+		InboundChannels::<T>::put(channels);
+		Self::deposit_event(Event::OpenChannelAccepted { sender });
+
+		Ok(())
+	}
+
+	/// Sends `call` to the relay chain, to be dispatched with the origin of this parachain.
+	fn send_to_relay(call: HrmpCall) -> DispatchResult {
+		let fee: Asset = (Here, T::RelayCallFee::get()).into();
+		let message = Xcm(vec![
+			WithdrawAsset(fee.clone().into()),
+			BuyExecution { fees: fee, weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: T::RelayCallWeight::get(),
+				call: (T::RelayHrmpPalletIndex::get(), call).encode().into(),
+			},
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: Location::new(0, [Parachain(T::SelfParaId::get().into())]),
+			},
+		]);
+
+		send_xcm::<T::XcmSender>(Location::parent(), message).map_err(|err| {
+			log::error!(target: LOG_TARGET, "Failed to send a call to the relay chain: {:?}", err);
+			Error::<T>::SendFailure
+		})?;
+
+		Ok(())
+	}
+
+	/// Brings the channels in `direction` in line with the `open` ones reported by the relay
+	/// chain at block `now`.
+	///
+	/// Channels opened without this pallet, e.g. before it was added to the runtime, are tracked
+	/// from here on, assuming the deposit of the config was reserved for them. Requests which are
+	/// still pending [`Config::RequestTtl`] blocks after they were made or accepted expire.
+	fn sync_channels(
+		direction: ChannelDirection,
+		open: Vec<(ParaId, AbridgedHrmpChannel)>,
+		now: RelayBlockNumber,
+	) {
+		let mut channels = Self::channels(direction);
+		let mut changed = false;
+
+		channels.retain(|para, channel| {
+			let is_open = open.binary_search_by_key(para, |(para, _)| *para).is_ok();
+			match channel.state {
+				ChannelState::Open | ChannelState::Closing if !is_open => {
+					Self::deposit_event(Event::ChannelClosed { para: *para, direction });
+				},
+				ChannelState::Requested | ChannelState::Accepted
+					if !is_open && channel.since.saturating_add(T::RequestTtl::get()) <= now =>
+				{
+					if direction == ChannelDirection::Outbound &&
+						channel.state == ChannelState::Requested
+					{
+						// Returns the deposit on the relay chain, unless the request never made it
+						// there.
+						let cancel = HrmpCall::CancelOpenRequest {
+							channel_id: Self::channel_id(*para, direction),
+							open_requests: T::MaxRelayOpenRequests::get(),
+						};
+						if let Err(err) = Self::send_to_relay(cancel) {
+							log::warn!(
+								target: LOG_TARGET,
+								"Failed to cancel the expired request to open a channel to {:?}: {:?}",
+								para,
+								err,
+							);
+						}
+					}
+					Self::deposit_event(Event::OpenRequestExpired { para: *para, direction });
+				},
+				_ => return true,
+			}
+			changed = true;
+			false
+		});
+
+		for (para, info) in open {
+			let Some(channel) = channels.get_mut(&para) else {
+				let deposit = match direction {
+					ChannelDirection::Outbound => T::SenderDeposit::get(),
+					ChannelDirection::Inbound => T::RecipientDeposit::get(),
+				};
+				let channel = Channel {
+					state: ChannelState::Open,
+					max_capacity: info.max_capacity,
+					max_message_size: info.max_message_size,
+					deposit,
+					since: now,
+				};
+				if channels.try_insert(para, channel).is_err() {
+					log::warn!(
+						target: LOG_TARGET,
+						"Too many channels to track the {:?} channel with {:?}",
+						direction,
+						para,
+					);
+					continue
+				}
+				Self::deposit_event(Event::ChannelOpened { para, direction });
+				changed = true;
+				continue
+			};
+
+			let opened = matches!(channel.state, ChannelState::Requested | ChannelState::Accepted);
+			if opened {
+				channel.state = ChannelState::Open;
+				channel.since = now;
+				Self::deposit_event(Event::ChannelOpened { para, direction });
+			}
+			if opened ||
+				channel.max_capacity != info.max_capacity ||
+				channel.max_message_size != info.max_message_size
+			{
+				channel.max_capacity = info.max_capacity;
+				channel.max_message_size = info.max_message_size;
+				changed = true;
+			}
+		}
+
+		if changed {
+			Self::put_channels(direction, channels);
+		}
+	}
+}
+
+impl<T: Config> HandleHrmpNewChannelOpenRequest for Pallet<T> {
+	fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> XcmResult {
+		let sender = ParaId::from(sender);
+		let mut channels = InboundChannels::<T>::get();
+		if channels.contains_key(&sender) {
+			return Ok(())
+		}
+		let channel = Channel {
+			state: ChannelState::Requested,
+			max_capacity,
+			max_message_size,
+			deposit: 0,
+			since: T::RelayBlockNumberProvider::current_block_number(),
+		};
+		if channels.try_insert(sender, channel).is_err() {
+			log::warn!(
+				target: LOG_TARGET,
+				"Too many channels to track the request of {:?} to open a channel",
+				sender,
+			);
+			return Ok(())
+		}
+		InboundChannels::<T>::put(channels);
+		Self::deposit_event(Event::OpenChannelRequestReceived {
+			sender,
+			max_capacity,
+			max_message_size,
+		});
+
+		if Policy::<T>::get().accepts(sender) {
+			if let Err(err) = Self::do_accept_channel(sender) {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to accept the request of {:?} to open a channel: {:?}",
+					sender,
+					err,
+				);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<T: Config> HandleHrmpChannelAccepted for Pallet<T> {
+	fn handle(recipient: u32) -> XcmResult {
+		let recipient = ParaId::from(recipient);
+		OutboundChannels::<T>::mutate(|channels| {
+			if let Some(channel) = channels.get_mut(&recipient) {
+				if channel.state == ChannelState::Requested {
+					channel.state = ChannelState::Accepted;
+					channel.since = T::RelayBlockNumberProvider::current_block_number();
+					Self::deposit_event(Event::OpenChannelAcceptedByRecipient { recipient });
+				}
+			}
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> HandleHrmpChannelClosing for Pallet<T> {
+	fn handle(_initiator: u32, sender: u32, recipient: u32) -> XcmResult {
+		let (para, direction) = if ParaId::from(sender) == T::SelfParaId::get() {
+			(ParaId::from(recipient), ChannelDirection::Outbound)
+		} else {
+			(ParaId::from(sender), ChannelDirection::Inbound)
+		};
+
+		let mut channels = Self::channels(direction);
+		if let Some(channel) = channels.get_mut(&para) {
+			if channel.state != ChannelState::Closing {
+				channel.state = ChannelState::Closing;
+				channel.since = T::RelayBlockNumberProvider::current_block_number();
+				Self::put_channels(direction, channels);
+				Self::deposit_event(Event::ChannelClosing { para, direction });
+			}
+		}
+		Ok(())
+	}
+}
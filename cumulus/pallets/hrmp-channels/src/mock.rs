@@ -0,0 +1,163 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{derive_impl, parameter_types, weights::Weight};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		HrmpChannels: crate,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletInfo = PalletInfo;
+}
+
+parameter_types! {
+	pub const SelfParaId: ParaId = ParaId::new(2000);
+	pub const RelayHrmpPalletIndex: u8 = 60;
+	pub const RelayCallFee: u128 = 1_000_000_000;
+	pub const RelayCallWeight: Weight = Weight::from_parts(1_000_000_000, 200_000);
+	pub const SenderDeposit: u128 = 100;
+	pub const RecipientDeposit: u128 = 100;
+	pub const MaxTotalDeposit: u128 = 500;
+	pub const ProposedMaxCapacity: u32 = 8;
+	pub const ProposedMaxMessageSize: u32 = 1024;
+	pub const MaxChannels: u32 = 4;
+	pub const RequestTtl: u32 = 100;
+	pub const MaxRelayOpenRequests: u32 = 1_000;
+
+	/// The block number of the relay chain.
+	pub static RelayChainBlockNumber: u32 = 0;
+
+	/// The open channels reported by the relay chain.
+	pub static IngressChannels: Vec<(ParaId, AbridgedHrmpChannel)> = vec![];
+	pub static EgressChannels: Vec<(ParaId, AbridgedHrmpChannel)> = vec![];
+
+	/// All messages sent to the relay chain.
+	pub static SentMessages: Vec<(Location, Xcm<()>)> = vec![];
+	pub static FailSending: bool = false;
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ChannelManager = EnsureRoot<u64>;
+	type ChannelList = MockChannelList;
+	type XcmSender = RecordingXcmSender;
+	type SelfParaId = SelfParaId;
+	type RelayBlockNumberProvider = MockRelayBlockNumberProvider;
+	type RelayHrmpPalletIndex = RelayHrmpPalletIndex;
+	type RelayCallFee = RelayCallFee;
+	type RelayCallWeight = RelayCallWeight;
+	type SenderDeposit = SenderDeposit;
+	type RecipientDeposit = RecipientDeposit;
+	type MaxTotalDeposit = MaxTotalDeposit;
+	type ProposedMaxCapacity = ProposedMaxCapacity;
+	type ProposedMaxMessageSize = ProposedMaxMessageSize;
+	type MaxChannels = MaxChannels;
+	type RequestTtl = RequestTtl;
+	type MaxRelayOpenRequests = MaxRelayOpenRequests;
+	type WeightInfo = ();
+}
+
+/// Can be used as [`Config::ChannelList`] to report the channels in [`IngressChannels`] and
+/// [`EgressChannels`].
+pub struct MockChannelList;
+impl ListHrmpChannels for MockChannelList {
+	fn ingress_channels() -> Vec<(ParaId, AbridgedHrmpChannel)> {
+		IngressChannels::get()
+	}
+
+	fn egress_channels() -> Vec<(ParaId, AbridgedHrmpChannel)> {
+		EgressChannels::get()
+	}
+}
+
+/// Can be used as [`Config::RelayBlockNumberProvider`] to report [`RelayChainBlockNumber`].
+pub struct MockRelayBlockNumberProvider;
+impl BlockNumberProvider for MockRelayBlockNumberProvider {
+	type BlockNumber = u32;
+
+	fn current_block_number() -> u32 {
+		RelayChainBlockNumber::get()
+	}
+
+	fn set_block_number(block: u32) {
+		RelayChainBlockNumber::set(block);
+	}
+}
+
+/// Can be used as [`Config::XcmSender`] to record all messages sent.
+pub struct RecordingXcmSender;
+impl SendXcm for RecordingXcmSender {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<(Location, Xcm<()>)> {
+		if FailSending::get() {
+			return Err(SendError::Transport("Intentional send failure used in tests"))
+		}
+		Ok(((dest.take().unwrap(), msg.take().unwrap()), Assets::new()))
+	}
+
+	fn deliver(ticket: (Location, Xcm<()>)) -> Result<XcmHash, SendError> {
+		SentMessages::mutate(|sent| sent.push(ticket));
+		Ok([0; 32])
+	}
+}
+
+/// An open channel as reported by the relay chain.
+pub fn abridged_channel(max_capacity: u32, max_message_size: u32) -> AbridgedHrmpChannel {
+	AbridgedHrmpChannel {
+		max_capacity,
+		max_total_size: max_capacity * max_message_size,
+		max_message_size,
+		msg_count: 0,
+		total_size: 0,
+		mqc_head: None,
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	IngressChannels::take();
+	EgressChannels::take();
+	SentMessages::take();
+	FailSending::set(false);
+	RelayChainBlockNumber::set(0);
+
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,466 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::{mock::*, *};
+
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn para(id: u32) -> ParaId {
+	ParaId::from(id)
+}
+
+fn paras(ids: &[u32]) -> BoundedVec<ParaId, MaxChannels> {
+	ids.iter().copied().map(para).collect::<Vec<_>>().try_into().unwrap()
+}
+
+fn channel(
+	state: ChannelState,
+	max_capacity: u32,
+	max_message_size: u32,
+	deposit: u128,
+) -> Channel {
+	Channel { state, max_capacity, max_message_size, deposit, since: 0 }
+}
+
+/// The calls sent to the HRMP pallet of the relay chain since the last time this was called.
+fn take_sent_calls() -> Vec<Vec<u8>> {
+	SentMessages::take()
+		.into_iter()
+		.map(|(dest, message)| {
+			assert_eq!(dest, Location::parent());
+			let call = message
+				.0
+				.iter()
+				.find_map(|instruction| match instruction {
+					Transact { origin_kind: OriginKind::Native, call, .. } =>
+						Some(call.clone().into_encoded()),
+					_ => None,
+				})
+				.expect("every message transacts a call");
+			assert_eq!(call[0], RelayHrmpPalletIndex::get());
+			call[1..].to_vec()
+		})
+		.collect()
+}
+
+fn init_open_channel_call(recipient: u32) -> Vec<u8> {
+	(0u8, para(recipient), ProposedMaxCapacity::get(), ProposedMaxMessageSize::get()).encode()
+}
+
+fn accept_open_channel_call(sender: u32) -> Vec<u8> {
+	(1u8, para(sender)).encode()
+}
+
+fn close_channel_call(sender: u32, recipient: u32) -> Vec<u8> {
+	(2u8, HrmpChannelId { sender: para(sender), recipient: para(recipient) }).encode()
+}
+
+fn cancel_open_request_call(sender: u32, recipient: u32) -> Vec<u8> {
+	let channel_id = HrmpChannelId { sender: para(sender), recipient: para(recipient) };
+	(6u8, channel_id, MaxRelayOpenRequests::get()).encode()
+}
+
+fn next_block() {
+	let n = System::block_number() + 1;
+	System::set_block_number(n);
+	HrmpChannels::on_initialize(n);
+}
+
+#[test]
+fn open_channels_requests_channels_from_the_relay_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2002])));
+
+		assert_eq!(
+			take_sent_calls(),
+			vec![init_open_channel_call(2001), init_open_channel_call(2002)]
+		);
+		let requested = channel(ChannelState::Requested, 8, 1024, SenderDeposit::get());
+		assert_eq!(
+			OutboundChannels::<Test>::get().into_inner(),
+			[(para(2001), requested.clone()), (para(2002), requested)].into(),
+		);
+		assert_eq!(HrmpChannels::total_deposit(), 200);
+		System::assert_has_event(Event::OpenChannelRequested { recipient: para(2001) }.into());
+		System::assert_last_event(Event::OpenChannelRequested { recipient: para(2002) }.into());
+	});
+}
+
+#[test]
+fn open_channels_fails_for_invalid_recipients() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpChannels::open_channels(RuntimeOrigin::signed(1), paras(&[2001])),
+			BadOrigin
+		);
+		assert_noop!(
+			HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2000])),
+			Error::<Test>::OwnParachain
+		);
+		assert_noop!(
+			HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2001])),
+			Error::<Test>::ChannelExists
+		);
+		// The deposits for an inbound channel and four outbound ones exceed `MaxTotalDeposit`.
+		InboundChannels::<Test>::mutate(|channels| {
+			channels
+				.try_insert(para(2005), channel(ChannelState::Open, 4, 512, 200))
+				.unwrap();
+		});
+		assert_noop!(
+			HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2002, 2003, 2004])),
+			Error::<Test>::DepositLimitExceeded
+		);
+
+		SentMessages::take();
+		FailSending::set(true);
+		assert_noop!(
+			HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001])),
+			Error::<Test>::SendFailure
+		);
+		assert!(SentMessages::get().is_empty());
+	});
+}
+
+#[test]
+fn outbound_channel_lifecycle_follows_the_relay_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001])));
+		take_sent_calls();
+
+		// The channel cannot be closed before it is open.
+		assert_noop!(
+			HrmpChannels::close_channel(
+				RuntimeOrigin::root(),
+				para(2001),
+				ChannelDirection::Outbound
+			),
+			Error::<Test>::NotOpen
+		);
+
+		assert_ok!(<HrmpChannels as HandleHrmpChannelAccepted>::handle(2001));
+		assert_eq!(OutboundChannels::<Test>::get()[&para(2001)].state, ChannelState::Accepted);
+		System::assert_last_event(
+			Event::OpenChannelAcceptedByRecipient { recipient: para(2001) }.into(),
+		);
+
+		// The relay chain opens the channel with the parameters the recipient agreed to.
+		EgressChannels::set(vec![(para(2001), abridged_channel(4, 512))]);
+		next_block();
+		assert_eq!(
+			OutboundChannels::<Test>::get()[&para(2001)],
+			channel(ChannelState::Open, 4, 512, SenderDeposit::get()),
+		);
+		System::assert_last_event(
+			Event::ChannelOpened { para: para(2001), direction: ChannelDirection::Outbound }.into(),
+		);
+
+		assert_ok!(HrmpChannels::close_channel(
+			RuntimeOrigin::root(),
+			para(2001),
+			ChannelDirection::Outbound
+		));
+		assert_eq!(take_sent_calls(), vec![close_channel_call(2000, 2001)]);
+		assert_eq!(OutboundChannels::<Test>::get()[&para(2001)].state, ChannelState::Closing);
+
+		// Nothing changes until the relay chain closes the channel.
+		next_block();
+		assert_eq!(OutboundChannels::<Test>::get()[&para(2001)].state, ChannelState::Closing);
+
+		EgressChannels::set(vec![]);
+		next_block();
+		assert!(OutboundChannels::<Test>::get().is_empty());
+		assert_eq!(HrmpChannels::total_deposit(), 0);
+		System::assert_last_event(
+			Event::ChannelClosed { para: para(2001), direction: ChannelDirection::Outbound }.into(),
+		);
+	});
+}
+
+#[test]
+fn inbound_requests_are_accepted_according_to_the_policy() {
+	new_test_ext().execute_with(|| {
+		// By default requests wait to be accepted manually.
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2001, 512, 4));
+		assert!(take_sent_calls().is_empty());
+		assert_eq!(
+			InboundChannels::<Test>::get()[&para(2001)],
+			channel(ChannelState::Requested, 4, 512, 0),
+		);
+		System::assert_last_event(
+			Event::OpenChannelRequestReceived {
+				sender: para(2001),
+				max_capacity: 4,
+				max_message_size: 512,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			HrmpChannels::accept_channel(RuntimeOrigin::root(), para(2002)),
+			Error::<Test>::NoOpenRequest
+		);
+		assert_ok!(HrmpChannels::accept_channel(RuntimeOrigin::root(), para(2001)));
+		assert_eq!(take_sent_calls(), vec![accept_open_channel_call(2001)]);
+		assert_eq!(
+			InboundChannels::<Test>::get()[&para(2001)],
+			channel(ChannelState::Accepted, 4, 512, RecipientDeposit::get()),
+		);
+		System::assert_last_event(Event::OpenChannelAccepted { sender: para(2001) }.into());
+		assert_noop!(
+			HrmpChannels::accept_channel(RuntimeOrigin::root(), para(2001)),
+			Error::<Test>::NoOpenRequest
+		);
+
+		assert_ok!(HrmpChannels::set_inbound_policy(
+			RuntimeOrigin::root(),
+			InboundPolicy::AcceptSystem
+		));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(1000, 512, 4));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2002, 512, 4));
+		assert_eq!(take_sent_calls(), vec![accept_open_channel_call(1000)]);
+		assert_eq!(InboundChannels::<Test>::get()[&para(1000)].state, ChannelState::Accepted);
+		assert_eq!(InboundChannels::<Test>::get()[&para(2002)].state, ChannelState::Requested);
+
+		assert_ok!(HrmpChannels::set_inbound_policy(
+			RuntimeOrigin::root(),
+			InboundPolicy::AcceptFrom(paras(&[2003]))
+		));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2003, 512, 4));
+		assert_eq!(take_sent_calls(), vec![accept_open_channel_call(2003)]);
+
+		// The relay chain opens the accepted channels.
+		IngressChannels::set(vec![
+			(para(1000), abridged_channel(4, 512)),
+			(para(2001), abridged_channel(4, 512)),
+			(para(2003), abridged_channel(4, 512)),
+		]);
+		next_block();
+		let channels = InboundChannels::<Test>::get();
+		assert_eq!(channels[&para(1000)].state, ChannelState::Open);
+		assert_eq!(channels[&para(2001)].state, ChannelState::Open);
+		assert_eq!(channels[&para(2002)].state, ChannelState::Requested);
+		assert_eq!(channels[&para(2003)].state, ChannelState::Open);
+	});
+}
+
+#[test]
+fn inbound_requests_are_not_accepted_beyond_the_deposit_limit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::set_inbound_policy(
+			RuntimeOrigin::root(),
+			InboundPolicy::AcceptAll
+		));
+		assert_ok!(HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2002])));
+		take_sent_calls();
+
+		// 200 for the outbound channels and 100 for each accepted inbound one.
+		for sender in 2003..=2006 {
+			assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(sender, 512, 4));
+		}
+		assert_eq!(
+			take_sent_calls(),
+			vec![
+				accept_open_channel_call(2003),
+				accept_open_channel_call(2004),
+				accept_open_channel_call(2005),
+			]
+		);
+		assert_eq!(HrmpChannels::total_deposit(), MaxTotalDeposit::get());
+		assert_eq!(InboundChannels::<Test>::get()[&para(2006)].state, ChannelState::Requested);
+
+		assert_noop!(
+			HrmpChannels::accept_channel(RuntimeOrigin::root(), para(2006)),
+			Error::<Test>::DepositLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn channels_closed_by_the_other_side_are_tracked() {
+	new_test_ext().execute_with(|| {
+		// Channels opened before the pallet was added are picked up from the relay chain.
+		EgressChannels::set(vec![(para(2001), abridged_channel(4, 512))]);
+		IngressChannels::set(vec![(para(2002), abridged_channel(8, 256))]);
+		next_block();
+		assert_eq!(
+			OutboundChannels::<Test>::get()[&para(2001)],
+			channel(ChannelState::Open, 4, 512, SenderDeposit::get()),
+		);
+		assert_eq!(
+			InboundChannels::<Test>::get()[&para(2002)],
+			channel(ChannelState::Open, 8, 256, RecipientDeposit::get()),
+		);
+
+		assert_ok!(<HrmpChannels as HandleHrmpChannelClosing>::handle(2001, 2000, 2001));
+		assert_ok!(<HrmpChannels as HandleHrmpChannelClosing>::handle(2002, 2002, 2000));
+		assert_eq!(OutboundChannels::<Test>::get()[&para(2001)].state, ChannelState::Closing);
+		assert_eq!(InboundChannels::<Test>::get()[&para(2002)].state, ChannelState::Closing);
+		System::assert_last_event(
+			Event::ChannelClosing { para: para(2002), direction: ChannelDirection::Inbound }.into(),
+		);
+
+		EgressChannels::set(vec![]);
+		IngressChannels::set(vec![]);
+		next_block();
+		assert!(OutboundChannels::<Test>::get().is_empty());
+		assert!(InboundChannels::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn open_requests_can_be_canceled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2002])));
+		assert_ok!(<HrmpChannels as HandleHrmpChannelAccepted>::handle(2002));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2003, 512, 4));
+		take_sent_calls();
+
+		assert_noop!(
+			HrmpChannels::cancel_open_request(
+				RuntimeOrigin::signed(1),
+				para(2001),
+				ChannelDirection::Outbound
+			),
+			BadOrigin
+		);
+		// Accepted requests cannot be canceled anymore.
+		assert_noop!(
+			HrmpChannels::cancel_open_request(
+				RuntimeOrigin::root(),
+				para(2002),
+				ChannelDirection::Outbound
+			),
+			Error::<Test>::NoOpenRequest
+		);
+		assert_noop!(
+			HrmpChannels::cancel_open_request(
+				RuntimeOrigin::root(),
+				para(2003),
+				ChannelDirection::Outbound
+			),
+			Error::<Test>::NoOpenRequest
+		);
+
+		assert_ok!(HrmpChannels::cancel_open_request(
+			RuntimeOrigin::root(),
+			para(2001),
+			ChannelDirection::Outbound
+		));
+		assert_eq!(take_sent_calls(), vec![cancel_open_request_call(2000, 2001)]);
+		assert!(!OutboundChannels::<Test>::get().contains_key(&para(2001)));
+		assert_eq!(HrmpChannels::total_deposit(), SenderDeposit::get());
+		System::assert_last_event(
+			Event::OpenRequestCanceled { para: para(2001), direction: ChannelDirection::Outbound }
+				.into(),
+		);
+
+		// Canceling an inbound request declines it.
+		assert_ok!(HrmpChannels::cancel_open_request(
+			RuntimeOrigin::root(),
+			para(2003),
+			ChannelDirection::Inbound
+		));
+		assert_eq!(take_sent_calls(), vec![cancel_open_request_call(2003, 2000)]);
+		assert!(InboundChannels::<Test>::get().is_empty());
+		System::assert_last_event(
+			Event::OpenRequestCanceled { para: para(2003), direction: ChannelDirection::Inbound }
+				.into(),
+		);
+
+		FailSending::set(true);
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2004, 512, 4));
+		assert_noop!(
+			HrmpChannels::cancel_open_request(
+				RuntimeOrigin::root(),
+				para(2004),
+				ChannelDirection::Inbound
+			),
+			Error::<Test>::SendFailure
+		);
+	});
+}
+
+#[test]
+fn stale_requests_expire() {
+	new_test_ext().execute_with(|| {
+		IngressChannels::set(vec![(para(2005), abridged_channel(4, 512))]);
+		assert_ok!(HrmpChannels::open_channels(RuntimeOrigin::root(), paras(&[2001, 2002])));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2003, 512, 4));
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2004, 512, 4));
+		next_block();
+		take_sent_calls();
+
+		// The recipient accepts one of the requests and this parachain accepts one, later.
+		RelayChainBlockNumber::set(50);
+		assert_ok!(<HrmpChannels as HandleHrmpChannelAccepted>::handle(2002));
+		assert_ok!(HrmpChannels::accept_channel(RuntimeOrigin::root(), para(2004)));
+		take_sent_calls();
+
+		RelayChainBlockNumber::set(RequestTtl::get() - 1);
+		next_block();
+		assert_eq!(OutboundChannels::<Test>::get().len(), 2);
+		assert_eq!(InboundChannels::<Test>::get().len(), 3);
+
+		// The requests which were not accepted expire, the outbound one is canceled on the relay
+		// chain.
+		RelayChainBlockNumber::set(RequestTtl::get());
+		next_block();
+		assert_eq!(take_sent_calls(), vec![cancel_open_request_call(2000, 2001)]);
+		assert_eq!(
+			OutboundChannels::<Test>::get().keys().copied().collect::<Vec<_>>(),
+			vec![para(2002)]
+		);
+		assert_eq!(
+			InboundChannels::<Test>::get().keys().copied().collect::<Vec<_>>(),
+			vec![para(2004), para(2005)]
+		);
+		System::assert_has_event(
+			Event::OpenRequestExpired { para: para(2001), direction: ChannelDirection::Outbound }
+				.into(),
+		);
+		System::assert_has_event(
+			Event::OpenRequestExpired { para: para(2003), direction: ChannelDirection::Inbound }
+				.into(),
+		);
+
+		// The accepted requests for which no channel was opened expire later, they cannot be
+		// canceled on the relay chain.
+		RelayChainBlockNumber::set(50 + RequestTtl::get());
+		next_block();
+		assert!(take_sent_calls().is_empty());
+		assert!(OutboundChannels::<Test>::get().is_empty());
+		assert_eq!(
+			InboundChannels::<Test>::get().keys().copied().collect::<Vec<_>>(),
+			vec![para(2005)]
+		);
+		assert_eq!(HrmpChannels::total_deposit(), RecipientDeposit::get());
+		System::assert_has_event(
+			Event::OpenRequestExpired { para: para(2002), direction: ChannelDirection::Outbound }
+				.into(),
+		);
+		System::assert_has_event(
+			Event::OpenRequestExpired { para: para(2004), direction: ChannelDirection::Inbound }
+				.into(),
+		);
+
+		// A request of a parachain whose request expired is tracked again.
+		assert_ok!(<HrmpChannels as HandleHrmpNewChannelOpenRequest>::handle(2003, 512, 4));
+		assert_eq!(InboundChannels::<Test>::get()[&para(2003)].since, 50 + RequestTtl::get());
+	});
+}
@@ -0,0 +1,283 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `cumulus_pallet_hrmp_channels`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// polkadot-omni-bencher
+// v1
+// benchmark
+// pallet
+// --pallet
+// cumulus_pallet_hrmp_channels
+// --extrinsic
+// 
+// --template
+// substrate/.maintain/frame-weight-template.hbs
+// --output
+// cumulus/pallets/hrmp-channels/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `cumulus_pallet_hrmp_channels`.
+pub trait WeightInfo {
+	fn open_channels(n: u32, ) -> Weight;
+	fn accept_channel() -> Weight;
+	fn close_channel() -> Weight;
+	fn set_inbound_policy() -> Weight;
+	fn cancel_open_request() -> Weight;
+	fn sync_channels() -> Weight;
+}
+
+/// Weights for `cumulus_pallet_hrmp_channels` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:0)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 30]`.
+	fn open_channels(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1026`
+		//  Estimated: `2511`
+		// Minimum execution time: 17_548_000 picoseconds.
+		Weight::from_parts(19_956_771, 2511)
+			// Standard Error: 115_532
+			.saturating_add(Weight::from_parts(5_397_941, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:0)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn accept_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2045`
+		//  Estimated: `3530`
+		// Minimum execution time: 37_442_000 picoseconds.
+		Weight::from_parts(43_560_000, 3530)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn close_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1028`
+		//  Estimated: `2513`
+		// Minimum execution time: 23_559_000 picoseconds.
+		Weight::from_parts(25_517_000, 2513)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::Policy` (r:0 w:1)
+	/// Proof: `HrmpChannels::Policy` (`max_values`: Some(1), `max_size`: Some(122), added: 617, mode: `MaxEncodedLen`)
+	fn set_inbound_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_171_000 picoseconds.
+		Weight::from_parts(3_477_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_open_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1028`
+		//  Estimated: `2513`
+		// Minimum execution time: 16_155_000 picoseconds.
+		Weight::from_parts(17_716_000, 2513)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	fn sync_channels() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2111`
+		//  Estimated: `3596`
+		// Minimum execution time: 150_168_000 picoseconds.
+		Weight::from_parts(154_316_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:0)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 30]`.
+	fn open_channels(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1026`
+		//  Estimated: `2511`
+		// Minimum execution time: 17_548_000 picoseconds.
+		Weight::from_parts(19_956_771, 2511)
+			// Standard Error: 115_532
+			.saturating_add(Weight::from_parts(5_397_941, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:0)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn accept_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2045`
+		//  Estimated: `3530`
+		// Minimum execution time: 37_442_000 picoseconds.
+		Weight::from_parts(43_560_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn close_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1028`
+		//  Estimated: `2513`
+		// Minimum execution time: 23_559_000 picoseconds.
+		Weight::from_parts(25_517_000, 2513)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpChannels::Policy` (r:0 w:1)
+	/// Proof: `HrmpChannels::Policy` (`max_values`: Some(1), `max_size`: Some(122), added: 617, mode: `MaxEncodedLen`)
+	fn set_inbound_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_171_000 picoseconds.
+		Weight::from_parts(3_477_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_open_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1028`
+		//  Estimated: `2513`
+		// Minimum execution time: 16_155_000 picoseconds.
+		Weight::from_parts(17_716_000, 2513)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpChannels::OutboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::OutboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpChannels::InboundChannels` (r:1 w:1)
+	/// Proof: `HrmpChannels::InboundChannels` (`max_values`: Some(1), `max_size`: Some(991), added: 1486, mode: `MaxEncodedLen`)
+	fn sync_channels() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2111`
+		//  Estimated: `3596`
+		// Minimum execution time: 150_168_000 picoseconds.
+		Weight::from_parts(154_316_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -30,9 +30,9 @@
 use codec::{Decode, Encode};
 use cumulus_primitives_core::{
 	relay_chain, AbridgedHostConfiguration, ChannelInfo, ChannelStatus, CollationInfo,
-	GetChannelInfo, InboundDownwardMessage, InboundHrmpMessage, ListChannelInfos, MessageSendError,
-	OutboundHrmpMessage, ParaId, PersistedValidationData, UpwardMessage, UpwardMessageSender,
-	XcmpMessageHandler, XcmpMessageSource,
+	GetChannelInfo, InboundDownwardMessage, InboundHrmpMessage, ListChannelInfos, ListHrmpChannels,
+	MessageSendError, OutboundHrmpMessage, ParaId, PersistedValidationData, UpwardMessage,
+	UpwardMessageSender, XcmpMessageHandler, XcmpMessageSource,
 };
 use cumulus_primitives_parachain_inherent::{MessageQueueChain, ParachainInherentData};
 use frame_support::{
@@ -1020,6 +1020,20 @@ impl<T: Config> ListChannelInfos for Pallet<T> {
 	}
 }
 
+impl<T: Config> ListHrmpChannels for Pallet<T> {
+	fn ingress_channels() -> Vec<(ParaId, cumulus_primitives_core::AbridgedHrmpChannel)> {
+		RelevantMessagingState::<T>::get()
+			.map(|state| state.ingress_channels)
+			.unwrap_or_default()
+	}
+
+	fn egress_channels() -> Vec<(ParaId, cumulus_primitives_core::AbridgedHrmpChannel)> {
+		RelevantMessagingState::<T>::get()
+			.map(|state| state.egress_channels)
+			.unwrap_or_default()
+	}
+}
+
 impl<T: Config> GetChannelInfo for Pallet<T> {
 	fn get_channel_status(id: ParaId) -> ChannelStatus {
 		// Note, that we are using `relevant_messaging_state` which may be from the previous
@@ -16,11 +16,11 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-31, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -55,11 +55,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 13_714_000 picoseconds.
-		Weight::from_parts(14_440_231, 0)
+		// Minimum execution time: 11_082_000 picoseconds.
+		Weight::from_parts(11_851_712, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 5
-			.saturating_add(Weight::from_parts(598, 0).saturating_mul(z.into()))
+			// Standard Error: 30
+			.saturating_add(Weight::from_parts(481, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -67,15 +67,15 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `262 + s * (2 ±0)`
+		//  Measured:  `295 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 44_768_000 picoseconds.
-		Weight::from_parts(33_662_218, 0)
+		// Minimum execution time: 29_552_000 picoseconds.
+		Weight::from_parts(29_391_622, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_633
-			.saturating_add(Weight::from_parts(128_927, 0).saturating_mul(s.into()))
-			// Standard Error: 16
-			.saturating_add(Weight::from_parts(1_543, 0).saturating_mul(z.into()))
+			// Standard Error: 12_074
+			.saturating_add(Weight::from_parts(262_274, 0).saturating_mul(s.into()))
+			// Standard Error: 118
+			.saturating_add(Weight::from_parts(211, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -85,15 +85,15 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `282`
+		//  Measured:  `315`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_745_000 picoseconds.
-		Weight::from_parts(20_559_891, 0)
+		// Minimum execution time: 20_679_000 picoseconds.
+		Weight::from_parts(18_094_490, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 914
-			.saturating_add(Weight::from_parts(103_601, 0).saturating_mul(s.into()))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(1_504, 0).saturating_mul(z.into()))
+			// Standard Error: 6_320
+			.saturating_add(Weight::from_parts(76_866, 0).saturating_mul(s.into()))
+			// Standard Error: 61
+			.saturating_add(Weight::from_parts(1_401, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -105,15 +105,15 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `385 + s * (33 ±0)`
+		//  Measured:  `418 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 51_506_000 picoseconds.
-		Weight::from_parts(36_510_777, 0)
+		// Minimum execution time: 31_487_000 picoseconds.
+		Weight::from_parts(25_162_056, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 2_183
-			.saturating_add(Weight::from_parts(183_764, 0).saturating_mul(s.into()))
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(1_653, 0).saturating_mul(z.into()))
+			// Standard Error: 8_707
+			.saturating_add(Weight::from_parts(294_895, 0).saturating_mul(s.into()))
+			// Standard Error: 85
+			.saturating_add(Weight::from_parts(630, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -122,13 +122,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `263 + s * (2 ±0)`
+		//  Measured:  `296 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 31_072_000 picoseconds.
-		Weight::from_parts(32_408_621, 0)
+		// Minimum execution time: 15_426_000 picoseconds.
+		Weight::from_parts(8_763_330, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 913
-			.saturating_add(Weight::from_parts(121_410, 0).saturating_mul(s.into()))
+			// Standard Error: 10_129
+			.saturating_add(Weight::from_parts(493_740, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -137,13 +137,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `282`
+		//  Measured:  `315`
 		//  Estimated: `6811`
-		// Minimum execution time: 18_301_000 picoseconds.
-		Weight::from_parts(18_223_547, 0)
+		// Minimum execution time: 11_213_000 picoseconds.
+		Weight::from_parts(17_519_173, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 747
-			.saturating_add(Weight::from_parts(114_584, 0).saturating_mul(s.into()))
+			// Standard Error: 9_423
+			.saturating_add(Weight::from_parts(77_296, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -152,14 +152,192 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `454 + s * (1 ±0)`
+		//  Measured:  `487 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 32_107_000 picoseconds.
-		Weight::from_parts(33_674_827, 0)
+		// Minimum execution time: 16_960_000 picoseconds.
+		Weight::from_parts(15_288_411, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_220
-			.saturating_add(Weight::from_parts(122_011, 0).saturating_mul(s.into()))
+			// Standard Error: 9_761
+			.saturating_add(Weight::from_parts(261_483, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `1489`
+		// Minimum execution time: 14_495_000 picoseconds.
+		Weight::from_parts(20_841_294, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 8_454
+			.saturating_add(Weight::from_parts(75_655, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `465 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 21_437_000 picoseconds.
+		Weight::from_parts(29_166_788, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 10_914
+			.saturating_add(Weight::from_parts(116_399, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `440 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 29_752_000 picoseconds.
+		Weight::from_parts(22_351_704, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 4_270
+			.saturating_add(Weight::from_parts(112_802, 0).saturating_mul(s.into()))
+			// Standard Error: 41
+			.saturating_add(Weight::from_parts(1_136, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `572 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 22_905_000 picoseconds.
+		Weight::from_parts(17_492_890, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 1_999
+			.saturating_add(Weight::from_parts(100_213, 0).saturating_mul(s.into()))
+			// Standard Error: 19
+			.saturating_add(Weight::from_parts(1_000, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `676 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 35_895_000 picoseconds.
+		Weight::from_parts(34_061_887, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 10_248
+			.saturating_add(Weight::from_parts(157_245, 0).saturating_mul(s.into()))
+			// Standard Error: 100
+			.saturating_add(Weight::from_parts(1_089, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 18_454_000 picoseconds.
+		Weight::from_parts(18_527_856, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_016
+			.saturating_add(Weight::from_parts(162_100, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `572 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_276_000 picoseconds.
+		Weight::from_parts(19_584_792, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 4_722
+			.saturating_add(Weight::from_parts(55_024, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `743 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 22_861_000 picoseconds.
+		Weight::from_parts(22_382_739, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_966
+			.saturating_add(Weight::from_parts(195_373, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 15_329_000 picoseconds.
+		Weight::from_parts(20_745_766, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 10_790
+			.saturating_add(Weight::from_parts(153_293, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
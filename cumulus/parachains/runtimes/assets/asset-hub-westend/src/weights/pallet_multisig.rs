@@ -15,11 +15,11 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-31, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-westend-dev")`, DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-westend-dev")`, DB CACHE: 1024
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -54,11 +54,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 14_098_000 picoseconds.
-		Weight::from_parts(14_915_657, 0)
+		// Minimum execution time: 11_836_000 picoseconds.
+		Weight::from_parts(16_798_018, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 6
-			.saturating_add(Weight::from_parts(454, 0).saturating_mul(z.into()))
+			// Standard Error: 59
+			.saturating_add(Weight::from_parts(287, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -68,13 +68,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `262 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 44_573_000 picoseconds.
-		Weight::from_parts(32_633_219, 0)
+		// Minimum execution time: 28_917_000 picoseconds.
+		Weight::from_parts(25_735_965, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_256
-			.saturating_add(Weight::from_parts(131_767, 0).saturating_mul(s.into()))
-			// Standard Error: 12
-			.saturating_add(Weight::from_parts(1_512, 0).saturating_mul(z.into()))
+			// Standard Error: 7_327
+			.saturating_add(Weight::from_parts(165_922, 0).saturating_mul(s.into()))
+			// Standard Error: 71
+			.saturating_add(Weight::from_parts(1_071, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -86,13 +86,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_035_000 picoseconds.
-		Weight::from_parts(20_179_371, 0)
+		// Minimum execution time: 21_936_000 picoseconds.
+		Weight::from_parts(10_623_142, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 827
-			.saturating_add(Weight::from_parts(110_520, 0).saturating_mul(s.into()))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(1_419, 0).saturating_mul(z.into()))
+			// Standard Error: 7_270
+			.saturating_add(Weight::from_parts(102_432, 0).saturating_mul(s.into()))
+			// Standard Error: 71
+			.saturating_add(Weight::from_parts(2_139, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -106,13 +106,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `385 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 50_444_000 picoseconds.
-		Weight::from_parts(36_060_265, 0)
+		// Minimum execution time: 32_772_000 picoseconds.
+		Weight::from_parts(28_924_900, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_604
-			.saturating_add(Weight::from_parts(187_796, 0).saturating_mul(s.into()))
-			// Standard Error: 15
-			.saturating_add(Weight::from_parts(1_506, 0).saturating_mul(z.into()))
+			// Standard Error: 14_766
+			.saturating_add(Weight::from_parts(391_983, 0).saturating_mul(s.into()))
+			// Standard Error: 144
+			.saturating_add(Weight::from_parts(1_076, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -123,11 +123,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_298_000 picoseconds.
-		Weight::from_parts(31_284_628, 0)
+		// Minimum execution time: 14_940_000 picoseconds.
+		Weight::from_parts(20_051_367, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 924
-			.saturating_add(Weight::from_parts(132_724, 0).saturating_mul(s.into()))
+			// Standard Error: 10_927
+			.saturating_add(Weight::from_parts(197_374, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -138,11 +138,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 17_486_000 picoseconds.
-		Weight::from_parts(18_518_530, 0)
+		// Minimum execution time: 10_581_000 picoseconds.
+		Weight::from_parts(12_764_303, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_274
-			.saturating_add(Weight::from_parts(103_767, 0).saturating_mul(s.into()))
+			// Standard Error: 5_546
+			.saturating_add(Weight::from_parts(145_693, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -153,12 +153,190 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 31_236_000 picoseconds.
-		Weight::from_parts(32_663_816, 0)
+		// Minimum execution time: 19_398_000 picoseconds.
+		Weight::from_parts(25_538_099, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_445
-			.saturating_add(Weight::from_parts(131_060, 0).saturating_mul(s.into()))
+			// Standard Error: 3_752
+			.saturating_add(Weight::from_parts(137_939, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `1489`
+		// Minimum execution time: 13_763_000 picoseconds.
+		Weight::from_parts(16_319_562, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 9_932
+			.saturating_add(Weight::from_parts(207_317, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `432 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 22_794_000 picoseconds.
+		Weight::from_parts(25_968_362, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 3_657
+			.saturating_add(Weight::from_parts(115_413, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `407 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 29_035_000 picoseconds.
+		Weight::from_parts(18_806_572, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 8_597
+			.saturating_add(Weight::from_parts(151_824, 0).saturating_mul(s.into()))
+			// Standard Error: 84
+			.saturating_add(Weight::from_parts(1_616, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 23_142_000 picoseconds.
+		Weight::from_parts(11_604_260, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 5_377
+			.saturating_add(Weight::from_parts(159_448, 0).saturating_mul(s.into()))
+			// Standard Error: 52
+			.saturating_add(Weight::from_parts(1_472, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `643 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 35_727_000 picoseconds.
+		Weight::from_parts(31_350_299, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 10_677
+			.saturating_add(Weight::from_parts(211_021, 0).saturating_mul(s.into()))
+			// Standard Error: 104
+			.saturating_add(Weight::from_parts(1_058, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 17_877_000 picoseconds.
+		Weight::from_parts(15_940_315, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 8_131
+			.saturating_add(Weight::from_parts(258_758, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_532_000 picoseconds.
+		Weight::from_parts(16_963_464, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 3_036
+			.saturating_add(Weight::from_parts(86_900, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 23_424_000 picoseconds.
+		Weight::from_parts(28_199_793, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 12_416
+			.saturating_add(Weight::from_parts(328_685, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 16_049_000 picoseconds.
+		Weight::from_parts(16_729_776, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 5_367
+			.saturating_add(Weight::from_parts(149_211, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -16,11 +16,11 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-31, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("bridge-hub-rococo-dev")`, DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("bridge-hub-rococo-dev")`, DB CACHE: 1024
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -55,27 +55,25 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 13_958_000 picoseconds.
-		Weight::from_parts(14_501_711, 0)
+		// Minimum execution time: 10_884_000 picoseconds.
+		Weight::from_parts(14_426_240, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(626, 0).saturating_mul(z.into()))
+			// Standard Error: 47
+			.saturating_add(Weight::from_parts(215, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_create(s: u32, z: u32, ) -> Weight {
+	fn as_multi_create(s: u32, _z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `263 + s * (2 ±0)`
+		//  Measured:  `262 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 44_067_000 picoseconds.
-		Weight::from_parts(33_432_998, 0)
+		// Minimum execution time: 28_537_000 picoseconds.
+		Weight::from_parts(33_864_891, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_250
-			.saturating_add(Weight::from_parts(131_851, 0).saturating_mul(s.into()))
-			// Standard Error: 12
-			.saturating_add(Weight::from_parts(1_459, 0).saturating_mul(z.into()))
+			// Standard Error: 8_478
+			.saturating_add(Weight::from_parts(129_336, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -87,13 +85,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_373_000 picoseconds.
-		Weight::from_parts(19_409_201, 0)
+		// Minimum execution time: 21_605_000 picoseconds.
+		Weight::from_parts(8_565_859, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 725
-			.saturating_add(Weight::from_parts(110_824, 0).saturating_mul(s.into()))
-			// Standard Error: 7
-			.saturating_add(Weight::from_parts(1_502, 0).saturating_mul(z.into()))
+			// Standard Error: 4_996
+			.saturating_add(Weight::from_parts(198_024, 0).saturating_mul(s.into()))
+			// Standard Error: 48
+			.saturating_add(Weight::from_parts(1_183, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -105,15 +103,15 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `388 + s * (33 ±0)`
+		//  Measured:  `385 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 49_724_000 picoseconds.
-		Weight::from_parts(34_153_321, 0)
+		// Minimum execution time: 30_168_000 picoseconds.
+		Weight::from_parts(13_338_531, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_376
-			.saturating_add(Weight::from_parts(174_634, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_753, 0).saturating_mul(z.into()))
+			// Standard Error: 8_412
+			.saturating_add(Weight::from_parts(301_756, 0).saturating_mul(s.into()))
+			// Standard Error: 82
+			.saturating_add(Weight::from_parts(1_796, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -124,11 +122,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 31_081_000 picoseconds.
-		Weight::from_parts(31_552_702, 0)
+		// Minimum execution time: 13_645_000 picoseconds.
+		Weight::from_parts(17_205_330, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_066
-			.saturating_add(Weight::from_parts(135_081, 0).saturating_mul(s.into()))
+			// Standard Error: 5_126
+			.saturating_add(Weight::from_parts(145_880, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -139,11 +137,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 17_807_000 picoseconds.
-		Weight::from_parts(18_241_044, 0)
+		// Minimum execution time: 9_063_000 picoseconds.
+		Weight::from_parts(8_952_303, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 768
-			.saturating_add(Weight::from_parts(112_957, 0).saturating_mul(s.into()))
+			// Standard Error: 3_268
+			.saturating_add(Weight::from_parts(173_498, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -154,12 +152,188 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 32_421_000 picoseconds.
-		Weight::from_parts(32_554_061, 0)
+		// Minimum execution time: 18_011_000 picoseconds.
+		Weight::from_parts(18_745_747, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_157
-			.saturating_add(Weight::from_parts(141_221, 0).saturating_mul(s.into()))
+			// Standard Error: 8_389
+			.saturating_add(Weight::from_parts(192_396, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `1489`
+		// Minimum execution time: 12_224_000 picoseconds.
+		Weight::from_parts(7_774_216, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 7_646
+			.saturating_add(Weight::from_parts(357_354, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `432 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 27_464_000 picoseconds.
+		Weight::from_parts(47_063_938, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 13_368
+			.saturating_add(Weight::from_parts(31_422, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `407 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 28_267_000 picoseconds.
+		Weight::from_parts(41_966_117, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 9_595
+			.saturating_add(Weight::from_parts(41_413, 0).saturating_mul(s.into()))
+			// Standard Error: 94
+			.saturating_add(Weight::from_parts(223, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_697_000 picoseconds.
+		Weight::from_parts(17_919_658, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 9_519
+			.saturating_add(Weight::from_parts(175_793, 0).saturating_mul(s.into()))
+			// Standard Error: 93
+			.saturating_add(Weight::from_parts(1_380, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `643 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 38_197_000 picoseconds.
+		Weight::from_parts(11_241_653, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 15_413
+			.saturating_add(Weight::from_parts(441_822, 0).saturating_mul(s.into()))
+			// Standard Error: 151
+			.saturating_add(Weight::from_parts(2_499, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 23_165_000 picoseconds.
+		Weight::from_parts(41_834_556, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_113_000 picoseconds.
+		Weight::from_parts(23_651_876, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 7_637
+			.saturating_add(Weight::from_parts(62_260, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_077_000 picoseconds.
+		Weight::from_parts(28_336_848, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 8_802
+			.saturating_add(Weight::from_parts(139_402, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 15_990_000 picoseconds.
+		Weight::from_parts(18_941_784, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 5_087
+			.saturating_add(Weight::from_parts(88_623, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
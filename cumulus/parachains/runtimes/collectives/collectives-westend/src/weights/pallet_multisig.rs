@@ -15,11 +15,11 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-31, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -54,27 +54,25 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 13_288_000 picoseconds.
-		Weight::from_parts(14_235_741, 0)
+		// Minimum execution time: 11_303_000 picoseconds.
+		Weight::from_parts(14_748_732, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 5
-			.saturating_add(Weight::from_parts(500, 0).saturating_mul(z.into()))
+			// Standard Error: 33
+			.saturating_add(Weight::from_parts(176, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_create(s: u32, z: u32, ) -> Weight {
+	fn as_multi_create(s: u32, _z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `328 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 44_865_000 picoseconds.
-		Weight::from_parts(33_468_056, 0)
+		// Minimum execution time: 29_236_000 picoseconds.
+		Weight::from_parts(32_149_550, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_513
-			.saturating_add(Weight::from_parts(130_544, 0).saturating_mul(s.into()))
-			// Standard Error: 14
-			.saturating_add(Weight::from_parts(1_422, 0).saturating_mul(z.into()))
+			// Standard Error: 7_608
+			.saturating_add(Weight::from_parts(195_671, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -86,13 +84,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `348`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_284_000 picoseconds.
-		Weight::from_parts(18_708_967, 0)
+		// Minimum execution time: 21_513_000 picoseconds.
+		Weight::from_parts(22_083_000, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 916
-			.saturating_add(Weight::from_parts(119_202, 0).saturating_mul(s.into()))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(1_447, 0).saturating_mul(z.into()))
+			// Standard Error: 4_642
+			.saturating_add(Weight::from_parts(111_745, 0).saturating_mul(s.into()))
+			// Standard Error: 46
+			.saturating_add(Weight::from_parts(755, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -106,13 +104,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 49_462_000 picoseconds.
-		Weight::from_parts(34_470_286, 0)
+		// Minimum execution time: 31_037_000 picoseconds.
+		Weight::from_parts(23_941_956, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_738
-			.saturating_add(Weight::from_parts(178_227, 0).saturating_mul(s.into()))
-			// Standard Error: 17
-			.saturating_add(Weight::from_parts(1_644, 0).saturating_mul(z.into()))
+			// Standard Error: 8_946
+			.saturating_add(Weight::from_parts(198_618, 0).saturating_mul(s.into()))
+			// Standard Error: 87
+			.saturating_add(Weight::from_parts(1_579, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -123,11 +121,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `329 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_749_000 picoseconds.
-		Weight::from_parts(31_841_438, 0)
+		// Minimum execution time: 14_733_000 picoseconds.
+		Weight::from_parts(14_673_740, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_033
-			.saturating_add(Weight::from_parts(123_126, 0).saturating_mul(s.into()))
+			// Standard Error: 2_169
+			.saturating_add(Weight::from_parts(193_230, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -138,11 +136,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `348`
 		//  Estimated: `6811`
-		// Minimum execution time: 17_436_000 picoseconds.
-		Weight::from_parts(18_036_002, 0)
+		// Minimum execution time: 10_115_000 picoseconds.
+		Weight::from_parts(11_089_371, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 829
-			.saturating_add(Weight::from_parts(109_450, 0).saturating_mul(s.into()))
+			// Standard Error: 2_640
+			.saturating_add(Weight::from_parts(147_607, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -153,12 +151,190 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `520 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 31_532_000 picoseconds.
-		Weight::from_parts(32_818_015, 0)
+		// Minimum execution time: 17_954_000 picoseconds.
+		Weight::from_parts(18_489_520, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 977
-			.saturating_add(Weight::from_parts(123_121, 0).saturating_mul(s.into()))
+			// Standard Error: 10_302
+			.saturating_add(Weight::from_parts(293_079, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `1489`
+		// Minimum execution time: 14_345_000 picoseconds.
+		Weight::from_parts(16_936_008, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 6_282
+			.saturating_add(Weight::from_parts(247_557, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 28_697_000 picoseconds.
+		Weight::from_parts(38_403_375, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 7_774
+			.saturating_add(Weight::from_parts(72_299, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `473 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 31_053_000 picoseconds.
+		Weight::from_parts(22_574_643, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 11_234
+			.saturating_add(Weight::from_parts(169_325, 0).saturating_mul(s.into()))
+			// Standard Error: 110
+			.saturating_add(Weight::from_parts(2_028, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `605 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_220_000 picoseconds.
+		Weight::from_parts(26_907_313, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_398
+			.saturating_add(Weight::from_parts(133_437, 0).saturating_mul(s.into()))
+			// Standard Error: 62
+			.saturating_add(Weight::from_parts(818, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `709 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 35_375_000 picoseconds.
+		Weight::from_parts(24_487_276, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 12_652
+			.saturating_add(Weight::from_parts(247_790, 0).saturating_mul(s.into()))
+			// Standard Error: 123
+			.saturating_add(Weight::from_parts(1_906, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 20_533_000 picoseconds.
+		Weight::from_parts(17_810_524, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 12_488
+			.saturating_add(Weight::from_parts(269_875, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `605 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 19_931_000 picoseconds.
+		Weight::from_parts(30_947_526, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 8_508
+			.saturating_add(Weight::from_parts(22_453, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `776 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_627_000 picoseconds.
+		Weight::from_parts(27_765_640, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 3_099
+			.saturating_add(Weight::from_parts(73_133, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `386 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 15_279_000 picoseconds.
+		Weight::from_parts(15_783_063, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 2_004
+			.saturating_add(Weight::from_parts(127_373, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
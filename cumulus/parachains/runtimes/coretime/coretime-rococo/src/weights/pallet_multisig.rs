@@ -16,10 +16,10 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-01-12, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-j8vvqcjr-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("coretime-rococo-dev")`, DB CACHE: 1024
 
 // Executed Command:
@@ -55,11 +55,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 12_905_000 picoseconds.
-		Weight::from_parts(13_544_225, 0)
+		// Minimum execution time: 10_273_000 picoseconds.
+		Weight::from_parts(11_517_579, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 2
-			.saturating_add(Weight::from_parts(596, 0).saturating_mul(z.into()))
+			// Standard Error: 38
+			.saturating_add(Weight::from_parts(292, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -69,13 +69,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `262 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 38_729_000 picoseconds.
-		Weight::from_parts(27_942_442, 0)
+		// Minimum execution time: 24_715_000 picoseconds.
+		Weight::from_parts(9_211_168, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 648
-			.saturating_add(Weight::from_parts(120_340, 0).saturating_mul(s.into()))
-			// Standard Error: 6
-			.saturating_add(Weight::from_parts(1_578, 0).saturating_mul(z.into()))
+			// Standard Error: 8_433
+			.saturating_add(Weight::from_parts(241_371, 0).saturating_mul(s.into()))
+			// Standard Error: 82
+			.saturating_add(Weight::from_parts(1_397, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -87,13 +87,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_936_000 picoseconds.
-		Weight::from_parts(16_537_903, 0)
+		// Minimum execution time: 20_461_000 picoseconds.
+		Weight::from_parts(16_274_634, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 412
-			.saturating_add(Weight::from_parts(105_835, 0).saturating_mul(s.into()))
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(1_534, 0).saturating_mul(z.into()))
+			// Standard Error: 4_847
+			.saturating_add(Weight::from_parts(84_427, 0).saturating_mul(s.into()))
+			// Standard Error: 47
+			.saturating_add(Weight::from_parts(1_006, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -107,13 +107,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `385 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 45_291_000 picoseconds.
-		Weight::from_parts(31_294_385, 0)
+		// Minimum execution time: 28_333_000 picoseconds.
+		Weight::from_parts(11_937_379, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 816
-			.saturating_add(Weight::from_parts(152_838, 0).saturating_mul(s.into()))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(1_638, 0).saturating_mul(z.into()))
+			// Standard Error: 6_746
+			.saturating_add(Weight::from_parts(310_360, 0).saturating_mul(s.into()))
+			// Standard Error: 66
+			.saturating_add(Weight::from_parts(1_529, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -124,11 +124,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_585_000 picoseconds.
-		Weight::from_parts(27_424_168, 0)
+		// Minimum execution time: 13_702_000 picoseconds.
+		Weight::from_parts(16_830_604, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 732
-			.saturating_add(Weight::from_parts(123_460, 0).saturating_mul(s.into()))
+			// Standard Error: 6_224
+			.saturating_add(Weight::from_parts(155_149, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -139,11 +139,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 15_228_000 picoseconds.
-		Weight::from_parts(15_568_631, 0)
+		// Minimum execution time: 9_223_000 picoseconds.
+		Weight::from_parts(8_376_317, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 441
-			.saturating_add(Weight::from_parts(107_463, 0).saturating_mul(s.into()))
+			// Standard Error: 5_162
+			.saturating_add(Weight::from_parts(166_434, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -154,12 +154,190 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 28_033_000 picoseconds.
-		Weight::from_parts(29_228_827, 0)
+		// Minimum execution time: 17_748_000 picoseconds.
+		Weight::from_parts(16_808_250, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 748
-			.saturating_add(Weight::from_parts(117_495, 0).saturating_mul(s.into()))
+			// Standard Error: 4_735
+			.saturating_add(Weight::from_parts(171_660, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `1489`
+		// Minimum execution time: 11_993_000 picoseconds.
+		Weight::from_parts(11_869_731, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 9_224
+			.saturating_add(Weight::from_parts(231_350, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `432 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 25_717_000 picoseconds.
+		Weight::from_parts(31_181_612, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 16_347
+			.saturating_add(Weight::from_parts(254_863, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `407 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 30_968_000 picoseconds.
+		Weight::from_parts(31_703_667, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 8_370
+			.saturating_add(Weight::from_parts(42_027, 0).saturating_mul(s.into()))
+			// Standard Error: 82
+			.saturating_add(Weight::from_parts(1_165, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 23_610_000 picoseconds.
+		Weight::from_parts(10_277_080, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_549
+			.saturating_add(Weight::from_parts(171_421, 0).saturating_mul(s.into()))
+			// Standard Error: 64
+			.saturating_add(Weight::from_parts(1_817, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `643 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 36_603_000 picoseconds.
+		Weight::from_parts(25_934_331, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 7_051
+			.saturating_add(Weight::from_parts(246_814, 0).saturating_mul(s.into()))
+			// Standard Error: 69
+			.saturating_add(Weight::from_parts(1_501, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 20_588_000 picoseconds.
+		Weight::from_parts(22_061_966, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_756
+			.saturating_add(Weight::from_parts(150_253, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 16_202_000 picoseconds.
+		Weight::from_parts(16_456_911, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 3_273
+			.saturating_add(Weight::from_parts(123_814, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_280_000 picoseconds.
+		Weight::from_parts(25_257_041, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 10_926
+			.saturating_add(Weight::from_parts(226_879, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 18_049_000 picoseconds.
+		Weight::from_parts(26_671_812, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 13_022
+			.saturating_add(Weight::from_parts(105_412, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
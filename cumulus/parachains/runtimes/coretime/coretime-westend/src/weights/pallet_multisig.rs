@@ -17,9 +17,9 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-02-08, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("coretime-westend-dev")`, DB CACHE: 1024
 
 // Executed Command:
@@ -55,11 +55,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 11_938_000 picoseconds.
-		Weight::from_parts(13_021_007, 0)
+		// Minimum execution time: 12_565_000 picoseconds.
+		Weight::from_parts(21_244_124, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(482, 0).saturating_mul(z.into()))
+			// Standard Error: 73
+			.saturating_add(Weight::from_parts(3, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -69,13 +69,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `262 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 37_643_000 picoseconds.
-		Weight::from_parts(27_088_068, 0)
+		// Minimum execution time: 31_232_000 picoseconds.
+		Weight::from_parts(11_484_712, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 828
-			.saturating_add(Weight::from_parts(123_693, 0).saturating_mul(s.into()))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(1_456, 0).saturating_mul(z.into()))
+			// Standard Error: 8_315
+			.saturating_add(Weight::from_parts(407_362, 0).saturating_mul(s.into()))
+			// Standard Error: 81
+			.saturating_add(Weight::from_parts(2_127, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -87,13 +87,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_825_000 picoseconds.
-		Weight::from_parts(15_698_835, 0)
+		// Minimum execution time: 24_511_000 picoseconds.
+		Weight::from_parts(5_790_659, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 568
-			.saturating_add(Weight::from_parts(111_928, 0).saturating_mul(s.into()))
-			// Standard Error: 5
-			.saturating_add(Weight::from_parts(1_421, 0).saturating_mul(z.into()))
+			// Standard Error: 6_025
+			.saturating_add(Weight::from_parts(307_199, 0).saturating_mul(s.into()))
+			// Standard Error: 59
+			.saturating_add(Weight::from_parts(2_547, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -107,13 +107,13 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `385 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 43_587_000 picoseconds.
-		Weight::from_parts(29_740_539, 0)
+		// Minimum execution time: 42_766_000 picoseconds.
+		Weight::from_parts(42_598_587, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 771
-			.saturating_add(Weight::from_parts(154_861, 0).saturating_mul(s.into()))
-			// Standard Error: 7
-			.saturating_add(Weight::from_parts(1_557, 0).saturating_mul(z.into()))
+			// Standard Error: 13_946
+			.saturating_add(Weight::from_parts(144_981, 0).saturating_mul(s.into()))
+			// Standard Error: 136
+			.saturating_add(Weight::from_parts(2_276, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -124,11 +124,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 24_966_000 picoseconds.
-		Weight::from_parts(25_879_458, 0)
+		// Minimum execution time: 15_155_000 picoseconds.
+		Weight::from_parts(20_295_861, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 777
-			.saturating_add(Weight::from_parts(122_823, 0).saturating_mul(s.into()))
+			// Standard Error: 8_926
+			.saturating_add(Weight::from_parts(146_311, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -139,11 +139,11 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `6811`
-		// Minimum execution time: 14_450_000 picoseconds.
-		Weight::from_parts(14_607_858, 0)
+		// Minimum execution time: 10_148_000 picoseconds.
+		Weight::from_parts(10_699_444, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 471
-			.saturating_add(Weight::from_parts(107_007, 0).saturating_mul(s.into()))
+			// Standard Error: 2_219
+			.saturating_add(Weight::from_parts(125_275, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -154,12 +154,190 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_861_000 picoseconds.
-		Weight::from_parts(27_846_825, 0)
+		// Minimum execution time: 17_564_000 picoseconds.
+		Weight::from_parts(18_691_914, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 714
-			.saturating_add(Weight::from_parts(116_914, 0).saturating_mul(s.into()))
+			// Standard Error: 1_690
+			.saturating_add(Weight::from_parts(146_907, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `1489`
+		// Minimum execution time: 13_222_000 picoseconds.
+		Weight::from_parts(17_133_174, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 3_940
+			.saturating_add(Weight::from_parts(117_572, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `432 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 23_548_000 picoseconds.
+		Weight::from_parts(24_503_187, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 2_441
+			.saturating_add(Weight::from_parts(149_199, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `407 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 29_921_000 picoseconds.
+		Weight::from_parts(13_882_974, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 5_230
+			.saturating_add(Weight::from_parts(177_060, 0).saturating_mul(s.into()))
+			// Standard Error: 51
+			.saturating_add(Weight::from_parts(1_647, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_105_000 picoseconds.
+		Weight::from_parts(15_710_993, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 2_741
+			.saturating_add(Weight::from_parts(113_068, 0).saturating_mul(s.into()))
+			// Standard Error: 26
+			.saturating_add(Weight::from_parts(1_160, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `643 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 36_956_000 picoseconds.
+		Weight::from_parts(25_301_734, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 6_962
+			.saturating_add(Weight::from_parts(230_060, 0).saturating_mul(s.into()))
+			// Standard Error: 68
+			.saturating_add(Weight::from_parts(1_241, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 18_473_000 picoseconds.
+		Weight::from_parts(18_954_001, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 5_028
+			.saturating_add(Weight::from_parts(180_501, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_033_000 picoseconds.
+		Weight::from_parts(18_075_949, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 3_513
+			.saturating_add(Weight::from_parts(72_314, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_300_000 picoseconds.
+		Weight::from_parts(25_652_950, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 2_722
+			.saturating_add(Weight::from_parts(110_848, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 14_358_000 picoseconds.
+		Weight::from_parts(16_800_168, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 4_521
+			.saturating_add(Weight::from_parts(122_038, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -13,7 +13,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Taken from Rococo Relay Chain. Needs to rerun.
+//! Autogenerated weights for `pallet_identity`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("people-rococo-dev")`, DB CACHE: 1024
+
+// Executed Command:
+// target/production/polkadot-parachain
+// benchmark
+// pallet
+// --steps=50
+// --repeat=20
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --json-file=/builds/parity/mirrors/polkadot-sdk/.git/.artifacts/bench.json
+// --pallet=pallet_identity
+// --chain=people-rococo-dev
+// --header=./cumulus/file_header.txt
+// --output=./cumulus/parachains/runtimes/people/people-rococo/src/weights/
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -26,289 +47,298 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_identity`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
-	/// Storage: Identity Registrars (r:1 w:1)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn add_registrar(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `32 + r * (57 ±0)`
 		//  Estimated: `2626`
-		// Minimum execution time: 12_290_000 picoseconds.
-		Weight::from_parts(12_664_362, 0)
+		// Minimum execution time: 7_889_000 picoseconds.
+		Weight::from_parts(9_759_592, 0)
 			.saturating_add(Weight::from_parts(0, 2626))
-			// Standard Error: 1_347
-			.saturating_add(Weight::from_parts(88_179, 0).saturating_mul(r.into()))
+			// Standard Error: 8_097
+			.saturating_add(Weight::from_parts(325_490, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
-	fn set_identity(r: u32, ) -> Weight {
+	fn set_identity(_r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `442 + r * (5 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 31_373_000 picoseconds.
-		Weight::from_parts(30_435_545, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 2_307
-			.saturating_add(Weight::from_parts(92_753, 0).saturating_mul(r.into()))
+		//  Estimated: `4303`
+		// Minimum execution time: 11_282_000 picoseconds.
+		Weight::from_parts(16_672_739, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:100 w:100)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 100]`.
 	fn set_subs_new(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `101`
-		//  Estimated: `11003 + s * (2589 ±0)`
-		// Minimum execution time: 9_251_000 picoseconds.
-		Weight::from_parts(22_039_210, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 40_779
-			.saturating_add(Weight::from_parts(2_898_525, 0).saturating_mul(s.into()))
+		//  Estimated: `6723 + s * (2589 ±0)`
+		// Minimum execution time: 5_247_000 picoseconds.
+		Weight::from_parts(5_538_000, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 22_726
+			.saturating_add(Weight::from_parts(2_510_135, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:0 w:100)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:0 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 100]`.
 	fn set_subs_old(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `194 + p * (32 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 9_329_000 picoseconds.
-		Weight::from_parts(24_055_061, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 3_428
-			.saturating_add(Weight::from_parts(1_130_604, 0).saturating_mul(p.into()))
+		//  Estimated: `6723`
+		// Minimum execution time: 8_168_000 picoseconds.
+		Weight::from_parts(18_624_077, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 4_528
+			.saturating_add(Weight::from_parts(1_434_872, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
 	}
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:0 w:100)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:0 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
-	fn clear_identity(_r: u32, s: u32, ) -> Weight {
+	fn clear_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `469 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 53_365_000 picoseconds.
-		Weight::from_parts(35_391_422, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 1_353
-			.saturating_add(Weight::from_parts(1_074_019, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+		//  Measured:  `594 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `6723 + r * (2636 ±0)`
+		// Minimum execution time: 121_094_000 picoseconds.
+		Weight::from_parts(132_747_000, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 117_597
+			.saturating_add(Weight::from_parts(5_176_921, 0).saturating_mul(r.into()))
+			// Standard Error: 23_505
+			.saturating_add(Weight::from_parts(418_275, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
 	}
-	/// Storage: Identity Registrars (r:1 w:0)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn request_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `367 + r * (57 ±0) + x * (66 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 32_509_000 picoseconds.
-		Weight::from_parts(31_745_585, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 2_214
-			.saturating_add(Weight::from_parts(83_822, 0).saturating_mul(r.into()))
+		//  Measured:  `432 + r * (57 ±0)`
+		//  Estimated: `4303`
+		// Minimum execution time: 18_790_000 picoseconds.
+		Weight::from_parts(29_325_826, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 9_979
+			.saturating_add(Weight::from_parts(278_369, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
-	fn cancel_request(r: u32, ) -> Weight {
+	fn cancel_request(_r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `398 + x * (66 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 29_609_000 picoseconds.
-		Weight::from_parts(28_572_602, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 2_528
-			.saturating_add(Weight::from_parts(85_593, 0).saturating_mul(r.into()))
+		//  Measured:  `463`
+		//  Estimated: `4303`
+		// Minimum execution time: 16_012_000 picoseconds.
+		Weight::from_parts(22_212_364, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity Registrars (r:1 w:1)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn set_fee(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `89 + r * (57 ±0)`
 		//  Estimated: `2626`
-		// Minimum execution time: 7_793_000 picoseconds.
-		Weight::from_parts(8_173_888, 0)
+		// Minimum execution time: 4_637_000 picoseconds.
+		Weight::from_parts(4_361_367, 0)
 			.saturating_add(Weight::from_parts(0, 2626))
-			// Standard Error: 1_569
-			.saturating_add(Weight::from_parts(72_367, 0).saturating_mul(r.into()))
+			// Standard Error: 14_215
+			.saturating_add(Weight::from_parts(312_642, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity Registrars (r:1 w:1)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
-	fn set_account_id(r: u32, ) -> Weight {
+	fn set_account_id(_r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `89 + r * (57 ±0)`
 		//  Estimated: `2626`
-		// Minimum execution time: 7_708_000 picoseconds.
-		Weight::from_parts(8_091_149, 0)
+		// Minimum execution time: 5_535_000 picoseconds.
+		Weight::from_parts(9_657_574, 0)
 			.saturating_add(Weight::from_parts(0, 2626))
-			// Standard Error: 869
-			.saturating_add(Weight::from_parts(87_993, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity Registrars (r:1 w:1)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
-	fn set_fields(r: u32, ) -> Weight {
+	fn set_fields(_r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `89 + r * (57 ±0)`
 		//  Estimated: `2626`
-		// Minimum execution time: 7_601_000 picoseconds.
-		Weight::from_parts(8_038_414, 0)
+		// Minimum execution time: 5_408_000 picoseconds.
+		Weight::from_parts(8_373_323, 0)
 			.saturating_add(Weight::from_parts(0, 2626))
-			// Standard Error: 1_041
-			.saturating_add(Weight::from_parts(82_588, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity Registrars (r:1 w:0)
-	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn provide_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `445 + r * (57 ±0) + x * (66 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 23_114_000 picoseconds.
-		Weight::from_parts(22_076_548, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 2_881
-			.saturating_add(Weight::from_parts(109_812, 0).saturating_mul(r.into()))
+		//  Measured:  `510 + r * (57 ±0)`
+		//  Estimated: `4303`
+		// Minimum execution time: 11_806_000 picoseconds.
+		Weight::from_parts(12_965_024, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 3_567
+			.saturating_add(Weight::from_parts(198_368, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity IdentityOf (r:1 w:1)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:0 w:100)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::ShouldRecordXcm` (r:1 w:0)
+	/// Proof: `PolkadotXcm::ShouldRecordXcm` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Identity::SuperOf` (r:0 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `676 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 70_007_000 picoseconds.
-		Weight::from_parts(50_186_495, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 6_533
-			.saturating_add(Weight::from_parts(15_486, 0).saturating_mul(r.into()))
-			// Standard Error: 1_275
-			.saturating_add(Weight::from_parts(1_085_117, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `797 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `6723 + r * (2636 ±0) + s * (32 ±0)`
+		// Minimum execution time: 125_480_000 picoseconds.
+		Weight::from_parts(22_217_244, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 131_907
+			.saturating_add(Weight::from_parts(6_063_194, 0).saturating_mul(r.into()))
+			// Standard Error: 25_737
+			.saturating_add(Weight::from_parts(1_241_588, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(s.into()))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:1 w:1)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:1 w:1)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 99]`.
 	fn add_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `475 + s * (36 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 28_453_000 picoseconds.
-		Weight::from_parts(33_165_934, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 1_217
-			.saturating_add(Weight::from_parts(65_401, 0).saturating_mul(s.into()))
+		//  Estimated: `6723`
+		// Minimum execution time: 14_271_000 picoseconds.
+		Weight::from_parts(19_408_799, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 1_807
+			.saturating_add(Weight::from_parts(141_669, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:1 w:1)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:1 w:1)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 100]`.
 	fn rename_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `591 + s * (3 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 12_846_000 picoseconds.
-		Weight::from_parts(14_710_284, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 496
-			.saturating_add(Weight::from_parts(19_539, 0).saturating_mul(s.into()))
+		//  Estimated: `4303`
+		// Minimum execution time: 9_554_000 picoseconds.
+		Weight::from_parts(11_624_009, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 1_301
+			.saturating_add(Weight::from_parts(49_322, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:1 w:1)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:1 w:1)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 100]`.
 	fn remove_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `638 + s * (35 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 32_183_000 picoseconds.
-		Weight::from_parts(35_296_731, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
-			// Standard Error: 854
-			.saturating_add(Weight::from_parts(52_028, 0).saturating_mul(s.into()))
+		//  Estimated: `6723`
+		// Minimum execution time: 18_382_000 picoseconds.
+		Weight::from_parts(23_697_878, 0)
+			.saturating_add(Weight::from_parts(0, 6723))
+			// Standard Error: 6_761
+			.saturating_add(Weight::from_parts(138_050, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: Identity SuperOf (r:1 w:1)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:0)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Identity::SuperOf` (r:1 w:1)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 99]`.
 	fn quit_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `704 + s * (37 ±0)`
+		//  Measured:  `616 + s * (37 ±0)`
 		//  Estimated: `6723`
-		// Minimum execution time: 24_941_000 picoseconds.
-		Weight::from_parts(27_433_059, 0)
+		// Minimum execution time: 13_957_000 picoseconds.
+		Weight::from_parts(16_342_266, 0)
 			.saturating_add(Weight::from_parts(0, 6723))
-			// Standard Error: 856
-			.saturating_add(Weight::from_parts(57_463, 0).saturating_mul(s.into()))
+			// Standard Error: 2_672
+			.saturating_add(Weight::from_parts(108_297, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -318,92 +348,193 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 13_873_000 picoseconds.
-		Weight::from_parts(13_873_000, 0)
+		// Minimum execution time: 2_689_000 picoseconds.
+		Weight::from_parts(2_871_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `Identity::UsernameAuthorities` (r:0 w:1)
+	/// Storage: `Identity::UsernameAuthorities` (r:1 w:1)
 	/// Proof: `Identity::UsernameAuthorities` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn remove_username_authority() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 10_653_000 picoseconds.
-		Weight::from_parts(10_653_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
+		//  Measured:  `80`
+		//  Estimated: `3517`
+		// Minimum execution time: 5_639_000 picoseconds.
+		Weight::from_parts(6_037_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Identity::UsernameAuthorities` (r:1 w:1)
 	/// Proof: `Identity::UsernameAuthorities` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::AccountOfUsername` (r:1 w:1)
-	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingUsernames` (r:1 w:0)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	fn set_username_for() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `80`
-		//  Estimated: `11037`
-		// Minimum execution time: 75_928_000 picoseconds.
-		Weight::from_parts(75_928_000, 0)
-			.saturating_add(Weight::from_parts(0, 11037))
-			.saturating_add(T::DbWeight::get().reads(3))
+		//  Estimated: `4303`
+		// Minimum execution time: 68_605_000 picoseconds.
+		Weight::from_parts(73_064_000, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::PendingUsernames` (r:1 w:1)
-	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::AccountOfUsername` (r:0 w:1)
-	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	fn accept_username() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `106`
-		//  Estimated: `11037`
-		// Minimum execution time: 38_157_000 picoseconds.
-		Weight::from_parts(38_157_000, 0)
-			.saturating_add(Weight::from_parts(0, 11037))
+		//  Measured:  `115`
+		//  Estimated: `4303`
+		// Minimum execution time: 11_083_000 picoseconds.
+		Weight::from_parts(11_965_000, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::PendingUsernames` (r:1 w:1)
-	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	fn remove_expired_approval() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `106`
-		//  Estimated: `3542`
-		// Minimum execution time: 46_821_000 picoseconds.
-		Weight::from_parts(46_821_000, 0)
-			.saturating_add(Weight::from_parts(0, 3542))
+		//  Measured:  `115`
+		//  Estimated: `3550`
+		// Minimum execution time: 12_840_000 picoseconds.
+		Weight::from_parts(28_636_000, 0)
+			.saturating_add(Weight::from_parts(0, 3550))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Identity::AccountOfUsername` (r:1 w:0)
-	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	fn set_primary_username() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `247`
-		//  Estimated: `11037`
-		// Minimum execution time: 22_515_000 picoseconds.
-		Weight::from_parts(22_515_000, 0)
-			.saturating_add(Weight::from_parts(0, 11037))
+		//  Measured:  `258`
+		//  Estimated: `4303`
+		// Minimum execution time: 10_186_000 picoseconds.
+		Weight::from_parts(10_868_000, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Identity::AccountOfUsername` (r:1 w:1)
-	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
 	fn remove_dangling_username() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `126`
-		//  Estimated: `11037`
-		// Minimum execution time: 15_997_000 picoseconds.
-		Weight::from_parts(15_997_000, 0)
-			.saturating_add(Weight::from_parts(0, 11037))
+		//  Measured:  `98`
+		//  Estimated: `4303`
+		// Minimum execution time: 7_053_000 picoseconds.
+		Weight::from_parts(7_521_000, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:0 w:1)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_attestation_key(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + r * (57 ±0)`
+		//  Estimated: `2626`
+		// Minimum execution time: 6_178_000 picoseconds.
+		Weight::from_parts(6_771_426, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			// Standard Error: 3_590
+			.saturating_add(Weight::from_parts(152_973, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:1 w:0)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:0)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn submit_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `722 + r * (60 ±0)`
+		//  Estimated: `4303`
+		// Minimum execution time: 78_359_000 picoseconds.
+		Weight::from_parts(89_368_167, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 127_945
+			.saturating_add(Weight::from_parts(1_347_263, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:0 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `792 + r * (60 ±0)`
+		//  Estimated: `4303`
+		// Minimum execution time: 25_943_000 picoseconds.
+		Weight::from_parts(31_518_656, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 31_191
+			.saturating_add(Weight::from_parts(401_840, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn remove_expired_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `744 + r * (3 ±0)`
+		//  Estimated: `4303`
+		// Minimum execution time: 24_770_000 picoseconds.
+		Weight::from_parts(36_815_943, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			// Standard Error: 84_171
+			.saturating_add(Weight::from_parts(249_751, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_expired_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3513`
+		// Minimum execution time: 8_176_000 picoseconds.
+		Weight::from_parts(9_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,112 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Setup code for [`super::command`] which would otherwise bloat that module.
+//!
+//! Should only be used for benchmarking as it may break in other contexts.
+
+use codec::{Codec, Encode};
+use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
+use cumulus_primitives_core::{relay_chain, ParaId};
+use sc_cli::Result;
+use sc_client_api::{Backend, StorageProvider, UsageProvider};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::{AuraApi, Slot, AURA_ENGINE_ID};
+use sp_inherents::{InherentData, InherentDataProvider};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT},
+	DigestItem, SaturatedConversion,
+};
+
+/// Slot duration of the mocked relay chain, in milliseconds.
+const RELAY_CHAIN_SLOT_DURATION_MILLIS: u64 = 6000;
+
+/// Generates inherent data and digest items for the `benchmark pov` command.
+///
+/// Parachain runtimes require the `set_validation_data` inherent, so the relay chain is mocked
+/// for the block on top of the best block. The block is authored at the start of the relay chain
+/// slot of the current time. If the runtime uses Aura, a pre-runtime digest for the matching
+/// parachain slot is returned as well, as the consensus hook checks that both slots agree.
+///
+/// Note: Should only be used for benchmarking.
+pub fn inherent_benchmark_data<Block, BE, Client, AuraId>(
+	client: &Client,
+	para_id: ParaId,
+) -> Result<(InherentData, Vec<DigestItem>)>
+where
+	Block: BlockT,
+	BE: Backend<Block>,
+	Client: ProvideRuntimeApi<Block>
+		+ UsageProvider<Block>
+		+ HeaderBackend<Block>
+		+ StorageProvider<Block, BE>,
+	Client::Api: AuraApi<Block, AuraId>,
+	AuraId: Codec,
+{
+	let best_hash = client.usage_info().chain.best_hash;
+	let parent_header = client
+		.header(best_hash)?
+		.ok_or_else(|| format!("Header of {} not found", best_hash))?;
+
+	let relay_slot = *sp_timestamp::Timestamp::current() / RELAY_CHAIN_SLOT_DURATION_MILLIS;
+	let timestamp = sp_timestamp::Timestamp::new(relay_slot * RELAY_CHAIN_SLOT_DURATION_MILLIS);
+
+	let mut digest_items = Vec::new();
+	let runtime_api = client.runtime_api();
+	let has_aura = runtime_api
+		.has_api::<dyn AuraApi<Block, AuraId>>(best_hash)
+		.map_err(|e| format!("Failed to check for the Aura runtime API: {}", e))?;
+	if has_aura {
+		let slot_duration = runtime_api
+			.slot_duration(best_hash)
+			.map_err(|e| format!("Failed to fetch the Aura slot duration: {}", e))?;
+		let slot = Slot::from_timestamp(timestamp, slot_duration);
+		digest_items.push(DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode()));
+	}
+
+	let validation_data = MockValidationDataInherentDataProvider {
+		current_para_block: (*parent_header.number()).saturated_into::<u32>() + 1,
+		para_id,
+		current_para_block_head: Some(parent_header.encode().into()),
+		// The relay parent number must be higher than the one of the best block. A real relay
+		// chain has far fewer blocks than slots, so the slot is used as block number as well.
+		relay_offset: relay_slot.saturated_into(),
+		relay_blocks_per_para_block: 0,
+		para_blocks_per_relay_epoch: 0,
+		relay_randomness_config: (),
+		xcm_config: MockXcmConfig::new(client, best_hash, Default::default()),
+		raw_downward_messages: Vec::new(),
+		raw_horizontal_messages: Vec::new(),
+		// Overwrite the slot of the mocked relay chain, which would be derived from the relay
+		// parent number otherwise.
+		additional_key_values: Some(vec![(
+			relay_chain::well_known_keys::CURRENT_SLOT.to_vec(),
+			Slot::from(relay_slot).encode(),
+		)]),
+	};
+
+	let mut inherent_data = InherentData::new();
+	futures::executor::block_on(async {
+		sp_timestamp::InherentDataProvider::new(timestamp)
+			.provide_inherent_data(&mut inherent_data)
+			.await?;
+		validation_data.provide_inherent_data(&mut inherent_data).await
+	})
+	.map_err(|e| format!("creating inherent data: {:?}", e))?;
+
+	Ok((inherent_data, digest_items))
+}
@@ -25,7 +25,7 @@ use crate::{
 };
 use cumulus_client_service::storage_proof_size::HostFunctions as ReclaimHostFunctions;
 use cumulus_primitives_core::ParaId;
-use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
+use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
 use parachains_common::{AssetHubPolkadotAuraId, AuraId};
 use sc_cli::{
@@ -590,6 +590,27 @@ pub fn run() -> Result<()> {
 				}),
 				BenchmarkCmd::Machine(cmd) =>
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())),
+				BenchmarkCmd::Pov(cmd) => runner.sync_run(|config| {
+					let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+						.map(|e| e.para_id)
+						.ok_or("Could not find parachain extension in chain-spec.")?;
+
+					construct_partials!(config, |partials| {
+						let (inherent_data, digest_items) =
+							crate::benchmarking::inherent_benchmark_data(
+								&*partials.client,
+								ParaId::from(para_id),
+							)?;
+						// The node is generic over its runtimes and can not build extrinsics for
+						// them, so only the block with the inherents is measured.
+						cmd.run(
+							partials.client,
+							inherent_data,
+							digest_items,
+							&ExtrinsicFactory(Vec::new()),
+						)
+					})
+				}),
 				// NOTE: this allows the Client to leniently implement
 				// new benchmark commands without requiring a companion MR.
 				#[allow(unreachable_patterns)]
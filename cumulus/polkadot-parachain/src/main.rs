@@ -19,6 +19,7 @@
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
 
+mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
@@ -0,0 +1,58 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::{Command, Output};
+use tempfile::tempdir;
+
+/// The chains that this command is tested with.
+static CHAINS: [&str; 2] = ["asset-hub-westend-dev", "shell"];
+
+/// The `benchmark pov` command builds a block with the parachain inherents, with and without Aura.
+#[test]
+fn benchmark_pov_works() {
+	for chain in CHAINS {
+		let output = benchmark_pov(chain, None);
+		assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+		let log = String::from_utf8_lossy(&output.stderr);
+		assert!(log.contains("inherents only"));
+		assert!(!log.contains("OVER LIMIT!"));
+	}
+}
+
+/// Blocks with a PoV larger than the `--max-pov-size` are marked.
+#[test]
+fn benchmark_pov_marks_blocks_over_the_limit() {
+	let output = benchmark_pov("asset-hub-westend-dev", Some(100));
+	assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+	let log = String::from_utf8_lossy(&output.stderr);
+	assert!(log.contains("OVER LIMIT!"));
+	assert!(log.contains("The compressed PoV of these blocks exceeds the max_pov_size"));
+}
+
+/// Invoke the `benchmark pov` sub-command for the given chain.
+fn benchmark_pov(chain: &str, max_pov_size: Option<u32>) -> Output {
+	let tmp_dir = tempdir().expect("could not create a temp dir");
+
+	let mut cmd = Command::new(cargo_bin("polkadot-parachain"));
+	cmd.args(["benchmark", "pov", "--chain", chain, "-d"]).arg(tmp_dir.path());
+	if let Some(max_pov_size) = max_pov_size {
+		cmd.args(["--max-pov-size", &max_pov_size.to_string()]);
+	}
+	cmd.output().unwrap()
+}
@@ -143,6 +143,14 @@ pub trait ListChannelInfos {
 	fn outgoing_channels() -> Vec<ParaId>;
 }
 
+/// List the open HRMP channels of this parachain, as last reported by the relay chain.
+pub trait ListHrmpChannels {
+	/// The open channels from other parachains to this one, sorted by sender.
+	fn ingress_channels() -> Vec<(ParaId, AbridgedHrmpChannel)>;
+	/// The open channels from this parachain to others, sorted by recipient.
+	fn egress_channels() -> Vec<(ParaId, AbridgedHrmpChannel)>;
+}
+
 /// Something that should be called when sending an upward message.
 pub trait UpwardMessageSender {
 	/// Send the given UMP message; return the expected number of blocks before the message will
@@ -280,6 +280,7 @@ async fn build_relay_chain_interface(
 				relay_chain_config,
 				task_manager,
 				rpc_target_urls,
+				collator_options.collation_snapshot_dir,
 			)
 			.await
 			.map(|r| r.0),
@@ -700,7 +701,10 @@ impl TestNodeBuilder {
 			false,
 		);
 
-		let collator_options = CollatorOptions { relay_chain_mode: self.relay_chain_mode };
+		let collator_options = CollatorOptions {
+			relay_chain_mode: self.relay_chain_mode,
+			collation_snapshot_dir: None,
+		};
 
 		relay_chain_config.network.node_name =
 			format!("{} (relay chain)", relay_chain_config.network.node_name);
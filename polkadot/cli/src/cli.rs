@@ -56,6 +56,13 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Inspect the disputes recorded by a running validator.
+	#[command(subcommand)]
+	Disputes(crate::disputes::DisputesCmd),
+
+	/// Replay a collation captured by a collator through the checks validators perform.
+	DryRunCollation(crate::dry_run::DryRunCollationCmd),
 }
 
 #[allow(missing_docs)]
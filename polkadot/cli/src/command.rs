@@ -256,6 +256,7 @@ where
 				execute_workers_max_num: cli.run.execute_workers_max_num,
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
+				collation_snapshot_dir: None,
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -489,6 +490,8 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::Disputes(cmd)) => cmd.run(),
+		Some(Subcommand::DryRunCollation(cmd)) => cmd.run(),
 	}?;
 
 	#[cfg(feature = "pyroscope")]
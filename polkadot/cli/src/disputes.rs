@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inspection of the disputes recorded by a running validator, through its RPC.
+
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use polkadot_primitives::{Hash, SessionIndex};
+use polkadot_rpc::disputes::{DisputeInfo, DisputesApiClient};
+
+use crate::error::Error;
+
+/// Sub-commands inspecting the disputes recorded by a running validator.
+///
+/// The validator needs to expose its unsafe RPC methods, e.g. via `--rpc-methods unsafe` on a
+/// local interface.
+#[derive(Debug, clap::Subcommand)]
+pub enum DisputesCmd {
+	/// List the recent disputes, most recent session first.
+	List(ListDisputesCmd),
+
+	/// Export all votes recorded for a disputed candidate, as JSON.
+	Votes(DisputeVotesCmd),
+}
+
+/// Parameters to reach the validator.
+#[derive(Debug, Clone, clap::Args)]
+pub struct RpcParams {
+	/// Websocket URL of the RPC server of the validator.
+	#[arg(long, default_value = "ws://127.0.0.1:9944")]
+	pub url: String,
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, clap::Parser)]
+pub struct ListDisputesCmd {
+	#[clap(flatten)]
+	pub rpc: RpcParams,
+
+	/// List at most this many disputes.
+	#[arg(long)]
+	pub limit: Option<u32>,
+
+	/// Print the disputes as JSON rather than as a table.
+	#[arg(long)]
+	pub json: bool,
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, clap::Parser)]
+pub struct DisputeVotesCmd {
+	#[clap(flatten)]
+	pub rpc: RpcParams,
+
+	/// The session the candidate appeared in.
+	#[arg(long)]
+	pub session: SessionIndex,
+
+	/// The hash of the disputed candidate.
+	#[arg(long)]
+	pub candidate_hash: Hash,
+}
+
+impl DisputesCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		sc_cli::build_runtime()
+			.map_err(|e| Error::Other(format!("Failed to start the async runtime: {e}")))?
+			.block_on(async {
+				match self {
+					DisputesCmd::List(cmd) => cmd.run().await,
+					DisputesCmd::Votes(cmd) => cmd.run().await,
+				}
+			})
+	}
+}
+
+impl RpcParams {
+	async fn client(&self) -> Result<WsClient, Error> {
+		Ok(WsClientBuilder::default().build(&self.url).await?)
+	}
+}
+
+impl ListDisputesCmd {
+	async fn run(&self) -> Result<(), Error> {
+		let disputes = self.rpc.client().await?.recent_disputes(self.limit).await?;
+
+		if self.json {
+			println!("{}", to_json(&disputes)?);
+		} else {
+			print_table(&disputes);
+		}
+		Ok(())
+	}
+}
+
+impl DisputeVotesCmd {
+	async fn run(&self) -> Result<(), Error> {
+		let votes = self
+			.rpc
+			.client()
+			.await?
+			.dispute_votes(self.session, self.candidate_hash)
+			.await?
+			.ok_or_else(|| Error::Other("No votes recorded for this candidate".into()))?;
+
+		println!("{}", to_json(&votes)?);
+		Ok(())
+	}
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, Error> {
+	serde_json::to_string_pretty(value).map_err(|e| Error::Other(e.to_string()))
+}
+
+fn print_table(disputes: &[DisputeInfo]) {
+	println!(
+		"{:>8}  {:<66}  {:>6}  {:<16}  {:>5}  {:>7}  {:<7}  {:<9}",
+		"SESSION", "CANDIDATE", "PARA", "OUTCOME", "VALID", "INVALID", "LOCAL", "VALIDATOR",
+	);
+	for dispute in disputes {
+		let local_vote = match dispute.local_vote {
+			Some(true) => "valid",
+			Some(false) => "invalid",
+			None => "-",
+		};
+		println!(
+			"{:>8}  {:<66}  {:>6}  {:<16}  {:>5}  {:>7}  {:<7}  {:<9}",
+			dispute.session,
+			format!("{:?}", dispute.candidate_hash),
+			dispute.para_id,
+			format!("{:?}", dispute.outcome),
+			dispute.valid_votes,
+			dispute.invalid_votes,
+			local_vote,
+			if dispute.local_validator { "yes" } else { "no" },
+		);
+	}
+}
@@ -0,0 +1,149 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-running the validation of a collation captured by a collator.
+
+use std::path::PathBuf;
+
+use codec::Decode;
+use futures::future::{self, Either};
+use polkadot_node_core_candidate_validation::dry_run::{
+	dry_run_collation, CheckOutcome, DryRunReport,
+};
+use polkadot_node_primitives::CollationSnapshot;
+
+use crate::error::Error;
+
+/// Replay a collation captured by a collator with `--collation-snapshot-dir` through the checks
+/// validators perform before backing it, and report the outcome of each of them.
+///
+/// The collation is executed with the PVF workers of this binary, using the executor parameters
+/// of the session it was built in.
+#[derive(Debug, clap::Parser)]
+pub struct DryRunCollationCmd {
+	/// The collation snapshot file.
+	#[arg(value_name = "FILE")]
+	pub snapshot: PathBuf,
+
+	/// Path to the directory where the PVF worker binaries reside.
+	///
+	/// If not specified, the directory of this binary and `/usr/lib/polkadot` are searched.
+	#[arg(long, value_name = "PATH")]
+	pub workers_path: Option<PathBuf>,
+
+	/// Directory to store the compiled PVF artifacts in.
+	///
+	/// Defaults to a temporary directory, which is removed afterwards.
+	#[arg(long, value_name = "PATH")]
+	pub artifacts_path: Option<PathBuf>,
+}
+
+impl DryRunCollationCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		let encoded = std::fs::read(&self.snapshot).map_err(|e| {
+			Error::Other(format!("Failed to read {}: {e}", self.snapshot.display()))
+		})?;
+		let snapshot = CollationSnapshot::decode(&mut &encoded[..])
+			.map_err(|e| Error::Other(format!("Invalid collation snapshot: {e}")))?;
+
+		println!(
+			"Candidate {:?} of para {} at relay-parent {:?}",
+			snapshot.candidate.hash(),
+			snapshot.candidate.descriptor.para_id,
+			snapshot.candidate.descriptor.relay_parent,
+		);
+
+		let artifacts_path = self.artifacts_path.clone().unwrap_or_else(|| {
+			std::env::temp_dir().join(format!("polkadot-dry-run-{}", std::process::id()))
+		});
+		let report = sc_cli::build_runtime()
+			.map_err(|e| Error::Other(format!("Failed to start the async runtime: {e}")))?
+			.block_on(self.dry_run(snapshot, artifacts_path.clone()));
+		if self.artifacts_path.is_none() {
+			let _ = std::fs::remove_dir_all(&artifacts_path);
+		}
+		let report = report?;
+
+		print_report(&report);
+		match report.failures().count() {
+			0 => Ok(()),
+			failures => Err(Error::Other(format!("The candidate failed {failures} check(s)"))),
+		}
+	}
+
+	#[cfg(feature = "full-node")]
+	async fn dry_run(
+		&self,
+		snapshot: CollationSnapshot,
+		artifacts_path: PathBuf,
+	) -> Result<DryRunReport, Error> {
+		use crate::cli::NODE_VERSION;
+
+		let (prep_worker_path, exec_worker_path) =
+			polkadot_service::workers::determine_workers_paths(
+				self.workers_path.clone(),
+				None,
+				Some(NODE_VERSION.to_string()),
+			)?;
+
+		let (validation_host, task) = polkadot_node_core_pvf::start(
+			polkadot_node_core_pvf::Config::new(
+				artifacts_path,
+				Some(NODE_VERSION.to_string()),
+				false,
+				prep_worker_path,
+				exec_worker_path,
+				1,
+				1,
+				1,
+			),
+			Default::default(),
+		)
+		.await
+		.map_err(|e| Error::Other(format!("Failed to start the PVF host: {e}")))?;
+
+		match future::select(Box::pin(dry_run_collation(validation_host, snapshot)), Box::pin(task))
+			.await
+		{
+			Either::Left((report, _)) => Ok(report),
+			Either::Right(((), _)) => Err(Error::Other("The PVF host stopped unexpectedly".into())),
+		}
+	}
+
+	#[cfg(not(feature = "full-node"))]
+	async fn dry_run(
+		&self,
+		_snapshot: CollationSnapshot,
+		_artifacts_path: PathBuf,
+	) -> Result<DryRunReport, Error> {
+		Err(Error::FeatureNotEnabled { feature: "full-node" })
+	}
+}
+
+fn print_report(report: &DryRunReport) {
+	for check in &report.checks {
+		let (outcome, reason) = match &check.outcome {
+			CheckOutcome::Passed => ("passed", None),
+			CheckOutcome::Failed(reason) => ("FAILED", Some(reason)),
+			CheckOutcome::Skipped(reason) => ("skipped", Some(reason)),
+		};
+		match reason {
+			Some(reason) => println!("{:<32}  {:<7}  {}", check.name, outcome, reason),
+			None => println!("{:<32}  {:<7}", check.name, outcome),
+		}
+	}
+}
@@ -48,6 +48,9 @@ pub enum Error {
 	#[error(transparent)]
 	Storage(#[from] sc_storage_monitor::Error),
 
+	#[error("Disputes RPC request failed: {0}")]
+	DisputesRpc(#[from] jsonrpsee::core::ClientError),
+
 	#[error("Other: {0}")]
 	Other(String),
 
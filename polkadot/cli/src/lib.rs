@@ -23,6 +23,10 @@ mod cli;
 #[cfg(feature = "cli")]
 mod command;
 #[cfg(feature = "cli")]
+mod disputes;
+#[cfg(feature = "cli")]
+mod dry_run;
+#[cfg(feature = "cli")]
 mod error;
 
 #[cfg(feature = "service")]
@@ -39,5 +43,11 @@ pub use cli::*;
 #[cfg(feature = "cli")]
 pub use command::*;
 
+#[cfg(feature = "cli")]
+pub use disputes::*;
+
+#[cfg(feature = "cli")]
+pub use dry_run::*;
+
 #[cfg(feature = "cli")]
 pub use sc_cli::{Error, Result};
@@ -0,0 +1,92 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use polkadot_erasure_coding::{CodingBackend, NovelPolyBackend, SimdBackend};
+use std::time::Duration;
+
+fn available_backends() -> Vec<Box<dyn CodingBackend>> {
+	let mut backends: Vec<Box<dyn CodingBackend>> = vec![Box::new(NovelPolyBackend)];
+	match SimdBackend::detect() {
+		Some(simd) => backends.push(Box::new(simd)),
+		None => println!("SIMD backend is not supported by this CPU, only benchmarking novelpoly"),
+	}
+	backends
+}
+
+fn compare_backends_5mb_pov(c: &mut Criterion) {
+	const N_VALIDATORS: [usize; 4] = [200, 500, 1000, 2000];
+
+	const KB: usize = 1024;
+	const MB: usize = 1024 * KB;
+
+	let pov = (0..5 * MB).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+	let backends = available_backends();
+
+	let mut group = c.benchmark_group("obtain_chunks");
+	for n_validators in N_VALIDATORS {
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		for backend in &backends {
+			group.bench_with_input(
+				BenchmarkId::new(backend.name(), n_validators),
+				&n_validators,
+				|b, &n| {
+					b.iter(|| backend.obtain_chunks(n, &pov).unwrap());
+				},
+			);
+		}
+	}
+	group.finish();
+
+	let mut group = c.benchmark_group("reconstruct");
+	for n_validators in N_VALIDATORS {
+		let all_chunks = NovelPolyBackend.obtain_chunks(n_validators, &pov).unwrap();
+		let threshold = polkadot_erasure_coding::recovery_threshold(n_validators).unwrap();
+
+		// Use the last chunks, so that no systematic chunk is available.
+		let chunks = all_chunks
+			.into_iter()
+			.enumerate()
+			.map(|(i, c)| (i >= n_validators - threshold).then_some(c))
+			.collect::<Vec<_>>();
+
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		for backend in &backends {
+			group.bench_with_input(
+				BenchmarkId::new(backend.name(), n_validators),
+				&n_validators,
+				|b, &n| {
+					b.iter(|| backend.reconstruct(n, chunks.clone()).unwrap());
+				},
+			);
+		}
+	}
+	group.finish();
+}
+
+fn criterion_config() -> Criterion {
+	Criterion::default()
+		.sample_size(15)
+		.warm_up_time(Duration::from_millis(200))
+		.measurement_time(Duration::from_secs(3))
+}
+
+criterion_group!(
+	name = backends;
+	config = criterion_config();
+	targets = compare_backends_5mb_pov,
+);
+criterion_main!(backends);
@@ -0,0 +1,117 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Implementations of the Reed-Solomon code behind the chunks.
+//!
+//! All backends must produce byte-identical chunks, as the erasure root of a candidate commits to
+//! them and validators recover data from chunks computed by one another.
+
+use std::sync::OnceLock;
+
+use novelpoly::WrappedShard;
+
+use crate::{code_params, Error, SimdBackend};
+
+/// A Reed-Solomon implementation operating on the encoded payload.
+pub trait CodingBackend: Send + Sync {
+	/// A short name for the backend.
+	fn name(&self) -> &'static str;
+
+	/// Obtain erasure-coded chunks of a non-empty payload, one for each validator.
+	fn obtain_chunks(&self, n_validators: usize, payload: &[u8]) -> Result<Vec<Vec<u8>>, Error>;
+
+	/// Reconstruct the payload from a set of chunks, indexed by validator. Missing chunks are
+	/// `None` and all present chunks must be of the same, even, length.
+	///
+	/// The result may be padded with zeros.
+	fn reconstruct(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Option<Vec<u8>>>,
+	) -> Result<Vec<u8>, Error>;
+
+	/// Reconstruct the payload from the systematic chunks, provided in order.
+	///
+	/// The result may be padded with zeros.
+	fn reconstruct_from_systematic(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Vec<u8>>,
+	) -> Result<Vec<u8>, Error>;
+}
+
+/// The reference implementation, backed by `novelpoly`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NovelPolyBackend;
+
+impl CodingBackend for NovelPolyBackend {
+	fn name(&self) -> &'static str {
+		"novelpoly"
+	}
+
+	fn obtain_chunks(&self, n_validators: usize, payload: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+		let params = code_params(n_validators)?;
+
+		if payload.is_empty() {
+			return Err(Error::BadPayload)
+		}
+
+		let shards = params.make_encoder().encode::<WrappedShard>(payload).expect(
+			"Payload non-empty, shard sizes are uniform, and validator numbers checked; qed",
+		);
+
+		Ok(shards.into_iter().map(|w: WrappedShard| w.into_inner()).collect())
+	}
+
+	fn reconstruct(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Option<Vec<u8>>>,
+	) -> Result<Vec<u8>, Error> {
+		let params = code_params(n_validators)?;
+		let received_shards =
+			chunks.into_iter().map(|chunk| chunk.map(WrappedShard::new)).collect();
+
+		Ok(params.make_encoder().reconstruct(received_shards)?)
+	}
+
+	fn reconstruct_from_systematic(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Vec<u8>>,
+	) -> Result<Vec<u8>, Error> {
+		let params = code_params(n_validators)?;
+
+		Ok(params
+			.make_encoder()
+			.reconstruct_from_systematic(chunks.into_iter().map(WrappedShard::new).collect())?)
+	}
+}
+
+/// The backend used by the functions of this crate.
+///
+/// Picked once, based on the features of the CPU we are running on: the SIMD backend if
+/// supported, `novelpoly` otherwise.
+pub fn default_backend() -> &'static dyn CodingBackend {
+	static BACKEND: OnceLock<Box<dyn CodingBackend>> = OnceLock::new();
+
+	BACKEND
+		.get_or_init(|| match SimdBackend::detect() {
+			Some(simd) => Box::new(simd),
+			None => Box::new(NovelPolyBackend),
+		})
+		.as_ref()
+}
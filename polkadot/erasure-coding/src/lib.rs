@@ -23,6 +23,8 @@
 //! Each of n validators stores their piece of data. We assume `n = 3f + k`, `0 < k ≤ 3`.
 //! f is the maximum number of faulty validators in the system.
 //! The data is coded so any f+1 chunks can be used to reconstruct the full data.
+//!
+//! The coding itself is done by a [`CodingBackend`], picked at runtime by [`default_backend`].
 
 use codec::{Decode, Encode};
 use polkadot_node_primitives::{AvailableData, Proof};
@@ -34,7 +36,13 @@ use sp_trie::{
 };
 use thiserror::Error;
 
-use novelpoly::{CodeParams, WrappedShard};
+use novelpoly::CodeParams;
+
+mod backend;
+mod simd;
+
+pub use backend::{default_backend, CodingBackend, NovelPolyBackend};
+pub use simd::SimdBackend;
 
 // we are limited to the field order of GF(2^16), which is 65536
 const MAX_VALIDATORS: usize = novelpoly::f2e16::FIELD_SIZE;
@@ -95,6 +103,7 @@ impl From<novelpoly::Error> for Error {
 			novelpoly::Error::WantedShardCountTooLow(_) => Self::NotEnoughValidators,
 			novelpoly::Error::PayloadSizeIsZero { .. } => Self::BadPayload,
 			novelpoly::Error::InconsistentShardLengths { .. } => Self::NonUniformChunks,
+			novelpoly::Error::EmptyShard => Self::NonUniformChunks,
 			_ => Self::UnknownReconstruction,
 		}
 	}
@@ -166,9 +175,8 @@ pub fn reconstruct_from_systematic<T: Decode>(
 		}
 	}
 
-	let bytes = code_params.make_encoder().reconstruct_from_systematic(
-		chunks.into_iter().take(k).map(|data| WrappedShard::new(data)).collect(),
-	)?;
+	let bytes = default_backend()
+		.reconstruct_from_systematic(n_validators, chunks.into_iter().take(k).collect())?;
 
 	Decode::decode(&mut &bytes[..]).map_err(|err| Error::Decode(err))
 }
@@ -184,19 +192,7 @@ pub fn obtain_chunks_v1(n_validators: usize, data: &AvailableData) -> Result<Vec
 ///
 /// Works only up to 65536 validators, and `n_validators` must be non-zero.
 pub fn obtain_chunks<T: Encode>(n_validators: usize, data: &T) -> Result<Vec<Vec<u8>>, Error> {
-	let params = code_params(n_validators)?;
-	let encoded = data.encode();
-
-	if encoded.is_empty() {
-		return Err(Error::BadPayload)
-	}
-
-	let shards = params
-		.make_encoder()
-		.encode::<WrappedShard>(&encoded[..])
-		.expect("Payload non-empty, shard sizes are uniform, and validator numbers checked; qed");
-
-	Ok(shards.into_iter().map(|w: WrappedShard| w.into_inner()).collect())
+	default_backend().obtain_chunks(n_validators, &data.encode())
 }
 
 /// Reconstruct the v1 available data from a set of chunks.
@@ -224,17 +220,17 @@ pub fn reconstruct<'a, I: 'a, T: Decode>(n_validators: usize, chunks: I) -> Resu
 where
 	I: IntoIterator<Item = (&'a [u8], usize)>,
 {
-	let params = code_params(n_validators)?;
-	let mut received_shards: Vec<Option<WrappedShard>> = vec![None; n_validators];
+	code_params(n_validators)?;
+	let mut received_shards: Vec<Option<Vec<u8>>> = vec![None; n_validators];
 	for (chunk_data, chunk_idx) in chunks.into_iter().take(n_validators) {
 		if chunk_data.len() % 2 != 0 {
 			return Err(Error::UnevenLength)
 		}
 
-		received_shards[chunk_idx] = Some(WrappedShard::new(chunk_data.to_vec()));
+		received_shards[chunk_idx] = Some(chunk_data.to_vec());
 	}
 
-	let payload_bytes = params.make_encoder().reconstruct(received_shards)?;
+	let payload_bytes = default_backend().reconstruct(n_validators, received_shards)?;
 
 	Decode::decode(&mut &payload_bytes[..]).map_err(|_| Error::BadPayload)
 }
@@ -411,6 +407,108 @@ mod tests {
 		QuickCheck::new().quickcheck(property as fn(ArbitraryAvailableData, u16))
 	}
 
+	// The SIMD backend supports all `x86_64` CPUs with SSSE3, the tests using it are ignored on
+	// other architectures.
+	fn backends() -> (NovelPolyBackend, SimdBackend) {
+		let simd = SimdBackend::detect().expect("SIMD backend is not supported by this CPU");
+		(NovelPolyBackend, simd)
+	}
+
+	#[test]
+	#[cfg_attr(not(target_arch = "x86_64"), ignore = "the SIMD backend requires x86_64")]
+	fn simd_chunks_are_identical() {
+		let (novelpoly, simd) = backends();
+
+		for n_validators in [2, 3, 4, 5, 7, 8, 10, 16, 17, 100, 300, 1000, 1023, 1024, 1025] {
+			for payload_len in [1, 2, 3, 33, 64, 1000, 65_537] {
+				let payload =
+					(0..payload_len).map(|i| (i * 7 + n_validators) as u8).collect::<Vec<_>>();
+				assert_eq!(
+					simd.obtain_chunks(n_validators, &payload),
+					novelpoly.obtain_chunks(n_validators, &payload),
+					"n_validators = {n_validators}, payload_len = {payload_len}",
+				);
+			}
+		}
+	}
+
+	#[test]
+	#[cfg_attr(not(target_arch = "x86_64"), ignore = "the SIMD backend requires x86_64")]
+	fn simd_round_trip_is_identical() {
+		fn property(available_data: ArbitraryAvailableData, n_validators: u16, offset: u16) {
+			let (novelpoly, simd) = backends();
+			let n_validators = (n_validators % 2048).max(2) as usize;
+			let payload = available_data.0.encode();
+
+			let chunks = simd.obtain_chunks(n_validators, &payload).unwrap();
+			assert_eq!(chunks, novelpoly.obtain_chunks(n_validators, &payload).unwrap());
+
+			// Take a window of chunks starting anywhere, which usually misses some of the
+			// systematic ones.
+			let threshold = recovery_threshold(n_validators).unwrap();
+			let mut received = vec![None; n_validators];
+			for i in (0..threshold).map(|i| (i + offset as usize) % n_validators) {
+				received[i] = Some(chunks[i].clone());
+			}
+			let reconstructed = simd.reconstruct(n_validators, received.clone()).unwrap();
+			assert_eq!(reconstructed, novelpoly.reconstruct(n_validators, received).unwrap());
+			assert_eq!(AvailableData::decode(&mut &reconstructed[..]).unwrap(), available_data.0);
+
+			let k = systematic_recovery_threshold(n_validators).unwrap();
+			let systematic = chunks.into_iter().take(k).collect::<Vec<_>>();
+			assert_eq!(
+				simd.reconstruct_from_systematic(n_validators, systematic.clone()),
+				novelpoly.reconstruct_from_systematic(n_validators, systematic),
+			);
+		}
+
+		QuickCheck::new()
+			.tests(20)
+			.quickcheck(property as fn(ArbitraryAvailableData, u16, u16))
+	}
+
+	#[test]
+	#[cfg_attr(not(target_arch = "x86_64"), ignore = "the SIMD backend requires x86_64")]
+	fn simd_errors_are_identical() {
+		let (novelpoly, simd) = backends();
+		let backends: [&dyn CodingBackend; 2] = [&novelpoly, &simd];
+		let results = |f: &dyn Fn(&dyn CodingBackend) -> Result<Vec<u8>, Error>| {
+			backends.map(|backend| f(backend))
+		};
+
+		let [a, b] = results(&|backend| backend.obtain_chunks(1, &[1, 2]).map(|_| Vec::new()));
+		assert_eq!(a, b);
+		let [a, b] = results(&|backend| backend.obtain_chunks(10, &[]).map(|_| Vec::new()));
+		assert_eq!(a, b);
+
+		let chunk = vec![0_u8; 4];
+		let [a, b] = results(&|backend| backend.reconstruct(10, vec![Some(chunk.clone()); 3]));
+		assert_eq!(a, Err(Error::NotEnoughChunks));
+		assert_eq!(a, b);
+		let [a, b] = results(&|backend| {
+			let mut chunks = vec![Some(chunk.clone()); 10];
+			chunks[3] = Some(vec![0; 6]);
+			backend.reconstruct(10, chunks)
+		});
+		assert_eq!(a, Err(Error::NonUniformChunks));
+		assert_eq!(a, b);
+		let [a, b] = results(&|backend| backend.reconstruct(10, vec![Some(Vec::new()); 10]));
+		assert_eq!(a, Err(Error::NonUniformChunks));
+		assert_eq!(a, b);
+
+		let [a, b] =
+			results(&|backend| backend.reconstruct_from_systematic(10, vec![chunk.clone()]));
+		assert_eq!(a, Err(Error::NotEnoughChunks));
+		assert_eq!(a, b);
+		let [a, b] = results(&|backend| {
+			let mut chunks = vec![chunk.clone(); 10];
+			chunks[1] = vec![0; 2];
+			backend.reconstruct_from_systematic(10, chunks)
+		});
+		assert_eq!(a, Err(Error::NonUniformChunks));
+		assert_eq!(a, b);
+	}
+
 	#[test]
 	fn reconstruct_does_not_panic_on_low_validator_count() {
 		let reconstructed = reconstruct_v1(1, [].iter().cloned());
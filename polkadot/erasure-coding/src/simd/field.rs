@@ -0,0 +1,203 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tables of `GF(2^16)` in the representation used by `novelpoly`.
+//!
+//! Field elements are kept in the "additive" form, where addition is a plain XOR, and
+//! multiplication goes through the logarithm tables. Everything here has to match `novelpoly`
+//! bit for bit, otherwise the produced chunks would differ.
+
+use std::sync::OnceLock;
+
+pub(crate) const FIELD_BITS: usize = 16;
+pub(crate) const FIELD_SIZE: usize = 1 << FIELD_BITS;
+pub(crate) const ONEMASK: u16 = (FIELD_SIZE - 1) as u16;
+
+/// Tail of the irreducible polynomial generating the field.
+const GENERATOR: u16 = 0x2D;
+
+/// Cantor basis of the field.
+const BASE: [u16; FIELD_BITS] = [
+	1, 44234, 15374, 5694, 50562, 60718, 37196, 16402, 27800, 4312, 27250, 47360, 64952, 64308,
+	65336, 39198,
+];
+
+/// Precomputed tables of the field and the additive FFT over it.
+pub(crate) struct Field {
+	log: Vec<u16>,
+	exp: Vec<u16>,
+	log_walsh: Vec<u16>,
+	/// Skew factors of the additive FFT in logarithm form.
+	skews: Vec<u16>,
+}
+
+impl Field {
+	/// The tables, computed on first use.
+	pub(crate) fn get() -> &'static Field {
+		static FIELD: OnceLock<Field> = OnceLock::new();
+		FIELD.get_or_init(Field::new)
+	}
+
+	fn new() -> Self {
+		let mut log = vec![0_u16; FIELD_SIZE];
+		let mut exp = vec![0_u16; FIELD_SIZE];
+
+		let mas: u16 = (1 << (FIELD_BITS - 1)) - 1;
+		let mut state: usize = 1;
+		for i in 0..(ONEMASK as usize) {
+			exp[state] = i as u16;
+			if (state >> (FIELD_BITS - 1)) != 0 {
+				state &= mas as usize;
+				state = (state << 1) ^ GENERATOR as usize;
+			} else {
+				state <<= 1;
+			}
+		}
+		exp[0] = ONEMASK;
+
+		log[0] = 0;
+		for i in 0..FIELD_BITS {
+			for j in 0..(1 << i) {
+				log[j + (1 << i)] = log[j] ^ BASE[i];
+			}
+		}
+		for i in 0..FIELD_SIZE {
+			log[i] = exp[log[i] as usize];
+		}
+		for i in 0..FIELD_SIZE {
+			exp[log[i] as usize] = i as u16;
+		}
+		exp[ONEMASK as usize] = exp[0];
+
+		let mut log_walsh = log.clone();
+		log_walsh[0] = 0;
+		walsh(&mut log_walsh);
+
+		let mut field = Field { log, exp, log_walsh, skews: Vec::new() };
+		field.skews = field.compute_skews();
+		field
+	}
+
+	fn compute_skews(&self) -> Vec<u16> {
+		let mut base = [0_u16; FIELD_BITS - 1];
+		let mut skews = vec![0_u16; ONEMASK as usize];
+
+		for i in 1..FIELD_BITS {
+			base[i - 1] = 1 << i;
+		}
+
+		for m in 0..(FIELD_BITS - 1) {
+			let step = 1 << (m + 1);
+			skews[(1 << m) - 1] = 0;
+			for i in m..(FIELD_BITS - 1) {
+				let s = 1 << (i + 1);
+				let mut j = (1 << m) - 1;
+				while j < s {
+					skews[j + s] = skews[j] ^ base[i];
+					j += step;
+				}
+			}
+
+			let idx = self.mul(base[m], self.log[(base[m] ^ 1) as usize]);
+			base[m] = ONEMASK - self.log[idx as usize];
+
+			for i in (m + 1)..(FIELD_BITS - 1) {
+				let b = (self.log[(base[i] ^ 1) as usize] as u32 + base[m] as u32) % ONEMASK as u32;
+				base[i] = self.mul(base[i], b as u16);
+			}
+		}
+
+		skews.into_iter().map(|skew| self.log[skew as usize]).collect()
+	}
+
+	/// Multiply the element `a` by the element with logarithm `log_b`.
+	#[inline]
+	pub(crate) fn mul(&self, a: u16, log_b: u16) -> u16 {
+		if a == 0 {
+			return 0
+		}
+		let log = self.log[a as usize] as u32 + log_b as u32;
+		self.exp[((log & ONEMASK as u32) + (log >> FIELD_BITS)) as usize]
+	}
+
+	/// Skew factor of the additive FFT at `index`, in logarithm form.
+	#[inline]
+	pub(crate) fn skew(&self, index: usize) -> u16 {
+		self.skews[index]
+	}
+
+	/// Evaluate the error locator polynomial for the given erasures, in logarithm form.
+	pub(crate) fn error_polynomial(&self, erasures: &[bool]) -> Vec<u16> {
+		let mut log_walsh2 = vec![0_u16; FIELD_SIZE];
+		for (i, erased) in erasures.iter().enumerate() {
+			log_walsh2[i] = *erased as u16;
+		}
+		walsh(&mut log_walsh2);
+		for (x, log_walsh) in log_walsh2.iter_mut().zip(&self.log_walsh) {
+			*x = ((*x as u32 * *log_walsh as u32) % ONEMASK as u32) as u16;
+		}
+		walsh(&mut log_walsh2);
+		for (x, erased) in log_walsh2.iter_mut().zip(erasures) {
+			if *erased {
+				*x = ONEMASK - *x;
+			}
+		}
+		log_walsh2
+	}
+}
+
+/// Fast Walsh-Hadamard transform modulo `ONEMASK` over the whole field.
+fn walsh(data: &mut [u16]) {
+	let mask = ONEMASK as u32;
+	let mut depart_no = 1;
+	while depart_no < FIELD_SIZE {
+		let mut j = 0;
+		while j < FIELD_SIZE {
+			for i in j..(j + depart_no) {
+				let tmp2 = data[i] as u32 + mask - data[i + depart_no] as u32;
+				let tmp1 = data[i] as u32 + data[i + depart_no] as u32;
+				data[i] = ((tmp1 & mask) + (tmp1 >> FIELD_BITS)) as u16;
+				data[i + depart_no] = ((tmp2 & mask) + (tmp2 >> FIELD_BITS)) as u16;
+			}
+			j += depart_no << 1;
+		}
+		depart_no <<= 1;
+	}
+}
+
+/// Lookup tables multiplying by a constant, one nibble of the input at a time.
+///
+/// Multiplication by a constant is linear over `GF(2)`, so the product of an element is the XOR
+/// of the products of its four nibbles, each split into its low and high output byte.
+pub(crate) struct MulTable {
+	pub(crate) lo: [[u8; 16]; 4],
+	pub(crate) hi: [[u8; 16]; 4],
+}
+
+impl MulTable {
+	/// Tables multiplying by the element with logarithm `log_c`.
+	pub(crate) fn new(field: &Field, log_c: u16) -> Self {
+		let mut table = MulTable { lo: [[0; 16]; 4], hi: [[0; 16]; 4] };
+		for nibble in 0..4 {
+			for x in 0..16 {
+				let product = field.mul((x as u16) << (4 * nibble), log_c);
+				table.lo[nibble][x] = product as u8;
+				table.hi[nibble][x] = (product >> 8) as u8;
+			}
+		}
+		table
+	}
+}
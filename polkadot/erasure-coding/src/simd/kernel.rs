@@ -0,0 +1,186 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Vectorised multiplication of whole rows by a constant.
+//!
+//! A row holds the low bytes of its symbols followed by their high bytes, and each half is a
+//! multiple of [`ROW_ALIGN`] bytes long. The products are computed with byte shuffles indexed
+//! by the nibbles of the input, see [`MulTable`].
+
+use super::field::MulTable;
+
+/// Every half of a row is a multiple of this many bytes.
+pub(crate) const ROW_ALIGN: usize = 32;
+
+/// The instruction set used to multiply rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kernel {
+	#[cfg(target_arch = "x86_64")]
+	Avx2,
+	#[cfg(target_arch = "x86_64")]
+	Ssse3,
+}
+
+impl Kernel {
+	/// The best kernel supported by the running CPU, if any.
+	pub(crate) fn detect() -> Option<Self> {
+		#[cfg(target_arch = "x86_64")]
+		{
+			if is_x86_feature_detected!("avx2") {
+				return Some(Kernel::Avx2)
+			}
+			if is_x86_feature_detected!("ssse3") {
+				return Some(Kernel::Ssse3)
+			}
+		}
+		None
+	}
+
+	/// `dst = src * c`
+	pub(crate) fn mul(self, dst: &mut [u8], src: &[u8], table: &MulTable) {
+		self.run(dst, src, table, false)
+	}
+
+	/// `dst ^= src * c`
+	pub(crate) fn mul_add(self, dst: &mut [u8], src: &[u8], table: &MulTable) {
+		self.run(dst, src, table, true)
+	}
+
+	#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+	fn run(self, dst: &mut [u8], src: &[u8], table: &MulTable, accumulate: bool) {
+		assert_eq!(dst.len(), src.len());
+		assert_eq!(dst.len() % (2 * ROW_ALIGN), 0);
+
+		match self {
+			// Safety: the kernel is only ever detected if the CPU supports it, and the row
+			// layout was checked above.
+			#[cfg(target_arch = "x86_64")]
+			Kernel::Avx2 => unsafe { x86::mul_avx2(dst, src, table, accumulate) },
+			#[cfg(target_arch = "x86_64")]
+			Kernel::Ssse3 => unsafe { x86::mul_ssse3(dst, src, table, accumulate) },
+		}
+	}
+}
+
+/// `dst ^= src`
+pub(crate) fn xor(dst: &mut [u8], src: &[u8]) {
+	// Simple enough to be vectorised by the compiler.
+	for (d, s) in dst.iter_mut().zip(src) {
+		*d ^= *s;
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+	use super::MulTable;
+	use core::arch::x86_64::*;
+
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn mul_avx2(dst: &mut [u8], src: &[u8], table: &MulTable, accumulate: bool) {
+		let half = dst.len() / 2;
+		let (dst_lo, dst_hi) = dst.split_at_mut(half);
+		let (src_lo, src_hi) = src.split_at(half);
+
+		let mut lo_table = [_mm256_setzero_si256(); 4];
+		let mut hi_table = [_mm256_setzero_si256(); 4];
+		for nibble in 0..4 {
+			lo_table[nibble] =
+				_mm256_broadcastsi128_si256(_mm_loadu_si128(table.lo[nibble].as_ptr().cast()));
+			hi_table[nibble] =
+				_mm256_broadcastsi128_si256(_mm_loadu_si128(table.hi[nibble].as_ptr().cast()));
+		}
+		let mask = _mm256_set1_epi8(0x0f);
+
+		for offset in (0..half).step_by(32) {
+			let lo = _mm256_loadu_si256(src_lo.as_ptr().add(offset).cast());
+			let hi = _mm256_loadu_si256(src_hi.as_ptr().add(offset).cast());
+
+			let nibbles = [
+				_mm256_and_si256(lo, mask),
+				_mm256_and_si256(_mm256_srli_epi16::<4>(lo), mask),
+				_mm256_and_si256(hi, mask),
+				_mm256_and_si256(_mm256_srli_epi16::<4>(hi), mask),
+			];
+
+			let mut product_lo = _mm256_setzero_si256();
+			let mut product_hi = _mm256_setzero_si256();
+			for nibble in 0..4 {
+				product_lo = _mm256_xor_si256(
+					product_lo,
+					_mm256_shuffle_epi8(lo_table[nibble], nibbles[nibble]),
+				);
+				product_hi = _mm256_xor_si256(
+					product_hi,
+					_mm256_shuffle_epi8(hi_table[nibble], nibbles[nibble]),
+				);
+			}
+
+			let out_lo = dst_lo.as_mut_ptr().add(offset).cast::<__m256i>();
+			let out_hi = dst_hi.as_mut_ptr().add(offset).cast::<__m256i>();
+			if accumulate {
+				product_lo = _mm256_xor_si256(product_lo, _mm256_loadu_si256(out_lo));
+				product_hi = _mm256_xor_si256(product_hi, _mm256_loadu_si256(out_hi));
+			}
+			_mm256_storeu_si256(out_lo, product_lo);
+			_mm256_storeu_si256(out_hi, product_hi);
+		}
+	}
+
+	#[target_feature(enable = "ssse3")]
+	pub(super) unsafe fn mul_ssse3(dst: &mut [u8], src: &[u8], table: &MulTable, accumulate: bool) {
+		let half = dst.len() / 2;
+		let (dst_lo, dst_hi) = dst.split_at_mut(half);
+		let (src_lo, src_hi) = src.split_at(half);
+
+		let mut lo_table = [_mm_setzero_si128(); 4];
+		let mut hi_table = [_mm_setzero_si128(); 4];
+		for nibble in 0..4 {
+			lo_table[nibble] = _mm_loadu_si128(table.lo[nibble].as_ptr().cast());
+			hi_table[nibble] = _mm_loadu_si128(table.hi[nibble].as_ptr().cast());
+		}
+		let mask = _mm_set1_epi8(0x0f);
+
+		for offset in (0..half).step_by(16) {
+			let lo = _mm_loadu_si128(src_lo.as_ptr().add(offset).cast());
+			let hi = _mm_loadu_si128(src_hi.as_ptr().add(offset).cast());
+
+			let nibbles = [
+				_mm_and_si128(lo, mask),
+				_mm_and_si128(_mm_srli_epi16::<4>(lo), mask),
+				_mm_and_si128(hi, mask),
+				_mm_and_si128(_mm_srli_epi16::<4>(hi), mask),
+			];
+
+			let mut product_lo = _mm_setzero_si128();
+			let mut product_hi = _mm_setzero_si128();
+			for nibble in 0..4 {
+				product_lo =
+					_mm_xor_si128(product_lo, _mm_shuffle_epi8(lo_table[nibble], nibbles[nibble]));
+				product_hi =
+					_mm_xor_si128(product_hi, _mm_shuffle_epi8(hi_table[nibble], nibbles[nibble]));
+			}
+
+			let out_lo = dst_lo.as_mut_ptr().add(offset).cast::<__m128i>();
+			let out_hi = dst_hi.as_mut_ptr().add(offset).cast::<__m128i>();
+			if accumulate {
+				product_lo = _mm_xor_si128(product_lo, _mm_loadu_si128(out_lo));
+				product_hi = _mm_xor_si128(product_hi, _mm_loadu_si128(out_hi));
+			}
+			_mm_storeu_si128(out_lo, product_lo);
+			_mm_storeu_si128(out_hi, product_hi);
+		}
+	}
+}
@@ -0,0 +1,306 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A SIMD implementation of the `novelpoly` Reed-Solomon code.
+//!
+//! `novelpoly` splits the payload into runs of `k` symbols and runs the additive FFT once per run,
+//! which yields one symbol of every chunk. Here the same transforms are applied to all runs at
+//! once: row `i` holds symbol `i` of every run, so each butterfly of the FFT becomes an XOR or a
+//! multiplication by a constant over whole rows, which vectorises well.
+//!
+//! The field arithmetic and the order of operations are the same as in `novelpoly`, hence the
+//! chunks are byte-identical.
+
+mod field;
+mod kernel;
+
+use self::{
+	field::{Field, MulTable, ONEMASK},
+	kernel::{xor, Kernel, ROW_ALIGN},
+};
+use crate::{backend::CodingBackend, code_params, Error};
+
+/// SIMD accelerated Reed-Solomon backend.
+///
+/// Only available if the running CPU supports one of the vectorised kernels.
+#[derive(Debug, Clone, Copy)]
+pub struct SimdBackend {
+	kernel: Kernel,
+}
+
+impl SimdBackend {
+	/// Create the backend if the running CPU supports it.
+	pub fn detect() -> Option<Self> {
+		Kernel::detect().map(|kernel| SimdBackend { kernel })
+	}
+
+	fn transform(&self) -> Transform {
+		Transform { field: Field::get(), kernel: self.kernel }
+	}
+}
+
+impl CodingBackend for SimdBackend {
+	fn name(&self) -> &'static str {
+		"simd"
+	}
+
+	fn obtain_chunks(&self, n_validators: usize, payload: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+		let params = code_params(n_validators)?;
+		let (n, k) = (params.n(), params.k());
+
+		if payload.is_empty() {
+			return Err(Error::BadPayload)
+		}
+
+		let n_runs = payload.len().div_ceil(2).div_ceil(k);
+
+		let mut data = Rows::new(k, n_runs);
+		for (run, bytes) in payload.chunks(2 * k).enumerate() {
+			for (i, symbol) in bytes.chunks(2).enumerate() {
+				data.set(i, run, symbol[0], symbol.get(1).copied().unwrap_or_default());
+			}
+		}
+
+		let mut chunks = Vec::with_capacity(n_validators);
+		// The code is systematic, the first `k` chunks are the payload itself.
+		chunks.extend((0..k.min(n_validators)).map(|i| data.to_chunk(i)));
+
+		let transform = self.transform();
+		transform.inverse_afft(&mut data, k, 0);
+
+		let mut block = Rows::new(k, n_runs);
+		for shift in (k..n).step_by(k).take_while(|shift| *shift < n_validators) {
+			block.data.copy_from_slice(&data.data);
+			transform.afft(&mut block, k, shift);
+			chunks.extend((0..k.min(n_validators - shift)).map(|i| block.to_chunk(i)));
+		}
+
+		Ok(chunks)
+	}
+
+	fn reconstruct(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Option<Vec<u8>>>,
+	) -> Result<Vec<u8>, Error> {
+		let params = code_params(n_validators)?;
+		let (n, k) = (params.n(), params.k());
+
+		let mut chunks = chunks;
+		chunks.resize(n, None);
+
+		let erasures = chunks.iter().map(Option::is_none).collect::<Vec<_>>();
+		if erasures.iter().filter(|erased| !**erased).count() < k {
+			return Err(Error::NotEnoughChunks)
+		}
+
+		let chunk_len = uniform_chunk_len(chunks.iter().flatten())?;
+		let n_runs = chunk_len / 2;
+
+		let field = Field::get();
+		let transform = self.transform();
+		let error_polynomial = field.error_polynomial(&erasures);
+
+		let mut codeword = Rows::new(n, n_runs);
+		let mut scratch = Rows::new(1, n_runs);
+		for (i, chunk) in chunks.iter().enumerate() {
+			if let Some(chunk) = chunk {
+				scratch.load_chunk(0, chunk);
+				let table = MulTable::new(field, error_polynomial[i]);
+				self.kernel.mul(codeword.row_mut(i), scratch.row(0), &table);
+			}
+		}
+
+		transform.inverse_afft(&mut codeword, n, 0);
+		transform.formal_derivative(&mut codeword, n);
+		transform.afft(&mut codeword, n, 0);
+
+		let mut payload = vec![0_u8; n_runs * 2 * k];
+		for i in 0..k {
+			let symbols = match chunks[i] {
+				Some(ref chunk) => chunk.as_slice(),
+				None => {
+					let table = MulTable::new(field, error_polynomial[i]);
+					self.kernel.mul(scratch.row_mut(0), codeword.row(i), &table);
+					scratch.fill_chunk(0);
+					scratch.chunk.as_slice()
+				},
+			};
+			for (run, symbol) in symbols.chunks_exact(2).enumerate() {
+				payload[run * 2 * k + 2 * i..][..2].copy_from_slice(symbol);
+			}
+		}
+
+		Ok(payload)
+	}
+
+	fn reconstruct_from_systematic(
+		&self,
+		n_validators: usize,
+		chunks: Vec<Vec<u8>>,
+	) -> Result<Vec<u8>, Error> {
+		let k = code_params(n_validators)?.k();
+
+		if chunks.len() < k {
+			return Err(Error::NotEnoughChunks)
+		}
+		let chunk_len = uniform_chunk_len(chunks.iter())?;
+
+		let mut payload = vec![0_u8; chunk_len * k];
+		for (i, chunk) in chunks.iter().take(k).enumerate() {
+			for (run, symbol) in chunk.chunks_exact(2).enumerate() {
+				payload[run * 2 * k + 2 * i..][..2].copy_from_slice(symbol);
+			}
+		}
+
+		Ok(payload)
+	}
+}
+
+/// The common length of the chunks, which must not be zero.
+fn uniform_chunk_len<'a>(mut chunks: impl Iterator<Item = &'a Vec<u8>>) -> Result<usize, Error> {
+	let len = chunks.next().map(Vec::len).ok_or(Error::NotEnoughChunks)?;
+	if len == 0 || chunks.any(|chunk| chunk.len() != len) {
+		return Err(Error::NonUniformChunks)
+	}
+	Ok(len)
+}
+
+/// Symbols of several rows, each stored as the low bytes followed by the high bytes.
+struct Rows {
+	data: Vec<u8>,
+	/// Length of each half of a row, padded to the kernel alignment.
+	stride: usize,
+	/// Number of symbols in each row.
+	len: usize,
+	/// Buffer to serialize rows into.
+	chunk: Vec<u8>,
+}
+
+impl Rows {
+	fn new(count: usize, len: usize) -> Self {
+		let stride = len.next_multiple_of(ROW_ALIGN);
+		Rows { data: vec![0; count * 2 * stride], stride, len, chunk: Vec::new() }
+	}
+
+	fn row(&self, i: usize) -> &[u8] {
+		&self.data[i * 2 * self.stride..][..2 * self.stride]
+	}
+
+	fn row_mut(&mut self, i: usize) -> &mut [u8] {
+		&mut self.data[i * 2 * self.stride..][..2 * self.stride]
+	}
+
+	/// The rows `i` and `j`, where `i < j`.
+	fn pair_mut(&mut self, i: usize, j: usize) -> (&mut [u8], &mut [u8]) {
+		let row_len = 2 * self.stride;
+		let (head, tail) = self.data.split_at_mut(j * row_len);
+		(&mut head[i * row_len..][..row_len], &mut tail[..row_len])
+	}
+
+	fn set(&mut self, i: usize, symbol: usize, hi: u8, lo: u8) {
+		let offset = i * 2 * self.stride + symbol;
+		self.data[offset] = lo;
+		self.data[offset + self.stride] = hi;
+	}
+
+	/// Load the big endian symbols of a chunk into row `i`.
+	fn load_chunk(&mut self, i: usize, chunk: &[u8]) {
+		for (symbol, bytes) in chunk.chunks_exact(2).enumerate() {
+			self.set(i, symbol, bytes[0], bytes[1]);
+		}
+	}
+
+	/// Serialize row `i` into `self.chunk`, as big endian symbols.
+	fn fill_chunk(&mut self, i: usize) {
+		let (lo, hi) = self.data[i * 2 * self.stride..][..2 * self.stride].split_at(self.stride);
+		self.chunk.clear();
+		self.chunk
+			.extend(hi[..self.len].iter().zip(&lo[..self.len]).flat_map(|(h, l)| [*h, *l]));
+	}
+
+	fn to_chunk(&mut self, i: usize) -> Vec<u8> {
+		self.fill_chunk(i);
+		self.chunk.clone()
+	}
+}
+
+/// The additive FFT of `novelpoly` over rows of symbols.
+struct Transform {
+	field: &'static Field,
+	kernel: Kernel,
+}
+
+impl Transform {
+	/// `rows[i] ^= rows[i + depart_no] * skew` for `i` in `(j - depart_no)..j`.
+	fn butterfly_up(&self, rows: &mut Rows, j: usize, depart_no: usize, skew: u16) {
+		if skew == ONEMASK {
+			return
+		}
+		let table = MulTable::new(self.field, skew);
+		for i in (j - depart_no)..j {
+			let (lhs, rhs) = rows.pair_mut(i, i + depart_no);
+			self.kernel.mul_add(lhs, rhs, &table);
+		}
+	}
+
+	/// `rows[i + depart_no] ^= rows[i]` for `i` in `(j - depart_no)..j`.
+	fn butterfly_down(&self, rows: &mut Rows, j: usize, depart_no: usize) {
+		for i in (j - depart_no)..j {
+			let (lhs, rhs) = rows.pair_mut(i, i + depart_no);
+			xor(rhs, lhs);
+		}
+	}
+
+	/// Inverse additive FFT in the "novel polynomial basis".
+	fn inverse_afft(&self, rows: &mut Rows, size: usize, index: usize) {
+		let mut depart_no = 1;
+		while depart_no < size {
+			let mut j = depart_no;
+			while j < size {
+				self.butterfly_down(rows, j, depart_no);
+				self.butterfly_up(rows, j, depart_no, self.field.skew(j + index - 1));
+				j += depart_no << 1;
+			}
+			depart_no <<= 1;
+		}
+	}
+
+	/// Additive FFT in the "novel polynomial basis".
+	fn afft(&self, rows: &mut Rows, size: usize, index: usize) {
+		let mut depart_no = size >> 1;
+		while depart_no > 0 {
+			let mut j = depart_no;
+			while j < size {
+				self.butterfly_up(rows, j, depart_no, self.field.skew(j + index - 1));
+				self.butterfly_down(rows, j, depart_no);
+				j += depart_no << 1;
+			}
+			depart_no >>= 1;
+		}
+	}
+
+	/// Formal derivative of the polynomial in the "novel polynomial basis".
+	fn formal_derivative(&self, rows: &mut Rows, size: usize) {
+		for i in 1..size {
+			let length = ((i ^ (i - 1)) + 1) >> 1;
+			for j in (i - length)..i {
+				let (lhs, rhs) = rows.pair_mut(j, j + length);
+				xor(lhs, rhs);
+			}
+		}
+	}
+}
@@ -32,6 +32,8 @@ pub enum Error {
 	Erasure(#[from] polkadot_erasure_coding::Error),
 	#[error("Parachain backing state not available in runtime.")]
 	MissingParaBackingState,
+	#[error("Validation code not available in runtime.")]
+	MissingValidationCode,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
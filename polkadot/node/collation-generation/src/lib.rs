@@ -28,14 +28,23 @@
 //!   * Invoke the `collator`, and use its outputs to produce a [`CandidateReceipt`], signed with
 //!     the configuration's `key`.
 //!   * Dispatch a [`CollatorProtocolMessage::DistributeCollation`]`(receipt, pov)`.
+//!
+//! If the subsystem is given a snapshot directory, every collation is also written there as a
+//! [`CollationSnapshot`], to be able to replay its validation offline. Snapshots are taken after
+//! the collation was distributed and written from a blocking task, so they never delay it.
 
 #![deny(missing_docs)]
 
 use codec::Encode;
-use futures::{channel::oneshot, future::FutureExt, join, select};
+use futures::{
+	channel::{mpsc, oneshot},
+	future::FutureExt,
+	join, select,
+	stream::StreamExt,
+};
 use polkadot_node_primitives::{
-	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal, PoV,
-	SubmitCollationParams,
+	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal,
+	CollationSnapshot, PoV, SubmitCollationParams,
 };
 use polkadot_node_subsystem::{
 	messages::{CollationGenerationMessage, CollatorProtocolMessage},
@@ -43,17 +52,21 @@ use polkadot_node_subsystem::{
 	SubsystemContext, SubsystemError, SubsystemResult,
 };
 use polkadot_node_subsystem_util::{
-	request_async_backing_params, request_availability_cores, request_para_backing_state,
-	request_persisted_validation_data, request_validation_code, request_validation_code_hash,
-	request_validators, vstaging::fetch_claim_queue,
+	executor_params_at_relay_parent, request_async_backing_params, request_availability_cores,
+	request_para_backing_state, request_persisted_validation_data, request_validation_code,
+	request_validation_code_by_hash, request_validation_code_hash, request_validators,
+	vstaging::fetch_claim_queue,
 };
 use polkadot_primitives::{
 	collator_signature_payload, CandidateCommitments, CandidateDescriptor, CandidateReceipt,
-	CollatorPair, CoreIndex, CoreState, Hash, Id as ParaId, OccupiedCoreAssumption,
-	PersistedValidationData, ScheduledCore, ValidationCodeHash,
+	CollatorPair, CommittedCandidateReceipt, CoreIndex, CoreState, Hash, Id as ParaId,
+	OccupiedCoreAssumption, PersistedValidationData, ScheduledCore, ValidationCodeHash,
 };
 use sp_core::crypto::Pair;
-use std::sync::Arc;
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+};
 
 mod error;
 
@@ -65,17 +78,30 @@ use self::metrics::Metrics;
 
 const LOG_TARGET: &'static str = "parachain::collation-generation";
 
+/// The number of collation snapshots which may wait to be written, further ones are dropped.
+const SNAPSHOT_QUEUE_SIZE: usize = 16;
+
+/// Hands collation snapshots over to the subsystem, which writes them to the snapshot directory.
+type SnapshotSender = mpsc::Sender<CollationSnapshot>;
+
 /// Collation Generation Subsystem
 pub struct CollationGenerationSubsystem {
 	config: Option<Arc<CollationGenerationConfig>>,
 	metrics: Metrics,
+	snapshot_dir: Option<Arc<PathBuf>>,
 }
 
 #[overseer::contextbounds(CollationGeneration, prefix = self::overseer)]
 impl CollationGenerationSubsystem {
 	/// Create a new instance of the `CollationGenerationSubsystem`.
 	pub fn new(metrics: Metrics) -> Self {
-		Self { config: None, metrics }
+		Self { config: None, metrics, snapshot_dir: None }
+	}
+
+	/// Create a new instance of the `CollationGenerationSubsystem`, which writes a
+	/// [`CollationSnapshot`] of every collation it distributes to `snapshot_dir`, if given.
+	pub fn with_snapshot_dir(metrics: Metrics, snapshot_dir: Option<PathBuf>) -> Self {
+		Self { config: None, metrics, snapshot_dir: snapshot_dir.map(Arc::new) }
 	}
 
 	/// Run this subsystem
@@ -90,13 +116,21 @@ impl CollationGenerationSubsystem {
 	/// If `err_tx` is not `None`, errors are forwarded onto that channel as they occur.
 	/// Otherwise, most are logged and then discarded.
 	async fn run<Context>(mut self, mut ctx: Context) {
+		let (snapshot_tx, mut snapshot_rx) = mpsc::channel(SNAPSHOT_QUEUE_SIZE);
+		let snapshot_tx = self.snapshot_dir.as_ref().map(|_| snapshot_tx);
+
 		loop {
 			select! {
 				incoming = ctx.recv().fuse() => {
-					if self.handle_incoming::<Context>(incoming, &mut ctx).await {
+					if self.handle_incoming::<Context>(incoming, &mut ctx, snapshot_tx.clone()).await {
 						break;
 					}
 				},
+				snapshot = snapshot_rx.select_next_some() => {
+					if let Some(snapshot_dir) = self.snapshot_dir.clone() {
+						spawn_snapshot_writer(&mut ctx, snapshot_dir, snapshot);
+					}
+				},
 			}
 		}
 	}
@@ -110,6 +144,7 @@ impl CollationGenerationSubsystem {
 		&mut self,
 		incoming: SubsystemResult<FromOrchestra<<Context as SubsystemContext>::Message>>,
 		ctx: &mut Context,
+		snapshots: Option<SnapshotSender>,
 	) -> bool {
 		match incoming {
 			Ok(FromOrchestra::Signal(OverseerSignal::ActiveLeaves(ActiveLeavesUpdate {
@@ -124,6 +159,7 @@ impl CollationGenerationSubsystem {
 						activated.into_iter().map(|v| v.hash),
 						ctx,
 						metrics,
+						snapshots,
 					)
 					.await
 					{
@@ -159,7 +195,7 @@ impl CollationGenerationSubsystem {
 			}) => {
 				if let Some(config) = &self.config {
 					if let Err(err) =
-						handle_submit_collation(params, config, ctx, &self.metrics).await
+						handle_submit_collation(params, config, ctx, &self.metrics, snapshots).await
 					{
 						gum::error!(target: LOG_TARGET, ?err, "Failed to submit collation");
 					}
@@ -202,6 +238,7 @@ async fn handle_new_activations<Context>(
 	activated: impl IntoIterator<Item = Hash>,
 	ctx: &mut Context,
 	metrics: Metrics,
+	snapshots: Option<SnapshotSender>,
 ) -> crate::error::Result<()> {
 	// follow the procedure from the guide:
 	// https://paritytech.github.io/polkadot-sdk/book/node/collators/collation-generation.html
@@ -389,6 +426,7 @@ async fn handle_new_activations<Context>(
 
 		let task_config = config.clone();
 		let metrics = metrics.clone();
+		let snapshots = snapshots.clone();
 		let mut task_sender = ctx.sender().clone();
 
 		ctx.spawn(
@@ -428,6 +466,7 @@ async fn handle_new_activations<Context>(
 						&mut task_sender,
 						result_sender,
 						&metrics,
+						snapshots.clone(),
 					)
 					.await;
 
@@ -448,6 +487,7 @@ async fn handle_submit_collation<Context>(
 	config: &CollationGenerationConfig,
 	ctx: &mut Context,
 	metrics: &Metrics,
+	snapshots: Option<SnapshotSender>,
 ) -> crate::error::Result<()> {
 	let _timer = metrics.time_submit_collation();
 
@@ -503,6 +543,7 @@ async fn handle_submit_collation<Context>(
 		ctx.sender(),
 		result_sender,
 		metrics,
+		snapshots,
 	)
 	.await;
 
@@ -521,12 +562,15 @@ struct PreparedCollation {
 
 /// Takes a prepared collation, along with its context, and produces a candidate receipt
 /// which is distributed to validators.
+///
+/// Once distributed, a snapshot of the collation is handed to `snapshots`, if given.
 async fn construct_and_distribute_receipt(
 	collation: PreparedCollation,
 	key: CollatorPair,
 	sender: &mut impl overseer::CollationGenerationSenderTrait,
 	result_sender: Option<oneshot::Sender<CollationSecondedSignal>>,
 	metrics: &Metrics,
+	snapshots: Option<SnapshotSender>,
 ) {
 	let PreparedCollation {
 		collation,
@@ -577,7 +621,7 @@ async fn construct_and_distribute_receipt(
 		&validation_code_hash,
 	);
 
-	let erasure_root = match erasure_root(n_validators, validation_data, pov.clone()) {
+	let erasure_root = match erasure_root(n_validators, validation_data.clone(), pov.clone()) {
 		Ok(erasure_root) => erasure_root,
 		Err(err) => {
 			gum::error!(
@@ -624,6 +668,11 @@ async fn construct_and_distribute_receipt(
 	);
 	metrics.on_collation_generated();
 
+	let candidate = snapshots
+		.as_ref()
+		.map(|_| CommittedCandidateReceipt { descriptor: ccr.descriptor.clone(), commitments });
+	let snapshot_pov = snapshots.as_ref().map(|_| pov.clone());
+
 	sender
 		.send_message(CollatorProtocolMessage::DistributeCollation {
 			candidate_receipt: ccr,
@@ -634,6 +683,114 @@ async fn construct_and_distribute_receipt(
 			core_index,
 		})
 		.await;
+
+	if let (Some(mut snapshots), Some(candidate), Some(pov)) = (snapshots, candidate, snapshot_pov)
+	{
+		match collect_collation_snapshot(candidate, pov, validation_data, sender).await {
+			Ok(snapshot) =>
+				if let Err(err) = snapshots.try_send(snapshot) {
+					gum::warn!(
+						target: LOG_TARGET,
+						para_id = %para_id,
+						?err,
+						"dropped collation snapshot",
+					);
+				},
+			Err(err) => gum::warn!(
+				target: LOG_TARGET,
+				para_id = %para_id,
+				?err,
+				"failed to take collation snapshot",
+			),
+		}
+	}
+}
+
+/// Takes a [`CollationSnapshot`] of `candidate`, fetching the relay chain state needed to validate
+/// it at its relay-parent.
+async fn collect_collation_snapshot(
+	candidate: CommittedCandidateReceipt,
+	pov: PoV,
+	validation_data: PersistedValidationData,
+	sender: &mut impl overseer::CollationGenerationSenderTrait,
+) -> crate::error::Result<CollationSnapshot> {
+	let relay_parent = candidate.descriptor.relay_parent;
+	let para_id = candidate.descriptor.para_id;
+
+	let validation_code = request_validation_code_by_hash(
+		relay_parent,
+		candidate.descriptor.validation_code_hash,
+		sender,
+	)
+	.await
+	.await??
+	.ok_or(crate::error::Error::MissingValidationCode)?;
+	let executor_params = executor_params_at_relay_parent(relay_parent, sender).await?;
+	let backing_state =
+		match request_para_backing_state(relay_parent, para_id, sender).await.await? {
+			Ok(backing_state) => backing_state,
+			Err(RuntimeApiError::NotSupported { .. }) => None,
+			Err(err) => return Err(err.into()),
+		};
+
+	Ok(CollationSnapshot {
+		candidate,
+		pov,
+		validation_data,
+		validation_code,
+		executor_params,
+		backing_state,
+	})
+}
+
+/// Spawns a blocking task writing `snapshot` to a file in `snapshot_dir`.
+#[overseer::contextbounds(CollationGeneration, prefix = self::overseer)]
+fn spawn_snapshot_writer<Context>(
+	ctx: &mut Context,
+	snapshot_dir: Arc<PathBuf>,
+	snapshot: CollationSnapshot,
+) {
+	let para_id = snapshot.candidate.descriptor.para_id;
+	let write = async move {
+		match write_collation_snapshot(&snapshot_dir, &snapshot) {
+			Ok(path) => gum::debug!(
+				target: LOG_TARGET,
+				para_id = %para_id,
+				?path,
+				"collation snapshot written",
+			),
+			Err(err) => gum::warn!(
+				target: LOG_TARGET,
+				para_id = %para_id,
+				?err,
+				"failed to write collation snapshot",
+			),
+		}
+	};
+
+	if let Err(err) = ctx.spawn_blocking("collation-snapshot-writer", write.boxed()) {
+		gum::warn!(target: LOG_TARGET, ?err, "failed to spawn collation snapshot writer");
+	}
+}
+
+/// Writes `snapshot` to a file in `snapshot_dir`, returning the path of the file.
+///
+/// The snapshot is written to a temporary file first, so the file only appears once complete.
+fn write_collation_snapshot(
+	snapshot_dir: &Path,
+	snapshot: &CollationSnapshot,
+) -> std::io::Result<PathBuf> {
+	let file_name = format!(
+		"{}-{:?}.collation",
+		snapshot.candidate.descriptor.para_id,
+		snapshot.candidate.hash().0
+	);
+	let path = snapshot_dir.join(&file_name);
+	let tmp_path = snapshot_dir.join(format!("{}.tmp", file_name));
+	std::fs::write(&tmp_path, snapshot.encode())?;
+	std::fs::rename(&tmp_path, &path)?;
+
+	Ok(path)
 }
 
 async fn obtain_validation_code_hash_with_assumption(
@@ -16,6 +16,7 @@
 
 use super::*;
 use assert_matches::assert_matches;
+use codec::Decode;
 use futures::{
 	lock::Mutex,
 	task::{Context as FuturesContext, Poll},
@@ -31,8 +32,8 @@ use polkadot_node_subsystem_test_helpers::{subsystem_test_harness, TestSubsystem
 use polkadot_node_subsystem_util::TimeoutExt;
 use polkadot_primitives::{
 	async_backing::{BackingState, CandidatePendingAvailability},
-	AsyncBackingParams, BlockNumber, CollatorPair, HeadData, PersistedValidationData,
-	ScheduledCore, ValidationCode,
+	AsyncBackingParams, BlockNumber, CollatorPair, ExecutorParam, ExecutorParams, HeadData,
+	PersistedValidationData, ScheduledCore, ValidationCode,
 };
 use polkadot_primitives_test_helpers::{
 	dummy_candidate_descriptor, dummy_hash, dummy_head_data, dummy_validator, make_candidate,
@@ -47,11 +48,21 @@ use std::{
 type VirtualOverseer = TestSubsystemContextHandle<CollationGenerationMessage>;
 
 fn test_harness<T: Future<Output = VirtualOverseer>>(test: impl FnOnce(VirtualOverseer) -> T) {
+	test_harness_with_snapshot_dir(None, test)
+}
+
+fn test_harness_with_snapshot_dir<T: Future<Output = VirtualOverseer>>(
+	snapshot_dir: Option<PathBuf>,
+	test: impl FnOnce(VirtualOverseer) -> T,
+) {
 	let pool = sp_core::testing::TaskExecutor::new();
 	let (context, virtual_overseer) =
 		polkadot_node_subsystem_test_helpers::make_subsystem_context(pool);
 	let subsystem = async move {
-		let subsystem = crate::CollationGenerationSubsystem::new(Metrics::default());
+		let subsystem = crate::CollationGenerationSubsystem::with_snapshot_dir(
+			Metrics::default(),
+			snapshot_dir,
+		);
 
 		subsystem.run(context).await;
 	};
@@ -235,6 +246,7 @@ fn requests_availability_per_relay_parent(#[case] runtime_version: u32) {
 			subsystem_activated_hashes,
 			&mut ctx,
 			Metrics(None),
+			None,
 		)
 		.await
 		.unwrap();
@@ -339,6 +351,7 @@ fn requests_validation_data_for_scheduled_matches(#[case] runtime_version: u32)
 			activated_hashes,
 			&mut ctx,
 			Metrics(None),
+			None,
 		)
 		.await
 		.unwrap();
@@ -457,7 +470,7 @@ fn sends_distribute_collation_message(#[case] runtime_version: u32) {
 	let subsystem_config = config.clone();
 
 	subsystem_test_harness(overseer, |mut ctx| async move {
-		handle_new_activations(subsystem_config, activated_hashes, &mut ctx, Metrics(None))
+		handle_new_activations(subsystem_config, activated_hashes, &mut ctx, Metrics(None), None)
 			.await
 			.unwrap();
 	});
@@ -640,7 +653,7 @@ fn fallback_when_no_validation_code_hash_api(#[case] runtime_version: u32) {
 
 	// empty vec doesn't allocate on the heap, so it's ok we throw it away
 	subsystem_test_harness(overseer, |mut ctx| async move {
-		handle_new_activations(subsystem_config, activated_hashes, &mut ctx, Metrics(None))
+		handle_new_activations(subsystem_config, activated_hashes, &mut ctx, Metrics(None), None)
 			.await
 			.unwrap();
 	});
@@ -762,6 +775,131 @@ fn submit_collation_leads_to_distribution() {
 	});
 }
 
+#[test]
+fn submit_collation_writes_snapshot() {
+	let relay_parent = Hash::repeat_byte(0);
+	let validation_code = ValidationCode(vec![1, 2, 3]);
+	let validation_code_hash = validation_code.hash();
+	let para_id = ParaId::from(5);
+	let pvd = PersistedValidationData {
+		parent_head: dummy_head_data(),
+		relay_parent_number: 10,
+		relay_parent_storage_root: Hash::repeat_byte(1),
+		max_pov_size: 1024,
+	};
+	let executor_params = ExecutorParams::from(&[ExecutorParam::MaxMemoryPages(100)][..]);
+	let backing_state = dummy_backing_state(Vec::new());
+
+	let snapshot_dir = tempfile::tempdir().unwrap();
+	let snapshot_path = snapshot_dir.path().to_owned();
+
+	test_harness_with_snapshot_dir(Some(snapshot_path.clone()), |mut virtual_overseer| {
+		let snapshot_path = snapshot_path.clone();
+		let pvd = pvd.clone();
+		let validation_code = validation_code.clone();
+		let executor_params = executor_params.clone();
+		let backing_state = backing_state.clone();
+		async move {
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id)),
+				})
+				.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
+						relay_parent,
+						collation: test_collation(),
+						parent_head: dummy_head_data(),
+						validation_code_hash,
+						result_sender: None,
+						core_index: CoreIndex(0),
+					}),
+				})
+				.await;
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::Validators(tx))) => {
+					let _ = tx.send(Ok(vec![dummy_validator(), dummy_validator()]));
+				}
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::PersistedValidationData(_, _, tx))) => {
+					let _ = tx.send(Ok(Some(pvd)));
+				}
+			);
+
+			// The collation is distributed before the snapshot is taken.
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::CollatorProtocol(CollatorProtocolMessage::DistributeCollation { .. })
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(rp, RuntimeApiRequest::ValidationCodeByHash(hash, tx))) => {
+					assert_eq!(rp, relay_parent);
+					assert_eq!(hash, validation_code_hash);
+					let _ = tx.send(Ok(Some(validation_code)));
+				}
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionIndexForChild(tx))) => {
+					let _ = tx.send(Ok(1));
+				}
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionExecutorParams(1, tx))) => {
+					let _ = tx.send(Ok(Some(executor_params)));
+				}
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ParaBackingState(id, tx))) => {
+					assert_eq!(id, para_id);
+					let _ = tx.send(Ok(Some(backing_state)));
+				}
+			);
+
+			// The snapshot is written by a blocking task.
+			for _ in 0..100 {
+				if std::fs::read_dir(&snapshot_path).unwrap().any(|entry| {
+					entry.unwrap().path().extension().map_or(false, |ext| ext == "collation")
+				}) {
+					break
+				}
+				futures::future::pending::<()>()
+					.timeout(std::time::Duration::from_millis(10))
+					.await;
+			}
+
+			virtual_overseer
+		}
+	});
+
+	let files = std::fs::read_dir(&snapshot_path).unwrap().collect::<Vec<_>>();
+	assert_eq!(files.len(), 1);
+	let encoded = std::fs::read(files[0].as_ref().unwrap().path()).unwrap();
+	let snapshot = CollationSnapshot::decode(&mut &encoded[..]).unwrap();
+
+	assert_eq!(snapshot.candidate.descriptor.para_id, para_id);
+	assert_eq!(snapshot.candidate.commitments.head_data, test_collation().head_data);
+	assert_eq!(snapshot.pov.hash(), snapshot.candidate.descriptor.pov_hash);
+	assert_eq!(snapshot.validation_data, pvd);
+	assert_eq!(snapshot.validation_code, validation_code);
+	assert_eq!(snapshot.executor_params, executor_params);
+	assert_eq!(snapshot.backing_state, Some(backing_state));
+}
+
 // There is one core in `Occupied` state and async backing is enabled. On new head activation
 // `CollationGeneration` should produce and distribute a new collation.
 #[rstest]
@@ -55,9 +55,8 @@ use polkadot_node_subsystem_util::{
 };
 use polkadot_primitives::{
 	ApprovalVoteMultipleCandidates, ApprovalVotingParams, BlockNumber, CandidateHash,
-	CandidateIndex, CandidateReceipt, CoreIndex, DisputeStatement, ExecutorParams, GroupIndex,
-	Hash, PvfExecKind, SessionIndex, SessionInfo, ValidDisputeStatementKind, ValidatorId,
-	ValidatorIndex, ValidatorPair, ValidatorSignature,
+	CandidateIndex, CandidateReceipt, CoreIndex, ExecutorParams, GroupIndex, Hash, PvfExecKind,
+	SessionIndex, SessionInfo, ValidatorId, ValidatorIndex, ValidatorPair, ValidatorSignature,
 };
 use sc_keystore::LocalKeystore;
 use sp_application_crypto::Pair;
@@ -83,6 +82,7 @@ use std::{
 	collections::{
 		btree_map::Entry as BTMEntry, hash_map::Entry as HMEntry, BTreeMap, HashMap, HashSet,
 	},
+	num::NonZeroUsize,
 	sync::Arc,
 	time::Duration,
 };
@@ -103,6 +103,7 @@ mod import;
 mod ops;
 mod persisted_entries;
 pub mod time;
+mod verification;
 
 use crate::{
 	approval_checking::{Check, TranchesToApproveResult},
@@ -110,6 +111,7 @@ use crate::{
 	backend::{Backend, OverlayedBackend},
 	criteria::InvalidAssignmentReason,
 	persisted_entries::OurApproval,
+	verification::{VerificationPool, VerificationTask},
 };
 
 #[cfg(test)]
@@ -124,6 +126,8 @@ const WAIT_FOR_SIGS_TIMEOUT: Duration = Duration::from_millis(500);
 const APPROVAL_CACHE_SIZE: u32 = 1024;
 
 const TICK_TOO_FAR_IN_FUTURE: Tick = 20; // 10 seconds.
+/// The maximum number of assignments and approvals verified in parallel before being imported.
+const MAX_CHECK_BATCH_SIZE: usize = 256;
 const APPROVAL_DELAY: Tick = 2;
 pub(crate) const LOG_TARGET: &str = "parachain::approval-voting";
 
@@ -826,7 +830,7 @@ struct State {
 	keystore: Arc<LocalKeystore>,
 	slot_duration_millis: u64,
 	clock: Box<dyn Clock + Send + Sync>,
-	assignment_criteria: Box<dyn AssignmentCriteria + Send + Sync>,
+	assignment_criteria: Arc<dyn AssignmentCriteria + Send + Sync>,
 	spans: HashMap<Hash, jaeger::PerLeafSpan>,
 	// Per block, candidate records about how long we take until we gather enough
 	// assignments, this is relevant because it gives us a good idea about how many
@@ -1161,7 +1165,7 @@ where
 		keystore: subsystem.keystore,
 		slot_duration_millis: subsystem.slot_duration_millis,
 		clock: subsystem.clock,
-		assignment_criteria,
+		assignment_criteria: assignment_criteria.into(),
 		spans: HashMap::new(),
 		per_block_assignments_gathering_times: LruMap::new(ByLength::new(
 			MAX_BLOCKS_WITH_ASSIGNMENT_TIMESTAMPS,
@@ -1178,6 +1182,14 @@ where
 	let mut currently_checking_set = CurrentlyCheckingSet::default();
 	let mut delayed_approvals_timers = DelayedApprovalTimer::default();
 	let mut approvals_cache = LruMap::new(ByLength::new(APPROVAL_CACHE_SIZE));
+	let mut verification_pool = VerificationPool::spawn(
+		&mut ctx,
+		std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+		state.assignment_criteria.clone(),
+	);
+	// A message received while checking a batch of assignments and approvals, to be handled
+	// before any other.
+	let mut deferred_msg = None;
 
 	let mut last_finalized_height: Option<BlockNumber> = {
 		let (tx, rx) = oneshot::channel();
@@ -1207,7 +1219,7 @@ where
 					&wakeups,
 				).await?
 			}
-			next_msg = ctx.recv().fuse() => {
+			next_msg = next_message(&mut ctx, &mut deferred_msg).fuse() => {
 				let mut actions = handle_from_overseer(
 					&mut ctx,
 					&mut state,
@@ -1217,6 +1229,8 @@ where
 					next_msg?,
 					&mut last_finalized_height,
 					&mut wakeups,
+					&mut verification_pool,
+					&mut deferred_msg,
 				).await?;
 
 				if let Mode::Syncing(ref mut oracle) = subsystem.mode {
@@ -1318,6 +1332,19 @@ where
 	Ok(())
 }
 
+// Returns the message deferred while checking a batch, if any, or the next message from the
+// overseer.
+#[overseer::contextbounds(ApprovalVoting, prefix = self::overseer)]
+async fn next_message<Context>(
+	ctx: &mut Context,
+	deferred_msg: &mut Option<FromOrchestra<ApprovalVotingMessage>>,
+) -> SubsystemResult<FromOrchestra<ApprovalVotingMessage>> {
+	match deferred_msg.take() {
+		Some(msg) => Ok(msg),
+		None => ctx.recv().await,
+	}
+}
+
 // Handle actions is a function that accepts a set of instructions
 // and subsequently updates the underlying approvals_db in accordance
 // with the linear set of instructions passed in. Therefore, actions
@@ -1780,6 +1807,8 @@ async fn handle_from_overseer<Context>(
 	x: FromOrchestra<ApprovalVotingMessage>,
 	last_finalized_height: &mut Option<BlockNumber>,
 	wakeups: &mut Wakeups,
+	verification_pool: &mut VerificationPool,
+	deferred_msg: &mut Option<FromOrchestra<ApprovalVotingMessage>>,
 ) -> SubsystemResult<Vec<Action>> {
 	let actions = match x {
 		FromOrchestra::Signal(OverseerSignal::ActiveLeaves(update)) => {
@@ -1872,35 +1901,23 @@ async fn handle_from_overseer<Context>(
 			vec![Action::Conclude]
 		},
 		FromOrchestra::Communication { msg } => match msg {
-			ApprovalVotingMessage::CheckAndImportAssignment(a, claimed_cores, res) => {
-				let (check_outcome, actions) = check_and_import_assignment(
-					ctx.sender(),
+			msg @ ApprovalVotingMessage::CheckAndImportAssignment(..) |
+			msg @ ApprovalVotingMessage::CheckAndImportApproval(..) => {
+				let (actions, next_msg) = check_and_import_batch(
+					ctx,
 					state,
 					db,
 					session_info_provider,
-					a,
-					claimed_cores,
+					metrics,
+					verification_pool,
+					wakeups,
+					msg,
 				)
 				.await?;
-				let _ = res.send(check_outcome);
+				*deferred_msg = next_msg;
 
 				actions
 			},
-			ApprovalVotingMessage::CheckAndImportApproval(a, res) =>
-				check_and_import_approval(
-					ctx.sender(),
-					state,
-					db,
-					session_info_provider,
-					metrics,
-					a,
-					|r| {
-						let _ = res.send(r);
-					},
-					&wakeups,
-				)
-				.await?
-				.0,
 			ApprovalVotingMessage::ApprovedAncestor(target, lower_bound, res) => {
 				let mut approved_ancestor_span = state
 					.spans
@@ -2439,19 +2456,153 @@ fn schedule_wakeup_action(
 	maybe_action
 }
 
-async fn check_and_import_assignment<Sender>(
+// An assignment whose certificate is being checked by the verification pool.
+struct PendingAssignment {
+	assignment: IndirectAssignmentCertV2,
+	candidate_indices: CandidateBitfield,
+	block_entry: BlockEntry,
+	claimed_core_indices: Vec<CoreIndex>,
+	assigned_candidate_hashes: Vec<CandidateHash>,
+	tranche: oneshot::Receiver<Result<DelayTranche, criteria::InvalidAssignment>>,
+	span: jaeger::Span,
+}
+
+// An approval whose signature is being checked by the verification pool.
+struct PendingApproval {
+	approval: IndirectSignedApprovalVoteV2,
+	approved_candidates_info: Vec<(CandidateIndex, CandidateHash)>,
+	signature_valid: oneshot::Receiver<bool>,
+}
+
+// A check waiting for the verification pool, along with the channel to respond on once imported.
+enum PendingCheck {
+	Assignment(PendingAssignment, oneshot::Sender<AssignmentCheckResult>),
+	Approval(PendingApproval, oneshot::Sender<ApprovalCheckResult>),
+}
+
+// Checks and imports a batch of consecutive assignments and approvals, starting with `first`.
+//
+// Further checks already queued for the subsystem are pulled into the batch, so the certificates
+// and signatures of the whole batch are verified in parallel by the verification pool. The
+// messages are nevertheless imported one by one, in the order they were received, so the outcome
+// is the same as when checking them sequentially.
+//
+// Returns the actions resulting from the imports and the first received message which is not a
+// check, to be handled next.
+#[overseer::contextbounds(ApprovalVoting, prefix = self::overseer)]
+async fn check_and_import_batch<Context>(
+	ctx: &mut Context,
+	state: &mut State,
+	db: &mut OverlayedBackend<'_, impl Backend>,
+	session_info_provider: &mut RuntimeInfo,
+	metrics: &Metrics,
+	verification_pool: &mut VerificationPool,
+	wakeups: &Wakeups,
+	first: ApprovalVotingMessage,
+) -> SubsystemResult<(Vec<Action>, Option<FromOrchestra<ApprovalVotingMessage>>)> {
+	let mut pending_checks = Vec::new();
+	let mut next_msg = Some(FromOrchestra::Communication { msg: first });
+	let mut deferred_msg = None;
+
+	while let Some(msg) = next_msg.take() {
+		match msg {
+			FromOrchestra::Communication {
+				msg: ApprovalVotingMessage::CheckAndImportAssignment(a, claimed_cores, res),
+			} => match prepare_assignment_check(
+				ctx.sender(),
+				state,
+				db,
+				session_info_provider,
+				verification_pool,
+				a,
+				claimed_cores,
+			)
+			.await?
+			{
+				Ok(pending) => pending_checks.push(PendingCheck::Assignment(pending, res)),
+				Err(check_outcome) => {
+					let _ = res.send(check_outcome);
+				},
+			},
+			FromOrchestra::Communication {
+				msg: ApprovalVotingMessage::CheckAndImportApproval(a, res),
+			} => match prepare_approval_check(
+				ctx.sender(),
+				db,
+				session_info_provider,
+				verification_pool,
+				a,
+			)
+			.await?
+			{
+				Ok(pending) => pending_checks.push(PendingCheck::Approval(pending, res)),
+				Err(check_outcome) => {
+					let _ = res.send(check_outcome);
+				},
+			},
+			msg => {
+				deferred_msg = Some(msg);
+				break
+			},
+		}
+
+		if pending_checks.len() < MAX_CHECK_BATCH_SIZE {
+			// An error means the overseer is gone, which the next `recv` reports.
+			next_msg = ctx.try_recv().await.ok().flatten();
+		}
+	}
+
+	let mut actions = Vec::new();
+	for pending_check in pending_checks {
+		match pending_check {
+			PendingCheck::Assignment(pending, res) => {
+				let (check_outcome, new_actions) = import_checked_assignment(
+					ctx.sender(),
+					state,
+					db,
+					session_info_provider,
+					pending,
+				)
+				.await?;
+				let _ = res.send(check_outcome);
+				actions.extend(new_actions);
+			},
+			PendingCheck::Approval(pending, res) => {
+				let (check_outcome, new_actions) = import_checked_approval(
+					ctx.sender(),
+					state,
+					db,
+					session_info_provider,
+					metrics,
+					pending,
+					wakeups,
+				)
+				.await?;
+				let _ = res.send(check_outcome);
+				actions.extend(new_actions);
+			},
+		}
+	}
+
+	Ok((actions, deferred_msg))
+}
+
+// Runs the checks of an assignment which depend on the subsystem state and submits its
+// certificate to the verification pool.
+//
+// Returns the outcome right away if the assignment can't be imported.
+async fn prepare_assignment_check<Sender>(
 	sender: &mut Sender,
 	state: &State,
 	db: &mut OverlayedBackend<'_, impl Backend>,
 	session_info_provider: &mut RuntimeInfo,
+	verification_pool: &mut VerificationPool,
 	assignment: IndirectAssignmentCertV2,
 	candidate_indices: CandidateBitfield,
-) -> SubsystemResult<(AssignmentCheckResult, Vec<Action>)>
+) -> SubsystemResult<Result<PendingAssignment, AssignmentCheckResult>>
 where
 	Sender: SubsystemSender<RuntimeApiMessage>,
 {
-	let tick_now = state.clock.tick_now();
-
 	let mut check_and_import_assignment_span = state
 		.spans
 		.get(&assignment.block_hash)
@@ -2467,12 +2618,9 @@ where
 	let block_entry = match db.load_block_entry(&assignment.block_hash)? {
 		Some(b) => b,
 		None =>
-			return Ok((
-				AssignmentCheckResult::Bad(AssignmentCheckError::UnknownBlock(
-					assignment.block_hash,
-				)),
-				Vec::new(),
-			)),
+			return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::UnknownBlock(
+				assignment.block_hash,
+			)))),
 	};
 
 	let session_info = match get_session_info(
@@ -2485,12 +2633,9 @@ where
 	{
 		Some(s) => s,
 		None =>
-			return Ok((
-				AssignmentCheckResult::Bad(AssignmentCheckError::UnknownSessionIndex(
-					block_entry.session(),
-				)),
-				Vec::new(),
-			)),
+			return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::UnknownSessionIndex(
+				block_entry.session(),
+			)))),
 	};
 
 	let n_cores = session_info.n_cores as usize;
@@ -2506,12 +2651,9 @@ where
 			"Oversized bitfield",
 		);
 
-		return Ok((
-			AssignmentCheckResult::Bad(AssignmentCheckError::InvalidBitfield(
-				candidate_indices.len(),
-			)),
-			Vec::new(),
-		))
+		return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::InvalidBitfield(
+			candidate_indices.len(),
+		))))
 	}
 
 	// The Compact VRF modulo assignment cert has multiple core assignments.
@@ -2524,24 +2666,18 @@ where
 			match block_entry.candidate(candidate_index) {
 				Some((c, h)) => (*c, *h),
 				None =>
-					return Ok((
-						AssignmentCheckResult::Bad(AssignmentCheckError::InvalidCandidateIndex(
-							candidate_index as _,
-						)),
-						Vec::new(),
-					)), // no candidate at core.
+					return Ok(Err(AssignmentCheckResult::Bad(
+						AssignmentCheckError::InvalidCandidateIndex(candidate_index as _),
+					))), // no candidate at core.
 			};
 
 		let mut candidate_entry = match db.load_candidate_entry(&assigned_candidate_hash)? {
 			Some(c) => c,
 			None =>
-				return Ok((
-					AssignmentCheckResult::Bad(AssignmentCheckError::InvalidCandidate(
-						candidate_index as _,
-						assigned_candidate_hash,
-					)),
-					Vec::new(),
-				)), // no candidate at core.
+				return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::InvalidCandidate(
+					candidate_index as _,
+					assigned_candidate_hash,
+				)))), // no candidate at core.
 		};
 
 		check_and_import_assignment_span
@@ -2554,13 +2690,10 @@ where
 		let approval_entry = match candidate_entry.approval_entry_mut(&assignment.block_hash) {
 			Some(a) => a,
 			None =>
-				return Ok((
-					AssignmentCheckResult::Bad(AssignmentCheckError::Internal(
-						assignment.block_hash,
-						assigned_candidate_hash,
-					)),
-					Vec::new(),
-				)),
+				return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::Internal(
+					assignment.block_hash,
+					assigned_candidate_hash,
+				)))),
 		};
 
 		backing_groups.push(approval_entry.backing_group());
@@ -2569,28 +2702,82 @@ where
 	}
 
 	// Error on null assignments.
-	if claimed_core_indices.is_empty() {
-		return Ok((
-			AssignmentCheckResult::Bad(AssignmentCheckError::InvalidCert(
+	let first_candidate_hash = match assigned_candidate_hashes.first() {
+		Some(candidate_hash) => *candidate_hash,
+		None =>
+			return Ok(Err(AssignmentCheckResult::Bad(AssignmentCheckError::InvalidCert(
 				assignment.validator,
 				format!("{:?}", InvalidAssignmentReason::NullAssignment),
-			)),
-			Vec::new(),
-		))
-	}
+			)))),
+	};
 
 	// Check the assignment certificate.
-	let res = state.assignment_criteria.check_assignment_cert(
-		claimed_core_indices
-			.clone()
-			.try_into()
-			.expect("Checked for null assignment above; qed"),
-		assignment.validator,
-		&criteria::Config::from(session_info),
-		block_entry.relay_vrf_story(),
-		&assignment.cert,
-		backing_groups,
-	);
+	let (tx, rx) = oneshot::channel();
+	verification_pool
+		.submit(
+			VerificationTask::Assignment {
+				claimed_core_indices: claimed_core_indices
+					.clone()
+					.try_into()
+					.expect("Checked for null assignment above; qed"),
+				validator_index: assignment.validator,
+				config: criteria::Config::from(session_info),
+				relay_vrf_story: block_entry.relay_vrf_story(),
+				cert: assignment.cert.clone(),
+				backing_groups,
+				response: tx,
+			},
+			first_candidate_hash,
+		)
+		.await;
+
+	Ok(Ok(PendingAssignment {
+		assignment,
+		candidate_indices,
+		block_entry,
+		claimed_core_indices,
+		assigned_candidate_hashes,
+		tranche: rx,
+		span: check_and_import_assignment_span,
+	}))
+}
+
+// Imports an assignment once its certificate has been checked by the verification pool.
+async fn import_checked_assignment<Sender>(
+	sender: &mut Sender,
+	state: &State,
+	db: &mut OverlayedBackend<'_, impl Backend>,
+	session_info_provider: &mut RuntimeInfo,
+	pending: PendingAssignment,
+) -> SubsystemResult<(AssignmentCheckResult, Vec<Action>)>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	let PendingAssignment {
+		assignment,
+		candidate_indices,
+		block_entry,
+		claimed_core_indices,
+		assigned_candidate_hashes,
+		tranche,
+		span: mut check_and_import_assignment_span,
+	} = pending;
+
+	let tick_now = state.clock.tick_now();
+
+	let res = match tranche.await {
+		Ok(res) => res,
+		Err(_) => {
+			gum::warn!(target: LOG_TARGET, "Verification worker dropped an assignment check");
+			return Ok((
+				AssignmentCheckResult::Bad(AssignmentCheckError::Internal(
+					assignment.block_hash,
+					assigned_candidate_hashes[0],
+				)),
+				Vec::new(),
+			))
+		},
+	};
 
 	let tranche = match res {
 		Err(crate::criteria::InvalidAssignment(reason)) =>
@@ -2704,41 +2891,26 @@ where
 	Ok((res, actions))
 }
 
-async fn check_and_import_approval<T, Sender>(
+// Runs the checks of an approval which depend on the subsystem state and submits its signature
+// to the verification pool.
+//
+// Returns the outcome right away if the approval can't be imported.
+async fn prepare_approval_check<Sender>(
 	sender: &mut Sender,
-	state: &mut State,
 	db: &mut OverlayedBackend<'_, impl Backend>,
 	session_info_provider: &mut RuntimeInfo,
-	metrics: &Metrics,
+	verification_pool: &mut VerificationPool,
 	approval: IndirectSignedApprovalVoteV2,
-	with_response: impl FnOnce(ApprovalCheckResult) -> T,
-	wakeups: &Wakeups,
-) -> SubsystemResult<(Vec<Action>, T)>
+) -> SubsystemResult<Result<PendingApproval, ApprovalCheckResult>>
 where
 	Sender: SubsystemSender<RuntimeApiMessage>,
 {
-	macro_rules! respond_early {
-		($e: expr) => {{
-			let t = with_response($e);
-			return Ok((Vec::new(), t))
-		}};
-	}
-	let mut span = state
-		.spans
-		.get(&approval.block_hash)
-		.map(|span| span.child("check-and-import-approval"))
-		.unwrap_or_else(|| jaeger::Span::new(approval.block_hash, "check-and-import-approval"))
-		.with_string_fmt_debug_tag("candidate-index", approval.candidate_indices.clone())
-		.with_relay_parent(approval.block_hash)
-		.with_stage(jaeger::Stage::ApprovalChecking);
-
 	let block_entry = match db.load_block_entry(&approval.block_hash)? {
 		Some(b) => b,
-		None => {
-			respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::UnknownBlock(
-				approval.block_hash
-			),))
-		},
+		None =>
+			return Ok(Err(ApprovalCheckResult::Bad(ApprovalCheckError::UnknownBlock(
+				approval.block_hash,
+			)))),
 	};
 
 	let approved_candidates_info: Result<Vec<(CandidateIndex, CandidateHash)>, ApprovalCheckError> =
@@ -2755,11 +2927,96 @@ where
 
 	let approved_candidates_info = match approved_candidates_info {
 		Ok(approved_candidates_info) => approved_candidates_info,
-		Err(err) => {
-			respond_early!(ApprovalCheckResult::Bad(err))
-		},
+		Err(err) => return Ok(Err(ApprovalCheckResult::Bad(err))),
 	};
 
+	let session_info = match get_session_info(
+		session_info_provider,
+		sender,
+		approval.block_hash,
+		block_entry.session(),
+	)
+	.await
+	{
+		Some(s) => s,
+		None =>
+			return Ok(Err(ApprovalCheckResult::Bad(ApprovalCheckError::UnknownSessionIndex(
+				block_entry.session(),
+			)))),
+	};
+
+	let pubkey = match session_info.validators.get(approval.validator) {
+		Some(k) => k.clone(),
+		None =>
+			return Ok(Err(ApprovalCheckResult::Bad(ApprovalCheckError::InvalidValidatorIndex(
+				approval.validator,
+			)))),
+	};
+
+	gum::trace!(
+		target: LOG_TARGET,
+		"Received approval for num_candidates {:}",
+		approval.candidate_indices.count_ones()
+	);
+
+	let candidate_hashes: Vec<CandidateHash> =
+		approved_candidates_info.iter().map(|candidate| candidate.1).collect();
+	let first_candidate_hash = match candidate_hashes.first() {
+		Some(candidate_hash) => *candidate_hash,
+		None =>
+			return Ok(Err(ApprovalCheckResult::Bad(ApprovalCheckError::InvalidValidatorIndex(
+				approval.validator,
+			)))),
+	};
+
+	// Signature check:
+	let (tx, rx) = oneshot::channel();
+	verification_pool
+		.submit(
+			VerificationTask::Approval {
+				candidate_hashes,
+				session_index: block_entry.session(),
+				pubkey,
+				signature: approval.signature.clone(),
+				response: tx,
+			},
+			first_candidate_hash,
+		)
+		.await;
+
+	Ok(Ok(PendingApproval { approval, approved_candidates_info, signature_valid: rx }))
+}
+
+// Imports an approval once its signature has been checked by the verification pool.
+async fn import_checked_approval<Sender>(
+	sender: &mut Sender,
+	state: &mut State,
+	db: &mut OverlayedBackend<'_, impl Backend>,
+	session_info_provider: &mut RuntimeInfo,
+	metrics: &Metrics,
+	pending: PendingApproval,
+	wakeups: &Wakeups,
+) -> SubsystemResult<(ApprovalCheckResult, Vec<Action>)>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	macro_rules! respond_early {
+		($e: expr) => {{
+			return Ok(($e, Vec::new()))
+		}};
+	}
+
+	let PendingApproval { approval, approved_candidates_info, signature_valid } = pending;
+
+	let mut span = state
+		.spans
+		.get(&approval.block_hash)
+		.map(|span| span.child("check-and-import-approval"))
+		.unwrap_or_else(|| jaeger::Span::new(approval.block_hash, "check-and-import-approval"))
+		.with_string_fmt_debug_tag("candidate-index", approval.candidate_indices.clone())
+		.with_relay_parent(approval.block_hash)
+		.with_stage(jaeger::Stage::ApprovalChecking);
+
 	span.add_string_tag("candidate-hashes", format!("{:?}", approved_candidates_info));
 	span.add_string_tag(
 		"traceIDs",
@@ -2774,66 +3031,25 @@ where
 		),
 	);
 
-	{
-		let session_info = match get_session_info(
-			session_info_provider,
-			sender,
-			approval.block_hash,
-			block_entry.session(),
-		)
-		.await
-		{
-			Some(s) => s,
-			None => {
-				respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::UnknownSessionIndex(
-					block_entry.session()
-				),))
-			},
-		};
-
-		let pubkey = match session_info.validators.get(approval.validator) {
-			Some(k) => k,
-			None => respond_early!(ApprovalCheckResult::Bad(
-				ApprovalCheckError::InvalidValidatorIndex(approval.validator),
-			)),
-		};
-
-		gum::trace!(
-			target: LOG_TARGET,
-			"Received approval for num_candidates {:}",
-			approval.candidate_indices.count_ones()
-		);
-
-		let candidate_hashes: Vec<CandidateHash> =
-			approved_candidates_info.iter().map(|candidate| candidate.1).collect();
-		// Signature check:
-		match DisputeStatement::Valid(
-			ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(candidate_hashes.clone()),
-		)
-		.check_signature(
-			&pubkey,
-			if let Some(candidate_hash) = candidate_hashes.first() {
-				*candidate_hash
-			} else {
-				respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::InvalidValidatorIndex(
-					approval.validator
-				),))
-			},
-			block_entry.session(),
-			&approval.signature,
-		) {
-			Err(_) => {
-				gum::error!(
-					target: LOG_TARGET,
-					"Error while checking signature {:}",
-					approval.candidate_indices.count_ones()
-				);
-				respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::InvalidSignature(
-					approval.validator
-				),))
-			},
-			Ok(()) => {},
-		};
+	match signature_valid.await {
+		Ok(true) => {},
+		Ok(false) => {
+			gum::error!(
+				target: LOG_TARGET,
+				"Error while checking signature {:}",
+				approval.candidate_indices.count_ones()
+			);
+			respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::InvalidSignature(
+				approval.validator
+			),))
+		},
+		Err(_) => {
+			gum::warn!(target: LOG_TARGET, "Verification worker dropped an approval check");
+			respond_early!(ApprovalCheckResult::Bad(ApprovalCheckError::Internal(
+				approval.block_hash,
+				approved_candidates_info[0].1
+			),))
+		},
 	}
 
 	let mut actions = Vec::new();
@@ -2897,10 +3113,7 @@ where
 		actions.extend(new_actions);
 	}
 
-	// importing the approval can be heavy as it may trigger acceptance for a series of blocks.
-	let t = with_response(ApprovalCheckResult::Accepted);
-
-	Ok((actions, t))
+	Ok((ApprovalCheckResult::Accepted, actions))
 }
 
 #[derive(Debug)]
@@ -0,0 +1,198 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Verification of assignment certificates and approval signatures.
+//!
+//! Checking an incoming assignment or approval is dominated by the VRF or signature
+//! verification, which does not depend on the state of the approval voting subsystem. The
+//! verification is therefore carried out by a pool of workers running on blocking threads,
+//! while the preparation and the import of the checked messages stay on the subsystem main loop.
+//!
+//! Tasks are sharded by candidate, so all messages about a candidate are verified by the same
+//! worker, in the order they were submitted.
+
+use futures::{
+	channel::{mpsc, oneshot},
+	prelude::*,
+};
+use polkadot_node_primitives::approval::{
+	v1::{DelayTranche, RelayVRFStory},
+	v2::{AssignmentCertV2, CoreBitfield},
+};
+use polkadot_node_subsystem::overseer;
+use polkadot_primitives::{
+	CandidateHash, DisputeStatement, GroupIndex, SessionIndex, ValidDisputeStatementKind,
+	ValidatorId, ValidatorIndex, ValidatorSignature,
+};
+use std::{num::NonZeroUsize, sync::Arc};
+
+use crate::{
+	criteria::{self, AssignmentCriteria, InvalidAssignment},
+	LOG_TARGET,
+};
+
+/// The maximum number of verification workers.
+pub(crate) const MAX_WORKERS: NonZeroUsize = match NonZeroUsize::new(4) {
+	Some(max_workers) => max_workers,
+	None => panic!("MAX_WORKERS must be non-zero"),
+};
+
+// The number of tasks which can be queued for each worker.
+const WORKER_QUEUE_SIZE: usize = 64;
+
+/// A CPU intensive check carried out by a verification worker.
+pub(crate) enum VerificationTask {
+	/// Check an assignment certificate, yielding the tranche of the assignment.
+	Assignment {
+		claimed_core_indices: CoreBitfield,
+		validator_index: ValidatorIndex,
+		config: criteria::Config,
+		relay_vrf_story: RelayVRFStory,
+		cert: AssignmentCertV2,
+		backing_groups: Vec<GroupIndex>,
+		response: oneshot::Sender<Result<DelayTranche, InvalidAssignment>>,
+	},
+	/// Check the signature of an approval vote for one or more candidates, yielding whether it
+	/// is valid.
+	Approval {
+		candidate_hashes: Vec<CandidateHash>,
+		session_index: SessionIndex,
+		pubkey: ValidatorId,
+		signature: ValidatorSignature,
+		response: oneshot::Sender<bool>,
+	},
+}
+
+impl VerificationTask {
+	fn run(self, assignment_criteria: &(dyn AssignmentCriteria + Send + Sync)) {
+		match self {
+			VerificationTask::Assignment {
+				claimed_core_indices,
+				validator_index,
+				config,
+				relay_vrf_story,
+				cert,
+				backing_groups,
+				response,
+			} => {
+				let _ = response.send(assignment_criteria.check_assignment_cert(
+					claimed_core_indices,
+					validator_index,
+					&config,
+					relay_vrf_story,
+					&cert,
+					backing_groups,
+				));
+			},
+			VerificationTask::Approval {
+				candidate_hashes,
+				session_index,
+				pubkey,
+				signature,
+				response,
+			} => {
+				let valid = match candidate_hashes.first().copied() {
+					Some(candidate_hash) => DisputeStatement::Valid(
+						ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(
+							candidate_hashes,
+						),
+					)
+					.check_signature(&pubkey, candidate_hash, session_index, &signature)
+					.is_ok(),
+					None => false,
+				};
+				let _ = response.send(valid);
+			},
+		}
+	}
+}
+
+/// A pool of workers verifying assignment certificates and approval signatures.
+///
+/// Tasks for which no worker is available are run in place.
+pub(crate) struct VerificationPool {
+	assignment_criteria: Arc<dyn AssignmentCriteria + Send + Sync>,
+	workers: Vec<mpsc::Sender<VerificationTask>>,
+}
+
+impl VerificationPool {
+	/// Creates a pool of `size` workers, where 1 <= `size` <= `MAX_WORKERS`.
+	///
+	/// Each worker is created by `spawn_blocking` and runs `verification_worker` on the receiver
+	/// side of its own channel.
+	#[overseer::contextbounds(ApprovalVoting, prefix = self::overseer)]
+	pub(crate) fn spawn<Context>(
+		ctx: &mut Context,
+		size: NonZeroUsize,
+		assignment_criteria: Arc<dyn AssignmentCriteria + Send + Sync>,
+	) -> Self {
+		let size = std::cmp::min(size, MAX_WORKERS);
+		let mut workers = Vec::with_capacity(size.get());
+
+		for index in 0..size.get() {
+			let (tx, rx) = mpsc::channel(WORKER_QUEUE_SIZE);
+
+			if let Err(err) = ctx.spawn_blocking(
+				"approval-verification-worker",
+				Box::pin(verification_worker(assignment_criteria.clone(), rx)),
+			) {
+				gum::warn!(
+					target: LOG_TARGET,
+					?err,
+					index,
+					"Failed to spawn a verification worker",
+				);
+				continue
+			}
+
+			workers.push(tx);
+		}
+
+		VerificationPool { assignment_criteria, workers }
+	}
+
+	/// Submits a task to the worker in charge of `candidate_hash`.
+	pub(crate) async fn submit(&mut self, task: VerificationTask, candidate_hash: CandidateHash) {
+		if self.workers.is_empty() {
+			return task.run(&*self.assignment_criteria)
+		}
+
+		let shard = candidate_hash.0.to_low_u64_le() as usize % self.workers.len();
+		let worker = &mut self.workers[shard];
+		let sent = match future::poll_fn(|cx| worker.poll_ready(cx)).await {
+			Ok(()) => worker.try_send(task).map_err(|err| err.into_inner()),
+			Err(_) => Err(task),
+		};
+
+		if let Err(task) = sent {
+			gum::debug!(target: LOG_TARGET, shard, "Verification worker is gone, verifying in place");
+			self.workers.swap_remove(shard);
+			task.run(&*self.assignment_criteria);
+		}
+	}
+}
+
+// Runs the CPU intensive verification tasks on a dedicated blocking thread.
+async fn verification_worker(
+	assignment_criteria: Arc<dyn AssignmentCriteria + Send + Sync>,
+	mut tasks: mpsc::Receiver<VerificationTask>,
+) {
+	while let Some(task) = tasks.next().await {
+		task.run(&*assignment_criteria);
+	}
+
+	gum::trace!(target: LOG_TARGET, "Verification task channel closed. Node shutting down ?");
+}
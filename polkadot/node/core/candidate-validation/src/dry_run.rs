@@ -0,0 +1,258 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-running the validation of collations captured by collators.
+//!
+//! Validators reject a candidate for the first reason they find and report it in the logs of
+//! whichever subsystem found it. A dry run takes a [`CollationSnapshot`] instead, performs the
+//! checks backing performs one after the other and reports the outcome of each of them. The
+//! execution goes through the same path and PVF host as candidates validated for backing.
+
+use polkadot_node_core_pvf::ValidationHost;
+use polkadot_node_primitives::{CollationSnapshot, ValidationResult};
+use polkadot_node_subsystem::messages::ValidationFailed;
+use polkadot_node_subsystem_util::inclusion_emulator::{
+	ConstraintModifications, Constraints, Fragment, RelayChainBlockInfo,
+};
+use polkadot_primitives::{
+	async_backing::{BackingState, CandidatePendingAvailability},
+	CommittedCandidateReceipt, PersistedValidationData, PvfExecKind,
+};
+
+use codec::Encode;
+
+use std::sync::Arc;
+
+use super::{perform_basic_checks, validate_candidate_exhaustive, Metrics, ValidationBackend};
+
+/// The outcome of a single check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckOutcome {
+	/// The candidate passed the check.
+	Passed,
+	/// The candidate failed the check, for the given reason.
+	Failed(String),
+	/// The check could not be performed, for the given reason.
+	Skipped(String),
+}
+
+/// A check performed during a dry run, along with its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+	/// What is checked.
+	pub name: &'static str,
+	/// The outcome of the check.
+	pub outcome: CheckOutcome,
+}
+
+/// The checks performed during a dry run, in the order they were performed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DryRunReport {
+	/// All checks performed.
+	pub checks: Vec<Check>,
+}
+
+impl DryRunReport {
+	/// Whether the candidate passed all checks which could be performed.
+	pub fn is_valid(&self) -> bool {
+		self.failures().next().is_none()
+	}
+
+	/// The checks the candidate failed.
+	pub fn failures(&self) -> impl Iterator<Item = &Check> {
+		self.checks
+			.iter()
+			.filter(|check| matches!(check.outcome, CheckOutcome::Failed(_)))
+	}
+
+	fn note(&mut self, name: &'static str, outcome: CheckOutcome) {
+		self.checks.push(Check { name, outcome });
+	}
+
+	fn note_that(&mut self, name: &'static str, passed: bool, reason: impl FnOnce() -> String) {
+		let outcome = if passed { CheckOutcome::Passed } else { CheckOutcome::Failed(reason()) };
+		self.note(name, outcome);
+	}
+}
+
+/// Dry-runs the validation of the collation captured in `snapshot`, executing it on
+/// `validation_host` as backing would.
+pub async fn dry_run_collation(
+	validation_host: ValidationHost,
+	snapshot: CollationSnapshot,
+) -> DryRunReport {
+	dry_run(validation_host, snapshot).await
+}
+
+pub(crate) async fn dry_run(
+	validation_backend: impl ValidationBackend + Send,
+	snapshot: CollationSnapshot,
+) -> DryRunReport {
+	let CollationSnapshot {
+		candidate,
+		pov,
+		validation_data,
+		validation_code,
+		executor_params,
+		backing_state,
+	} = snapshot;
+	let descriptor = &candidate.descriptor;
+	let commitments = &candidate.commitments;
+	let mut report = DryRunReport::default();
+
+	let pov_size = pov.encoded_size();
+	report.note_that("PoV size", pov_size <= validation_data.max_pov_size as usize, || {
+		format!(
+			"the PoV has {} bytes, the limit is {} bytes",
+			pov_size, validation_data.max_pov_size,
+		)
+	});
+	report.note_that("PoV hash", pov.hash() == descriptor.pov_hash, || {
+		format!("the PoV hashes to {:?}, the descriptor has {:?}", pov.hash(), descriptor.pov_hash)
+	});
+	report.note_that(
+		"validation code hash",
+		validation_code.hash() == descriptor.validation_code_hash,
+		|| {
+			format!(
+				"the validation code of the para hashes to {:?}, the descriptor has {:?}",
+				validation_code.hash(),
+				descriptor.validation_code_hash,
+			)
+		},
+	);
+	report.note_that("collator signature", descriptor.check_collator_signature().is_ok(), || {
+		format!("the descriptor is not signed by collator {:?}", descriptor.collator)
+	});
+	report.note_that(
+		"persisted validation data hash",
+		validation_data.hash() == descriptor.persisted_validation_data_hash,
+		|| {
+			format!(
+				"the persisted validation data hashes to {:?}, the descriptor has {:?}",
+				validation_data.hash(),
+				descriptor.persisted_validation_data_hash,
+			)
+		},
+	);
+	report.note_that("head data", commitments.head_data.hash() == descriptor.para_head, || {
+		format!(
+			"the head data hashes to {:?}, the descriptor has {:?}",
+			commitments.head_data.hash(),
+			descriptor.para_head,
+		)
+	});
+
+	let constraints_outcome = match backing_state {
+		Some(backing_state) => check_constraints(
+			backing_state,
+			RelayChainBlockInfo {
+				hash: descriptor.relay_parent,
+				number: validation_data.relay_parent_number,
+				storage_root: validation_data.relay_parent_storage_root,
+			},
+			&candidate,
+			&validation_data,
+		),
+		None => CheckOutcome::Skipped("the relay chain does not support async backing".into()),
+	};
+	report.note("relay chain constraints", constraints_outcome);
+
+	// Execution is never reached if any of the basic checks fails.
+	let execution_outcome = match perform_basic_checks(
+		descriptor,
+		validation_data.max_pov_size,
+		&pov,
+		&validation_code.hash(),
+	) {
+		Err(_) => CheckOutcome::Skipped("the candidate is rejected before execution".into()),
+		Ok(()) => match validate_candidate_exhaustive(
+			validation_backend,
+			validation_data,
+			validation_code,
+			candidate.to_plain(),
+			Arc::new(pov),
+			executor_params,
+			PvfExecKind::Backing,
+			&Metrics::default(),
+		)
+		.await
+		{
+			Ok(ValidationResult::Valid(..)) => CheckOutcome::Passed,
+			Ok(ValidationResult::Invalid(err)) => CheckOutcome::Failed(format!("{:?}", err)),
+			Err(ValidationFailed(err)) =>
+				CheckOutcome::Skipped(format!("the candidate could not be validated: {}", err)),
+		},
+	};
+	report.note("execution", execution_outcome);
+
+	report
+}
+
+/// Checks the candidate against the constraints of the para at the relay-parent, after applying
+/// the outputs of the candidates pending availability it builds upon. This covers the parent head
+/// data, the validation code, the relay-parent and the UMP, HRMP and DMP limits.
+fn check_constraints(
+	backing_state: BackingState,
+	relay_parent: RelayChainBlockInfo,
+	candidate: &CommittedCandidateReceipt,
+	validation_data: &PersistedValidationData,
+) -> CheckOutcome {
+	let mut constraints = Constraints::from(backing_state.constraints);
+
+	for pending in backing_state.pending_availability {
+		constraints = match constraints.apply_modifications(&pending_modifications(&pending)) {
+			Ok(constraints) => constraints,
+			Err(err) =>
+				return CheckOutcome::Skipped(format!(
+					"the outputs of candidate {:?} pending availability could not be applied: {:?}",
+					pending.candidate_hash, err,
+				)),
+		};
+	}
+
+	match Fragment::check_against_constraints(
+		&relay_parent,
+		&constraints,
+		&candidate.commitments,
+		&candidate.descriptor.validation_code_hash,
+		validation_data,
+	) {
+		Ok(_) => CheckOutcome::Passed,
+		Err(err) => CheckOutcome::Failed(format!("{:?}", err)),
+	}
+}
+
+/// The modifications a candidate pending availability makes to the constraints of its para.
+///
+/// The relay chain already accepted the candidate, so its HRMP watermark is left out: it may
+/// not be valid under the constraints at a later relay-parent anymore.
+fn pending_modifications(pending: &CandidatePendingAvailability) -> ConstraintModifications {
+	let commitments = &pending.commitments;
+	let mut modifications = ConstraintModifications::identity();
+
+	modifications.required_parent = Some(commitments.head_data.clone());
+	for message in &commitments.horizontal_messages {
+		let record = modifications.outbound_hrmp.entry(message.recipient).or_default();
+		record.bytes_submitted += message.data.len();
+		record.messages_submitted += 1;
+	}
+	modifications.ump_messages_sent = commitments.upward_messages.len();
+	modifications.ump_bytes_sent = commitments.upward_messages.iter().map(|msg| msg.len()).sum();
+	modifications.dmp_messages_processed = commitments.processed_downward_messages as _;
+
+	modifications
+}
@@ -69,6 +69,8 @@ use async_trait::async_trait;
 mod metrics;
 use self::metrics::Metrics;
 
+pub mod dry_run;
+
 #[cfg(test)]
 mod tests;
 
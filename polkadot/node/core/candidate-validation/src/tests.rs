@@ -18,10 +18,19 @@ use super::*;
 use assert_matches::assert_matches;
 use futures::executor;
 use polkadot_node_core_pvf::PrepareError;
+use polkadot_node_primitives::CollationSnapshot;
 use polkadot_node_subsystem::messages::AllMessages;
 use polkadot_node_subsystem_util::reexports::SubsystemContext;
-use polkadot_primitives::{HeadData, Id as ParaId, UpwardMessage};
-use polkadot_primitives_test_helpers::{dummy_hash, make_valid_candidate_descriptor};
+use polkadot_primitives::{
+	async_backing::{
+		BackingState, CandidatePendingAvailability, Constraints as PrimitiveConstraints,
+		InboundHrmpLimitations,
+	},
+	CandidateHash, CommittedCandidateReceipt, HeadData, Id as ParaId, UpwardMessage,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_candidate_descriptor, dummy_hash, make_valid_candidate_descriptor,
+};
 use sp_core::testing::TaskExecutor;
 use sp_keyring::Sr25519Keyring;
 
@@ -1263,3 +1272,151 @@ fn precheck_properly_classifies_outcomes() {
 	inner(Err(PrepareError::TimedOut), PreCheckOutcome::Failed);
 	inner(Err(PrepareError::IoErr("fizz".to_owned())), PreCheckOutcome::Failed);
 }
+
+fn dry_run_snapshot(
+	max_pov_size: u32,
+	para_head: HeadData,
+	commitments: CandidateCommitments,
+	constraints: PrimitiveConstraints,
+) -> CollationSnapshot {
+	let validation_data = PersistedValidationData {
+		parent_head: HeadData(vec![1]),
+		relay_parent_number: 5,
+		relay_parent_storage_root: dummy_hash(),
+		max_pov_size,
+	};
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		para_head.hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	);
+
+	// The candidate builds on top of the one pending availability.
+	let pending = CandidatePendingAvailability {
+		candidate_hash: CandidateHash(dummy_hash()),
+		descriptor: dummy_candidate_descriptor(dummy_hash()),
+		commitments: CandidateCommitments {
+			head_data: validation_data.parent_head.clone(),
+			..Default::default()
+		},
+		relay_parent_number: 4,
+		max_pov_size,
+	};
+
+	CollationSnapshot {
+		candidate: CommittedCandidateReceipt { descriptor, commitments },
+		pov,
+		validation_data,
+		validation_code: validation_code.clone(),
+		executor_params: ExecutorParams::default(),
+		backing_state: Some(BackingState {
+			constraints: PrimitiveConstraints {
+				validation_code_hash: validation_code.hash(),
+				max_pov_size,
+				..constraints
+			},
+			pending_availability: vec![pending],
+		}),
+	}
+}
+
+fn dry_run_constraints() -> PrimitiveConstraints {
+	PrimitiveConstraints {
+		min_relay_parent_number: 0,
+		max_pov_size: 0,
+		max_code_size: 1_000,
+		ump_remaining: 10,
+		ump_remaining_bytes: 1_000,
+		max_ump_num_per_candidate: 10,
+		dmp_remaining_messages: vec![],
+		hrmp_inbound: InboundHrmpLimitations { valid_watermarks: vec![] },
+		hrmp_channels_out: vec![],
+		max_hrmp_num_per_candidate: 0,
+		required_parent: HeadData(vec![0]),
+		validation_code_hash: ValidationCode(vec![]).hash(),
+		upgrade_restriction: None,
+		future_validation_code: None,
+	}
+}
+
+#[test]
+fn dry_run_of_valid_collation_passes_all_checks() {
+	let commitments = CandidateCommitments {
+		head_data: HeadData(vec![1, 1, 1]),
+		upward_messages: vec![vec![1, 2, 3]].try_into().unwrap(),
+		hrmp_watermark: 5,
+		..Default::default()
+	};
+	let validation_result = WasmValidationResult {
+		head_data: commitments.head_data.clone(),
+		new_validation_code: None,
+		upward_messages: commitments.upward_messages.clone(),
+		horizontal_messages: Default::default(),
+		processed_downward_messages: 0,
+		hrmp_watermark: 5,
+	};
+	let snapshot =
+		dry_run_snapshot(1024, commitments.head_data.clone(), commitments, dry_run_constraints());
+
+	let report = executor::block_on(dry_run::dry_run(
+		MockValidateCandidateBackend::with_hardcoded_result(Ok(validation_result)),
+		snapshot,
+	));
+
+	assert!(report.is_valid());
+	assert_eq!(
+		report.checks.iter().map(|check| check.name).collect::<Vec<_>>(),
+		vec![
+			"PoV size",
+			"PoV hash",
+			"validation code hash",
+			"collator signature",
+			"persisted validation data hash",
+			"head data",
+			"relay chain constraints",
+			"execution",
+		],
+	);
+	assert!(report.checks.iter().all(|check| check.outcome == dry_run::CheckOutcome::Passed));
+}
+
+#[test]
+fn dry_run_reports_every_failed_check() {
+	let commitments = CandidateCommitments {
+		head_data: HeadData(vec![1, 1, 1]),
+		upward_messages: vec![vec![1, 2, 3]].try_into().unwrap(),
+		hrmp_watermark: 5,
+		..Default::default()
+	};
+	let snapshot = dry_run_snapshot(
+		10,
+		HeadData(vec![2, 2, 2]),
+		commitments,
+		PrimitiveConstraints { max_ump_num_per_candidate: 0, ..dry_run_constraints() },
+	);
+
+	// Execution is not attempted, the backend panics if it is called.
+	let report = executor::block_on(dry_run::dry_run(
+		MockValidateCandidateBackend::with_hardcoded_result_list(vec![]),
+		snapshot,
+	));
+
+	assert!(!report.is_valid());
+	assert_eq!(
+		report.failures().map(|check| check.name).collect::<Vec<_>>(),
+		vec!["PoV size", "head data", "relay chain constraints"],
+	);
+	assert_matches!(&report.checks.last().unwrap().outcome, dry_run::CheckOutcome::Skipped(_));
+	assert_matches!(
+		&report.checks[6].outcome,
+		dry_run::CheckOutcome::Failed(reason) if reason.contains("UmpMessagesPerCandidateOverflow")
+	);
+}
@@ -0,0 +1,68 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! dispute-coordinator regression tests
+//!
+//! Disputes benchmark based on Kusama parameters and scale.
+//!
+//! Subsystems involved:
+//! - dispute-coordinator
+//! - dispute-distribution
+//! - availability-recovery
+
+use polkadot_subsystem_bench::{
+	configuration::TestConfiguration,
+	disputes::{benchmark_dispute_coordinator, prepare_test, DisputesOptions, TestState},
+	usage::BenchmarkUsage,
+	utils::save_to_file,
+};
+use std::io::Write;
+
+const BENCH_COUNT: usize = 10;
+
+fn main() -> Result<(), String> {
+	let mut config = TestConfiguration::default();
+	config.n_cores = 20;
+	config.n_validators = 300;
+	config.num_blocks = 5;
+	config.generate_pov_sizes();
+	let options = DisputesOptions { n_disputes: 5, n_invalid: 2 };
+	let state = TestState::new(&config, &options);
+
+	println!("Benchmarking...");
+	let usages: Vec<BenchmarkUsage> = (0..BENCH_COUNT)
+		.map(|n| {
+			print!("\r[{}{}]", "#".repeat(n), "_".repeat(BENCH_COUNT - n));
+			std::io::stdout().flush().unwrap();
+			let (mut env, _cfgs) = prepare_test(&state, false);
+			env.runtime().block_on(benchmark_dispute_coordinator(&mut env, &state))
+		})
+		.collect();
+	println!("\rDone!{}", " ".repeat(BENCH_COUNT));
+
+	let average_usage = BenchmarkUsage::average(&usages);
+	save_to_file(
+		"charts/dispute-coordinator-regression-bench.json",
+		average_usage.to_chart_json().map_err(|e| e.to_string())?,
+	)
+	.map_err(|e| e.to_string())?;
+	println!("{}", average_usage);
+
+	// The usage is not checked against baselines until they are measured on the CI runners, the
+	// chart tracks it meanwhile. A run fails if the node under test does not participate in all
+	// disputes in time.
+	Ok(())
+}
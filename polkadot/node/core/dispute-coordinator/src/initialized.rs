@@ -17,7 +17,7 @@
 //! Dispute coordinator subsystem in initialized state (after first active leaf is received).
 
 use std::{
-	collections::{BTreeMap, VecDeque},
+	collections::{btree_map::Entry, BTreeMap, HashSet, VecDeque},
 	sync::Arc,
 };
 
@@ -29,13 +29,13 @@ use futures::{
 use sc_keystore::LocalKeystore;
 
 use polkadot_node_primitives::{
-	disputes::ValidCandidateVotes, CandidateVotes, DisputeStatus, SignedDisputeStatement,
-	Timestamp, DISPUTE_WINDOW,
+	disputes::ValidCandidateVotes, CandidateVotes, DisputeStatus, DisputeSummary,
+	SignedDisputeStatement, Timestamp, DISPUTE_WINDOW,
 };
 use polkadot_node_subsystem::{
 	messages::{
 		ApprovalVotingMessage, BlockDescription, ChainSelectionMessage, DisputeCoordinatorMessage,
-		DisputeDistributionMessage, ImportStatementsResult,
+		DisputeDistributionMessage, ImportStatementsResult, RecentDisputesError,
 	},
 	overseer, ActivatedLeaf, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, RuntimeApiError,
 };
@@ -52,7 +52,7 @@ use schnellru::{LruMap, UnlimitedCompact};
 use crate::{
 	db,
 	error::{log_error, FatalError, FatalResult, JfyiError, JfyiResult, Result},
-	import::{CandidateEnvironment, CandidateVoteState},
+	import::{find_controlled_validator_indices, CandidateEnvironment, CandidateVoteState},
 	is_potential_spam,
 	metrics::Metrics,
 	scraping::ScrapedUpdates,
@@ -866,6 +866,14 @@ impl Initialized {
 						.collect(),
 				);
 			},
+			DisputeCoordinatorMessage::RecentDisputeSummaries(tx) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					"DisputeCoordinatorMessage::RecentDisputeSummaries"
+				);
+				let summaries = self.recent_dispute_summaries(ctx, overlay_db).await?;
+				let _ = tx.send(summaries);
+			},
 			DisputeCoordinatorMessage::QueryCandidateVotes(query, tx) => {
 				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::QueryCandidateVotes");
 				let mut query_output = Vec::new();
@@ -926,6 +934,89 @@ impl Initialized {
 		Ok(Box::new(|| Ok(())))
 	}
 
+	/// Summarize all recent disputes, from the point of view of this node.
+	///
+	/// The validators controlled by this node are determined once per session, from the
+	/// `SessionInfo` cached on active leaves updates. Should it not be available for any session,
+	/// no summaries are returned.
+	async fn recent_dispute_summaries<Context>(
+		&mut self,
+		ctx: &mut Context,
+		overlay_db: &mut OverlayedBackend<'_, impl Backend>,
+	) -> Result<std::result::Result<Vec<DisputeSummary>, RecentDisputesError>> {
+		let recent_disputes = overlay_db.load_recent_disputes()?.unwrap_or_default();
+
+		let mut controlled_per_session: BTreeMap<SessionIndex, HashSet<ValidatorIndex>> =
+			BTreeMap::new();
+		let mut summaries = Vec::with_capacity(recent_disputes.len());
+		for ((session, candidate_hash), status) in recent_disputes {
+			let votes: CandidateVotes =
+				match overlay_db.load_candidate_votes(session, &candidate_hash)? {
+					Some(votes) => votes.into(),
+					None => {
+						gum::debug!(
+							target: LOG_TARGET,
+							?candidate_hash,
+							session,
+							"No votes found for recent dispute",
+						);
+						continue
+					},
+				};
+
+			let controlled_indices = match controlled_per_session.entry(session) {
+				Entry::Occupied(entry) => entry.into_mut(),
+				Entry::Vacant(entry) => {
+					let session_info = match self
+						.runtime_info
+						.get_session_info_by_index(
+							ctx.sender(),
+							votes.candidate_receipt.descriptor.relay_parent,
+							session,
+						)
+						.await
+					{
+						Ok(extended_session_info) => &extended_session_info.session_info,
+						Err(err) => {
+							gum::debug!(
+								target: LOG_TARGET,
+								session,
+								?err,
+								"Could not retrieve session info for recent dispute",
+							);
+							return Ok(Err(RecentDisputesError::SessionInfoUnavailable(session)))
+						},
+					};
+					entry.insert(find_controlled_validator_indices(
+						&self.keystore,
+						&session_info.validators,
+					))
+				},
+			};
+
+			let local_vote = if controlled_indices.iter().any(|i| votes.invalid.contains_key(i)) {
+				Some(false)
+			} else if controlled_indices.iter().any(|i| votes.valid.raw().contains_key(i)) {
+				Some(true)
+			} else {
+				None
+			};
+
+			summaries.push(DisputeSummary {
+				session,
+				candidate_hash,
+				para_id: votes.candidate_receipt.descriptor.para_id,
+				status,
+				valid_votes: votes.valid.raw().len() as u32,
+				invalid_votes: votes.invalid.len() as u32,
+				local_vote,
+				local_validator: !controlled_indices.is_empty(),
+			});
+		}
+
+		Ok(Ok(summaries))
+	}
+
 	// We use fatal result rather than result here. Reason being, We for example increase
 	// spam slots in this function. If then the import fails for some non fatal and
 	// unrelated reason, we should likely actually decrement previously incremented spam
@@ -32,8 +32,8 @@ use futures::{
 use polkadot_node_subsystem_util::database::Database;
 
 use polkadot_node_primitives::{
-	DisputeMessage, DisputeStatus, SignedDisputeStatement, SignedFullStatement, Statement,
-	DISPUTE_WINDOW,
+	DisputeMessage, DisputeStatus, DisputeSummary, SignedDisputeStatement, SignedFullStatement,
+	Statement, DISPUTE_WINDOW,
 };
 use polkadot_node_subsystem::{
 	messages::{
@@ -1091,6 +1091,91 @@ fn dispute_gets_confirmed_via_participation() {
 	});
 }
 
+#[test]
+fn recent_dispute_summaries_include_local_vote() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(
+					&mut virtual_overseer,
+					session,
+					1,
+					vec![make_candidate_backed_event(candidate_receipt.clone())],
+				)
+				.await;
+
+			let (valid_vote, invalid_vote) = generate_opposing_votes_pair(
+				&test_state,
+				ValidatorIndex(3),
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				VoteType::Explicit,
+			)
+			.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(valid_vote, ValidatorIndex(3)),
+							(invalid_vote, ValidatorIndex(1)),
+						],
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			participation_with_distribution(
+				&mut virtual_overseer,
+				&candidate_hash,
+				candidate_receipt.commitments_hash,
+			)
+			.await;
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::RecentDisputeSummaries(tx),
+				})
+				.await;
+
+			assert_eq!(
+				rx.await.unwrap(),
+				Ok(vec![DisputeSummary {
+					session,
+					candidate_hash,
+					para_id: candidate_receipt.descriptor.para_id,
+					status: DisputeStatus::Active,
+					valid_votes: 2,
+					invalid_votes: 1,
+					local_vote: Some(true),
+					local_validator: true,
+				}])
+			);
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+
+			// No more messages expected:
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn dispute_gets_confirmed_at_byzantine_threshold() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -18,7 +18,10 @@
 //!
 //! # Lifecycle of an artifact
 //!
-//! 1. During node start-up, we prune all the cached artifacts, if any.
+//! 1. During node start-up, we reload the artifacts cached by previous runs of the same node
+//!    version. An artifact is only reloaded if its checksum matches the one recorded in its file
+//!    name. All the other artifacts, e.g. those prepared by other node versions, corrupted ones or
+//!    ones using an unknown naming scheme, are pruned.
 //!
 //! 2. In order to be executed, a PVF should be prepared first. This means that artifacts should
 //!    have an [`ArtifactState::Prepared`] entry for that artifact in the table. If not, the
@@ -34,8 +37,9 @@
 //!
 //! 3. The pool gets an available worker and instructs it to work on the given PVF. The worker
 //!    starts compilation. When the worker finishes successfully, it writes the serialized artifact
-//!    into a temporary file and notifies the host that it's done. The host atomically moves
-//!    (renames) the temporary file to the destination filename of the artifact.
+//!    into a temporary file and notifies the host that it's done, along with the checksum of the
+//!    artifact. The host atomically moves (renames) the temporary file to the destination filename
+//!    of the artifact, which encodes the node version, the artifact id and the checksum.
 //!
 //! 4. If the worker concluded successfully or returned an error, then the pool notifies the queue.
 //!    In both cases, the queue reports to the host that the result is ready.
@@ -54,9 +58,11 @@
 //!    older by a predefined parameter. This process is run very rarely (say, once a day). Once the
 //!    artifact is expired it is removed from disk eagerly atomically.
 
-use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX};
+use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX, LOG_TARGET};
 use always_assert::always;
+use codec::Decode;
 use polkadot_node_core_pvf_common::{error::PrepareError, prepare::PrepareStats, pvf::PvfPrepData};
+use polkadot_node_primitives::NODE_VERSION;
 use polkadot_parachain_primitives::primitives::ValidationCodeHash;
 use polkadot_primitives::ExecutorParamsPrepHash;
 use std::{
@@ -72,17 +78,15 @@ const ARTIFACT_EXTENSION: &str = "pvf";
 /// The prefix that artifacts used to start with under the old naming scheme.
 const ARTIFACT_OLD_PREFIX: &str = "wasmtime_";
 
-pub fn generate_artifact_path(cache_path: &Path) -> PathBuf {
-	let file_name = {
-		use array_bytes::Hex;
-		use rand::RngCore;
-		let mut bytes = [0u8; 64];
-		rand::thread_rng().fill_bytes(&mut bytes);
-		bytes.hex("0x")
-	};
-	let mut artifact_path = cache_path.join(file_name);
-	artifact_path.set_extension(ARTIFACT_EXTENSION);
-	artifact_path
+/// The prefix of the node version tag that the file names of artifacts start with.
+const NODE_PREFIX: &str = "polkadot_v";
+
+/// The number of random bytes in the file names of artifacts.
+const ARTIFACT_NONCE_LEN: usize = 8;
+
+/// Returns the prefix of the file names of artifacts prepared by this node version.
+fn artifact_prefix() -> String {
+	format!("{}{}", NODE_PREFIX, NODE_VERSION)
 }
 
 /// Identifier of an artifact. Encodes a code hash of the PVF and a hash of preparation-related
@@ -107,6 +111,57 @@ impl ArtifactId {
 	pub fn from_pvf_prep_data(pvf: &PvfPrepData) -> Self {
 		Self::new(pvf.code_hash(), pvf.executor_params().prep_hash())
 	}
+
+	/// Returns a new path to a concluded artifact with the given checksum.
+	///
+	/// The file name tags the artifact with the node version and its id, so that it can be
+	/// reloaded after a restart, and with its checksum, so that it can be verified. It also
+	/// contains some randomness so that a re-prepared artifact never conflicts with a previous one
+	/// which is still being removed.
+	pub(crate) fn path(&self, cache_path: &Path, checksum: &str) -> PathBuf {
+		let nonce = {
+			use array_bytes::Hex;
+			use rand::RngCore;
+			let mut bytes = [0u8; ARTIFACT_NONCE_LEN];
+			rand::thread_rng().fill_bytes(&mut bytes);
+			bytes.hex("")
+		};
+		let file_name = format!(
+			"{}_{:#x}_{:#x}_{}_{}",
+			artifact_prefix(),
+			self.code_hash,
+			self.executor_params_prep_hash,
+			checksum,
+			nonce,
+		);
+		let mut artifact_path = cache_path.join(file_name);
+		artifact_path.set_extension(ARTIFACT_EXTENSION);
+		artifact_path
+	}
+
+	/// Tries to recover the artifact id and the checksum from the file name of an artifact.
+	///
+	/// Returns `None` if the file name was not produced by [`ArtifactId::path`] on this node
+	/// version.
+	fn from_file_name(file_name: &str) -> Option<(Self, &str)> {
+		fn decode_hash<T: Decode>(hex: &str) -> Option<T> {
+			let bytes = array_bytes::hex2bytes(hex).ok()?;
+			(bytes.len() == 32).then(|| T::decode(&mut &bytes[..]).ok()).flatten()
+		}
+
+		let file_name = file_name.strip_suffix(ARTIFACT_EXTENSION)?.strip_suffix('.')?;
+		let file_name = file_name.strip_prefix(&artifact_prefix())?.strip_prefix('_')?;
+		let mut parts = file_name.split('_');
+		let code_hash = decode_hash(parts.next()?)?;
+		let executor_params_prep_hash = decode_hash(parts.next()?)?;
+		let checksum = parts.next()?;
+		let _nonce = parts.next()?;
+		if parts.next().is_some() {
+			return None
+		}
+
+		Some((Self::new(code_hash, executor_params_prep_hash), checksum))
+	}
 }
 
 /// A bundle of the artifact ID and the path.
@@ -214,14 +269,39 @@ impl Artifacts {
 		self.inner.keys().cloned().collect()
 	}
 
-	/// Create an empty table and the cache directory on-disk if it doesn't exist.
+	/// Create the cache directory on-disk if it doesn't exist and build the table from the
+	/// artifacts found in it.
+	///
+	/// Only the artifacts prepared by this node version and whose checksum is valid are kept. They
+	/// are tagged with the hash of the preparation-related executor parameters they were prepared
+	/// with, so an artifact is never used for a PVF with different parameters.
 	pub async fn new(cache_path: &Path) -> Self {
 		// Make sure that the cache path directory and all its parents are created.
 		let _ = tokio::fs::create_dir_all(cache_path).await;
 
-		// Delete any leftover artifacts and worker dirs from previous runs. We don't delete the
-		// entire cache directory in case the user made a mistake and set it to e.g. their home
-		// directory. This is a best-effort to do clean-up, so ignore any errors.
+		// Reading and hashing the artifacts blocks, and can take a while with a large cache, so do
+		// it on a blocking thread.
+		let cache_path = cache_path.to_owned();
+		let inner = tokio::task::spawn_blocking(move || Self::reload(&cache_path))
+			.await
+			.unwrap_or_else(|err| {
+				gum::warn!(target: LOG_TARGET, "failed to reload the artifacts: {}", err);
+				HashMap::new()
+			});
+
+		Self { inner }
+	}
+
+	/// Builds the table from the artifacts found in the cache directory, and prunes the ones which
+	/// cannot be reused.
+	fn reload(cache_path: &Path) -> HashMap<ArtifactId, ArtifactState> {
+		let mut inner = HashMap::new();
+		let now = SystemTime::now();
+
+		// Delete any leftover worker dirs, and any artifacts from previous runs that cannot be
+		// reused. We don't delete the entire cache directory in case the user made a mistake and
+		// set it to e.g. their home directory. This is a best-effort to do clean-up, so ignore any
+		// errors.
 		for entry in fs::read_dir(cache_path).into_iter().flatten().flatten() {
 			let path = entry.path();
 			let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else { continue };
@@ -230,11 +310,59 @@ impl Artifacts {
 			} else if path.extension().map_or(false, |ext| ext == ARTIFACT_EXTENSION) ||
 				file_name.starts_with(ARTIFACT_OLD_PREFIX)
 			{
-				let _ = fs::remove_file(path);
+				let Some((artifact_id, size)) = Self::verify_artifact(&path, file_name) else {
+					let _ = fs::remove_file(path);
+					continue
+				};
+				if let Some(ArtifactState::Prepared { path: duplicate, .. }) = inner.insert(
+					artifact_id,
+					ArtifactState::Prepared {
+						path,
+						last_time_needed: now,
+						size,
+						prepare_stats: PrepareStats::default(),
+					},
+				) {
+					let _ = fs::remove_file(duplicate);
+				}
 			}
 		}
 
-		Self { inner: HashMap::new() }
+		gum::debug!(
+			target: LOG_TARGET,
+			"reloaded {} artifacts from the cache at {}",
+			inner.len(),
+			cache_path.display(),
+		);
+
+		inner
+	}
+
+	/// Checks that the artifact at `path` was prepared by this node version and that its contents
+	/// match the checksum in its file name. Returns the artifact id and its size if so.
+	fn verify_artifact(path: &Path, file_name: &str) -> Option<(ArtifactId, u64)> {
+		let Some((artifact_id, checksum)) = ArtifactId::from_file_name(file_name) else {
+			gum::debug!(target: LOG_TARGET, "pruning stale artifact {}", path.display());
+			return None
+		};
+		let bytes = match fs::read(path) {
+			Ok(bytes) => bytes,
+			Err(err) => {
+				gum::warn!(
+					target: LOG_TARGET,
+					"failed to read the artifact {}: {}",
+					path.display(),
+					err,
+				);
+				return None
+			},
+		};
+		if blake3::hash(&bytes).to_hex().as_str() != checksum {
+			gum::warn!(target: LOG_TARGET, "pruning corrupted artifact {}", path.display());
+			return None
+		}
+
+		Some((artifact_id, bytes.len() as u64))
 	}
 
 	/// Returns the state of the given artifact by its ID.
@@ -363,18 +491,66 @@ mod tests {
 		assert_eq!(artifacts.len(), 0);
 	}
 
+	#[tokio::test]
+	async fn cache_reloaded_on_startup() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+		let checksum = |bytes: &[u8]| blake3::hash(bytes).to_hex().to_string();
+
+		// This should be reloaded.
+		let path1 = artifact_id(1).path(cache_path, &checksum(b"artifact"));
+		fs::write(&path1, "artifact").unwrap();
+
+		// These should be cleared: a corrupted artifact, an artifact of another node version and
+		// an artifact of a malformed name.
+		let path2 = artifact_id(2).path(cache_path, &checksum(b"artifact"));
+		fs::write(&path2, "corrupted").unwrap();
+		let path3 = artifact_id(3).path(cache_path, &checksum(b"artifact"));
+		let file_name = path3.file_name().unwrap().to_str().unwrap();
+		let path3 = cache_path.join(file_name.replacen(NODE_VERSION, "0.0.0", 1));
+		fs::write(&path3, "artifact").unwrap();
+		let path4 = cache_path.join(format!(
+			"{}_0x00_0x00_{}_00.pvf",
+			artifact_prefix(),
+			checksum(b"artifact")
+		));
+		fs::write(&path4, "artifact").unwrap();
+
+		let mut artifacts = Artifacts::new(cache_path).await;
+
+		assert!(path1.exists());
+		assert!(!path2.exists());
+		assert!(!path3.exists());
+		assert!(!path4.exists());
+		assert_eq!(artifacts.artifact_ids(), vec![artifact_id(1)]);
+		assert_matches::assert_matches!(
+			artifacts.artifact_state_mut(&artifact_id(1)),
+			Some(ArtifactState::Prepared { path, size: 8, .. }) if *path == path1
+		);
+	}
+
+	#[test]
+	fn artifact_file_name_roundtrip() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let path = artifact_id(1).path(tempdir.path(), "checksum");
+		let file_name = path.file_name().unwrap().to_str().unwrap();
+
+		assert_eq!(ArtifactId::from_file_name(file_name), Some((artifact_id(1), "checksum")));
+		assert_ne!(path, artifact_id(1).path(tempdir.path(), "checksum"));
+	}
+
 	#[tokio::test]
 	async fn test_pruned_by_cache_size() {
 		let mock_now = SystemTime::now();
 		let tempdir = tempfile::tempdir().unwrap();
 		let cache_path = tempdir.path();
 
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
-		let path3 = generate_artifact_path(cache_path);
 		let artifact_id1 = artifact_id(1);
 		let artifact_id2 = artifact_id(2);
 		let artifact_id3 = artifact_id(3);
+		let path1 = artifact_id1.path(cache_path, "");
+		let path2 = artifact_id2.path(cache_path, "");
+		let path3 = artifact_id3.path(cache_path, "");
 
 		let mut artifacts = Artifacts::new(cache_path).await;
 		let cleanup_config = ArtifactsCleanupConfig::new(1500, Duration::from_secs(0));
@@ -417,12 +593,12 @@ mod tests {
 		let tempdir = tempfile::tempdir().unwrap();
 		let cache_path = tempdir.path();
 
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
-		let path3 = generate_artifact_path(cache_path);
 		let artifact_id1 = artifact_id(1);
 		let artifact_id2 = artifact_id(2);
 		let artifact_id3 = artifact_id(3);
+		let path1 = artifact_id1.path(cache_path, "");
+		let path2 = artifact_id2.path(cache_path, "");
+		let path3 = artifact_id3.path(cache_path, "");
 
 		let mut artifacts = Artifacts::new(cache_path).await;
 		let cleanup_config = ArtifactsCleanupConfig::new(1500, Duration::from_secs(12));
@@ -223,7 +223,8 @@ pub async fn start(
 ) -> SubsystemResult<(ValidationHost, impl Future<Output = ()>)> {
 	gum::debug!(target: LOG_TARGET, ?config, "starting PVF validation host");
 
-	// Make sure the cache is initialized before doing anything else.
+	// Make sure the cache is initialized, and the artifacts of previous runs reloaded, before doing
+	// anything else.
 	let artifacts = Artifacts::new(&config.cache_path).await;
 
 	// Run checks for supported security features once per host startup. If some checks fail, warn
@@ -902,7 +903,7 @@ async fn handle_artifact_removal(
 		.send(())
 		.expect("the execute queue waits for the artifact remove confirmation; qed");
 	// Thanks to the randomness of the artifact name (see
-	// `artifacts::generate_artifact_path`) there is no issue with any name conflict on
+	// `artifacts::ArtifactId::path`) there is no issue with any name conflict on
 	// future repreparation.
 	// So we can confirm the artifact removal already
 	gum::debug!(
@@ -963,7 +964,7 @@ fn pulse_every(interval: std::time::Duration) -> impl futures::Stream<Item = ()>
 #[cfg(test)]
 pub(crate) mod tests {
 	use super::*;
-	use crate::{artifacts::generate_artifact_path, testing::artifact_id, PossiblyInvalidError};
+	use crate::{testing::artifact_id, PossiblyInvalidError};
 	use assert_matches::assert_matches;
 	use futures::future::BoxFuture;
 	use polkadot_node_core_pvf_common::prepare::PrepareStats;
@@ -1182,8 +1183,8 @@ pub(crate) mod tests {
 		let mut builder = Builder::default();
 		builder.cleanup_pulse_interval = Duration::from_millis(100);
 		builder.cleanup_config = ArtifactsCleanupConfig::new(1024, Duration::from_secs(0));
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
+		let path1 = artifact_id(1).path(cache_path, "");
+		let path2 = artifact_id(2).path(cache_path, "");
 		builder.artifacts.insert_prepared(
 			artifact_id(1),
 			path1.clone(),
@@ -81,6 +81,10 @@
 //! doesn't contain the artifact contents though, only a flag for the state of the given artifact
 //! and some associated data. If the artifact failed to process, this also includes the error.
 //!
+//! Artifacts are kept across restarts of the node. On startup, the table is rebuilt from the
+//! artifacts found on disk which were prepared by the same node version and whose checksum is
+//! valid.
+//!
 //! A pruning task will run at a fixed interval of time. This task will remove all artifacts that
 //! weren't used or received a heads up signal for a while.
 //!
@@ -17,7 +17,7 @@
 //! Host interface to the prepare worker.
 
 use crate::{
-	artifacts::generate_artifact_path,
+	artifacts::ArtifactId,
 	metrics::Metrics,
 	worker_interface::{
 		clear_worker_dir_path, framed_recv, framed_send, spawn_with_program_path, IdleWorker,
@@ -165,6 +165,7 @@ pub async fn start_work(
 						prepare_worker_result,
 						pid,
 						tmp_artifact_file,
+						ArtifactId::from_pvf_prep_data(&pvf),
 						&cache_path,
 						preparation_timeout,
 					)
@@ -204,22 +205,19 @@ async fn handle_response(
 	result: PrepareWorkerResult,
 	worker_pid: u32,
 	tmp_file: PathBuf,
+	artifact_id: ArtifactId,
 	cache_path: &Path,
 	preparation_timeout: Duration,
 ) -> Outcome {
-	// TODO: Add `checksum` to `ArtifactPathId`. See:
-	//       https://github.com/paritytech/polkadot-sdk/issues/2399
-	let PrepareWorkerSuccess {
-		checksum: _,
-		stats: PrepareStats { cpu_time_elapsed, memory_stats },
-	} = match result.clone() {
-		Ok(result) => result,
-		// Timed out on the child. This should already be logged by the child.
-		Err(PrepareError::TimedOut) => return Outcome::TimedOut,
-		Err(PrepareError::JobDied { err, job_pid }) => return Outcome::JobDied { err, job_pid },
-		Err(PrepareError::OutOfMemory) => return Outcome::OutOfMemory,
-		Err(err) => return Outcome::Concluded { worker, result: Err(err) },
-	};
+	let PrepareWorkerSuccess { checksum, stats: PrepareStats { cpu_time_elapsed, memory_stats } } =
+		match result.clone() {
+			Ok(result) => result,
+			// Timed out on the child. This should already be logged by the child.
+			Err(PrepareError::TimedOut) => return Outcome::TimedOut,
+			Err(PrepareError::JobDied { err, job_pid }) => return Outcome::JobDied { err, job_pid },
+			Err(PrepareError::OutOfMemory) => return Outcome::OutOfMemory,
+			Err(err) => return Outcome::Concluded { worker, result: Err(err) },
+		};
 
 	if cpu_time_elapsed > preparation_timeout {
 		// The job didn't complete within the timeout.
@@ -234,12 +232,12 @@ async fn handle_response(
 		return Outcome::TimedOut
 	}
 
-	let size = match tokio::fs::metadata(cache_path).await {
+	let size = match tokio::fs::metadata(&tmp_file).await {
 		Ok(metadata) => metadata.len(),
 		Err(err) => {
 			gum::warn!(
 				target: LOG_TARGET,
-				?cache_path,
+				?tmp_file,
 				"failed to read size of the artifact: {}",
 				err,
 			);
@@ -247,11 +245,10 @@ async fn handle_response(
 		},
 	};
 
-	// The file name should uniquely identify the artifact even across restarts. In case the cache
-	// for some reason is not cleared correctly, we cannot
-	// accidentally execute an artifact compiled under a different wasmtime version, host
-	// environment, etc.
-	let artifact_path = generate_artifact_path(cache_path);
+	// The file name identifies the artifact even across restarts, and records its checksum so that
+	// it can be verified before being reloaded. This way we cannot accidentally execute an artifact
+	// compiled under a different node version or executor parameters, or one that got corrupted.
+	let artifact_path = artifact_id.path(cache_path, &checksum);
 
 	gum::debug!(
 		target: LOG_TARGET,
@@ -440,8 +440,17 @@ async fn corrupted_prepared_artifact_does_not_dispute() {
 	);
 }
 
+// Returns the paths to the artifacts in the given cache dir.
+fn artifact_paths(cache_dir: &std::path::Path) -> Vec<std::path::PathBuf> {
+	std::fs::read_dir(cache_dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.filter(|path| path.extension().map_or(false, |ext| ext == "pvf"))
+		.collect()
+}
+
 #[tokio::test]
-async fn cache_cleared_on_startup() {
+async fn cache_reloaded_on_startup() {
 	// Don't drop this host, it owns the `TempDir` which gets cleared on drop.
 	let host = TestHost::new().await;
 
@@ -453,8 +462,41 @@ async fn cache_cleared_on_startup() {
 	// The cache dir should contain one artifact and one worker dir.
 	let cache_dir = host.cache_dir.path().to_owned();
 	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 2);
+	let artifacts = artifact_paths(&cache_dir);
+	assert_eq!(artifacts.len(), 1);
+
+	// Start a new host, the previous artifact should be kept while the worker dir is cleared.
+	let new_host = TestHost::new_with_config(|cfg| {
+		cfg.cache_path = cache_dir.clone();
+	})
+	.await;
+	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+	// The reloaded artifact is used without preparing the PVF again.
+	new_host
+		.precheck_pvf(test_parachain_halt::wasm_binary_unwrap(), Default::default())
+		.await
+		.unwrap();
+	assert_eq!(artifact_paths(&cache_dir), artifacts);
+}
+
+#[tokio::test]
+async fn corrupted_cache_cleared_on_startup() {
+	// Don't drop this host, it owns the `TempDir` which gets cleared on drop.
+	let host = TestHost::new().await;
+
+	let _stats = host
+		.precheck_pvf(test_parachain_halt::wasm_binary_unwrap(), Default::default())
+		.await
+		.unwrap();
+
+	// Corrupt the artifact.
+	let cache_dir = host.cache_dir.path().to_owned();
+	let artifacts = artifact_paths(&cache_dir);
+	assert_eq!(artifacts.len(), 1);
+	std::fs::write(&artifacts[0], b"corrupted wasm").unwrap();
 
-	// Start a new host, previous artifact should be cleared.
+	// Start a new host, the corrupted artifact should be cleared.
 	let _host = TestHost::new_with_config(|cfg| {
 		cfg.cache_path = cache_dir.clone();
 	})
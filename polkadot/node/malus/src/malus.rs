@@ -42,6 +42,10 @@ enum NemesisVariant {
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
 	/// Spam many request statements instead of sending a single one.
 	SpamStatementRequests(SpamStatementRequestsOptions),
+	/// Refuse to serve erasure chunks after backing.
+	WithholdAvailabilityChunks(WithholdAvailabilityChunksOptions),
+	/// Issue conflicting `Seconded` statements when backing.
+	SecondConflictingCandidates(SecondConflictingCandidatesOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -105,6 +109,33 @@ impl MalusCli {
 
 				polkadot_cli::run_node(cli, SpamStatementRequests { spam_factor }, finality_delay)?
 			},
+			NemesisVariant::WithholdAvailabilityChunks(opts) => {
+				let WithholdAvailabilityChunksOptions {
+					percentage,
+					validator_indices,
+					withhold_available_data,
+					cli,
+				} = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdAvailabilityChunks {
+						percentage,
+						validator_indices,
+						withhold_available_data,
+					},
+					finality_delay,
+				)?
+			},
+			NemesisVariant::SecondConflictingCandidates(opts) => {
+				let SecondConflictingCandidatesOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					SecondConflictingCandidates { percentage },
+					finality_delay,
+				)?
+			},
 		}
 		Ok(())
 	}
@@ -244,4 +275,45 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn withhold_availability_chunks_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-availability-chunks",
+			"--validator-indices",
+			"1,3,5",
+			"--withhold-available-data",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdAvailabilityChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 100);
+			assert_eq!(opts.validator_indices, vec![1, 3, 5]);
+			assert!(opts.withhold_available_data);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn percentage_works_second_conflicting_candidates() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"second-conflicting-candidates",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::SecondConflictingCandidates(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
 }
@@ -0,0 +1,404 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that equivocates in backing.
+//!
+//! This malus variant seconds the candidates it is asked to second honestly. On top of that, for
+//! each such candidate it signs `Seconded` statements for as many conflicting candidates as the
+//! seconding limit allows per relay parent: same para, relay parent, parent head and collator
+//! signature, but different commitments. Along with the honest statement, this makes the node
+//! second one candidate more than it is allowed to at the relay parent.
+//!
+//! Statement distribution refuses to share `Seconded` statements beyond the seconding limit, so
+//! the conflicting statements are sent by the network bridge instead, to the peers the honest
+//! statement is sent to. The peers report the node for the excessive `Seconded` statements.
+//!
+//! Some extra quirks which generally should be insignificant:
+//! - The conflicting candidates are not backed by a valid PoV and the node cannot serve them, so
+//! honest group members will fail to fetch them.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_network_protocol::{
+	v2 as protocol_v2, v3 as protocol_v3, PeerId, Versioned, VersionedValidationProtocol,
+};
+use polkadot_node_subsystem::{messages::NetworkBridgeTxMessage, SpawnGlue};
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_node_subsystem_util::{
+	request_async_backing_params, request_session_index_for_child, request_validators,
+	signing_key_and_index,
+};
+use polkadot_primitives::{
+	CandidateDescriptor, CandidateHash, CommittedCandidateReceipt, CompactStatement,
+	SignedStatement, SigningContext, UncheckedSignedStatement,
+};
+use sp_core::traits::SpawnNamed;
+use sp_keystore::KeystorePtr;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS, variants::create_fake_candidate_commitments};
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+
+/// The conflicting `Seconded` statements signed for each candidate seconded by the node, until
+/// they are sent along with the statement of the candidate.
+type ConflictingStatements = Arc<Mutex<HashMap<CandidateHash, Vec<UncheckedSignedStatement>>>>;
+
+/// Wraps around candidate backing and signs conflicting `Seconded` statements for every candidate
+/// it is asked to second.
+#[derive(Clone)]
+struct EquivocateSecond<Spawner> {
+	spawner: Spawner,
+	keystore: KeystorePtr,
+	percentage: f64,
+	conflicting_statements: ConflictingStatements,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for EquivocateSecond<Spawner>
+where
+	Sender: overseer::CandidateBackingSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = CandidateBackingMessage;
+
+	/// Intercept incoming `Second` requests from the `collator-protocol` subsystem, pass them
+	/// through and sign conflicting statements alongside.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg:
+					CandidateBackingMessage::Second(
+						relay_parent,
+						ref candidate,
+						ref validation_data,
+						ref _pov,
+					),
+			} => {
+				let distribution = Bernoulli::new(self.percentage / 100.0)
+					.expect("Invalid probability! Percentage must be in range [0..=100].");
+				if !distribution.sample(&mut rand::thread_rng()) {
+					return Some(msg)
+				}
+
+				let mut sender = subsystem_sender.clone();
+				let keystore = self.keystore.clone();
+				let conflicting_statements = self.conflicting_statements.clone();
+				let candidate = candidate.clone();
+				let validation_data = validation_data.clone();
+				self.spawner.spawn_blocking(
+					"malus-second-conflicting-candidate",
+					Some("malus"),
+					Box::pin(async move {
+						let validators =
+							match request_validators(relay_parent, &mut sender).await.await {
+								Ok(Ok(validators)) => validators,
+								_ => {
+									gum::error!(
+										target: MALUS,
+										?relay_parent,
+										"😈 Failed to fetch validators",
+									);
+									return
+								},
+							};
+						let session_index =
+							match request_session_index_for_child(relay_parent, &mut sender)
+								.await
+								.await
+							{
+								Ok(Ok(session_index)) => session_index,
+								_ => {
+									gum::error!(
+										target: MALUS,
+										?relay_parent,
+										"😈 Failed to fetch session index",
+									);
+									return
+								},
+							};
+						let seconding_limit =
+							match request_async_backing_params(relay_parent, &mut sender)
+								.await
+								.await
+							{
+								Ok(Ok(params)) => params.max_candidate_depth as usize + 1,
+								_ => {
+									gum::error!(
+										target: MALUS,
+										?relay_parent,
+										"😈 Failed to fetch async backing params",
+									);
+									return
+								},
+							};
+						let Some((validator_id, validator_index)) =
+							signing_key_and_index(&validators, &keystore)
+						else {
+							gum::debug!(
+								target: MALUS,
+								?relay_parent,
+								"😈 Not a validator, nothing to equivocate with.",
+							);
+							return
+						};
+
+						let signing_context =
+							SigningContext { session_index, parent_hash: relay_parent };
+						let mut statements = Vec::with_capacity(seconding_limit);
+						for i in 0..seconding_limit {
+							// Every conflicting candidate builds on the same parent head as the
+							// seconded one, and has a different head of its own.
+							let mut commitments =
+								create_fake_candidate_commitments(&validation_data);
+							commitments.head_data.0.extend((i as u32).to_le_bytes());
+							let conflicting_candidate = CommittedCandidateReceipt {
+								descriptor: CandidateDescriptor {
+									para_head: commitments.head_data.hash(),
+									..candidate.descriptor.clone()
+								},
+								commitments,
+							};
+							let conflicting_candidate_hash = conflicting_candidate.hash();
+
+							match SignedStatement::sign(
+								&keystore,
+								CompactStatement::Seconded(conflicting_candidate_hash),
+								&signing_context,
+								validator_index,
+								&validator_id,
+							) {
+								Ok(Some(statement)) => statements.push(statement.into()),
+								_ => {
+									gum::error!(
+										target: MALUS,
+										?relay_parent,
+										"😈 Failed to sign conflicting statement",
+									);
+									return
+								},
+							}
+
+							gum::info!(
+								target: MALUS,
+								candidate_hash = ?candidate.hash(),
+								?conflicting_candidate_hash,
+								"😈 Seconding conflicting candidate.",
+							);
+						}
+
+						conflicting_statements
+							.lock()
+							.expect("poisoned lock")
+							.insert(candidate.hash(), statements);
+					}),
+				);
+
+				Some(msg)
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+/// Wraps around the network bridge and sends the conflicting `Seconded` statements along with the
+/// statement of the candidate they conflict with.
+#[derive(Clone)]
+struct SendConflictingStatements {
+	conflicting_statements: ConflictingStatements,
+}
+
+impl SendConflictingStatements {
+	/// Returns the messages carrying the conflicting statements of the candidate seconded in
+	/// `msg`, if any, to be sent to `peers`.
+	fn conflicting_messages(
+		&self,
+		peers: &[PeerId],
+		msg: &VersionedValidationProtocol,
+	) -> Vec<(Vec<PeerId>, VersionedValidationProtocol)> {
+		let (relay_parent, statement) = match msg {
+			Versioned::V2(protocol_v2::ValidationProtocol::StatementDistribution(
+				protocol_v2::StatementDistributionMessage::Statement(relay_parent, statement),
+			)) |
+			Versioned::V3(protocol_v3::ValidationProtocol::StatementDistribution(
+				protocol_v3::StatementDistributionMessage::Statement(relay_parent, statement),
+			)) => (*relay_parent, statement),
+			_ => return Vec::new(),
+		};
+		let CompactStatement::Seconded(candidate_hash) = statement.unchecked_payload() else {
+			return Vec::new()
+		};
+		let Some(statements) =
+			self.conflicting_statements.lock().expect("poisoned lock").remove(candidate_hash)
+		else {
+			return Vec::new()
+		};
+
+		gum::info!(
+			target: MALUS,
+			?candidate_hash,
+			n_peers = peers.len(),
+			"😈 Sending {} conflicting `Seconded` statements.",
+			statements.len(),
+		);
+
+		statements
+			.into_iter()
+			.map(|statement| {
+				let msg = match msg {
+					Versioned::V3(_) => Versioned::V3(
+						protocol_v3::StatementDistributionMessage::Statement(
+							relay_parent,
+							statement,
+						)
+						.into(),
+					),
+					_ => Versioned::V2(
+						protocol_v2::StatementDistributionMessage::Statement(
+							relay_parent,
+							statement,
+						)
+						.into(),
+					),
+				};
+				(peers.to_vec(), msg)
+			})
+			.collect()
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for SendConflictingStatements
+where
+	Sender: overseer::NetworkBridgeTxSenderTrait + Clone + Send + 'static,
+{
+	type Message = NetworkBridgeTxMessage;
+
+	/// Intercept the statements sent by statement distribution and send the conflicting
+	/// statements of the seconded candidates along.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessage(peers, msg),
+			} => {
+				let mut msgs = vec![(peers.clone(), msg.clone())];
+				msgs.extend(self.conflicting_messages(&peers, &msg));
+				let msg = if msgs.len() == 1 {
+					NetworkBridgeTxMessage::SendValidationMessage(peers, msg)
+				} else {
+					NetworkBridgeTxMessage::SendValidationMessages(msgs)
+				};
+				Some(FromOrchestra::Communication { msg })
+			},
+			FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessages(msgs),
+			} => {
+				let msgs = msgs
+					.into_iter()
+					.flat_map(|(peers, msg)| {
+						let conflicting = self.conflicting_messages(&peers, &msg);
+						std::iter::once((peers, msg)).chain(conflicting)
+					})
+					.collect();
+				Some(FromOrchestra::Communication {
+					msg: NetworkBridgeTxMessage::SendValidationMessages(msgs),
+				})
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct SecondConflictingCandidatesOptions {
+	/// Determines the percentage of seconded candidates for which conflicting `Seconded`
+	/// statements are issued. Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// SecondConflictingCandidates implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct SecondConflictingCandidates {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for SecondConflictingCandidates {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node with a {:?} percent chance of seconding conflicting candidates.",
+			&self.percentage,
+		);
+
+		let ext_args =
+			ext_args.expect("Extended arguments required to build validator overseer are provided");
+		let conflicting_statements = ConflictingStatements::default();
+		let equivocate_second = EquivocateSecond {
+			spawner: SpawnGlue(args.spawner.clone()),
+			keystore: ext_args.keystore.clone(),
+			percentage: f64::from(self.percentage),
+			conflicting_statements: conflicting_statements.clone(),
+		};
+		let send_conflicting_statements = SendConflictingStatements { conflicting_statements };
+
+		validator_overseer_builder(args, ext_args)?
+			.replace_candidate_backing(move |cb| InterceptedSubsystem::new(cb, equivocate_second))
+			.replace_network_bridge_tx(move |nb| {
+				InterceptedSubsystem::new(nb, send_conflicting_statements)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,185 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that withholds erasure chunks after backing.
+//!
+//! This malus variant behaves honestly in backing, stores all the chunks it receives and keeps
+//! signing availability bitfields for them. The maliciousness comes from refusing to hand out
+//! the stored chunks (and optionally the full available data) whenever they are queried from the
+//! availability store, which is what `availability-distribution` and the available data request
+//! handler rely on to answer network requests.
+//!
+//! Since the node still claims availability, candidates get included and everyone else has to
+//! recover the data from the remaining chunks or from the backers.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::{CandidateHash, ValidatorIndex};
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{collections::HashSet, sync::Arc};
+
+/// Wraps around the availability store and replaces it.
+#[derive(Clone)]
+struct ChunkWithholder {
+	/// Percentage of candidates for which chunks are withheld.
+	percentage: u8,
+	/// Validator indices whose chunks are withheld. Empty means all of them.
+	validator_indices: Arc<HashSet<ValidatorIndex>>,
+	/// Whether to also withhold the full available data.
+	withhold_available_data: bool,
+}
+
+impl ChunkWithholder {
+	/// The decision is derived from the candidate hash, so that all the chunks of a candidate are
+	/// treated the same way regardless of who is asking and when.
+	fn withholds_candidate(&self, candidate_hash: &CandidateHash) -> bool {
+		candidate_hash.0.to_low_u64_be() % 100 < u64::from(self.percentage)
+	}
+
+	fn withholds_chunk(
+		&self,
+		candidate_hash: &CandidateHash,
+		validator_index: &ValidatorIndex,
+	) -> bool {
+		self.withholds_candidate(candidate_hash) &&
+			(self.validator_indices.is_empty() ||
+				self.validator_indices.contains(validator_index))
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ChunkWithholder
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	/// Intercept chunk and available data queries and answer them as if nothing was stored.
+	/// Everything else, including `QueryChunkAvailability` used for bitfield signing and
+	/// `QueryAllChunks` used for local recovery, is passed through untouched.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunk(candidate_hash, validator_index, tx),
+			} if self.withholds_chunk(&candidate_hash, &validator_index) => {
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					?validator_index,
+					"😈 Withholding erasure chunk.",
+				);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAvailableData(candidate_hash, tx),
+			} if self.withhold_available_data && self.withholds_candidate(&candidate_hash) => {
+				gum::info!(target: MALUS, ?candidate_hash, "😈 Withholding available data.",);
+				let _ = tx.send(None);
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdAvailabilityChunksOptions {
+	/// Determines the percentage of candidates for which chunks are withheld.
+	/// Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	/// Only withhold the chunks of these validator indices. Withholds all chunks if empty.
+	#[clap(long, value_delimiter = ',')]
+	pub validator_indices: Vec<u32>,
+
+	/// Also refuse requests for the full available data, disabling recovery from this backer.
+	#[clap(long)]
+	pub withhold_available_data: bool,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdAvailabilityChunks implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdAvailabilityChunks {
+	/// Percentage of candidates for which chunks are withheld.
+	pub percentage: u8,
+	/// Validator indices whose chunks are withheld. Empty means all of them.
+	pub validator_indices: Vec<u32>,
+	/// Whether to also withhold the full available data.
+	pub withhold_available_data: bool,
+}
+
+impl OverseerGen for WithholdAvailabilityChunks {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node withholding chunks of {:?} percent of candidates, validator indices = {:?}, withhold available data = {:?}.",
+			&self.percentage,
+			&self.validator_indices,
+			&self.withhold_available_data,
+		);
+
+		let chunk_withholder = ChunkWithholder {
+			percentage: self.percentage,
+			validator_indices: Arc::new(
+				self.validator_indices.iter().copied().map(ValidatorIndex).collect(),
+			),
+			withhold_available_data: self.withhold_available_data,
+		};
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_store(move |cb| InterceptedSubsystem::new(cb, chunk_withholder))
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
@@ -871,6 +871,7 @@ impl State {
 								MessageSource::Peer(peer_id),
 								assignment,
 								claimed_indices,
+								None,
 								rng,
 							)
 							.await;
@@ -881,6 +882,7 @@ impl State {
 								metrics,
 								MessageSource::Peer(peer_id),
 								approval_vote,
+								None,
 							)
 							.await;
 						},
@@ -936,6 +938,8 @@ impl State {
 	) where
 		R: CryptoRng + Rng,
 	{
+		let mut to_import = Vec::with_capacity(assignments.len());
+		let mut checked = HashSet::new();
 		for (assignment, claimed_indices) in assignments {
 			if let Some(pending) = self.pending_known.get_mut(&assignment.block_hash) {
 				let block_hash = &assignment.block_hash;
@@ -955,18 +959,67 @@ impl State {
 				continue
 			}
 
+			let pending_check = self
+				.check_assignment_ahead(ctx, peer_id, &assignment, &claimed_indices, &mut checked)
+				.await;
+			to_import.push((assignment, claimed_indices, pending_check));
+		}
+
+		for (assignment, claimed_indices, pending_check) in to_import {
 			self.import_and_circulate_assignment(
 				ctx,
 				metrics,
 				MessageSource::Peer(peer_id),
 				assignment,
 				claimed_indices,
+				pending_check,
 				rng,
 			)
 			.await;
 		}
 	}
 
+	// Sends an assignment received from `peer_id` to approval-voting to be checked, if
+	// `import_and_circulate_assignment` would do so.
+	//
+	// Sending all checks of a batch before awaiting any of them lets approval-voting verify them
+	// in parallel. The returned receiver is to be passed on to `import_and_circulate_assignment`.
+	// Assignments from peers out of view and repeated ones, tracked in `checked`, are left to be
+	// checked on import, after the reputation changes for them.
+	async fn check_assignment_ahead<Context>(
+		&self,
+		ctx: &mut Context,
+		peer_id: PeerId,
+		assignment: &IndirectAssignmentCertV2,
+		claimed_candidate_indices: &CandidateBitfield,
+		checked: &mut HashSet<MessageSubject>,
+	) -> Option<oneshot::Receiver<AssignmentCheckResult>> {
+		let entry = self.blocks.get(&assignment.block_hash)?;
+		let peer_knowledge = entry.known_by.get(&peer_id)?;
+		let message_subject = MessageSubject(
+			assignment.block_hash,
+			claimed_candidate_indices.clone(),
+			assignment.validator,
+		);
+
+		if peer_knowledge.contains(&message_subject, MessageKind::Assignment) ||
+			entry.knowledge.contains(&message_subject, MessageKind::Assignment) ||
+			!checked.insert(message_subject)
+		{
+			return None
+		}
+
+		let (tx, rx) = oneshot::channel();
+		ctx.send_message(ApprovalVotingMessage::CheckAndImportAssignment(
+			assignment.clone(),
+			claimed_candidate_indices.clone(),
+			tx,
+		))
+		.await;
+
+		Some(rx)
+	}
+
 	// Entry point for processing an approval coming from a peer.
 	async fn process_incoming_approvals<Context>(
 		&mut self,
@@ -981,6 +1034,8 @@ impl State {
 			num = approvals.len(),
 			"Processing approvals from a peer",
 		);
+		let mut to_import = Vec::with_capacity(approvals.len());
+		let mut checked = HashSet::new();
 		for approval_vote in approvals.into_iter() {
 			if let Some(pending) = self.pending_known.get_mut(&approval_vote.block_hash) {
 				let block_hash = approval_vote.block_hash;
@@ -1000,16 +1055,62 @@ impl State {
 				continue
 			}
 
+			let pending_check =
+				self.check_approval_ahead(ctx, peer_id, &approval_vote, &mut checked).await;
+			to_import.push((approval_vote, pending_check));
+		}
+
+		for (approval_vote, pending_check) in to_import {
 			self.import_and_circulate_approval(
 				ctx,
 				metrics,
 				MessageSource::Peer(peer_id),
 				approval_vote,
+				pending_check,
 			)
 			.await;
 		}
 	}
 
+	// Sends an approval received from `peer_id` to approval-voting to be checked, if
+	// `import_and_circulate_approval` would do so.
+	//
+	// See `check_assignment_ahead`.
+	async fn check_approval_ahead<Context>(
+		&self,
+		ctx: &mut Context,
+		peer_id: PeerId,
+		vote: &IndirectSignedApprovalVoteV2,
+		checked: &mut HashSet<MessageSubject>,
+	) -> Option<oneshot::Receiver<ApprovalCheckResult>> {
+		let entry = self
+			.blocks
+			.get(&vote.block_hash)
+			.filter(|entry| entry.contains_candidates(&vote.candidate_indices))?;
+		let peer_knowledge = entry.known_by.get(&peer_id)?;
+
+		let assignments_knowledge_keys = PeerKnowledge::generate_assignments_keys(vote);
+		let (message_subject, message_kind) = PeerKnowledge::generate_approval_key(vote);
+
+		let assignments_known =
+			assignments_knowledge_keys.iter().all(|(message_subject, message_kind)| {
+				entry.knowledge.contains(message_subject, *message_kind)
+			});
+		if !assignments_known ||
+			peer_knowledge.contains(&message_subject, message_kind) ||
+			entry.knowledge.contains(&message_subject, message_kind) ||
+			!checked.insert(message_subject)
+		{
+			return None
+		}
+
+		let (tx, rx) = oneshot::channel();
+		ctx.send_message(ApprovalVotingMessage::CheckAndImportApproval(vote.clone(), tx))
+			.await;
+
+		Some(rx)
+	}
+
 	async fn process_incoming_peer_message<Context, R>(
 		&mut self,
 		ctx: &mut Context,
@@ -1182,6 +1283,7 @@ impl State {
 		source: MessageSource,
 		assignment: IndirectAssignmentCertV2,
 		claimed_candidate_indices: CandidateBitfield,
+		pending_check: Option<oneshot::Receiver<AssignmentCheckResult>>,
 		rng: &mut R,
 	) where
 		R: CryptoRng + Rng,
@@ -1309,14 +1411,21 @@ impl State {
 				return
 			}
 
-			let (tx, rx) = oneshot::channel();
+			let rx = match pending_check {
+				Some(rx) => rx,
+				None => {
+					let (tx, rx) = oneshot::channel();
 
-			ctx.send_message(ApprovalVotingMessage::CheckAndImportAssignment(
-				assignment.clone(),
-				claimed_candidate_indices.clone(),
-				tx,
-			))
-			.await;
+					ctx.send_message(ApprovalVotingMessage::CheckAndImportAssignment(
+						assignment.clone(),
+						claimed_candidate_indices.clone(),
+						tx,
+					))
+					.await;
+
+					rx
+				},
+			};
 
 			let timer = metrics.time_awaiting_approval_voting();
 			let result = match rx.await {
@@ -1606,6 +1715,7 @@ impl State {
 		metrics: &Metrics,
 		source: MessageSource,
 		vote: IndirectSignedApprovalVoteV2,
+		pending_check: Option<oneshot::Receiver<ApprovalCheckResult>>,
 	) {
 		let _span = self
 			.spans
@@ -1673,10 +1783,20 @@ impl State {
 				return
 			}
 
-			let (tx, rx) = oneshot::channel();
+			let rx = match pending_check {
+				Some(rx) => rx,
+				None => {
+					let (tx, rx) = oneshot::channel();
+
+					ctx.send_message(ApprovalVotingMessage::CheckAndImportApproval(
+						vote.clone(),
+						tx,
+					))
+					.await;
 
-			ctx.send_message(ApprovalVotingMessage::CheckAndImportApproval(vote.clone(), tx))
-				.await;
+					rx
+				},
+			};
 
 			let timer = metrics.time_awaiting_approval_voting();
 			let result = match rx.await {
@@ -2499,6 +2619,7 @@ impl ApprovalDistribution {
 						MessageSource::Local,
 						cert,
 						candidate_indices,
+						None,
 						rng,
 					)
 					.await;
@@ -2512,7 +2633,7 @@ impl ApprovalDistribution {
 				);
 
 				state
-					.import_and_circulate_approval(ctx, metrics, MessageSource::Local, vote)
+					.import_and_circulate_approval(ctx, metrics, MessageSource::Local, vote, None)
 					.await;
 			},
 			ApprovalDistributionMessage::GetApprovalSignatures(indices, tx) => {
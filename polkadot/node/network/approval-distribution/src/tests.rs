@@ -578,6 +578,58 @@ fn try_import_the_same_assignment_v2() {
 	});
 }
 
+/// A peer sending the same assignment twice in one message gets it checked only once, the
+/// second one is treated as a duplicate.
+#[test]
+fn try_import_the_same_assignment_in_one_batch() {
+	let peer_a = PeerId::random();
+	let parent_hash = Hash::repeat_byte(0xFF);
+	let hash = Hash::repeat_byte(0xAA);
+
+	let _ = test_harness(state_without_reputation_delay(), |mut virtual_overseer| async move {
+		let overseer = &mut virtual_overseer;
+		setup_peer_with_view(overseer, &peer_a, view![hash], ValidationVersion::V1).await;
+
+		// new block `hash` with 1 candidate
+		let meta = BlockApprovalMeta {
+			hash,
+			parent_hash,
+			number: 2,
+			candidates: vec![Default::default(); 1],
+			slot: 1.into(),
+			session: 1,
+		};
+		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
+		overseer_send(overseer, msg).await;
+
+		// send the same assignment twice
+		let cert = fake_assignment_cert(hash, ValidatorIndex(0));
+		let assignments = vec![(cert.clone(), 0u32), (cert.clone(), 0u32)];
+
+		let msg = protocol_v1::ApprovalDistributionMessage::Assignments(assignments);
+		send_message_from_peer(overseer, &peer_a, msg).await;
+
+		assert_matches!(
+			overseer_recv(overseer).await,
+			AllMessages::ApprovalVoting(ApprovalVotingMessage::CheckAndImportAssignment(
+				assignment,
+				claimed_indices,
+				tx,
+			)) => {
+				assert_eq!(claimed_indices, 0u32.into());
+				assert_eq!(assignment, cert.into());
+				tx.send(AssignmentCheckResult::Accepted).unwrap();
+			}
+		);
+
+		expect_reputation_change(overseer, &peer_a, BENEFIT_VALID_MESSAGE_FIRST).await;
+		expect_reputation_change(overseer, &peer_a, COST_DUPLICATE_MESSAGE).await;
+
+		assert!(overseer.recv().timeout(TIMEOUT).await.is_none(), "no message should be sent");
+		virtual_overseer
+	});
+}
+
 /// import an assignment
 /// connect a new peer
 /// state sends aggregated reputation change
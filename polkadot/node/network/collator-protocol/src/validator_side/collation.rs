@@ -27,7 +27,12 @@
 //!    ┌──────────────────────────────────────────┐
 //!    └─▶Advertised ─▶ Pending ─▶ Fetched ─▶ Validated
 
-use std::{collections::VecDeque, future::Future, pin::Pin, task::Poll};
+use std::{
+	collections::{HashMap, VecDeque},
+	future::Future,
+	pin::Pin,
+	task::Poll,
+};
 
 use futures::{future::BoxFuture, FutureExt};
 use polkadot_node_network_protocol::{
@@ -46,6 +51,7 @@ use polkadot_primitives::{
 };
 use tokio_util::sync::CancellationToken;
 
+use super::scores::CollatorScores;
 use crate::{error::SecondingError, LOG_TARGET};
 
 /// Candidate supplied with a para head it's built on top of.
@@ -229,12 +235,22 @@ pub struct Collations {
 	pub waiting_queue: VecDeque<(PendingCollation, CollatorId)>,
 	/// How many collations have been seconded.
 	pub seconded_count: usize,
+	/// How many collations have been seconded per para.
+	pub seconded_per_para: HashMap<ParaId, usize>,
+	/// How many fetches have been started per collator.
+	pub fetches_per_collator: HashMap<CollatorId, usize>,
 }
 
 impl Collations {
 	/// Note a seconded collation for a given para.
-	pub(super) fn note_seconded(&mut self) {
-		self.seconded_count += 1
+	pub(super) fn note_seconded(&mut self, para_id: ParaId) {
+		self.seconded_count += 1;
+		*self.seconded_per_para.entry(para_id).or_default() += 1;
+	}
+
+	/// Note that we started fetching a collation from the given collator.
+	pub(super) fn note_fetch(&mut self, collator_id: &CollatorId) {
+		*self.fetches_per_collator.entry(collator_id.clone()).or_default() += 1;
 	}
 
 	/// Returns the next collation to fetch from the `waiting_queue`.
@@ -243,10 +259,15 @@ impl Collations {
 	///
 	/// Returns `Some(_)` if there is any collation to fetch, the `status` is not `Seconded` and
 	/// the passed in `finished_one` is the currently `waiting_collation`.
+	///
+	/// The collation is picked fairly among the paras in the `claim_queue` and the collators
+	/// which advertised them, see [`Self::pick_next_collation`].
 	pub(super) fn get_next_collation_to_fetch(
 		&mut self,
 		finished_one: &(CollatorId, Option<CandidateHash>),
 		relay_parent_mode: ProspectiveParachainsMode,
+		claim_queue: &[ParaId],
+		scores: &CollatorScores,
 	) -> Option<(PendingCollation, CollatorId)> {
 		// If finished one does not match waiting_collation, then we already dequeued another fetch
 		// to replace it.
@@ -273,27 +294,94 @@ impl Collations {
 				if self.is_seconded_limit_reached(relay_parent_mode) {
 					None
 				} else {
-					self.waiting_queue.pop_front()
+					self.pick_next_collation(relay_parent_mode, claim_queue, scores)
 				},
 			CollationStatus::WaitingOnValidation | CollationStatus::Fetching =>
 				unreachable!("We have reset the status above!"),
 		}
 	}
 
+	/// Removes the collation to fetch next from the `waiting_queue`.
+	///
+	/// Paras which reached their share of the seconded limit are skipped. Among the others, the
+	/// para which used the smallest part of its share goes first. Within a para, collators we
+	/// fetched from the least at this relay parent go first, then the ones with a higher score.
+	/// Remaining ties are broken by the order of the advertisements.
+	fn pick_next_collation(
+		&mut self,
+		relay_parent_mode: ProspectiveParachainsMode,
+		claim_queue: &[ParaId],
+		scores: &CollatorScores,
+	) -> Option<(PendingCollation, CollatorId)> {
+		let (index, _) = self
+			.waiting_queue
+			.iter()
+			.enumerate()
+			.filter(|(_, (collation, _))| {
+				!self.is_para_seconded_limit_reached(
+					collation.para_id,
+					relay_parent_mode,
+					claim_queue,
+				)
+			})
+			.min_by(|(_, (a, a_id)), (_, (b, b_id))| {
+				let (a_seconded, a_claims) = self.para_usage(a.para_id, claim_queue);
+				let (b_seconded, b_claims) = self.para_usage(b.para_id, claim_queue);
+
+				// Compare the shares used, paras without claims having a share of one claim.
+				(a_seconded * b_claims.max(1))
+					.cmp(&(b_seconded * a_claims.max(1)))
+					.then_with(|| self.fetches_from(a_id).cmp(&self.fetches_from(b_id)))
+					.then_with(|| scores.score(b_id).cmp(&scores.score(a_id)))
+			})?;
+
+		self.waiting_queue.remove(index)
+	}
+
 	/// Checks the limit of seconded candidates.
 	pub(super) fn is_seconded_limit_reached(
 		&self,
 		relay_parent_mode: ProspectiveParachainsMode,
 	) -> bool {
-		let seconded_limit =
-			if let ProspectiveParachainsMode::Enabled { max_candidate_depth, .. } =
-				relay_parent_mode
-			{
-				max_candidate_depth + 1
-			} else {
-				1
-			};
-		self.seconded_count >= seconded_limit
+		self.seconded_count >= seconded_limit(relay_parent_mode)
+	}
+
+	/// Checks the limit of seconded candidates for a para.
+	///
+	/// The seconded limit is shared among the paras proportionally to the number of their claims
+	/// in the `claim_queue`, rounding up. A para outside of the `claim_queue` may use all of it.
+	pub(super) fn is_para_seconded_limit_reached(
+		&self,
+		para_id: ParaId,
+		relay_parent_mode: ProspectiveParachainsMode,
+		claim_queue: &[ParaId],
+	) -> bool {
+		let seconded_limit = seconded_limit(relay_parent_mode);
+		let para_limit = match self.para_usage(para_id, claim_queue) {
+			(_, 0) => seconded_limit,
+			(_, claims) => (seconded_limit * claims).div_ceil(claim_queue.len()),
+		};
+		self.seconded_per_para.get(&para_id).copied().unwrap_or(0) >= para_limit
+	}
+
+	// Returns the number of seconded candidates and claims in the `claim_queue` of a para.
+	fn para_usage(&self, para_id: ParaId, claim_queue: &[ParaId]) -> (usize, usize) {
+		let seconded = self.seconded_per_para.get(&para_id).copied().unwrap_or(0);
+		let claims = claim_queue.iter().filter(|claim| **claim == para_id).count();
+		(seconded, claims)
+	}
+
+	fn fetches_from(&self, collator_id: &CollatorId) -> usize {
+		self.fetches_per_collator.get(collator_id).copied().unwrap_or(0)
+	}
+}
+
+// The maximum number of candidates seconded per relay parent.
+fn seconded_limit(relay_parent_mode: ProspectiveParachainsMode) -> usize {
+	if let ProspectiveParachainsMode::Enabled { max_candidate_depth, .. } = relay_parent_mode {
+		max_candidate_depth + 1
+	} else {
+		1
 	}
 }
 
@@ -374,3 +462,83 @@ impl Future for CollationFetchRequest {
 		res
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::validator_side::scores::ScoreEvent;
+	use sp_keyring::Sr25519Keyring;
+
+	const MODE: ProspectiveParachainsMode =
+		ProspectiveParachainsMode::Enabled { max_candidate_depth: 2, allowed_ancestry_len: 2 };
+
+	fn advertisement(para_id: ParaId, collator: Sr25519Keyring) -> (PendingCollation, CollatorId) {
+		let pending_collation =
+			PendingCollation::new(Hash::zero(), para_id, &PeerId::random(), None);
+		(pending_collation, collator.public().into())
+	}
+
+	#[test]
+	fn fetches_are_shared_among_paras_and_collators() {
+		let para_a = ParaId::from(1);
+		let para_b = ParaId::from(2);
+		// Shares of the seconded limit of 3 are 2 for `para_a` and 1 for `para_b`.
+		let claim_queue = [para_a, para_a, para_b];
+		let scores = CollatorScores::default();
+
+		let first_a = advertisement(para_a, Sr25519Keyring::Alice);
+		let second_a = advertisement(para_a, Sr25519Keyring::Alice);
+		let third_a = advertisement(para_a, Sr25519Keyring::Bob);
+		let first_b = advertisement(para_b, Sr25519Keyring::Charlie);
+
+		let mut collations = Collations {
+			waiting_queue: [first_a.clone(), second_a, third_a.clone(), first_b.clone()].into(),
+			..Default::default()
+		};
+		collations.note_fetch(&first_a.1);
+		collations.note_seconded(para_a);
+
+		// `para_b` did not use any of its share yet.
+		let next = collations.pick_next_collation(MODE, &claim_queue, &scores);
+		assert_eq!(next.map(|(pc, _)| pc), Some(first_b.0));
+		collations.note_seconded(para_b);
+		assert!(collations.is_para_seconded_limit_reached(para_b, MODE, &claim_queue));
+
+		// We already fetched from Alice, so Bob goes first.
+		let next = collations.pick_next_collation(MODE, &claim_queue, &scores);
+		assert_eq!(next.map(|(pc, _)| pc), Some(third_a.0));
+		collations.note_seconded(para_a);
+
+		assert!(collations.is_para_seconded_limit_reached(para_a, MODE, &claim_queue));
+		assert!(collations.is_seconded_limit_reached(MODE));
+		assert!(collations.pick_next_collation(MODE, &claim_queue, &scores).is_none());
+		assert_eq!(collations.waiting_queue.len(), 2);
+	}
+
+	#[test]
+	fn collators_with_higher_score_are_fetched_first() {
+		let para_id = ParaId::from(1);
+		let claim_queue = [para_id];
+		let mut scores = CollatorScores::default();
+
+		let first = advertisement(para_id, Sr25519Keyring::Alice);
+		let second = advertisement(para_id, Sr25519Keyring::Bob);
+		let third = advertisement(para_id, Sr25519Keyring::Charlie);
+
+		scores.note(first.1.clone(), ScoreEvent::FetchFailed);
+		scores.note(second.1.clone(), ScoreEvent::Seconded);
+		scores.note(third.1.clone(), ScoreEvent::Seconded);
+		scores.note(third.1.clone(), ScoreEvent::Seconded);
+
+		let mut collations = Collations {
+			waiting_queue: [first.clone(), second.clone(), third.clone()].into(),
+			..Default::default()
+		};
+
+		let order: Vec<_> = std::iter::from_fn(|| {
+			collations.pick_next_collation(MODE, &claim_queue, &scores).map(|(pc, _)| pc)
+		})
+		.collect();
+		assert_eq!(order, vec![third.0, second.0, first.0]);
+	}
+}
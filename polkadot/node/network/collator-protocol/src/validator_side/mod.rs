@@ -21,6 +21,7 @@ use futures_timer::Delay;
 use std::{
 	collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
 	future::Future,
+	sync::Arc,
 	time::{Duration, Instant},
 };
 use tokio_util::sync::CancellationToken;
@@ -49,6 +50,7 @@ use polkadot_node_subsystem::{
 };
 use polkadot_node_subsystem_util::{
 	backing_implicit_view::View as ImplicitView,
+	database::Database,
 	reputation::{ReputationAggregator, REPUTATION_CHANGE_INTERVAL},
 	runtime::{prospective_parachains_mode, ProspectiveParachainsMode},
 	vstaging::fetch_claim_queue,
@@ -66,12 +68,14 @@ use super::{modify_reputation, tick_stream, LOG_TARGET};
 
 mod collation;
 mod metrics;
+mod scores;
 
 use collation::{
 	fetched_collation_sanity_check, CollationEvent, CollationFetchError, CollationFetchRequest,
 	CollationStatus, Collations, FetchedCollation, PendingCollation, PendingCollationFetch,
 	ProspectiveCandidate,
 };
+use scores::{CollatorScores, ScoreEvent};
 
 #[cfg(test)]
 mod tests;
@@ -116,6 +120,13 @@ const MAX_UNSHARED_DOWNLOAD_TIME: Duration = Duration::from_millis(100);
 #[cfg(test)]
 const ACTIVITY_POLL: Duration = Duration::from_millis(10);
 
+/// How often the collator scores are written to the database, if they changed.
+#[cfg(not(test))]
+const PERSIST_SCORES_INTERVAL: Duration = Duration::from_secs(60);
+
+#[cfg(test)]
+const PERSIST_SCORES_INTERVAL: Duration = Duration::from_millis(10);
+
 #[derive(Debug)]
 struct CollatingPeerState {
 	collator_id: CollatorId,
@@ -444,6 +455,9 @@ struct State {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// Scores of the collators, deciding the order of collation fetches.
+	collator_scores: CollatorScores,
 }
 
 fn is_relay_parent_in_implicit_view(
@@ -747,6 +761,7 @@ async fn request_collation(
 	let maybe_candidate_hash =
 		prospective_candidate.as_ref().map(ProspectiveCandidate::candidate_hash);
 	per_relay_parent.collations.status = CollationStatus::Fetching;
+	per_relay_parent.collations.note_fetch(&collator_id);
 	per_relay_parent
 		.collations
 		.fetching_from
@@ -1030,6 +1045,7 @@ async fn second_unblocked_collations<Context>(
 		for mut unblocked_collation in unblocked_collations {
 			unblocked_collation.maybe_parent_head_data = Some(head_data.clone());
 			let peer_id = unblocked_collation.collation_event.pending_collation.peer_id;
+			let collator_id = unblocked_collation.collation_event.collator_id.clone();
 			let relay_parent = unblocked_collation.candidate_receipt.descriptor.relay_parent;
 
 			if let Err(err) = kick_off_seconding(ctx, state, unblocked_collation).await {
@@ -1051,6 +1067,7 @@ async fn second_unblocked_collations<Context>(
 						COST_REPORT_BAD,
 					)
 					.await;
+					state.collator_scores.note(collator_id, ScoreEvent::Invalid);
 				}
 			}
 		}
@@ -1107,7 +1124,12 @@ where
 		)
 		.map_err(AdvertisementError::Invalid)?;
 
-	if per_relay_parent.collations.is_seconded_limit_reached(relay_parent_mode) {
+	if per_relay_parent.collations.is_seconded_limit_reached(relay_parent_mode) ||
+		per_relay_parent.collations.is_para_seconded_limit_reached(
+			collator_para_id,
+			relay_parent_mode,
+			&assignment.current,
+		) {
 		return Err(AdvertisementError::SecondedLimitReached)
 	}
 
@@ -1199,7 +1221,12 @@ where
 		});
 
 	let collations = &mut per_relay_parent.collations;
-	if collations.is_seconded_limit_reached(relay_parent_mode) {
+	if collations.is_seconded_limit_reached(relay_parent_mode) ||
+		collations.is_para_seconded_limit_reached(
+			para_id,
+			relay_parent_mode,
+			&per_relay_parent.assignment.current,
+		) {
 		gum::trace!(
 			target: LOG_TARGET,
 			peer_id = ?peer_id,
@@ -1465,6 +1492,7 @@ async fn process_msg<Context>(
 			);
 		},
 		ReportCollator(id) => {
+			state.collator_scores.note(id.clone(), ScoreEvent::Invalid);
 			report_collator(&mut state.reputation, ctx.sender(), &state.peer_data, id).await;
 		},
 		NetworkBridgeUpdate(event) => {
@@ -1504,6 +1532,7 @@ async fn process_msg<Context>(
 					collator_id.clone(),
 				)
 				.await;
+				state.collator_scores.note(collator_id.clone(), ScoreEvent::Seconded);
 				if let Some(peer_data) = state.peer_data.get(&peer_id) {
 					notify_collation_seconded(
 						ctx.sender(),
@@ -1517,7 +1546,7 @@ async fn process_msg<Context>(
 
 				if let Some(rp_state) = state.per_relay_parent.get_mut(&parent) {
 					rp_state.collations.status = CollationStatus::Seconded;
-					rp_state.collations.note_seconded();
+					rp_state.collations.note_seconded(fetched_collation.para_id);
 				}
 
 				// See if we've unblocked other collations for seconding.
@@ -1574,6 +1603,7 @@ async fn process_msg<Context>(
 				Entry::Vacant(_) => return,
 			};
 
+			state.collator_scores.note(id.clone(), ScoreEvent::Invalid);
 			report_collator(&mut state.reputation, ctx.sender(), &state.peer_data, id.clone())
 				.await;
 
@@ -1589,12 +1619,16 @@ pub(crate) async fn run<Context>(
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
 	metrics: Metrics,
+	db: Arc<dyn Database>,
+	col_collator_scores: u32,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	run_inner(
 		ctx,
 		keystore,
 		eviction_policy,
 		metrics,
+		db,
+		col_collator_scores,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
 	)
@@ -1607,17 +1641,27 @@ async fn run_inner<Context>(
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
 	metrics: Metrics,
+	db: Arc<dyn Database>,
+	col_collator_scores: u32,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	let new_reputation_delay = || futures_timer::Delay::new(reputation_interval).fuse();
 	let mut reputation_delay = new_reputation_delay();
 
-	let mut state = State { metrics, reputation, ..Default::default() };
+	let mut state = State {
+		metrics,
+		reputation,
+		collator_scores: CollatorScores::load(&*db, col_collator_scores),
+		..Default::default()
+	};
 
 	let next_inactivity_stream = tick_stream(ACTIVITY_POLL);
 	futures::pin_mut!(next_inactivity_stream);
 
+	let next_persist_scores_stream = tick_stream(PERSIST_SCORES_INTERVAL);
+	futures::pin_mut!(next_persist_scores_stream);
+
 	let mut network_error_freq = gum::Freq::new();
 	let mut canceled_freq = gum::Freq::new();
 
@@ -1645,6 +1689,9 @@ async fn run_inner<Context>(
 			_ = next_inactivity_stream.next() => {
 				disconnect_inactive_peers(ctx.sender(), &eviction_policy, &state.peer_data).await;
 			}
+			_ = next_persist_scores_stream.next() => {
+				state.collator_scores.persist(&*db, col_collator_scores);
+			}
 
 			resp = state.collation_requests.select_next_some() => {
 				let res = match handle_collation_fetch_response(
@@ -1679,6 +1726,7 @@ async fn run_inner<Context>(
 						if err.is_malicious() {
 							// Report malicious peer.
 							modify_reputation(&mut state.reputation, ctx.sender(), pending_collation.peer_id, COST_REPORT_BAD).await;
+							state.collator_scores.note(collator_id.clone(), ScoreEvent::Invalid);
 						}
 						let maybe_candidate_hash =
 						pending_collation.prospective_candidate.as_ref().map(ProspectiveCandidate::candidate_hash);
@@ -1724,6 +1772,8 @@ async fn run_inner<Context>(
 		}
 	}
 
+	state.collator_scores.persist(&*db, col_collator_scores);
+
 	Ok(())
 }
 
@@ -1736,11 +1786,15 @@ async fn dequeue_next_collation_and_fetch<Context>(
 	// The collator we tried to fetch from last, optionally which candidate.
 	previous_fetch: (CollatorId, Option<CandidateHash>),
 ) {
-	while let Some((next, id)) = state.per_relay_parent.get_mut(&relay_parent).and_then(|state| {
-		state
-			.collations
-			.get_next_collation_to_fetch(&previous_fetch, state.prospective_parachains_mode)
-	}) {
+	while let Some((next, id)) =
+		state.per_relay_parent.get_mut(&relay_parent).and_then(|rp_state| {
+			rp_state.collations.get_next_collation_to_fetch(
+				&previous_fetch,
+				rp_state.prospective_parachains_mode,
+				&rp_state.assignment.current,
+				&state.collator_scores,
+			)
+		}) {
 		gum::debug!(
 			target: LOG_TARGET,
 			?relay_parent,
@@ -2014,6 +2068,8 @@ async fn handle_collation_fetch_response(
 			);
 			// For now we don't want to change reputation on timeout, to mitigate issues like
 			// this: https://github.com/paritytech/polkadot/issues/4617
+			// The score only affects the order of our own fetches, hence it is lowered.
+			state.collator_scores.note(collator_id, ScoreEvent::FetchFailed);
 			Err(None)
 		},
 		Err(RequestError::NetworkError(err)) => {
@@ -2031,6 +2087,7 @@ async fn handle_collation_fetch_response(
 			// sensible. In theory this could be exploited, by DoSing this node,
 			// which would result in reduced reputation for proper nodes, but the
 			// same can happen for penalties on timeouts, which we also have.
+			state.collator_scores.note(collator_id, ScoreEvent::FetchFailed);
 			Err(Some((pending_collation.peer_id, COST_NETWORK_ERROR)))
 		},
 		Err(RequestError::Canceled(err)) => {
@@ -2058,6 +2115,7 @@ async fn handle_collation_fetch_response(
 				"Got wrong para ID for requested collation."
 			);
 
+			state.collator_scores.note(collator_id, ScoreEvent::Invalid);
 			Err(Some((pending_collation.peer_id, COST_WRONG_PARA)))
 		},
 		Ok(request_v1::CollationFetchingResponse::Collation(candidate_receipt, pov)) => {
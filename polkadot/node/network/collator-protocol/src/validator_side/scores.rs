@@ -0,0 +1,210 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Scoring of collators based on the outcome of the collations fetched from them.
+//!
+//! Unlike peer reputation, which is aggregated by the network bridge and forgotten on reconnect,
+//! scores are tracked per collator id and persisted in the database, so they survive restarts.
+//! Among the collators advertising to us for the same para, the ones with a higher score are
+//! fetched from first.
+//!
+//! Collators we know nothing about start at the lowest possible score, otherwise a penalized
+//! collator could escape its score by advertising with a fresh collator id.
+
+use std::collections::HashMap;
+
+use codec::{Decode, Encode};
+
+use polkadot_node_subsystem_util::database::{DBTransaction, Database};
+use polkadot_primitives::CollatorId;
+
+use crate::LOG_TARGET;
+
+/// The maximum number of collators a score is kept for.
+pub const MAX_SCORED_COLLATORS: usize = 1024;
+
+/// Upper bound of a score.
+pub const MAX_SCORE: i32 = 1_000;
+
+/// Lower bound of a score, which is also the score of unknown collators.
+pub const MIN_SCORE: i32 = -1_000;
+
+/// The key the scores are stored under in their column.
+const SCORES_KEY: &[u8] = b"CollatorScores";
+
+/// Events affecting the score of a collator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreEvent {
+	/// A collation provided by the collator got seconded.
+	Seconded,
+	/// Fetching a collation from the collator timed out or failed with a network error.
+	FetchFailed,
+	/// The collator provided an invalid collation.
+	Invalid,
+}
+
+impl ScoreEvent {
+	fn delta(self) -> i32 {
+		match self {
+			ScoreEvent::Seconded => 10,
+			ScoreEvent::FetchFailed => -20,
+			ScoreEvent::Invalid => -200,
+		}
+	}
+}
+
+/// Scores of the collators we fetched collations from.
+#[derive(Debug, Default)]
+pub struct CollatorScores {
+	scores: HashMap<CollatorId, i32>,
+	/// Whether the scores changed since they were last persisted.
+	dirty: bool,
+}
+
+impl CollatorScores {
+	/// Loads the scores persisted in `col` of `db`.
+	///
+	/// If they can't be read or decoded, all collators start out unknown.
+	pub fn load(db: &dyn Database, col: u32) -> Self {
+		let scores = match db.get(col, SCORES_KEY) {
+			Ok(Some(raw)) => match Vec::<(CollatorId, i32)>::decode(&mut &raw[..]) {
+				Ok(scores) => scores.into_iter().collect(),
+				Err(err) => {
+					gum::warn!(target: LOG_TARGET, ?err, "Failed to decode collator scores");
+					HashMap::new()
+				},
+			},
+			Ok(None) => HashMap::new(),
+			Err(err) => {
+				gum::warn!(target: LOG_TARGET, ?err, "Failed to read collator scores");
+				HashMap::new()
+			},
+		};
+
+		Self { scores, dirty: false }
+	}
+
+	/// Writes the scores to `col` of `db`, if they changed since they were last written.
+	pub fn persist(&mut self, db: &dyn Database, col: u32) {
+		if !self.dirty {
+			return
+		}
+
+		let mut tx = DBTransaction::new();
+		tx.put_vec(col, SCORES_KEY, self.scores.iter().collect::<Vec<_>>().encode());
+		match db.write(tx) {
+			Ok(()) => self.dirty = false,
+			Err(err) => {
+				gum::warn!(target: LOG_TARGET, ?err, "Failed to persist collator scores");
+			},
+		}
+	}
+
+	/// Returns the score of a collator, which is `MIN_SCORE` for unknown collators.
+	pub fn score(&self, collator_id: &CollatorId) -> i32 {
+		self.scores.get(collator_id).copied().unwrap_or(MIN_SCORE)
+	}
+
+	/// Adjusts the score of a collator according to `event`.
+	///
+	/// If `MAX_SCORED_COLLATORS` are already tracked, the collator with the lowest score is
+	/// forgotten to make room for a new one, as forgetting it changes its score the least.
+	pub fn note(&mut self, collator_id: CollatorId, event: ScoreEvent) {
+		if !self.scores.contains_key(&collator_id) && self.scores.len() >= MAX_SCORED_COLLATORS {
+			let lowest =
+				self.scores.iter().min_by_key(|(_, score)| **score).map(|(id, _)| id.clone());
+			if let Some(id) = lowest {
+				self.scores.remove(&id);
+			}
+		}
+
+		let score = self.scores.entry(collator_id).or_insert(MIN_SCORE);
+		*score = score.saturating_add(event.delta()).clamp(MIN_SCORE, MAX_SCORE);
+		self.dirty = true;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+	use sp_core::sr25519;
+
+	fn collator(index: u32) -> CollatorId {
+		let mut raw = [0u8; 32];
+		raw[..4].copy_from_slice(&index.to_le_bytes());
+		sr25519::Public::from_raw(raw).into()
+	}
+
+	#[test]
+	fn scores_are_bounded() {
+		let mut scores = CollatorScores::default();
+
+		assert_eq!(scores.score(&collator(0)), MIN_SCORE);
+
+		scores.note(collator(0), ScoreEvent::Seconded);
+		scores.note(collator(1), ScoreEvent::FetchFailed);
+		assert_eq!(scores.score(&collator(0)), MIN_SCORE + 10);
+		assert_eq!(scores.score(&collator(1)), MIN_SCORE);
+
+		for _ in 0..300 {
+			scores.note(collator(0), ScoreEvent::Seconded);
+		}
+		assert_eq!(scores.score(&collator(0)), MAX_SCORE);
+
+		for _ in 0..10 {
+			scores.note(collator(0), ScoreEvent::Invalid);
+		}
+		assert_eq!(scores.score(&collator(0)), MIN_SCORE);
+	}
+
+	#[test]
+	fn lowest_scored_collator_is_forgotten() {
+		let mut scores = CollatorScores::default();
+
+		for index in 0..MAX_SCORED_COLLATORS as u32 {
+			scores.note(collator(index), ScoreEvent::Seconded);
+			scores.note(collator(index), ScoreEvent::Seconded);
+		}
+		// Brings the score of collator 1 back down.
+		scores.note(collator(1), ScoreEvent::FetchFailed);
+		assert_eq!(scores.score(&collator(1)), MIN_SCORE);
+
+		scores.note(collator(u32::MAX), ScoreEvent::Seconded);
+
+		assert_eq!(scores.scores.len(), MAX_SCORED_COLLATORS);
+		assert!(!scores.scores.contains_key(&collator(1)));
+		assert_eq!(scores.score(&collator(0)), MIN_SCORE + 20);
+		assert_eq!(scores.score(&collator(u32::MAX)), MIN_SCORE + 10);
+	}
+
+	#[test]
+	fn scores_are_persisted() {
+		const COL: u32 = 0;
+		let db = DbAdapter::new(kvdb_memorydb::create(1), &[]);
+
+		let mut scores = CollatorScores::load(&db, COL);
+		scores.note(collator(0), ScoreEvent::Seconded);
+		scores.note(collator(1), ScoreEvent::Invalid);
+		scores.persist(&db, COL);
+		assert!(!scores.dirty);
+
+		let loaded = CollatorScores::load(&db, COL);
+		assert_eq!(loaded.scores, scores.scores);
+		assert_eq!(loaded.score(&collator(0)), MIN_SCORE + 10);
+		assert_eq!(loaded.score(&collator(2)), MIN_SCORE);
+	}
+}
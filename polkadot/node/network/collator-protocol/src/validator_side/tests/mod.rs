@@ -40,7 +40,9 @@ use polkadot_node_subsystem::{
 	messages::{AllMessages, ReportPeerMessage, RuntimeApiMessage, RuntimeApiRequest},
 };
 use polkadot_node_subsystem_test_helpers as test_helpers;
-use polkadot_node_subsystem_util::{reputation::add_reputation, TimeoutExt};
+use polkadot_node_subsystem_util::{
+	database::kvdb_impl::DbAdapter, reputation::add_reputation, TimeoutExt,
+};
 use polkadot_primitives::{
 	CandidateReceipt, CollatorPair, CoreIndex, CoreState, GroupIndex, GroupRotationInfo, HeadData,
 	OccupiedCore, PersistedValidationData, ScheduledCore, ValidatorId, ValidatorIndex,
@@ -183,6 +185,8 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 			undeclared: DECLARE_TIMEOUT,
 		},
 		Metrics::default(),
+		Arc::new(DbAdapter::new(kvdb_memorydb::create(1), &[])),
+		0,
 		reputation,
 		REPUTATION_CHANGE_TEST_INTERVAL,
 	);
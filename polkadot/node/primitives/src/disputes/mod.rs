@@ -25,7 +25,7 @@ use sp_application_crypto::AppCrypto;
 use sp_keystore::{Error as KeystoreError, KeystorePtr};
 
 use polkadot_primitives::{
-	CandidateHash, CandidateReceipt, CompactStatement, DisputeStatement, EncodeAs,
+	CandidateHash, CandidateReceipt, CompactStatement, DisputeStatement, EncodeAs, Id as ParaId,
 	InvalidDisputeStatementKind, SessionIndex, SigningContext, UncheckedSigned,
 	ValidDisputeStatementKind, ValidatorId, ValidatorIndex, ValidatorSignature,
 };
@@ -81,6 +81,32 @@ impl CandidateVotes {
 	}
 }
 
+/// Summary of a dispute, as seen by the local node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisputeSummary {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate was backed for.
+	pub para_id: ParaId,
+	/// Current status of the dispute.
+	pub status: DisputeStatus,
+	/// Number of votes for the validity of the candidate.
+	pub valid_votes: u32,
+	/// Number of votes against the validity of the candidate.
+	pub invalid_votes: u32,
+	/// The vote of the local node, `true` meaning valid.
+	///
+	/// `None` if we did not vote (yet). Should we have voted both ways, the invalid vote is
+	/// reported.
+	pub local_vote: Option<bool>,
+	/// Whether the local node is a parachain validator in the session of the dispute.
+	///
+	/// Only then it is expected to vote, whether it did is reflected by `local_vote`.
+	pub local_validator: bool,
+}
+
 #[derive(Debug, Clone)]
 /// Valid candidate votes.
 ///
@@ -30,10 +30,10 @@ use futures::Future;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use polkadot_primitives::{
-	BlakeTwo256, BlockNumber, CandidateCommitments, CandidateHash, ChunkIndex, CollatorPair,
-	CommittedCandidateReceipt, CompactStatement, CoreIndex, EncodeAs, Hash, HashT, HeadData,
-	Id as ParaId, PersistedValidationData, SessionIndex, Signed, UncheckedSigned, ValidationCode,
-	ValidationCodeHash, MAX_CODE_SIZE, MAX_POV_SIZE,
+	async_backing::BackingState, BlakeTwo256, BlockNumber, CandidateCommitments, CandidateHash,
+	ChunkIndex, CollatorPair, CommittedCandidateReceipt, CompactStatement, CoreIndex, EncodeAs,
+	ExecutorParams, Hash, HashT, HeadData, Id as ParaId, PersistedValidationData, SessionIndex,
+	Signed, UncheckedSigned, ValidationCode, ValidationCodeHash, MAX_CODE_SIZE, MAX_POV_SIZE,
 };
 pub use sp_consensus_babe::{
 	AllowedSlots as BabeAllowedSlots, BabeEpochConfiguration, Epoch as BabeEpoch,
@@ -50,7 +50,7 @@ pub mod approval;
 pub mod disputes;
 pub use disputes::{
 	dispute_is_inactive, CandidateVotes, DisputeMessage, DisputeMessageCheckError, DisputeStatus,
-	InvalidDisputeVote, SignedDisputeStatement, Timestamp, UncheckedDisputeMessage,
+	DisputeSummary, InvalidDisputeVote, SignedDisputeStatement, Timestamp, UncheckedDisputeMessage,
 	ValidDisputeVote, ACTIVE_DURATION_SECS,
 };
 
@@ -529,6 +529,27 @@ pub struct SubmitCollationParams {
 	pub core_index: CoreIndex,
 }
 
+/// A collation along with the relay chain state it was built against, as captured by a collator.
+///
+/// This is everything needed to replay the validation of the candidate offline, away from the
+/// relay chain, e.g. with the `dry-run-collation` subcommand of the polkadot binary.
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct CollationSnapshot {
+	/// The candidate, including its commitments.
+	pub candidate: CommittedCandidateReceipt,
+	/// The Proof-of-Validity of the candidate.
+	pub pov: PoV,
+	/// The persisted validation data the candidate was built with.
+	pub validation_data: PersistedValidationData,
+	/// The validation code of the para at the relay-parent.
+	pub validation_code: ValidationCode,
+	/// The executor parameters of the session of the relay-parent.
+	pub executor_params: ExecutorParams,
+	/// The backing state of the para at the relay-parent, if the relay chain supports async
+	/// backing.
+	pub backing_state: Option<BackingState>,
+}
+
 /// This is the data we keep available for each candidate included in the relay chain.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
 pub struct AvailableData {
@@ -477,6 +477,7 @@ fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	overseer_handle: Option<Handle>,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -618,6 +619,9 @@ where
 					beefy_best_block_stream: beefy_rpc_links.from_voter_best_beefy_stream.clone(),
 					subscription_executor,
 				},
+				disputes: overseer_handle
+					.clone()
+					.map(|overseer_handle| polkadot_rpc::DisputesDeps { overseer_handle }),
 				backend: backend.clone(),
 			};
 
@@ -662,6 +666,9 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_soft_max_num: Option<usize>,
 	/// An optional absolute number of pvf workers that can be spawned in the pvf prepare pool.
 	pub prepare_workers_hard_max_num: Option<usize>,
+	/// An optional directory to write a snapshot of every collation produced alongside this node
+	/// to, see [`polkadot_node_primitives::CollationSnapshot`].
+	pub collation_snapshot_dir: Option<PathBuf>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -760,6 +767,7 @@ pub fn new_full<
 		execute_workers_max_num,
 		prepare_workers_soft_max_num,
 		prepare_workers_hard_max_num,
+		collation_snapshot_dir,
 	}: NewFullParams<OverseerGenerator>,
 ) -> Result<NewFull, Error> {
 	use polkadot_availability_recovery::FETCH_CHUNKS_THRESHOLD;
@@ -829,7 +837,12 @@ pub fn new_full<
 		import_queue,
 		transaction_pool,
 		other: (rpc_extensions_builder, import_setup, rpc_setup, slot_duration, mut telemetry),
-	} = new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+	} = new_partial::<SelectRelayChain<_>>(
+		&mut config,
+		basics,
+		select_chain,
+		role.is_authority().then(|| overseer_handle.clone()),
+	)?;
 
 	let metrics = Network::register_notification_metrics(
 		config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
@@ -1022,6 +1035,7 @@ pub fn new_full<
 			dispute_req_receiver,
 			dispute_coordinator_config,
 			chain_selection_config,
+			col_collator_scores: parachains_db::REAL_COLUMNS.col_collator_scores,
 			fetch_chunks_threshold,
 		})
 	};
@@ -1176,6 +1190,7 @@ pub fn new_full<
 					req_protocol_names,
 					peerset_protocol_names,
 					notification_services,
+					collation_snapshot_dir,
 				},
 				ext_overseer_args,
 			)
@@ -1414,7 +1429,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let sc_service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
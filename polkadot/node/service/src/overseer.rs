@@ -43,7 +43,7 @@ use sc_authority_discovery::Service as AuthorityDiscoveryService;
 use sc_client_api::AuxStore;
 use sc_keystore::LocalKeystore;
 use sc_network::{NetworkStateInfo, NotificationService};
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 pub use polkadot_approval_distribution::ApprovalDistribution as ApprovalDistributionSubsystem;
 pub use polkadot_availability_bitfield_distribution::BitfieldDistribution as BitfieldDistributionSubsystem;
@@ -106,6 +106,8 @@ where
 	pub peerset_protocol_names: PeerSetProtocolNames,
 	/// Notification services for validation/collation protocols.
 	pub notification_services: HashMap<PeerSet, Box<dyn NotificationService>>,
+	/// Directory to write a snapshot of every produced collation to, if any.
+	pub collation_snapshot_dir: Option<PathBuf>,
 }
 
 pub struct ExtendedOverseerGenArgs {
@@ -135,6 +137,8 @@ pub struct ExtendedOverseerGenArgs {
 	pub dispute_coordinator_config: DisputeCoordinatorConfig,
 	/// Configuration for the chain selection subsystem.
 	pub chain_selection_config: ChainSelectionConfig,
+	/// The column of the parachains DB the collator protocol persists collator scores in.
+	pub col_collator_scores: u32,
 	/// Optional availability recovery fetch chunks threshold. If PoV size size is lower
 	/// than the value put in here we always try to recovery availability from backers.
 	/// The presence of this parameter here is needed to have different values per chain.
@@ -158,6 +162,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		req_protocol_names,
 		peerset_protocol_names,
 		notification_services,
+		collation_snapshot_dir: _,
 	}: OverseerGenArgs<Spawner, RuntimeClient>,
 	ExtendedOverseerGenArgs {
 		keystore,
@@ -173,6 +178,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		dispute_req_receiver,
 		dispute_coordinator_config,
 		chain_selection_config,
+		col_collator_scores,
 		fetch_chunks_threshold,
 	}: ExtendedOverseerGenArgs,
 ) -> Result<
@@ -291,6 +297,8 @@ where
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
 					metrics: Metrics::register(registry)?,
+					db: parachains_db.clone(),
+					col_collator_scores,
 				},
 			};
 			CollatorProtocolSubsystem::new(side)
@@ -367,6 +375,7 @@ pub fn collator_overseer_builder<Spawner, RuntimeClient>(
 		req_protocol_names,
 		peerset_protocol_names,
 		notification_services,
+		collation_snapshot_dir,
 	}: OverseerGenArgs<Spawner, RuntimeClient>,
 ) -> Result<
 	InitializedOverseerBuilder<
@@ -448,7 +457,10 @@ where
 		.candidate_validation(DummySubsystem)
 		.pvf_checker(DummySubsystem)
 		.chain_api(ChainApiSubsystem::new(runtime_client.clone(), Metrics::register(registry)?))
-		.collation_generation(CollationGenerationSubsystem::new(Metrics::register(registry)?))
+		.collation_generation(CollationGenerationSubsystem::with_snapshot_dir(
+			Metrics::register(registry)?,
+			collation_snapshot_dir,
+		))
 		.collator_protocol({
 			let side = match is_parachain_node {
 				IsParachainNode::No =>
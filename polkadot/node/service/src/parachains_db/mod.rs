@@ -57,6 +57,21 @@ pub(crate) mod columns {
 		pub const ORDERED_COL: &[u32] =
 			&[COL_AVAILABILITY_META, COL_CHAIN_SELECTION_DATA, COL_DISPUTE_COORDINATOR_DATA];
 	}
+
+	// Version 5 only changed structures in approval voting, so we can re-export the v4 definitions.
+	pub mod v5 {
+		pub use super::v4::NUM_COLUMNS;
+	}
+
+	pub mod v6 {
+		pub use super::v4::{
+			COL_APPROVAL_DATA, COL_AVAILABILITY_DATA, COL_AVAILABILITY_META,
+			COL_CHAIN_SELECTION_DATA, COL_DISPUTE_COORDINATOR_DATA, ORDERED_COL,
+		};
+
+		pub const NUM_COLUMNS: u32 = 6;
+		pub const COL_COLLATOR_SCORES: u32 = 5;
+	}
 }
 
 /// Columns used by different subsystems.
@@ -73,16 +88,19 @@ pub struct ColumnsConfig {
 	pub col_chain_selection_data: u32,
 	/// The column used by dispute coordinator for data.
 	pub col_dispute_coordinator_data: u32,
+	/// The column used by the collator protocol for collator scores.
+	pub col_collator_scores: u32,
 }
 
 /// The real columns used by the parachains DB.
 #[cfg(any(test, feature = "full-node"))]
 pub const REAL_COLUMNS: ColumnsConfig = ColumnsConfig {
-	col_availability_data: columns::v4::COL_AVAILABILITY_DATA,
-	col_availability_meta: columns::v4::COL_AVAILABILITY_META,
-	col_approval_data: columns::v4::COL_APPROVAL_DATA,
-	col_chain_selection_data: columns::v4::COL_CHAIN_SELECTION_DATA,
-	col_dispute_coordinator_data: columns::v4::COL_DISPUTE_COORDINATOR_DATA,
+	col_availability_data: columns::v6::COL_AVAILABILITY_DATA,
+	col_availability_meta: columns::v6::COL_AVAILABILITY_META,
+	col_approval_data: columns::v6::COL_APPROVAL_DATA,
+	col_chain_selection_data: columns::v6::COL_CHAIN_SELECTION_DATA,
+	col_dispute_coordinator_data: columns::v6::COL_DISPUTE_COORDINATOR_DATA,
+	col_collator_scores: columns::v6::COL_COLLATOR_SCORES,
 };
 
 #[derive(PartialEq, Copy, Clone)]
@@ -130,17 +148,17 @@ pub fn open_creating_rocksdb(
 
 	let path = root.join("parachains").join("db");
 
-	let mut db_config = DatabaseConfig::with_columns(columns::v4::NUM_COLUMNS);
+	let mut db_config = DatabaseConfig::with_columns(columns::v6::NUM_COLUMNS);
 
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_AVAILABILITY_DATA, cache_sizes.availability_data);
+		.insert(columns::v6::COL_AVAILABILITY_DATA, cache_sizes.availability_data);
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_AVAILABILITY_META, cache_sizes.availability_meta);
+		.insert(columns::v6::COL_AVAILABILITY_META, cache_sizes.availability_meta);
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_APPROVAL_DATA, cache_sizes.approval_data);
+		.insert(columns::v6::COL_APPROVAL_DATA, cache_sizes.approval_data);
 
 	let path_str = path
 		.to_str()
@@ -151,7 +169,7 @@ pub fn open_creating_rocksdb(
 	let db = Database::open(&db_config, &path_str)?;
 	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
 		db,
-		columns::v4::ORDERED_COL,
+		columns::v6::ORDERED_COL,
 	);
 
 	Ok(Arc::new(db))
@@ -171,12 +189,12 @@ pub fn open_creating_paritydb(
 	std::fs::create_dir_all(&path_str)?;
 	upgrade::try_upgrade_db(&path, DatabaseKind::ParityDB, upgrade::CURRENT_VERSION)?;
 
-	let db = parity_db::Db::open_or_create(&upgrade::paritydb_version_3_config(&path))
+	let db = parity_db::Db::open_or_create(&upgrade::paritydb_version_6_config(&path))
 		.map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{:?}", err)))?;
 
 	let db = polkadot_node_subsystem_util::database::paritydb_impl::DbAdapter::new(
 		db,
-		columns::v4::ORDERED_COL,
+		columns::v6::ORDERED_COL,
 	);
 	Ok(Arc::new(db))
 }
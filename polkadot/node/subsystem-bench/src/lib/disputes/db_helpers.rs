@@ -0,0 +1,118 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::usage::ResourceUsage;
+use kvdb::DBOp;
+use polkadot_node_core_dispute_coordinator::Config;
+use polkadot_node_subsystem_util::database::{
+	kvdb_impl::DbAdapter, DBKeyValue, DBTransaction, DBValue, Database, KeyValueDB,
+};
+use std::{
+	io,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+};
+
+mod columns {
+	pub const DATA: u32 = 0;
+	pub const NUM_COLUMNS: u32 = 1;
+}
+
+pub const TEST_CONFIG: Config = Config { col_dispute_data: columns::DATA };
+
+/// Accounting of the writes the dispute-coordinator does to its database.
+#[derive(Clone, Default)]
+pub struct DatabaseStats {
+	transactions: Arc<AtomicUsize>,
+	bytes_written: Arc<AtomicUsize>,
+}
+
+impl DatabaseStats {
+	pub fn usage(&self, num_blocks: usize) -> Vec<ResourceUsage> {
+		let transactions = self.transactions.load(Ordering::SeqCst);
+		let total_written = self.bytes_written.load(Ordering::SeqCst) as f64 / 1024.0;
+
+		gum::debug!(target: super::LOG_TARGET, transactions, total_written, "Database writes");
+
+		vec![ResourceUsage {
+			resource_name: "dispute-coordinator".to_string(),
+			total: total_written,
+			per_block: total_written / num_blocks as f64,
+		}]
+	}
+}
+
+/// An in-memory database which keeps track of the written bytes.
+struct MeteredDatabase {
+	inner: DbAdapter<kvdb_memorydb::InMemory>,
+	stats: DatabaseStats,
+}
+
+impl KeyValueDB for MeteredDatabase {
+	fn transaction(&self) -> DBTransaction {
+		self.inner.transaction()
+	}
+
+	fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>> {
+		self.inner.get(col, key)
+	}
+
+	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
+		self.inner.get_by_prefix(col, prefix)
+	}
+
+	fn write(&self, transaction: DBTransaction) -> io::Result<()> {
+		let bytes_written = transaction
+			.ops
+			.iter()
+			.map(|op| match op {
+				DBOp::Insert { key, value, .. } => key.len() + value.len(),
+				DBOp::Delete { key, .. } => key.len(),
+				DBOp::DeletePrefix { prefix, .. } => prefix.len(),
+			})
+			.sum::<usize>();
+		self.stats.transactions.fetch_add(1, Ordering::SeqCst);
+		self.stats.bytes_written.fetch_add(bytes_written, Ordering::SeqCst);
+
+		self.inner.write(transaction)
+	}
+
+	fn iter<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		self.inner.iter(col)
+	}
+
+	fn iter_with_prefix<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		self.inner.iter_with_prefix(col, prefix)
+	}
+}
+
+impl Database for MeteredDatabase {
+	fn is_indexed_column(&self, col: u32) -> bool {
+		self.inner.is_indexed_column(col)
+	}
+}
+
+pub fn test_store(stats: DatabaseStats) -> Arc<dyn Database> {
+	let db = kvdb_memorydb::create(columns::NUM_COLUMNS);
+	let inner = DbAdapter::new(db, &[columns::DATA]);
+	Arc::new(MeteredDatabase { inner, stats })
+}
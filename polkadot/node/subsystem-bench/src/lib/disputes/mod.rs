@@ -0,0 +1,296 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	disputes::db_helpers::{test_store, TEST_CONFIG},
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		authority_discovery::MockAuthorityDiscovery,
+		av_store::{MockAvailabilityStore, NetworkAvailabilityState},
+		candidate_validation::MockCandidateValidation,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	usage::BenchmarkUsage,
+};
+use colored::Colorize;
+use futures::{channel::oneshot, stream::FuturesUnordered, StreamExt};
+use polkadot_availability_recovery::AvailabilityRecoverySubsystem;
+use polkadot_dispute_distribution::DisputeDistributionSubsystem;
+use polkadot_node_core_dispute_coordinator::DisputeCoordinatorSubsystem;
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::request_response::IncomingRequest;
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, Block, Hash, ValidatorId};
+use sc_keystore::LocalKeystore;
+use sc_network::request_responses::{IncomingRequest as RawIncomingRequest, ProtocolConfig};
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	sync::{atomic::Ordering, Arc},
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod db_helpers;
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::disputes";
+
+/// The maximum time the node under test can take to participate in all the disputes of a block.
+const MAX_PARTICIPATION_TIME: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DisputesOptions {
+	#[clap(long, default_value_t = 1)]
+	/// Number of disputes raised on every block. Every connected peer sends its vote on each
+	/// dispute, votes above the `dispute-distribution` rate limit are dropped by the node.
+	pub n_disputes: usize,
+	#[clap(long, default_value_t = 0)]
+	/// Number of disputed candidates per block the node under test finds invalid when
+	/// participating. The remaining ones are valid.
+	pub n_invalid: usize,
+}
+
+/// The keystore of the node under test, it only contains the keys of `//Node0`.
+fn make_keystore() -> Arc<LocalKeystore> {
+	let keystore = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (
+	Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>,
+	OverseerHandle,
+	Vec<ProtocolConfig>,
+) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		state.included_candidates.clone(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Occupied,
+	);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let mock_av_store = MockAvailabilityStore::new(
+		state.chunks.clone(),
+		state.chunk_indices.clone(),
+		state.candidate_hashes.clone(),
+		state.candidate_hash_to_core_index.clone(),
+	);
+	let mock_candidate_validation = MockCandidateValidation::new(state.invalid_candidates.clone());
+
+	let (available_data_req_receiver, available_data_req_cfg) =
+		IncomingRequest::get_config_receiver::<Block, sc_network::NetworkWorker<Block, Hash>>(
+			&state.req_protocol_names,
+		);
+	let (dispute_req_receiver, dispute_req_cfg) = IncomingRequest::get_config_receiver::<
+		Block,
+		sc_network::NetworkWorker<Block, Hash>,
+	>(&state.req_protocol_names);
+
+	let keystore = make_keystore();
+	let availability_recovery = AvailabilityRecoverySubsystem::for_validator(
+		None,
+		available_data_req_receiver,
+		&state.req_protocol_names,
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let dispute_coordinator = DisputeCoordinatorSubsystem::new(
+		test_store(state.db_stats.clone()),
+		TEST_CONFIG,
+		keystore.clone(),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let dispute_distribution = DisputeDistributionSubsystem::new(
+		keystore as KeystorePtr,
+		dispute_req_receiver,
+		MockAuthorityDiscovery::new(&state.test_authorities),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, Some(dispute_req_cfg));
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_availability_store(|_| mock_av_store)
+		.replace_candidate_validation(|_| mock_candidate_validation)
+		.replace_availability_recovery(|_| availability_recovery)
+		.replace_dispute_coordinator(|_| dispute_coordinator)
+		.replace_dispute_distribution(|_| dispute_distribution)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle, vec![available_data_req_cfg])
+}
+
+pub fn prepare_test(
+	state: &TestState,
+	with_prometheus_endpoint: bool,
+) -> (TestEnvironment, Vec<ProtocolConfig>) {
+	let dependencies = TestEnvironmentDependencies::default();
+	let availability_state = NetworkAvailabilityState {
+		candidate_hashes: state.candidate_hashes.clone(),
+		candidate_hash_to_core_index: state.candidate_hash_to_core_index.clone(),
+		available_data: state.available_data.clone(),
+		chunks: state.chunks.clone(),
+		chunk_indices: state.chunk_indices.clone(),
+		req_protocol_names: state.req_protocol_names.clone(),
+	};
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone()), Arc::new(availability_state)],
+	);
+	let (overseer, overseer_handle, cfg) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	(
+		TestEnvironment::new(
+			dependencies,
+			state.config.clone(),
+			network,
+			overseer,
+			overseer_handle,
+			state.test_authorities.clone(),
+			with_prometheus_endpoint,
+		),
+		cfg,
+	)
+}
+
+pub async fn benchmark_dispute_coordinator(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	state.reset_trackers();
+
+	let config = env.config().clone();
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+
+		let block_start_ts = Instant::now();
+		env.import_block(block_info.clone()).await;
+
+		// Every connected peer sends its votes to the node under test.
+		let mut pending_responses = FuturesUnordered::new();
+		for (voter, payload) in state.dispute_requests.get(&block_info.hash).unwrap() {
+			let authority_id =
+				state.test_authorities.validator_authority_id.get(voter.0 as usize).unwrap();
+			if !env.network().is_peer_connected(authority_id) {
+				continue
+			}
+
+			let (pending_response, response_receiver) = oneshot::channel();
+			let request = RawIncomingRequest {
+				peer: *state.test_authorities.peer_ids.get(voter.0 as usize).unwrap(),
+				payload: payload.clone(),
+				pending_response,
+			};
+			let _ = env.network().send_request_from_peer(authority_id, request);
+			pending_responses.push(response_receiver);
+		}
+
+		gum::info!(target: LOG_TARGET, "{}", format!("{} dispute requests pending", pending_responses.len()).bright_black());
+		while pending_responses.next().await.is_some() {}
+
+		// Wait until the node under test participated in all disputes of the block.
+		let candidates = state.candidate_receipts.get(&block_info.hash).unwrap();
+		let participation_start_ts = Instant::now();
+		loop {
+			let participated_count = candidates
+				.iter()
+				.filter(|receipt| {
+					state.own_votes_tracker.get(&receipt.hash()).unwrap().load(Ordering::SeqCst)
+				})
+				.count();
+			gum::debug!(target: LOG_TARGET, "{}/{} disputes participated", participated_count, candidates.len());
+
+			if participated_count == candidates.len() {
+				break
+			}
+			if participation_start_ts.elapsed() > MAX_PARTICIPATION_TIME {
+				panic!(
+					"{} ms maximum participation time breached, participated in {}/{} disputes",
+					MAX_PARTICIPATION_TIME.as_millis(),
+					participated_count,
+					candidates.len(),
+				)
+			}
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+
+		let block_time = block_start_ts.elapsed().as_millis() as u64;
+		env.metrics().set_block_time(block_time);
+		gum::info!(target: LOG_TARGET, "All work for block completed in {}", format!("{:?}ms", block_time).cyan());
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", test_start.elapsed().as_millis() / env.config().num_blocks as u128).red()
+	);
+
+	env.stop().await;
+	let mut usage = env.collect_resource_usage(&[
+		"dispute-coordinator",
+		"dispute-distribution",
+		"availability-recovery",
+	]);
+	usage.db_usage = state.db_stats.usage(config.num_blocks);
+	usage
+}
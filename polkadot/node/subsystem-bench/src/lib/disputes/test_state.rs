@@ -0,0 +1,332 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	disputes::{db_helpers::DatabaseStats, DisputesOptions},
+	environment::GENESIS_HASH,
+	mock::runtime_api::{node_features_with_chunk_mapping_enabled, session_info_for_peers},
+	network::{HandleNetworkMessage, NetworkMessage},
+};
+use codec::Encode;
+use colored::Colorize;
+use itertools::Itertools;
+use polkadot_node_network_protocol::request_response::{
+	v1::{DisputeRequest, DisputeResponse},
+	ReqProtocolNames, Requests,
+};
+use polkadot_node_primitives::{
+	AvailableData, BlockData, DisputeMessage, ErasureChunk, PoV, SignedDisputeStatement,
+};
+use polkadot_node_subsystem_test_helpers::{
+	derive_erasure_chunks_with_proofs_and_root, mock::new_block_import_info,
+};
+use polkadot_node_subsystem_util::availability_chunks::availability_chunk_indices;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	BlockNumber, CandidateEvent, CandidateHash, CandidateReceipt, ChunkIndex, CoreIndex,
+	GroupIndex, Hash, HeadData, Header, Id, PersistedValidationData, SessionInfo, ValidatorIndex,
+};
+use polkadot_primitives_test_helpers::{dummy_candidate_receipt, dummy_hash};
+use sc_network::ProtocolName;
+use sp_core::H256;
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+const LOG_TARGET: &str = "subsystem-bench::disputes::test_state";
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Dispute specific options
+	pub options: DisputesOptions,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Session info
+	pub session_info: SessionInfo,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// Disputed candidates per block
+	pub candidate_receipts: HashMap<H256, Vec<CandidateReceipt>>,
+	// Inclusion events of the disputed candidates per block
+	pub included_candidates: HashMap<H256, Vec<CandidateEvent>>,
+	// Candidates the node under test finds invalid when participating
+	pub invalid_candidates: HashSet<CandidateHash>,
+	// Request protocol names
+	pub req_protocol_names: ReqProtocolNames,
+	// Map from generated candidate hashes to candidate index in `available_data` and `chunks`.
+	pub candidate_hashes: HashMap<CandidateHash, usize>,
+	// Map from candidate hash to occupied core index.
+	pub candidate_hash_to_core_index: HashMap<CandidateHash, CoreIndex>,
+	// Per candidate index `AvailableData`
+	pub available_data: Vec<AvailableData>,
+	// Per candidate index chunks
+	pub chunks: Vec<Vec<ErasureChunk>>,
+	// Per-core ValidatorIndex -> ChunkIndex mapping
+	pub chunk_indices: Vec<Vec<ChunkIndex>>,
+	// Pregenerated encoded dispute requests per block, along with the index of the sending peer
+	pub dispute_requests: HashMap<H256, Vec<(ValidatorIndex, Vec<u8>)>>,
+	// Tracks if the node under test distributed its own vote on a disputed candidate
+	pub own_votes_tracker: HashMap<CandidateHash, Arc<AtomicBool>>,
+	// Writes of the dispute-coordinator to its database
+	pub db_stats: DatabaseStats,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &DisputesOptions) -> Self {
+		assert!(
+			options.n_invalid <= options.n_disputes,
+			"Number of invalid candidates can't exceed the number of disputes"
+		);
+		assert!(config.n_validators > 2, "Disputes need at least two peers to vote");
+
+		let test_authorities = config.generate_authorities();
+		let session_info = session_info_for_peers(config, &test_authorities);
+		let mut state = Self {
+			config: config.clone(),
+			options: options.clone(),
+			test_authorities,
+			session_info,
+			block_infos: (1..=config.num_blocks).map(generate_block_info).collect(),
+			block_headers: Default::default(),
+			candidate_receipts: Default::default(),
+			included_candidates: Default::default(),
+			invalid_candidates: Default::default(),
+			req_protocol_names: ReqProtocolNames::new(GENESIS_HASH, None),
+			candidate_hashes: Default::default(),
+			candidate_hash_to_core_index: Default::default(),
+			available_data: Default::default(),
+			chunks: Default::default(),
+			chunk_indices: Default::default(),
+			dispute_requests: Default::default(),
+			own_votes_tracker: Default::default(),
+			db_stats: Default::default(),
+		};
+
+		state.block_headers = state.block_infos.iter().map(generate_block_header).collect();
+		state.chunk_indices = (0..config.n_cores)
+			.map(|core_index| {
+				availability_chunk_indices(
+					Some(&node_features_with_chunk_mapping_enabled()),
+					config.n_validators,
+					CoreIndex(core_index as u32),
+				)
+				.unwrap()
+			})
+			.collect();
+
+		// We use it for all candidates.
+		let persisted_validation_data = PersistedValidationData {
+			parent_head: HeadData(vec![7, 8, 9]),
+			relay_parent_number: Default::default(),
+			max_pov_size: 1024,
+			relay_parent_storage_root: Default::default(),
+		};
+
+		// For each unique pov we create a candidate receipt template.
+		let pov_sizes = Vec::from(config.pov_sizes());
+		let mut pov_size_to_candidate = HashMap::new();
+		let mut receipt_templates = Vec::new();
+		for (index, pov_size) in pov_sizes.iter().cloned().unique().enumerate() {
+			gum::info!(target: LOG_TARGET, index, pov_size, "{}", "Generating template candidate".bright_blue());
+
+			let available_data = AvailableData {
+				validation_data: persisted_validation_data.clone(),
+				pov: Arc::new(PoV { block_data: BlockData(vec![index as u8; pov_size]) }),
+			};
+			let (chunks, erasure_root) = derive_erasure_chunks_with_proofs_and_root(
+				config.n_validators,
+				&available_data,
+				|_, _| {},
+			);
+			let mut receipt = dummy_candidate_receipt(dummy_hash());
+			receipt.descriptor.erasure_root = erasure_root;
+
+			state.chunks.push(chunks);
+			state.available_data.push(available_data);
+			pov_size_to_candidate.insert(pov_size, index);
+			receipt_templates.push(receipt);
+		}
+
+		let n_groups = state.session_info.validator_groups.len();
+		for block_info in state.block_infos.iter() {
+			for index in 0..options.n_disputes {
+				let core_index = CoreIndex((index % config.n_cores) as u32);
+				let pov_size = pov_sizes.get(core_index.0 as usize).expect("One per core; qed");
+				let candidate_index =
+					*pov_size_to_candidate.get(pov_size).expect("pov_size always exists; qed");
+				let mut receipt = receipt_templates[candidate_index].clone();
+				// Make it unique.
+				receipt.descriptor.para_id = Id::new(index as u32 + 1);
+				receipt.descriptor.relay_parent = block_info.hash;
+
+				let candidate_hash = receipt.hash();
+				state.candidate_hashes.insert(candidate_hash, candidate_index);
+				state.candidate_hash_to_core_index.insert(candidate_hash, core_index);
+				state.own_votes_tracker.insert(candidate_hash, Arc::new(AtomicBool::new(false)));
+				// The first `n_invalid` candidates of every block are invalid.
+				if index < options.n_invalid {
+					state.invalid_candidates.insert(candidate_hash);
+				}
+
+				state.included_candidates.entry(block_info.hash).or_default().push(
+					CandidateEvent::CandidateIncluded(
+						receipt.clone(),
+						HeadData(vec![]),
+						core_index,
+						GroupIndex((index % n_groups) as u32),
+					),
+				);
+				state.candidate_receipts.entry(block_info.hash).or_default().push(receipt);
+			}
+		}
+
+		let requests_count = options.n_disputes * config.num_blocks * (config.n_validators - 2);
+		gum::info!(target: LOG_TARGET, "{}", format!("Pre-generating {} dispute requests.", requests_count).bright_blue());
+		state.dispute_requests = state
+			.candidate_receipts
+			.iter()
+			.map(|(block_hash, receipts)| {
+				let requests = receipts
+					.iter()
+					.flat_map(|receipt| state.generate_dispute_requests(receipt))
+					.collect_vec();
+				(*block_hash, requests)
+			})
+			.collect();
+
+		gum::info!(target: LOG_TARGET, "{}", "Created test environment.".bright_blue());
+
+		state
+	}
+
+	/// Generates one dispute request per voting peer. Peers vote the way the node under test
+	/// is going to, and the last validator initiates the dispute by voting the opposite way.
+	fn generate_dispute_requests(
+		&self,
+		receipt: &CandidateReceipt,
+	) -> Vec<(ValidatorIndex, Vec<u8>)> {
+		let candidate_hash = receipt.hash();
+		let is_valid = !self.invalid_candidates.contains(&candidate_hash);
+		let initiator = ValidatorIndex(self.config.n_validators as u32 - 1);
+		let initiator_vote = self.sign_dispute_statement(!is_valid, candidate_hash, initiator);
+
+		// The node under test is `ValidatorIndex(0)` and votes on its own.
+		(1..initiator.0)
+			.map(ValidatorIndex)
+			.map(|voter| {
+				let vote = self.sign_dispute_statement(is_valid, candidate_hash, voter);
+				let ((valid_vote, valid_index), (invalid_vote, invalid_index)) = if is_valid {
+					((vote, voter), (initiator_vote.clone(), initiator))
+				} else {
+					((initiator_vote.clone(), initiator), (vote, voter))
+				};
+				let message = DisputeMessage::from_signed_statements(
+					valid_vote,
+					valid_index,
+					invalid_vote,
+					invalid_index,
+					receipt.clone(),
+					&self.session_info,
+				)
+				.expect("Dispute message is well formed; qed");
+
+				(voter, DisputeRequest::from(message).encode())
+			})
+			.collect()
+	}
+
+	fn sign_dispute_statement(
+		&self,
+		valid: bool,
+		candidate_hash: CandidateHash,
+		validator_index: ValidatorIndex,
+	) -> SignedDisputeStatement {
+		let validator_public = self
+			.test_authorities
+			.validator_public
+			.get(validator_index.0 as usize)
+			.expect("All validator keys are known")
+			.clone();
+
+		SignedDisputeStatement::sign_explicit(
+			&self.test_authorities.keyring.keystore(),
+			valid,
+			candidate_hash,
+			0,
+			validator_public,
+		)
+		.ok()
+		.flatten()
+		.expect("should be signed")
+	}
+
+	pub fn reset_trackers(&self) {
+		self.own_votes_tracker
+			.values()
+			.for_each(|v| v.as_ref().store(false, Ordering::SeqCst));
+	}
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(_authority_id, Requests::DisputeSendingV1(req)) => {
+				// The node under test participated and distributes its own vote.
+				let candidate_hash = req.payload.0.candidate_receipt.hash();
+				if let Some(tracker) = self.own_votes_tracker.get(&candidate_hash) {
+					tracker.as_ref().store(true, Ordering::SeqCst);
+				}
+
+				let _ = req
+					.pending_response
+					.send(Ok((DisputeResponse::Confirmed.encode(), ProtocolName::from(""))));
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
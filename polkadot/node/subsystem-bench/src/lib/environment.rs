@@ -355,6 +355,7 @@ impl TestEnvironment {
 		BenchmarkUsage {
 			network_usage: self.network_usage(),
 			cpu_usage: self.cpu_usage(subsystems_under_test),
+			db_usage: Vec::new(),
 		}
 	}
 
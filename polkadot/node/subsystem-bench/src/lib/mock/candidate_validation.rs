@@ -0,0 +1,89 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A generic candidate validation subsystem mockup suitable to be used in benchmarks.
+
+use futures::FutureExt;
+use polkadot_node_primitives::{InvalidCandidate, ValidationResult};
+use polkadot_node_subsystem::{
+	messages::CandidateValidationMessage, overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use polkadot_primitives::{CandidateCommitments, CandidateHash};
+use std::collections::HashSet;
+
+const LOG_TARGET: &str = "subsystem-bench::candidate-validation-mock";
+
+/// A mocked `candidate-validation` subsystem. Candidates are valid unless explicitly marked
+/// as invalid, the PVF is never executed.
+pub struct MockCandidateValidation {
+	invalid_candidates: HashSet<CandidateHash>,
+}
+
+impl MockCandidateValidation {
+	pub fn new(invalid_candidates: HashSet<CandidateHash>) -> Self {
+		Self { invalid_candidates }
+	}
+}
+
+#[overseer::subsystem(CandidateValidation, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockCandidateValidation {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(CandidateValidation, prefix = self::overseer)]
+impl MockCandidateValidation {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					CandidateValidationMessage::ValidateFromExhaustive {
+						validation_data,
+						candidate_receipt,
+						response_sender,
+						..
+					} => {
+						let candidate_hash = candidate_receipt.hash();
+						gum::debug!(target: LOG_TARGET, ?candidate_hash, "Responding to ValidateFromExhaustive");
+
+						let result = if self.invalid_candidates.contains(&candidate_hash) {
+							ValidationResult::Invalid(InvalidCandidate::InvalidOutputs)
+						} else {
+							ValidationResult::Valid(
+								CandidateCommitments::default(),
+								validation_data,
+							)
+						};
+						let _ = response_sender.send(Ok(result));
+					},
+					message => {
+						unimplemented!("Unexpected candidate-validation message: {:?}", message)
+					},
+				},
+			}
+		}
+	}
+}
@@ -18,8 +18,10 @@ use polkadot_node_subsystem::HeadSupportsParachains;
 use polkadot_node_subsystem_types::Hash;
 use sp_consensus::SyncOracle;
 
+pub mod authority_discovery;
 pub mod av_store;
 pub mod candidate_backing;
+pub mod candidate_validation;
 pub mod chain_api;
 pub mod dummy;
 pub mod network_bridge;
@@ -30,6 +30,7 @@ use polkadot_primitives::{
 	GroupRotationInfo, IndexedVec, NodeFeatures, OccupiedCore, ScheduledCore, SessionIndex,
 	SessionInfo, ValidatorIndex,
 };
+use polkadot_primitives_test_helpers::dummy_validation_code;
 use sp_consensus_babe::Epoch as BabeEpoch;
 use sp_core::H256;
 use std::collections::HashMap;
@@ -288,6 +289,26 @@ impl MockRuntimeApi {
 							};
 							tx.send(Ok((groups, group_rotation_info))).unwrap();
 						},
+						RuntimeApiMessage::Request(
+							_block_hash,
+							RuntimeApiRequest::FetchOnChainVotes(sender),
+						) => {
+							// No disputes are ever concluded on chain.
+							let _ = sender.send(Ok(None));
+						},
+						RuntimeApiMessage::Request(
+							_block_hash,
+							RuntimeApiRequest::UnappliedSlashes(sender),
+						) => {
+							let _ = sender.send(Ok(vec![]));
+						},
+						RuntimeApiMessage::Request(
+							_block_hash,
+							RuntimeApiRequest::ValidationCodeByHash(_code_hash, sender),
+						) => {
+							// The code is never executed, the candidate validation is mocked.
+							let _ = sender.send(Ok(Some(dummy_validation_code())));
+						},
 						// Long term TODO: implement more as needed.
 						message => {
 							unimplemented!("Unexpected runtime-api message: {:?}", message)
@@ -25,6 +25,9 @@ use std::collections::HashMap;
 pub struct BenchmarkUsage {
 	pub network_usage: Vec<ResourceUsage>,
 	pub cpu_usage: Vec<ResourceUsage>,
+	// Only reported by objectives running subsystems backed by a database.
+	#[serde(default)]
+	pub db_usage: Vec<ResourceUsage>,
 }
 
 impl std::fmt::Display for BenchmarkUsage {
@@ -46,7 +49,23 @@ impl std::fmt::Display for BenchmarkUsage {
 				.sorted()
 				.collect::<Vec<String>>()
 				.join("\n")
-		)
+		)?;
+
+		if !self.db_usage.is_empty() {
+			write!(
+				f,
+				"\n{}\n{}\n",
+				format!("{:<32}{:>12}{:>12}", "DB writes, KiB", "total", "per block").blue(),
+				self.db_usage
+					.iter()
+					.map(|v| v.to_string())
+					.sorted()
+					.collect::<Vec<String>>()
+					.join("\n")
+			)?;
+		}
+
+		Ok(())
 	}
 }
 
@@ -55,10 +74,12 @@ impl BenchmarkUsage {
 		let all_network_usages: Vec<&ResourceUsage> =
 			usages.iter().flat_map(|v| &v.network_usage).collect();
 		let all_cpu_usage: Vec<&ResourceUsage> = usages.iter().flat_map(|v| &v.cpu_usage).collect();
+		let all_db_usage: Vec<&ResourceUsage> = usages.iter().flat_map(|v| &v.db_usage).collect();
 
 		Self {
 			network_usage: ResourceUsage::average_by_resource_name(&all_network_usages),
 			cpu_usage: ResourceUsage::average_by_resource_name(&all_cpu_usage),
+			db_usage: ResourceUsage::average_by_resource_name(&all_db_usage),
 		}
 	}
 
@@ -70,6 +91,10 @@ impl BenchmarkUsage {
 		check_usage(&self.cpu_usage, checks)
 	}
 
+	pub fn check_db_usage(&self, checks: &[ResourceUsageCheck]) -> Vec<String> {
+		check_usage(&self.db_usage, checks)
+	}
+
 	pub fn cpu_usage_diff(&self, other: &Self, resource_name: &str) -> Option<f64> {
 		let self_res = self.cpu_usage.iter().find(|v| v.resource_name == resource_name);
 		let other_res = other.cpu_usage.iter().find(|v| v.resource_name == resource_name);
@@ -96,6 +121,11 @@ impl BenchmarkUsage {
 				unit: "seconds".to_string(),
 				value: v.per_block,
 			}))
+			.chain(self.db_usage.iter().map(|v| ChartItem {
+				name: v.resource_name.clone(),
+				unit: "KiB".to_string(),
+				value: v.per_block,
+			}))
 			.collect::<Vec<_>>();
 
 		Ok(serde_json::to_string(&chart)?)
@@ -37,7 +37,7 @@ use polkadot_node_primitives::{
 		v2::{CandidateBitfield, IndirectAssignmentCertV2, IndirectSignedApprovalVoteV2},
 	},
 	AvailableData, BabeEpoch, BlockWeight, CandidateVotes, CollationGenerationConfig,
-	CollationSecondedSignal, DisputeMessage, DisputeStatus, ErasureChunk, PoV,
+	CollationSecondedSignal, DisputeMessage, DisputeStatus, DisputeSummary, ErasureChunk, PoV,
 	SignedDisputeStatement, SignedFullStatement, SignedFullStatementWithPVD, SubmitCollationParams,
 	ValidationResult,
 };
@@ -306,6 +306,10 @@ pub enum DisputeCoordinatorMessage {
 	/// Fetch a list of all active disputes that the coordinator is aware of.
 	/// These disputes are either not yet concluded or recently concluded.
 	ActiveDisputes(oneshot::Sender<Vec<(SessionIndex, CandidateHash, DisputeStatus)>>),
+	/// Fetch a summary of all recent disputes, including the votes of the local node.
+	///
+	/// Meant for inspection by node operators, ordered by session and candidate hash.
+	RecentDisputeSummaries(oneshot::Sender<Result<Vec<DisputeSummary>, RecentDisputesError>>),
 	/// Get candidate votes for a candidate.
 	QueryCandidateVotes(
 		Vec<(SessionIndex, CandidateHash)>,
@@ -341,6 +345,14 @@ pub enum ImportStatementsResult {
 	ValidImport,
 }
 
+/// The error result type of a [`DisputeCoordinatorMessage::RecentDisputeSummaries`] request.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum RecentDisputesError {
+	#[error("Session info of session {0} is not available")]
+	SessionInfoUnavailable(SessionIndex),
+}
+
 /// Messages going to the dispute distribution subsystem.
 #[derive(Debug)]
 pub enum DisputeDistributionMessage {
@@ -100,6 +100,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					execute_workers_max_num: None,
 					prepare_workers_hard_max_num: None,
 					prepare_workers_soft_max_num: None,
+					collation_snapshot_dir: None,
 				},
 			),
 		sc_network::config::NetworkBackendType::Litep2p =>
@@ -122,6 +123,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					execute_workers_max_num: None,
 					prepare_workers_hard_max_num: None,
 					prepare_workers_soft_max_num: None,
+					collation_snapshot_dir: None,
 				},
 			),
 	}
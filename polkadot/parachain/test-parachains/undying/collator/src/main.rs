@@ -100,6 +100,7 @@ fn main() -> Result<()> {
 						execute_workers_max_num: None,
 						prepare_workers_hard_max_num: None,
 						prepare_workers_soft_max_num: None,
+						collation_snapshot_dir: None,
 					},
 				)
 				.map_err(|e| e.to_string())?;
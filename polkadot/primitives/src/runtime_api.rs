@@ -295,3 +295,19 @@ sp_api::decl_runtime_apis! {
 		fn candidates_pending_availability(para_id: ppp::Id) -> Vec<CommittedCandidateReceipt<Hash>>;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API for querying the on-demand order queue, e.g. before placing an order.
+	pub trait OnDemandApi<Balance> where Balance: codec::Codec {
+		/// Get the number of orders in the on-demand queue.
+		fn queue_depth() -> u32;
+
+		/// Get the spot price for the current block followed by the spot prices for the next
+		/// `blocks` blocks, assuming the size of the queue stays the same.
+		fn spot_price_trajectory(blocks: u32) -> Vec<Balance>;
+
+		/// Get an estimate of the wait for an order placed now for the given para, as the number of
+		/// orders which may be served before it.
+		fn estimated_wait(para_id: ppp::Id) -> u32;
+	}
+}
@@ -0,0 +1,440 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC exposing the dispute history recorded by the dispute coordinator of a validator.
+//!
+//! The data is local to the node, hence all calls are considered unsafe.
+
+use codec::Encode;
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+
+use polkadot_node_primitives::{CandidateVotes, DisputeStatus, DisputeSummary};
+use polkadot_node_subsystem_types::messages::{DisputeCoordinatorMessage, RecentDisputesError};
+use polkadot_overseer::Handle;
+use polkadot_primitives::{
+	CandidateHash, DisputeStatement, Hash, InvalidDisputeStatementKind, SessionIndex,
+	ValidDisputeStatementKind,
+};
+use sc_rpc_api::{DenyUnsafe, UnsafeRpcError};
+use sp_core::Bytes;
+
+/// Base code for all dispute errors.
+const DISPUTES_ERROR: i32 = 9000;
+
+/// Origin of the messages sent to the overseer.
+const ORIGIN: &str = "DisputesRpc";
+
+/// Outcome of a dispute, so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DisputeOutcome {
+	/// The dispute is ongoing.
+	Active,
+	/// The dispute is ongoing and more than a byzantine threshold of validators took part.
+	Confirmed,
+	/// The dispute concluded in favor of the candidate.
+	ConcludedFor,
+	/// The dispute concluded against the candidate.
+	ConcludedAgainst,
+}
+
+/// A recent dispute, as seen by the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeInfo {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// The para the candidate was backed for.
+	pub para_id: u32,
+	/// The outcome of the dispute.
+	pub outcome: DisputeOutcome,
+	/// Unix timestamp in seconds of the conclusion, if the dispute concluded.
+	pub concluded_at: Option<u64>,
+	/// Number of votes for the validity of the candidate.
+	pub valid_votes: u32,
+	/// Number of votes against the validity of the candidate.
+	pub invalid_votes: u32,
+	/// The vote of the node, `true` meaning valid, if it voted.
+	pub local_vote: Option<bool>,
+	/// Whether the node was a parachain validator in the session of the dispute.
+	pub local_validator: bool,
+}
+
+impl From<DisputeSummary> for DisputeInfo {
+	fn from(summary: DisputeSummary) -> Self {
+		let (outcome, concluded_at) = match summary.status {
+			DisputeStatus::Active => (DisputeOutcome::Active, None),
+			DisputeStatus::Confirmed => (DisputeOutcome::Confirmed, None),
+			DisputeStatus::ConcludedFor(at) => (DisputeOutcome::ConcludedFor, Some(at)),
+			DisputeStatus::ConcludedAgainst(at) => (DisputeOutcome::ConcludedAgainst, Some(at)),
+		};
+
+		DisputeInfo {
+			session: summary.session,
+			candidate_hash: summary.candidate_hash.0,
+			para_id: summary.para_id.into(),
+			outcome,
+			concluded_at,
+			valid_votes: summary.valid_votes,
+			invalid_votes: summary.invalid_votes,
+			local_vote: summary.local_vote,
+			local_validator: summary.local_validator,
+		}
+	}
+}
+
+/// A single vote on a disputed candidate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeVote {
+	/// The index of the validator in the session.
+	pub validator_index: u32,
+	/// The kind of statement the vote was derived from, e.g. `backingSeconded` or `explicit`.
+	pub kind: String,
+	/// The SCALE encoded `DisputeStatement`.
+	pub statement: Bytes,
+	/// The signature of the validator.
+	pub signature: Bytes,
+}
+
+/// All votes the node recorded on a disputed candidate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeVotes {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// The SCALE encoded `CandidateReceipt`.
+	pub candidate_receipt: Bytes,
+	/// Votes for the validity of the candidate, by validator index.
+	pub valid: Vec<DisputeVote>,
+	/// Votes against the validity of the candidate, by validator index.
+	pub invalid: Vec<DisputeVote>,
+}
+
+impl DisputeVotes {
+	fn new(session: SessionIndex, candidate_hash: CandidateHash, votes: CandidateVotes) -> Self {
+		let valid = votes
+			.valid
+			.into_iter()
+			.map(|(index, (kind, signature))| DisputeVote {
+				validator_index: index.0,
+				kind: valid_kind_name(&kind).into(),
+				statement: DisputeStatement::Valid(kind).encode().into(),
+				signature: signature.encode().into(),
+			})
+			.collect();
+		let invalid = votes
+			.invalid
+			.into_iter()
+			.map(|(index, (kind, signature))| DisputeVote {
+				validator_index: index.0,
+				kind: invalid_kind_name(&kind).into(),
+				statement: DisputeStatement::Invalid(kind).encode().into(),
+				signature: signature.encode().into(),
+			})
+			.collect();
+
+		DisputeVotes {
+			session,
+			candidate_hash: candidate_hash.0,
+			candidate_receipt: votes.candidate_receipt.encode().into(),
+			valid,
+			invalid,
+		}
+	}
+}
+
+fn valid_kind_name(kind: &ValidDisputeStatementKind) -> &'static str {
+	match kind {
+		ValidDisputeStatementKind::Explicit => "explicit",
+		ValidDisputeStatementKind::BackingSeconded(_) => "backingSeconded",
+		ValidDisputeStatementKind::BackingValid(_) => "backingValid",
+		ValidDisputeStatementKind::ApprovalChecking => "approvalChecking",
+		ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(_) =>
+			"approvalCheckingMultipleCandidates",
+	}
+}
+
+fn invalid_kind_name(kind: &InvalidDisputeStatementKind) -> &'static str {
+	match kind {
+		InvalidDisputeStatementKind::Explicit => "explicit",
+	}
+}
+
+/// Errors of the disputes RPC.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The dispute coordinator did not answer, e.g. because the node is not a validator.
+	#[error("The dispute coordinator is not available")]
+	DisputeCoordinatorUnavailable,
+	/// The dispute coordinator failed to summarize the recent disputes.
+	#[error(transparent)]
+	RecentDisputes(#[from] RecentDisputesError),
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::DisputeCoordinatorUnavailable =>
+				ErrorObject::owned(DISPUTES_ERROR + 1, error.to_string(), None::<()>),
+			Error::RecentDisputes(_) =>
+				ErrorObject::owned(DISPUTES_ERROR + 2, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
+
+/// Provides access to the disputes recorded by a validator.
+#[rpc(client, server)]
+pub trait DisputesApi {
+	/// Returns the recent disputes the node is aware of, most recent session first.
+	///
+	/// At most `limit` disputes are returned, if given.
+	#[method(name = "parachain_recentDisputes")]
+	async fn recent_disputes(&self, limit: Option<u32>) -> Result<Vec<DisputeInfo>, Error>;
+
+	/// Returns all votes recorded for the given candidate, if any.
+	#[method(name = "parachain_disputeVotes")]
+	async fn dispute_votes(
+		&self,
+		session: SessionIndex,
+		candidate_hash: Hash,
+	) -> Result<Option<DisputeVotes>, Error>;
+}
+
+/// Implements the [`DisputesApiServer`] RPC trait by querying the dispute coordinator.
+pub struct Disputes {
+	overseer_handle: Handle,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl Disputes {
+	/// Creates a new instance of the disputes RPC handler.
+	pub fn new(overseer_handle: Handle, deny_unsafe: DenyUnsafe) -> Self {
+		Self { overseer_handle, deny_unsafe }
+	}
+
+	async fn request<T>(
+		&self,
+		message: impl FnOnce(oneshot::Sender<T>) -> DisputeCoordinatorMessage,
+	) -> Result<T, Error> {
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle.clone().send_msg(message(tx), ORIGIN).await;
+		rx.await.map_err(|_| Error::DisputeCoordinatorUnavailable)
+	}
+}
+
+#[async_trait]
+impl DisputesApiServer for Disputes {
+	async fn recent_disputes(&self, limit: Option<u32>) -> Result<Vec<DisputeInfo>, Error> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let summaries = self.request(DisputeCoordinatorMessage::RecentDisputeSummaries).await??;
+
+		Ok(summaries
+			.into_iter()
+			.rev()
+			.take(limit.map_or(usize::MAX, |limit| limit as usize))
+			.map(Into::into)
+			.collect())
+	}
+
+	async fn dispute_votes(
+		&self,
+		session: SessionIndex,
+		candidate_hash: Hash,
+	) -> Result<Option<DisputeVotes>, Error> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let query = vec![(session, CandidateHash(candidate_hash))];
+		let votes = self
+			.request(|tx| DisputeCoordinatorMessage::QueryCandidateVotes(query, tx))
+			.await?;
+
+		Ok(votes.into_iter().next().map(|(session, candidate_hash, votes)| {
+			DisputeVotes::new(session, candidate_hash, votes)
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+	use jsonrpsee::{MethodsError, RpcModule};
+	use polkadot_overseer::{
+		self as overseer,
+		dummy::dummy_overseer_builder,
+		gen::{FromOrchestra, SpawnedSubsystem},
+		HeadSupportsParachains, OverseerSignal, SubsystemError,
+	};
+	use polkadot_primitives::Id as ParaId;
+	use sp_core::testing::TaskExecutor;
+
+	struct AlwaysSupportsParachains;
+
+	#[async_trait]
+	impl HeadSupportsParachains for AlwaysSupportsParachains {
+		async fn head_supports_parachains(&self, _head: &Hash) -> bool {
+			true
+		}
+	}
+
+	/// Answers all `RecentDisputeSummaries` requests with the same response.
+	struct MockDisputeCoordinator(Result<Vec<DisputeSummary>, RecentDisputesError>);
+
+	impl<C> overseer::Subsystem<C, SubsystemError> for MockDisputeCoordinator
+	where
+		C: overseer::SubsystemContext<Message = DisputeCoordinatorMessage, Signal = OverseerSignal>,
+	{
+		fn start(self, mut ctx: C) -> SpawnedSubsystem<SubsystemError> {
+			SpawnedSubsystem {
+				name: "mock-dispute-coordinator",
+				future: Box::pin(async move {
+					loop {
+						match ctx.recv().await {
+							Ok(FromOrchestra::Communication {
+								msg: DisputeCoordinatorMessage::RecentDisputeSummaries(tx),
+							}) => {
+								let _ = tx.send(self.0.clone());
+							},
+							Ok(FromOrchestra::Signal(OverseerSignal::Conclude)) | Err(_) =>
+								return Ok(()),
+							_ => (),
+						}
+					}
+				}),
+			}
+		}
+	}
+
+	fn test_disputes_rpc_module(
+		response: Result<Vec<DisputeSummary>, RecentDisputesError>,
+		deny_unsafe: DenyUnsafe,
+	) -> RpcModule<Disputes> {
+		let (overseer, handle) =
+			dummy_overseer_builder(TaskExecutor::new(), AlwaysSupportsParachains, None)
+				.unwrap()
+				.replace_dispute_coordinator(move |_| MockDisputeCoordinator(response))
+				.build()
+				.unwrap();
+		tokio::spawn(overseer.run());
+
+		Disputes::new(Handle::new(handle), deny_unsafe).into_rpc()
+	}
+
+	fn summary(session: SessionIndex, status: DisputeStatus) -> DisputeSummary {
+		DisputeSummary {
+			session,
+			candidate_hash: CandidateHash(Hash::repeat_byte(session as u8)),
+			para_id: ParaId::from(100),
+			status,
+			valid_votes: 3,
+			invalid_votes: 1,
+			local_vote: Some(true),
+			local_validator: true,
+		}
+	}
+
+	#[tokio::test]
+	async fn recent_disputes_works() {
+		let api = test_disputes_rpc_module(
+			Ok(vec![
+				summary(1, DisputeStatus::ConcludedFor(42)),
+				summary(2, DisputeStatus::Active),
+			]),
+			DenyUnsafe::No,
+		);
+
+		let disputes: Vec<DisputeInfo> =
+			api.call("parachain_recentDisputes", [None::<u32>]).await.unwrap();
+		assert_eq!(
+			disputes,
+			vec![
+				DisputeInfo {
+					session: 2,
+					candidate_hash: Hash::repeat_byte(2),
+					para_id: 100,
+					outcome: DisputeOutcome::Active,
+					concluded_at: None,
+					valid_votes: 3,
+					invalid_votes: 1,
+					local_vote: Some(true),
+					local_validator: true,
+				},
+				DisputeInfo {
+					session: 1,
+					candidate_hash: Hash::repeat_byte(1),
+					para_id: 100,
+					outcome: DisputeOutcome::ConcludedFor,
+					concluded_at: Some(42),
+					valid_votes: 3,
+					invalid_votes: 1,
+					local_vote: Some(true),
+					local_validator: true,
+				},
+			]
+		);
+
+		// Only the most recent dispute is returned.
+		let disputes: Vec<DisputeInfo> =
+			api.call("parachain_recentDisputes", [Some(1u32)]).await.unwrap();
+		assert_eq!(disputes.len(), 1);
+		assert_eq!(disputes[0].session, 2);
+	}
+
+	#[tokio::test]
+	async fn recent_disputes_reports_errors() {
+		let api = test_disputes_rpc_module(
+			Err(RecentDisputesError::SessionInfoUnavailable(1)),
+			DenyUnsafe::No,
+		);
+
+		let err = api
+			.call::<_, Vec<DisputeInfo>>("parachain_recentDisputes", [None::<u32>])
+			.await
+			.unwrap_err();
+		assert_matches!(
+			err,
+			MethodsError::JsonRpc(err) if err.code() == DISPUTES_ERROR + 2 &&
+				err.message() == "Session info of session 1 is not available"
+		);
+	}
+
+	#[tokio::test]
+	async fn recent_disputes_is_unsafe() {
+		let api = test_disputes_rpc_module(Ok(Vec::new()), DenyUnsafe::Yes);
+
+		let request = r#"{"jsonrpc":"2.0","method":"parachain_recentDisputes","params":[],"id":1}"#;
+		let (response, _) = api.raw_json_request(request, 1).await.unwrap();
+		let expected = r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"RPC call is unsafe to be called externally"},"id":1}"#;
+
+		assert_eq!(response, expected);
+	}
+}
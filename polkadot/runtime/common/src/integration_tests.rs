@@ -280,6 +280,7 @@ impl pallet_identity::Config for Test {
 	type BasicDeposit = ConstU32<100>;
 	type ByteDeposit = ConstU32<10>;
 	type SubAccountDeposit = ConstU32<100>;
+	type AttestationDeposit = ConstU32<10>;
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<ConstU32<2>>;
 	type MaxRegistrars = ConstU32<20>;
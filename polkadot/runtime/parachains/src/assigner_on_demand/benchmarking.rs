@@ -90,6 +90,76 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id)
 	}
 
+	#[benchmark]
+	fn place_order_with_expiry(s: Linear<1, MAX_FILL_BENCH>) {
+		// Setup
+		let caller = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		Pallet::<T>::populate_queue(para_id, s);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id, expires_at)
+	}
+
+	#[benchmark]
+	fn cancel_order(s: Linear<1, MAX_FILL_BENCH>) -> Result<(), BenchmarkError> {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		Pallet::<T>::populate_queue(para_id, s);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Pallet::<T>::place_order_with_expiry(
+			RawOrigin::Signed(caller.clone()).into(),
+			BalanceOf::<T>::max_value(),
+			para_id,
+			expires_at,
+		)?;
+		// The order is the last one in the queue.
+		let order_index = QueueStatus::<T>::get().next_index.0.overflowing_sub(1).0;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), order_index);
+
+		assert!(RefundableOrders::<T>::get(order_index).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn drop_expired_orders(e: Linear<0, MAX_EXPIRED_ORDERS_PER_POP>) -> Result<(), BenchmarkError> {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let now = frame_system::Pallet::<T>::block_number();
+		for _ in 0..e {
+			Pallet::<T>::place_order_with_expiry(
+				RawOrigin::Signed(caller.clone()).into(),
+				BalanceOf::<T>::max_value(),
+				para_id,
+				now + 1u32.into(),
+			)?;
+		}
+		// Served once the expired orders are dropped, unless the limit of dropped orders is hit.
+		Pallet::<T>::populate_queue(para_id, 1);
+		frame_system::Pallet::<T>::set_block_number(now + 2u32.into());
+
+		#[block]
+		{
+			Pallet::<T>::pop_assignment_for_core(CoreIndex(0));
+		}
+
+		assert_eq!(RefundableOrders::<T>::iter().count(), 0);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(
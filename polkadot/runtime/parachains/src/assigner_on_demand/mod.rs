@@ -25,6 +25,11 @@
 //! a specific `ParaId`, orders for blockspace for that `ParaId` will only be assigned to
 //! that `CoreIndex`.
 //!
+//! Orders placed with an expiry are dropped and refunded if they were not served by the time they
+//! expire. They can also be cancelled and refunded as long as they are queued. A part of the price
+//! paid, `Config::NonRefundableFee`, is kept on refunds, so filling the queue with orders which are
+//! never served is not free.
+//!
 //! NOTE: Once we have elastic scaling implemented we might want to extend this module to support
 //! ignoring core affinity up to a certain extend. This should be opt-in though as the parachain
 //! needs to support multiple cores in the same block. If we want to enable a single parachain
@@ -67,11 +72,21 @@ use sp_std::{
 
 const LOG_TARGET: &str = "runtime::parachains::assigner-on-demand";
 
+/// The maximum number of blocks a spot price trajectory is computed for.
+pub const MAX_SPOT_PRICE_TRAJECTORY: u32 = 1_000;
+
+/// The maximum number of expired orders dropped and refunded by a single call to
+/// `pop_assignment_for_core`.
+pub const MAX_EXPIRED_ORDERS_PER_POP: u32 = 10;
+
 pub use pallet::*;
 
 pub trait WeightInfo {
 	fn place_order_allow_death(s: u32) -> Weight;
 	fn place_order_keep_alive(s: u32) -> Weight;
+	fn place_order_with_expiry(s: u32) -> Weight;
+	fn cancel_order(s: u32) -> Weight;
+	fn drop_expired_orders(e: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -85,6 +100,18 @@ impl WeightInfo for TestWeightInfo {
 	fn place_order_keep_alive(_: u32) -> Weight {
 		Weight::MAX
 	}
+
+	fn place_order_with_expiry(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn cancel_order(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn drop_expired_orders(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// Meta data for full queue.
@@ -103,7 +130,8 @@ impl WeightInfo for TestWeightInfo {
 ///
 ///  - insert: O(1) for placing an order, O(log(N)) for push backs.
 ///  - pop_assignment_for_core: O(log(N)), O(N) worst case: Can only happen for one core, next core
-///  is already less work.
+///  is already less work. Expired orders met on the way are dropped and refunded, up to
+///  `MAX_EXPIRED_ORDERS_PER_POP` per call, each being another pop.
 ///  - report_processed & push back: If affinity dropped to 0, then O(N) in the worst case. Again
 ///  this divides per core.
 ///
@@ -205,6 +233,23 @@ enum QueuePushDirection {
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// An order placed with an expiry, refunded if it is not served in time.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, RuntimeDebug)]
+pub struct RefundableOrder<AccountId, Balance, BlockNumber> {
+	/// The account which placed the order, and gets refunded.
+	pub ordered_by: AccountId,
+	/// The para the order was placed for.
+	pub para_id: ParaId,
+	/// The spot price paid for the order.
+	pub paid: Balance,
+	/// The last block in which the order may be served.
+	pub expires_at: BlockNumber,
+}
+
+/// Shorthand for the refundable orders of the runtime.
+type RefundableOrderOf<T> =
+	RefundableOrder<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
 /// Errors that can happen during spot traffic calculation.
 #[derive(PartialEq, RuntimeDebug)]
 enum SpotTrafficCalculationErr {
@@ -324,6 +369,11 @@ pub mod pallet {
 		/// The default value for the spot traffic multiplier.
 		#[pallet::constant]
 		type TrafficDefaultValue: Get<FixedU128>;
+
+		/// The part of the price paid for an order with an expiry which is kept when the order is
+		/// cancelled or expires.
+		#[pallet::constant]
+		type NonRefundableFee: Get<Perbill>;
 	}
 
 	/// Creates an empty queue status for an empty queue with initial traffic value.
@@ -365,6 +415,14 @@ pub mod pallet {
 		EntriesOnEmpty<T>,
 	>;
 
+	/// Orders placed with an expiry which are still queued, by their index in the queue.
+	///
+	/// Orders are removed once popped for a core, so they are not refunded any more when they get
+	/// pushed back.
+	#[pallet::storage]
+	pub(super) type RefundableOrders<T: Config> =
+		StorageMap<_, Twox64Concat, u32, RefundableOrderOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -372,6 +430,16 @@ pub mod pallet {
 		OnDemandOrderPlaced { para_id: ParaId, spot_price: BalanceOf<T>, ordered_by: T::AccountId },
 		/// The value of the spot price has likely changed
 		SpotPriceSet { spot_price: BalanceOf<T> },
+		/// An order with an expiry was placed, it can be cancelled by its index until served.
+		RefundableOrderPlaced { order_index: u32, para_id: ParaId, expires_at: BlockNumberFor<T> },
+		/// An order was cancelled or expired, and the price paid, less the `NonRefundableFee`, was
+		/// refunded.
+		OrderRefunded {
+			order_index: u32,
+			para_id: ParaId,
+			refund: BalanceOf<T>,
+			ordered_by: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -381,6 +449,12 @@ pub mod pallet {
 		/// The current spot price is higher than the max amount specified in the `place_order`
 		/// call, making it invalid.
 		SpotPriceHigherThanMaxAmount,
+		/// The expiry of an order is not after the current block.
+		InvalidExpiry,
+		/// There is no queued order with an expiry at the given index.
+		UnknownOrder,
+		/// Only the account which placed an order can cancel it before it expires.
+		NotOrderOwner,
 	}
 
 	#[pallet::hooks]
@@ -393,8 +467,14 @@ pub mod pallet {
 				Self::update_spot_traffic(&config, queue_status);
 			});
 
+			// The scheduler pops assignments for the cores of this block, which may drop expired
+			// orders on every core.
+			let drop_expired_orders =
+				<T as Config>::WeightInfo::drop_expired_orders(MAX_EXPIRED_ORDERS_PER_POP)
+					.saturating_mul(config.scheduler_params.num_cores.into());
+
 			// 2 reads in config and queuestatus, at maximum 1 write to queuestatus.
-			T::DbWeight::get().reads_writes(2, 1)
+			T::DbWeight::get().reads_writes(2, 1).saturating_add(drop_expired_orders)
 		}
 	}
 
@@ -423,7 +503,7 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, AllowDeath)
+			Pallet::<T>::do_place_order(sender, max_amount, para_id, AllowDeath).map(|_| ())
 		}
 
 		/// Same as the [`place_order_allow_death`](Self::place_order_allow_death) call , but with a
@@ -449,7 +529,91 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, KeepAlive)
+			Pallet::<T>::do_place_order(sender, max_amount, para_id, KeepAlive).map(|_| ())
+		}
+
+		/// Same as the [`place_order_keep_alive`](Self::place_order_keep_alive) call, but the
+		/// order expires if it is not served by `expires_at`. Expired orders are dropped and the
+		/// spot price paid is refunded, except for the `NonRefundableFee`. Until then, the order
+		/// can be cancelled with [`cancel_order`](Self::cancel_order).
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, funds will be withdrawn from this account.
+		/// - `max_amount`: The maximum balance to withdraw from the origin to place an order.
+		/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
+		/// - `expires_at`: The last block in which the order may be served.
+		///
+		/// Errors:
+		/// - `InsufficientBalance`: from the Currency implementation
+		/// - `QueueFull`
+		/// - `SpotPriceHigherThanMaxAmount`
+		/// - `InvalidExpiry`
+		///
+		/// Events:
+		/// - `OnDemandOrderPlaced`
+		/// - `RefundableOrderPlaced`
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order_with_expiry(QueueStatus::<T>::get().size()))]
+		pub fn place_order_with_expiry(
+			origin: OriginFor<T>,
+			max_amount: BalanceOf<T>,
+			para_id: ParaId,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			let (QueueIndex(order_index), paid) =
+				Pallet::<T>::do_place_order(sender.clone(), max_amount, para_id, KeepAlive)?;
+			RefundableOrders::<T>::insert(
+				order_index,
+				RefundableOrder { ordered_by: sender, para_id, paid, expires_at },
+			);
+			Pallet::<T>::deposit_event(Event::<T>::RefundableOrderPlaced {
+				order_index,
+				para_id,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a queued order placed with
+		/// [`place_order_with_expiry`](Self::place_order_with_expiry), refunding the spot price
+		/// paid, except for the `NonRefundableFee`, to the account which placed it.
+		///
+		/// Before it expires, an order can only be cancelled by the account which placed it.
+		/// Expired orders can be cancelled by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call.
+		/// - `order_index`: The index of the order, as in the `RefundableOrderPlaced` event.
+		///
+		/// Errors:
+		/// - `UnknownOrder`
+		/// - `NotOrderOwner`
+		///
+		/// Events:
+		/// - `OrderRefunded`
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order(QueueStatus::<T>::get().size()))]
+		pub fn cancel_order(origin: OriginFor<T>, order_index: u32) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let order = RefundableOrders::<T>::get(order_index).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(
+				order.ordered_by == sender ||
+					order.expires_at < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::NotOrderOwner
+			);
+
+			RefundableOrders::<T>::remove(order_index);
+			Pallet::<T>::remove_on_demand_order(QueueIndex(order_index), order.para_id);
+			Pallet::<T>::refund_order(order_index, order);
+
+			Ok(())
 		}
 	}
 }
@@ -461,9 +625,40 @@ where
 {
 	/// Take the next queued entry that is available for a given core index.
 	///
+	/// Expired orders are dropped and refunded on the way. Once `MAX_EXPIRED_ORDERS_PER_POP` of
+	/// them were dropped, no assignment is returned and the remaining entries are left to later
+	/// calls.
+	///
 	/// Parameters:
 	/// - `core_index`: The core index
 	pub fn pop_assignment_for_core(core_index: CoreIndex) -> Option<Assignment> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut dropped = 0;
+		let entry = loop {
+			let entry = Pallet::<T>::pop_entry_for_core(core_index, now)?;
+			match RefundableOrders::<T>::take(entry.idx.0) {
+				Some(order) if order.expires_at < now => {
+					Pallet::<T>::refund_order(entry.idx.0, order);
+					dropped += 1;
+					if dropped >= MAX_EXPIRED_ORDERS_PER_POP {
+						return None
+					}
+				},
+				_ => break entry,
+			}
+		};
+
+		let assignment = Assignment::Pool { para_id: entry.para_id, core_index };
+
+		Pallet::<T>::increase_affinity(assignment.para_id(), core_index);
+		Some(assignment)
+	}
+
+	/// Remove the next queued entry that is available for a given core index from the queue.
+	///
+	/// Picking an entry from the free queue moves all other free entries of its para to the
+	/// affinity queue of the core, unless the entry expired.
+	fn pop_entry_for_core(core_index: CoreIndex, now: BlockNumberFor<T>) -> Option<EnqueuedOrder> {
 		let entry: Result<EnqueuedOrder, ()> = QueueStatus::<T>::try_mutate(|queue_status| {
 			AffinityEntries::<T>::try_mutate(core_index, |affinity_entries| {
 				let free_entry = FreeEntries::<T>::try_mutate(|free_entries| {
@@ -476,12 +671,14 @@ where
 					};
 					if pick_free {
 						let entry = free_entries.pop().ok_or(())?;
-						let (mut affinities, free): (BinaryHeap<_>, BinaryHeap<_>) =
-							take(free_entries)
-								.into_iter()
-								.partition(|e| e.para_id == entry.para_id);
-						affinity_entries.append(&mut affinities);
-						*free_entries = free;
+						if !Pallet::<T>::is_expired(&entry, now) {
+							let (mut affinities, free): (BinaryHeap<_>, BinaryHeap<_>) =
+								take(free_entries)
+									.into_iter()
+									.partition(|e| e.para_id == entry.para_id);
+							affinity_entries.append(&mut affinities);
+							*free_entries = free;
+						}
 						Ok(entry)
 					} else {
 						Err(())
@@ -493,10 +690,12 @@ where
 			})
 		});
 
-		let assignment = entry.map(|e| Assignment::Pool { para_id: e.para_id, core_index }).ok()?;
+		entry.ok()
+	}
 
-		Pallet::<T>::increase_affinity(assignment.para_id(), core_index);
-		Some(assignment)
+	/// Whether the entry is an order with an expiry before `now`.
+	fn is_expired(entry: &EnqueuedOrder, now: BlockNumberFor<T>) -> bool {
+		RefundableOrders::<T>::get(entry.idx.0).map_or(false, |order| order.expires_at < now)
 	}
 
 	/// Report that the `para_id` & `core_index` combination was processed.
@@ -526,6 +725,48 @@ where
 		});
 	}
 
+	/// The number of orders in the queue, including the ones with core affinity.
+	pub fn queue_depth() -> u32 {
+		QueueStatus::<T>::get().size()
+	}
+
+	/// The spot price for the current block followed by the spot prices for the next `blocks`
+	/// blocks, up to `MAX_SPOT_PRICE_TRAJECTORY`, assuming the size of the queue stays the same.
+	pub fn spot_price_trajectory(blocks: u32) -> Vec<BalanceOf<T>> {
+		let config = configuration::ActiveConfig::<T>::get();
+		let queue_status = QueueStatus::<T>::get();
+		let base_fee = config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>();
+		let blocks = blocks.min(MAX_SPOT_PRICE_TRAJECTORY);
+
+		let mut traffic = queue_status.traffic;
+		let mut prices = Vec::with_capacity(blocks as usize + 1);
+		prices.push(traffic.saturating_mul_int(base_fee));
+		for _ in 0..blocks {
+			traffic = Self::calculate_spot_traffic(
+				traffic,
+				config.scheduler_params.on_demand_queue_max_size,
+				queue_status.size(),
+				config.scheduler_params.on_demand_target_queue_utilization,
+				config.scheduler_params.on_demand_fee_variability,
+			)
+			.unwrap_or(traffic);
+			prices.push(traffic.saturating_mul_int(base_fee));
+		}
+		prices
+	}
+
+	/// An estimate of the wait for an order placed now for `para_id`, as the number of orders
+	/// which may be served before it.
+	///
+	/// This is an upper bound, as orders without core affinity may be served by any on demand
+	/// core.
+	pub fn estimated_wait(para_id: ParaId) -> u32 {
+		let affinity_entries = ParaIdAffinity::<T>::get(para_id)
+			.map_or(0, |affinity| AffinityEntries::<T>::get(affinity.core_index).len());
+		let free_entries = FreeEntries::<T>::get().len();
+		affinity_entries.saturating_add(free_entries).saturated_into()
+	}
+
 	/// Helper function for `place_order_*` calls. Used to differentiate between placing orders
 	/// with a keep alive check or to allow the account to be reaped.
 	///
@@ -535,6 +776,8 @@ where
 	/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
 	/// - `existence_requirement`: Whether or not to ensure that the account will not be reaped.
 	///
+	/// Returns: The index of the order in the queue and the spot price paid.
+	///
 	/// Errors:
 	/// - `InsufficientBalance`: from the Currency implementation
 	/// - `QueueFull`
@@ -547,7 +790,7 @@ where
 		max_amount: BalanceOf<T>,
 		para_id: ParaId,
 		existence_requirement: ExistenceRequirement,
-	) -> DispatchResult {
+	) -> Result<(QueueIndex, BalanceOf<T>), DispatchError> {
 		let config = configuration::ActiveConfig::<T>::get();
 
 		QueueStatus::<T>::mutate(|queue_status| {
@@ -574,17 +817,32 @@ where
 				queue_status.size() < config.scheduler_params.on_demand_queue_max_size,
 				Error::<T>::QueueFull
 			);
-			Pallet::<T>::add_on_demand_order(queue_status, para_id, QueuePushDirection::Back);
+			let idx =
+				Pallet::<T>::add_on_demand_order(queue_status, para_id, QueuePushDirection::Back);
 			Pallet::<T>::deposit_event(Event::<T>::OnDemandOrderPlaced {
 				para_id,
 				spot_price,
 				ordered_by: sender,
 			});
 
-			Ok(())
+			Ok((idx, spot_price))
 		})
 	}
 
+	/// Refunds the spot price paid for an order which won't be served, except for the
+	/// `NonRefundableFee`.
+	fn refund_order(order_index: u32, order: RefundableOrderOf<T>) {
+		let RefundableOrder { ordered_by, para_id, paid, .. } = order;
+		let refund = paid.saturating_sub(T::NonRefundableFee::get() * paid);
+		let _ = T::Currency::deposit_creating(&ordered_by, refund);
+		Pallet::<T>::deposit_event(Event::<T>::OrderRefunded {
+			order_index,
+			para_id,
+			refund,
+			ordered_by,
+		});
+	}
+
 	/// Calculate and update spot traffic.
 	fn update_spot_traffic(
 		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
@@ -698,11 +956,13 @@ where
 	/// - `location`: Whether to push this entry to the back or the front of the queue. Pushing an
 	///   entry to the front of the queue is only used when the scheduler wants to push back an
 	///   entry it has already popped.
+	///
+	/// Returns: The index of the order in the queue.
 	fn add_on_demand_order(
 		queue_status: &mut QueueStatusType,
 		para_id: ParaId,
 		location: QueuePushDirection,
-	) {
+	) -> QueueIndex {
 		let idx = match location {
 			QueuePushDirection::Back => queue_status.push_back(),
 			QueuePushDirection::Front => queue_status.push_front(),
@@ -718,6 +978,29 @@ where
 			Some(affinity) =>
 				AffinityEntries::<T>::mutate(affinity.core_index, |entries| entries.push(order)),
 		}
+		idx
+	}
+
+	/// Removes an order from the on demand queue.
+	///
+	/// The order is looked up in the affinity entries of the core the para has affinity with, if
+	/// any, then in the free entries.
+	fn remove_on_demand_order(idx: QueueIndex, para_id: ParaId) {
+		let remove = |entries: &mut BinaryHeap<EnqueuedOrder>| {
+			let len = entries.len();
+			entries.retain(|e| e.idx != idx);
+			entries.len() != len
+		};
+
+		let removed = ParaIdAffinity::<T>::get(para_id)
+			.map_or(false, |affinity| AffinityEntries::<T>::mutate(affinity.core_index, remove)) ||
+			FreeEntries::<T>::mutate(remove);
+
+		if removed {
+			QueueStatus::<T>::mutate(|queue_status| queue_status.consume_index(idx));
+		} else {
+			log::debug!(target: LOG_TARGET, "Order to remove not found in queue: {:?}", idx);
+		}
 	}
 
 	/// Decrease core affinity for para and update queue
@@ -327,6 +327,214 @@ fn place_order_keep_alive_keeps_alive() {
 	});
 }
 
+#[test]
+fn expired_orders_are_refunded() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let para_a = ParaId::from(111);
+	let para_b = ParaId::from(110);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_a, ParaKind::Parathread);
+		schedule_blank_para(para_b, ParaKind::Parathread);
+		Balances::make_free_balance_be(&alice, amt);
+		run_to_block(11, |n| if n == 11 { Some(Default::default()) } else { None });
+
+		assert_noop!(
+			OnDemandAssigner::place_order_with_expiry(
+				RuntimeOrigin::signed(alice),
+				amt,
+				para_a,
+				11
+			),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(OnDemandAssigner::place_order_with_expiry(
+			RuntimeOrigin::signed(alice),
+			amt,
+			para_a,
+			12
+		));
+		assert_ok!(OnDemandAssigner::place_order_with_expiry(
+			RuntimeOrigin::signed(alice),
+			amt,
+			para_b,
+			20
+		));
+		assert!(Balances::free_balance(&alice) < amt);
+		assert_eq!(OnDemandAssigner::queue_depth(), 2);
+
+		// Don't let the scheduler pop the orders.
+		System::set_block_number(13);
+
+		// The order for `para_a` expired, it gets skipped and refunded, except for the
+		// non-refundable fee.
+		assert_eq!(
+			OnDemandAssigner::pop_assignment_for_core(CoreIndex(0)).map(|a| a.para_id()),
+			Some(para_b)
+		);
+		System::assert_has_event(
+			Event::<Test>::OrderRefunded {
+				order_index: 0,
+				para_id: para_a,
+				refund: 9_000,
+				ordered_by: alice,
+			}
+			.into(),
+		);
+		assert_eq!(OnDemandAssigner::queue_depth(), 0);
+		assert_eq!(OnDemandAssigner::get_affinity_map(para_a), None);
+
+		// Served orders are not refunded.
+		assert_noop!(
+			OnDemandAssigner::cancel_order(RuntimeOrigin::signed(alice), 1),
+			Error::<Test>::UnknownOrder
+		);
+		assert_eq!(Balances::free_balance(&alice), amt - 11_000);
+	});
+}
+
+#[test]
+fn expired_orders_dropped_per_pop_are_limited() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		Balances::make_free_balance_be(&alice, amt);
+		run_to_block(11, |n| if n == 11 { Some(Default::default()) } else { None });
+
+		for _ in 0..MAX_EXPIRED_ORDERS_PER_POP + 1 {
+			assert_ok!(OnDemandAssigner::place_order_with_expiry(
+				RuntimeOrigin::signed(alice),
+				amt,
+				para_id,
+				12
+			));
+		}
+		assert_ok!(OnDemandAssigner::place_order_allow_death(
+			RuntimeOrigin::signed(alice),
+			amt,
+			para_id
+		));
+
+		// Don't let the scheduler pop the orders.
+		System::set_block_number(13);
+
+		// No assignment once the limit of dropped orders is hit.
+		assert_eq!(OnDemandAssigner::pop_assignment_for_core(CoreIndex(0)), None);
+		assert_eq!(OnDemandAssigner::queue_depth(), 2);
+
+		// The next call drops the remaining expired order and serves the one without expiry.
+		assert_eq!(
+			OnDemandAssigner::pop_assignment_for_core(CoreIndex(0)).map(|a| a.para_id()),
+			Some(para_id)
+		);
+		assert_eq!(OnDemandAssigner::queue_depth(), 0);
+	});
+}
+
+#[test]
+fn cancel_order_works() {
+	let alice = 1u64;
+	let bob = 2u64;
+	let amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		Balances::make_free_balance_be(&alice, amt);
+		Balances::make_free_balance_be(&bob, amt);
+		run_to_block(11, |n| if n == 11 { Some(Default::default()) } else { None });
+
+		for _ in 0..3 {
+			assert_ok!(OnDemandAssigner::place_order_with_expiry(
+				RuntimeOrigin::signed(alice),
+				amt,
+				para_id,
+				20
+			));
+		}
+		assert_eq!(OnDemandAssigner::queue_depth(), 3);
+
+		// Only the owner can cancel an order before it expires.
+		assert_noop!(
+			OnDemandAssigner::cancel_order(RuntimeOrigin::signed(bob), 1),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_ok!(OnDemandAssigner::cancel_order(RuntimeOrigin::signed(alice), 1));
+		assert_eq!(OnDemandAssigner::queue_depth(), 2);
+		assert_noop!(
+			OnDemandAssigner::cancel_order(RuntimeOrigin::signed(alice), 1),
+			Error::<Test>::UnknownOrder
+		);
+
+		// Orders with core affinity can be cancelled as well.
+		assert_eq!(
+			OnDemandAssigner::pop_assignment_for_core(CoreIndex(0)).map(|a| a.para_id()),
+			Some(para_id)
+		);
+		assert_eq!(OnDemandAssigner::get_affinity_entries(CoreIndex(0)).len(), 1);
+		assert_ok!(OnDemandAssigner::cancel_order(RuntimeOrigin::signed(alice), 2));
+		assert!(OnDemandAssigner::get_affinity_entries(CoreIndex(0)).is_empty());
+		assert_eq!(OnDemandAssigner::queue_depth(), 0);
+
+		// Anyone can cancel expired orders, the owner gets refunded.
+		assert_ok!(OnDemandAssigner::place_order_with_expiry(
+			RuntimeOrigin::signed(alice),
+			amt,
+			para_id,
+			20
+		));
+		System::set_block_number(21);
+		let balance = Balances::free_balance(&alice);
+		assert_ok!(OnDemandAssigner::cancel_order(RuntimeOrigin::signed(bob), 3));
+		assert!(Balances::free_balance(&alice) > balance);
+		assert_eq!(Balances::free_balance(&bob), amt);
+		assert_eq!(OnDemandAssigner::queue_depth(), 0);
+	});
+}
+
+#[test]
+fn queue_info_works() {
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let para_a = ParaId::from(111);
+		let para_b = ParaId::from(110);
+		schedule_blank_para(para_a, ParaKind::Parathread);
+		schedule_blank_para(para_b, ParaKind::Parathread);
+
+		run_to_block(11, |n| if n == 11 { Some(Default::default()) } else { None });
+
+		assert_eq!(OnDemandAssigner::queue_depth(), 0);
+		assert_eq!(OnDemandAssigner::estimated_wait(para_a), 0);
+		// The traffic does not go below its default value on an idle queue.
+		assert_eq!(OnDemandAssigner::spot_price_trajectory(3), vec![10_000; 4]);
+
+		for _ in 0..30 {
+			place_order(para_a);
+		}
+		place_order(para_b);
+		assert_eq!(OnDemandAssigner::queue_depth(), 31);
+		assert_eq!(OnDemandAssigner::estimated_wait(para_a), 31);
+
+		// The queue is above its target utilization, the price is rising.
+		let trajectory = OnDemandAssigner::spot_price_trajectory(5);
+		assert_eq!(trajectory.len(), 6);
+		assert!(trajectory.windows(2).all(|prices| prices[0] < prices[1]));
+		assert_eq!(
+			OnDemandAssigner::spot_price_trajectory(u32::MAX).len(),
+			MAX_SPOT_PRICE_TRAJECTORY as usize + 1
+		);
+
+		// Orders for `para_a` only compete with the ones on the core `para_a` has affinity with.
+		OnDemandAssigner::pop_assignment_for_core(CoreIndex(0));
+		assert_eq!(OnDemandAssigner::estimated_wait(para_a), 30);
+		assert_eq!(OnDemandAssigner::estimated_wait(para_b), 1);
+	});
+}
+
 #[test]
 fn pop_assignment_for_core_works() {
 	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
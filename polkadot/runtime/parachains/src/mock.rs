@@ -393,6 +393,7 @@ impl pallet_message_queue::Config for Test {
 
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
+	pub const OnDemandNonRefundableFee: Perbill = Perbill::from_percent(10);
 }
 
 impl assigner_parachains::Config for Test {}
@@ -401,6 +402,7 @@ impl assigner_on_demand::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
+	type NonRefundableFee = OnDemandNonRefundableFee;
 	type WeightInfo = crate::assigner_on_demand::TestWeightInfo;
 }
 
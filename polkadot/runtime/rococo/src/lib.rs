@@ -532,6 +532,7 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxTranches = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -679,6 +680,7 @@ parameter_types! {
 	pub const BasicDeposit: Balance = 1000 * CENTS;       // 258 bytes on-chain
 	pub const ByteDeposit: Balance = deposit(0, 1);
 	pub const SubAccountDeposit: Balance = 200 * CENTS;   // 53 bytes on-chain
+	pub const AttestationDeposit: Balance = deposit(1, 109);
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
@@ -690,6 +692,7 @@ impl pallet_identity::Config for Runtime {
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type AttestationDeposit = AttestationDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
@@ -735,6 +738,9 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 500 * CENTS;
+	pub const RecoveryConfigChangeDelay: BlockNumber = 2 * DAYS;
+	pub const InheritanceDeposit: Balance = 500 * CENTS;
+	pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -746,6 +752,9 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type ConfigChangeDelay = RecoveryConfigChangeDelay;
+	type InheritanceDeposit = InheritanceDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
 }
 
 parameter_types! {
@@ -1073,12 +1082,14 @@ impl coretime::Config for Runtime {
 
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
+	pub const OnDemandNonRefundableFee: Perbill = Perbill::from_percent(20);
 }
 
 impl parachains_assigner_on_demand::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
+	type NonRefundableFee = OnDemandNonRefundableFee;
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 }
 
@@ -1617,6 +1628,7 @@ pub mod migrations {
 		paras_registrar::migration::MigrateToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, pallet_referenda::Instance2>,
+		pallet_conviction_voting::migration::v1::MigrateV0ToV1<Runtime>,
 
 		// Unlock & unreserve Gov1 funds
 
@@ -1803,6 +1815,27 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		RuntimeCall,
+		u16,
+		OriginCaller,
+		Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError>,
+	> for Runtime {
+		fn referendum_preview(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::ReferendumPreview<
+			RuntimeCall,
+			u16,
+			OriginCaller,
+			Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError>,
+		>> {
+			Referenda::api_referendum_preview::<governance::ProposalInspector, _>(index, |origin, call| {
+				XcmPallet::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
+			})
+		}
+	}
+
 	impl sp_api::Metadata<Block> for Runtime {
 		fn metadata() -> OpaqueMetadata {
 			OpaqueMetadata::new(Runtime::metadata().into())
@@ -1817,6 +1850,15 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
@@ -2020,6 +2062,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl polkadot_primitives::runtime_api::OnDemandApi<Block, Balance> for Runtime {
+		fn queue_depth() -> u32 {
+			OnDemandAssignmentProvider::queue_depth()
+		}
+
+		fn spot_price_trajectory(blocks: u32) -> Vec<Balance> {
+			OnDemandAssignmentProvider::spot_price_trajectory(blocks)
+		}
+
+		fn estimated_wait(para_id: ParaId) -> u32 {
+			OnDemandAssignmentProvider::estimated_wait(para_id)
+		}
+	}
+
 	#[api_version(3)]
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
@@ -2243,6 +2299,7 @@ sp_api::impl_runtime_apis! {
 	}
 
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			log::info!("try-runtime::on_runtime_upgrade rococo.");
@@ -2250,6 +2307,21 @@ sp_api::impl_runtime_apis! {
 			(weight, BlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_metadata(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+			old_metadata: Vec<u8>,
+		) -> (Weight, Weight) {
+			log::info!("try-runtime::on_runtime_upgrade_with_metadata rococo.");
+			let new_metadata = Runtime::metadata_at_version(15).expect("V15 is supported; qed");
+			let weight = Executive::try_runtime_upgrade_with_metadata(
+				checks,
+				&old_metadata,
+				&new_metadata,
+			)
+			.unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
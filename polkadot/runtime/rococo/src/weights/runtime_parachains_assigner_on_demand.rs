@@ -88,4 +88,50 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::assigner_on_demand::W
 			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(Weight::from_parts(0, 8).saturating_mul(s.into()))
 	}
+	/// Storage: `OnDemandAssignmentProvider::QueueStatus` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::QueueStatus` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::ParaIdAffinity` (r:1 w:0)
+	/// Proof: `OnDemandAssignmentProvider::ParaIdAffinity` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::FreeEntries` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::FreeEntries` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::RefundableOrders` (r:0 w:1)
+	/// Proof: `OnDemandAssignmentProvider::RefundableOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 9999]`.
+	fn place_order_with_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `218 + s * (8 ±0)`
+		//  Estimated: `3681 + s * (8 ±0)`
+		// Minimum execution time: 22_917_000 picoseconds.
+		Weight::from_parts(19_104_352, 0)
+			.saturating_add(Weight::from_parts(0, 3681))
+			// Standard Error: 106
+			.saturating_add(Weight::from_parts(18_812, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(s.into()))
+	}
+	/// Storage: `OnDemandAssignmentProvider::RefundableOrders` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::RefundableOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::ParaIdAffinity` (r:1 w:0)
+	/// Proof: `OnDemandAssignmentProvider::ParaIdAffinity` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::FreeEntries` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::FreeEntries` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::QueueStatus` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::QueueStatus` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 9999]`.
+	fn cancel_order(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `405 + s * (8 ±0)`
+		//  Estimated: `3870 + s * (8 ±0)`
+		// Minimum execution time: 31_408_000 picoseconds.
+		Weight::from_parts(26_735_019, 0)
+			.saturating_add(Weight::from_parts(0, 3870))
+			// Standard Error: 121
+			.saturating_add(Weight::from_parts(24_937, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(s.into()))
+	}
 }
@@ -616,6 +616,8 @@ parameter_types! {
 	pub const MaxNominators: u32 = 64;
 	pub const MaxNominations: u32 = <NposCompactSolution16 as frame_election_provider_support::NposSolution>::LIMIT as u32;
 	pub const MaxControllersInDeprecationBatch: u32 = 751;
+	// One storage item; key size 32, value size 16.
+	pub const AutoPayoutOptOutDeposit: Balance = deposit(1, 48);
 }
 
 impl pallet_staking::Config for Runtime {
@@ -643,6 +645,7 @@ impl pallet_staking::Config for Runtime {
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type AutoPayoutOptOutDeposit = AutoPayoutOptOutDeposit;
 	type BenchmarkingConfig = polkadot_runtime_common::StakingBenchmarkingConfig;
 	type EventListeners = (NominationPools, DelegatedStaking);
 	type WeightInfo = weights::pallet_staking::WeightInfo<Runtime>;
@@ -717,6 +720,7 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxTranches = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -820,6 +824,7 @@ parameter_types! {
 	pub const BasicDeposit: Balance = 1000 * CENTS;       // 258 bytes on-chain
 	pub const ByteDeposit: Balance = deposit(0, 1);
 	pub const SubAccountDeposit: Balance = 200 * CENTS;   // 53 bytes on-chain
+	pub const AttestationDeposit: Balance = deposit(1, 109);
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
@@ -832,6 +837,7 @@ impl pallet_identity::Config for Runtime {
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type AttestationDeposit = AttestationDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
@@ -876,6 +882,9 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 500 * CENTS;
+	pub const RecoveryConfigChangeDelay: BlockNumber = 2 * DAYS;
+	pub const InheritanceDeposit: Balance = 500 * CENTS;
+	pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -887,6 +896,9 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type ConfigChangeDelay = RecoveryConfigChangeDelay;
+	type InheritanceDeposit = InheritanceDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
 }
 
 parameter_types! {
@@ -1202,12 +1214,14 @@ impl coretime::Config for Runtime {
 
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
+	pub const OnDemandNonRefundableFee: Perbill = Perbill::from_percent(20);
 }
 
 impl parachains_assigner_on_demand::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
+	type NonRefundableFee = OnDemandNonRefundableFee;
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 }
 
@@ -1678,6 +1692,7 @@ pub mod migrations {
 			MaxPoolsToMigrate,
 		>,
 		pallet_staking::migrations::v15::MigrateV14ToV15<Runtime>,
+		pallet_conviction_voting::migration::v1::MigrateV0ToV1<Runtime>,
 	);
 }
 
@@ -1783,6 +1798,15 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
@@ -1986,6 +2010,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl polkadot_primitives::runtime_api::OnDemandApi<Block, Balance> for Runtime {
+		fn queue_depth() -> u32 {
+			OnDemandAssignmentProvider::queue_depth()
+		}
+
+		fn spot_price_trajectory(blocks: u32) -> Vec<Balance> {
+			OnDemandAssignmentProvider::spot_price_trajectory(blocks)
+		}
+
+		fn estimated_wait(para_id: ParaId) -> u32 {
+			OnDemandAssignmentProvider::estimated_wait(para_id)
+		}
+	}
+
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
 			pallet_beefy::GenesisBlock::<Runtime>::get()
@@ -2265,6 +2303,27 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		RuntimeCall,
+		u16,
+		OriginCaller,
+		Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError>,
+	> for Runtime {
+		fn referendum_preview(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::ReferendumPreview<
+			RuntimeCall,
+			u16,
+			OriginCaller,
+			Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError>,
+		>> {
+			Referenda::api_referendum_preview::<governance::ProposalInspector, _>(index, |origin, call| {
+				XcmPallet::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
+			})
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -2299,6 +2358,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2311,9 +2371,14 @@ sp_api::impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn unlocking_chunks(account: AccountId) -> Vec<sp_staking::UnlockingChunkInfo<Balance>> {
+			Staking::api_unlocking_chunks(account)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			log::info!("try-runtime::on_runtime_upgrade westend.");
@@ -2321,6 +2386,21 @@ sp_api::impl_runtime_apis! {
 			(weight, BlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_metadata(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+			old_metadata: Vec<u8>,
+		) -> (Weight, Weight) {
+			log::info!("try-runtime::on_runtime_upgrade_with_metadata westend.");
+			let new_metadata = Runtime::metadata_at_version(15).expect("V15 is supported; qed");
+			let weight = Executive::try_runtime_upgrade_with_metadata(
+				checks,
+				&old_metadata,
+				&new_metadata,
+			)
+			.unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
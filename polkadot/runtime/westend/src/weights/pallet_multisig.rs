@@ -16,11 +16,11 @@
 
 //! Autogenerated weights for `pallet_multisig`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner--ss9ysm1-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("westend-dev"), DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("westend-dev")`, DB CACHE: 1024
 
 // Executed Command:
 // ./target/production/polkadot
@@ -55,111 +55,287 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 13_218_000 picoseconds.
-		Weight::from_parts(14_749_472, 0)
+		// Minimum execution time: 10_794_000 picoseconds.
+		Weight::from_parts(11_546_120, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 10
-			.saturating_add(Weight::from_parts(507, 0).saturating_mul(z.into()))
+			// Standard Error: 59
+			.saturating_add(Weight::from_parts(1_252, 0).saturating_mul(z.into()))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `309 + s * (2 ±0)`
+		//  Measured:  `266 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 45_891_000 picoseconds.
-		Weight::from_parts(33_546_627, 0)
+		// Minimum execution time: 29_040_000 picoseconds.
+		Weight::from_parts(24_909_111, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 2_347
-			.saturating_add(Weight::from_parts(136_466, 0).saturating_mul(s.into()))
-			// Standard Error: 23
-			.saturating_add(Weight::from_parts(1_595, 0).saturating_mul(z.into()))
+			// Standard Error: 8_109
+			.saturating_add(Weight::from_parts(120_512, 0).saturating_mul(s.into()))
+			// Standard Error: 79
+			.saturating_add(Weight::from_parts(805, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
+	fn as_multi_approve(s: u32, _z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `286`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_355_000 picoseconds.
-		Weight::from_parts(19_611_682, 0)
+		// Minimum execution time: 21_490_000 picoseconds.
+		Weight::from_parts(25_662_337, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_383
-			.saturating_add(Weight::from_parts(123_652, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_488, 0).saturating_mul(z.into()))
+			// Standard Error: 6_263
+			.saturating_add(Weight::from_parts(112_779, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `392 + s * (33 ±0)`
+		//  Measured:  `389 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 50_453_000 picoseconds.
-		Weight::from_parts(35_628_285, 0)
+		// Minimum execution time: 29_717_000 picoseconds.
+		Weight::from_parts(19_436_675, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 3_693
-			.saturating_add(Weight::from_parts(203_453, 0).saturating_mul(s.into()))
-			// Standard Error: 36
-			.saturating_add(Weight::from_parts(1_726, 0).saturating_mul(z.into()))
+			// Standard Error: 6_687
+			.saturating_add(Weight::from_parts(258_646, 0).saturating_mul(s.into()))
+			// Standard Error: 65
+			.saturating_add(Weight::from_parts(1_166, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `314 + s * (2 ±0)`
+		//  Measured:  `267 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 32_500_000 picoseconds.
-		Weight::from_parts(33_231_806, 0)
+		// Minimum execution time: 15_013_000 picoseconds.
+		Weight::from_parts(17_495_769, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_511
-			.saturating_add(Weight::from_parts(134_500, 0).saturating_mul(s.into()))
+			// Standard Error: 11_093
+			.saturating_add(Weight::from_parts(235_900, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `286`
 		//  Estimated: `6811`
-		// Minimum execution time: 17_906_000 picoseconds.
-		Weight::from_parts(18_757_928, 0)
+		// Minimum execution time: 13_683_000 picoseconds.
+		Weight::from_parts(15_064_215, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_172
-			.saturating_add(Weight::from_parts(113_535, 0).saturating_mul(s.into()))
+			// Standard Error: 5_741
+			.saturating_add(Weight::from_parts(105_690, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `458 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 33_018_000 picoseconds.
-		Weight::from_parts(34_186_533, 0)
+		// Minimum execution time: 16_937_000 picoseconds.
+		Weight::from_parts(16_749_325, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_188
-			.saturating_add(Weight::from_parts(128_449, 0).saturating_mul(s.into()))
+			// Standard Error: 4_620
+			.saturating_add(Weight::from_parts(205_717, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1489`
+		// Minimum execution time: 13_417_000 picoseconds.
+		Weight::from_parts(17_012_555, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 7_502
+			.saturating_add(Weight::from_parts(106_636, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 23_474_000 picoseconds.
+		Weight::from_parts(25_209_295, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 12_017
+			.saturating_add(Weight::from_parts(189_567, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `411 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 29_240_000 picoseconds.
+		Weight::from_parts(19_819_383, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 4_669
+			.saturating_add(Weight::from_parts(168_549, 0).saturating_mul(s.into()))
+			// Standard Error: 45
+			.saturating_add(Weight::from_parts(1_091, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 26_237_000 picoseconds.
+		Weight::from_parts(27_038_812, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 9_498
+			.saturating_add(Weight::from_parts(66_203, 0).saturating_mul(s.into()))
+			// Standard Error: 93
+			.saturating_add(Weight::from_parts(1_197, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `647 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 36_290_000 picoseconds.
+		Weight::from_parts(13_904_176, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 10_480
+			.saturating_add(Weight::from_parts(414_737, 0).saturating_mul(s.into()))
+			// Standard Error: 102
+			.saturating_add(Weight::from_parts(2_246, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `414 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 22_245_000 picoseconds.
+		Weight::from_parts(32_628_131, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 9_234
+			.saturating_add(Weight::from_parts(162_242, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 17_169_000 picoseconds.
+		Weight::from_parts(17_632_576, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 5_557
+			.saturating_add(Weight::from_parts(123_661, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `714 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 24_384_000 picoseconds.
+		Weight::from_parts(21_768_543, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 11_446
+			.saturating_add(Weight::from_parts(334_390, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `324 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 21_378_000 picoseconds.
+		Weight::from_parts(35_983_274, 0)
+			.saturating_add(Weight::from_parts(0, 6761))
+			// Standard Error: 13_840
+			.saturating_add(Weight::from_parts(74_820, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
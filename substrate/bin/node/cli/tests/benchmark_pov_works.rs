@@ -0,0 +1,63 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::{Command, Output};
+use tempfile::tempdir;
+
+/// Tests that the `benchmark pov` command works for the substrate dev runtime.
+#[test]
+fn benchmark_pov_works() {
+	let output = benchmark_pov(None);
+	assert!(output.status.success());
+
+	let log = String::from_utf8_lossy(&output.stderr);
+	assert!(log.contains("inherents only"));
+	assert!(log.contains("system::remark"));
+	assert!(log.contains("balances::transfer_keep_alive"));
+	assert!(log.contains("PoV usage per extrinsic in the mixed block"));
+	assert!(!log.contains("OVER LIMIT!"));
+}
+
+/// Tests that blocks with a PoV larger than the `--max-pov-size` are marked.
+#[test]
+fn benchmark_pov_marks_blocks_over_the_limit() {
+	let output = benchmark_pov(Some(1000));
+	assert!(output.status.success());
+
+	let log = String::from_utf8_lossy(&output.stderr);
+	assert!(log.contains("OVER LIMIT!"));
+	assert!(log.contains("The compressed PoV of these blocks exceeds the max_pov_size"));
+}
+
+fn benchmark_pov(max_pov_size: Option<u32>) -> Output {
+	let tmp_dir = tempdir().expect("could not create a temp dir");
+
+	// Only put 10 extrinsics into the blocks otherwise it takes forever to build them
+	// especially for a non-release build.
+	let mut cmd = Command::new(cargo_bin("substrate-node"));
+	cmd.args(["benchmark", "pov", "--dev", "-d"])
+		.arg(tmp_dir.path())
+		.args(["--extrinsic", "system::remark", "--extrinsic", "balances::transfer_keep_alive"])
+		.args(["--max-ext-per-block", "10"])
+		.args(["--wasm-execution=compiled"]);
+	if let Some(max_pov_size) = max_pov_size {
+		cmd.args(["--max-pov-size", &max_pov_size.to_string()]);
+	}
+	cmd.output().unwrap()
+}
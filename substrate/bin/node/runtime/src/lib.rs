@@ -657,6 +657,8 @@ parameter_types! {
 	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
 	pub const MaxNominators: u32 = 64;
 	pub const MaxControllersInDeprecationBatch: u32 = 5900;
+	// One storage item; key size 32, value size 16.
+	pub const AutoPayoutOptOutDeposit: Balance = deposit(1, 48);
 	pub OffchainRepeat: BlockNumber = 5;
 	pub HistoryDepth: u32 = 84;
 }
@@ -699,6 +701,7 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type AutoPayoutOptOutDeposit = AutoPayoutOptOutDeposit;
 	type HistoryDepth = HistoryDepth;
 	type EventListeners = NominationPools;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
@@ -934,6 +937,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegations = ConstU32<16>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
 }
@@ -986,6 +990,8 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 }
 pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
 
+pallet_referenda::impl_inspect_call!(ProposalInspector, RuntimeCall);
+
 impl pallet_referenda::Config for Runtime {
 	type WeightInfo = pallet_referenda::weights::SubstrateWeight<Self>;
 	type RuntimeCall = RuntimeCall;
@@ -1251,6 +1257,7 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<Assets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxTranches = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1504,6 +1511,7 @@ parameter_types! {
 	pub const BasicDeposit: Balance = deposit(1, 17);
 	pub const ByteDeposit: Balance = deposit(0, 1);
 	pub const SubAccountDeposit: Balance = 2 * DOLLARS;   // 53 bytes on-chain
+	pub const AttestationDeposit: Balance = deposit(1, 109);
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
@@ -1515,6 +1523,7 @@ impl pallet_identity::Config for Runtime {
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type AttestationDeposit = AttestationDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
@@ -1535,6 +1544,9 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 5 * DOLLARS;
+	pub const RecoveryConfigChangeDelay: BlockNumber = 2 * DAYS;
+	pub const InheritanceDeposit: Balance = 5 * DOLLARS;
+	pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -1546,6 +1558,9 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type ConfigChangeDelay = RecoveryConfigChangeDelay;
+	type InheritanceDeposit = InheritanceDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
 }
 
 parameter_types! {
@@ -1699,6 +1714,11 @@ parameter_types! {
 	pub const PoolSetupFee: Balance = 1 * DOLLARS; // should be more or equal to the existential deposit
 	pub const MintMinLiquidity: Balance = 100;  // 100 is good enough when the main currency has 10-12 decimals.
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub LPFeeTiers: BoundedVec<Permill, ConstU32<4>> = sp_runtime::bounded_vec![
+		Permill::from_parts(100),
+		Permill::from_parts(500),
+		Permill::from_percent(1),
+	];
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 }
 
@@ -1729,9 +1749,14 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type PalletId = AssetConversionPalletId;
 	type LPFee = ConstU32<3>; // means 0.3%
+	type LPFeeTiers = LPFeeTiers;
+	type MaxLPFeeTiers = ConstU32<4>;
+	type MaxAmplification = ConstU32<10_000>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<48>;
+	type PriceObservationPeriod = ConstU32<HOURS>;
 	type MintMinLiquidity = MintMinLiquidity;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -2678,6 +2703,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
@@ -2787,6 +2821,29 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		RuntimeCall,
+		u16,
+		OriginCaller,
+		frame_support::dispatch::DispatchResultWithPostInfo,
+	> for Runtime {
+		fn referendum_preview(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::ReferendumPreview<
+			RuntimeCall,
+			u16,
+			OriginCaller,
+			frame_support::dispatch::DispatchResultWithPostInfo,
+		>> {
+			use sp_runtime::traits::Dispatchable;
+			Referenda::api_referendum_preview::<ProposalInspector, _>(index, |origin, call| {
+				call.dispatch(origin.into())
+			})
+		}
+	}
+
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2799,6 +2856,10 @@ impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn unlocking_chunks(account: AccountId) -> Vec<sp_staking::UnlockingChunkInfo<Balance>> {
+			Staking::api_unlocking_chunks(account)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -2966,6 +3027,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -2983,6 +3045,10 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn quote_price_time_weighted(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>, amount: Balance, window: u32) -> Option<Balance> {
+			AssetConversion::quote_price_time_weighted(asset1, asset2, amount, window)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -3163,6 +3229,7 @@ impl_runtime_apis! {
 	}
 
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
@@ -3172,6 +3239,22 @@ impl_runtime_apis! {
 			(weight, RuntimeBlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_metadata(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+			old_metadata: Vec<u8>,
+		) -> (Weight, Weight) {
+			// NOTE: intentional unwrap, see `on_runtime_upgrade`. Also fails if a storage item
+			// changed its layout without a migration.
+			let new_metadata = Runtime::metadata_at_version(15).expect("V15 is supported; qed");
+			let weight = Executive::try_runtime_upgrade_with_metadata(
+				checks,
+				&old_metadata,
+				&new_metadata,
+			)
+			.unwrap();
+			(weight, RuntimeBlockWeights::get().max_block)
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
@@ -83,6 +83,7 @@ parameter_types! {
 	pub const BasicDeposit: u64 = 100;
 	pub const ByteDeposit: u64 = 10;
 	pub const SubAccountDeposit: u64 = 100;
+	pub const AttestationDeposit: u64 = 10;
 	pub const MaxSubAccounts: u32 = 2;
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
@@ -104,6 +105,7 @@ impl pallet_identity::Config for Test {
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type AttestationDeposit = AttestationDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
@@ -31,12 +31,12 @@ use frame_support::{
 		},
 		AsEnsureOriginWithArg, ConstU32, ConstU64,
 	},
-	PalletId,
+	BoundedVec, PalletId,
 };
 use frame_system::{EnsureSigned, EnsureSignedBy};
 use pallet_asset_conversion::{self, AccountIdConverter, AccountIdConverterNoSeed, Ascending};
 use sp_arithmetic::Permill;
-use sp_runtime::{traits::AccountIdConversion, BuildStorage};
+use sp_runtime::{bounded_vec, traits::AccountIdConversion, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -84,6 +84,8 @@ parameter_types! {
   pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
   pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
   pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+  pub LPFeeTiers: BoundedVec<Permill, ConstU32<4>> =
+	bounded_vec![Permill::from_parts(500), Permill::from_percent(1)];
 }
 
 ord_parameter_types! {
@@ -111,8 +113,13 @@ impl pallet_asset_conversion::Config for Test {
 	type PalletId = AssetConversionPalletId;
 	type WeightInfo = ();
 	type LPFee = ConstU32<3>;
+	type LPFeeTiers = LPFeeTiers;
+	type MaxLPFeeTiers = ConstU32<4>;
+	type MaxAmplification = ConstU32<10_000>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<4>;
+	type PriceObservationPeriod = ConstU64<10>;
 	type MintMinLiquidity = ConstU64<100>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -165,6 +165,39 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Fill the price oracle of the pool of `asset1` and `asset2` with the maximum number of
+/// observations and advance the block, so that the next update drops the oldest one.
+fn fill_price_oracle<T: Config>(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+	let period = T::PriceObservationPeriod::get().max(One::one());
+	for _ in 0..=T::MaxPriceObservations::get() {
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + period);
+		AssetConversion::<T>::update_price_oracle(asset1, asset2);
+	}
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now + period);
+}
+
+/// Configure the pool of `asset1` and `asset2` as a stable swap pool, the most expensive kind of
+/// pool to swap through.
+fn set_stable_swap_pool<T: Config>(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+	let pool_id = T::PoolLocator::pool_id(asset1, asset2).unwrap();
+	let kind = PoolKind::StableSwap { amplification: T::MaxAmplification::get() };
+	PoolConfigs::<T>::insert(
+		pool_id,
+		PoolConfig { kind, lp_fee: AssetConversion::<T>::default_lp_fee() },
+	);
+}
+
+/// The amount of the first asset of `path` that buys a single unit of the last one.
+///
+/// Stable swap pools round down by a unit on every hop, in their favour, so they take more than
+/// constant product pools for such small amounts.
+fn min_swap_amount<T: Config>(path: &[Box<T::AssetKind>]) -> T::Balance {
+	let path = path.iter().map(|asset| *asset.clone()).collect();
+	AssetConversion::<T>::balance_path_from_amount_out(T::Balance::one(), path).unwrap()[0].1
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -198,6 +231,38 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_pool_with_config() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+		create_asset::<T>(&caller, &asset1, T::Assets::minimum_balance(asset1.clone()), true);
+		create_asset::<T>(&caller, &asset2, T::Assets::minimum_balance(asset2.clone()), true);
+
+		let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+		create_fee_asset::<T>(&caller);
+		mint_setup_fee_asset::<T>(&caller, &asset1, &asset2, &lp_token);
+
+		let kind = PoolKind::StableSwap { amplification: T::MaxAmplification::get() };
+		// the last fee tier is the most expensive one to look up.
+		let lp_fee = T::LPFeeTiers::get()
+			.last()
+			.copied()
+			.unwrap_or_else(AssetConversion::<T>::default_lp_fee);
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			kind,
+			lp_fee,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_eq!(PoolConfigs::<T>::get(&pool_id), Some(PoolConfig { kind, lp_fee }));
+		assert_last_event::<T>(Event::PoolConfigured { pool_id, kind, lp_fee }.into());
+	}
+
 	#[benchmark]
 	fn add_liquidity() {
 		let caller: T::AccountId = whitelisted_caller();
@@ -206,6 +271,7 @@ mod benchmarks {
 		create_fee_asset::<T>(&caller);
 		let (lp_token, liquidity1, liquidity2) =
 			create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+		fill_price_oracle::<T>(&asset1, &asset2);
 
 		#[extrinsic_call]
 		_(
@@ -248,6 +314,7 @@ mod benchmarks {
 			T::Balance::zero(),
 			caller.clone(),
 		));
+		fill_price_oracle::<T>(&asset1, &asset2);
 		let total_supply =
 			<T::PoolAssets as Inspect<T::AccountId>>::total_issuance(lp_token.clone());
 
@@ -294,8 +361,11 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			fill_price_oracle::<T>(&asset1, &asset2);
+			set_stable_swap_pool::<T>(&asset1, &asset2);
 		}
 
+		let swap_amount = swap_amount.max(min_swap_amount::<T>(&path));
 		let asset_in = *path.first().unwrap().clone();
 		assert_ok!(T::Assets::mint_into(
 			asset_in.clone(),
@@ -346,8 +416,11 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			fill_price_oracle::<T>(&asset1, &asset2);
+			set_stable_swap_pool::<T>(&asset1, &asset2);
 		}
 
+		let max_swap_amount = max_swap_amount.max(min_swap_amount::<T>(&path));
 		let asset_in = *path.first().unwrap().clone();
 		let asset_out = *path.last().unwrap().clone();
 		assert_ok!(T::Assets::mint_into(asset_in, &caller, max_swap_amount));
@@ -23,7 +23,9 @@
 //!
 //! This pallet allows you to:
 //!
-//!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets, or [one with its own fee
+//!    tier and invariant](`Pallet::create_pool_with_config()`), like the stable swap invariant for
+//!    assets of (almost) the same value
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -34,6 +36,8 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query a time-weighted average price](`AssetConversionApi::quote_price_time_weighted`) via a
+//!    runtime api endpoint, or from other pallets through [`TwapOracle`].
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
@@ -58,6 +62,8 @@
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+mod oracle;
+mod stable_swap;
 mod swap;
 #[cfg(test)]
 mod tests;
@@ -65,6 +71,7 @@ mod types;
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, NativeOrWithIdFactory};
+pub use oracle::*;
 pub use pallet::*;
 pub use swap::*;
 pub use types::*;
@@ -103,7 +110,7 @@ pub mod pallet {
 		traits::fungibles::Refund,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_arithmetic::{traits::Unsigned, Permill};
+	use sp_arithmetic::{traits::Unsigned, PerThing, Permill};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -118,6 +125,7 @@ pub mod pallet {
 
 		/// A type used for calculations concerning the `Balance` type to avoid possible overflows.
 		type HigherPrecisionBalance: IntegerSquareRoot
+			+ Clone
 			+ One
 			+ Ensure
 			+ Unsigned
@@ -157,9 +165,24 @@ pub mod pallet {
 			+ Refund<Self::AccountId, AssetId = Self::PoolAssetId>;
 
 		/// A % the liquidity providers will take of every swap. Represents 10ths of a percent.
+		///
+		/// Applies to the pools created with [`Pallet::create_pool`].
 		#[pallet::constant]
 		type LPFee: Get<u32>;
 
+		/// The fee tiers a pool can be created with through [`Pallet::create_pool_with_config`],
+		/// in addition to the [`Config::LPFee`].
+		#[pallet::constant]
+		type LPFeeTiers: Get<BoundedVec<Permill, Self::MaxLPFeeTiers>>;
+
+		/// The maximal number of [`Config::LPFeeTiers`].
+		#[pallet::constant]
+		type MaxLPFeeTiers: Get<u32>;
+
+		/// The maximal amplification coefficient of a [`PoolKind::StableSwap`] pool.
+		#[pallet::constant]
+		type MaxAmplification: Get<u32>;
+
 		/// A one-time fee to setup the pool.
 		#[pallet::constant]
 		type PoolSetupFee: Get<Self::Balance>;
@@ -187,6 +210,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The maximal number of price snapshots kept per pool for the time-weighted average
+		/// price oracle.
+		///
+		/// Together with [`Config::PriceObservationPeriod`], it bounds the longest window the
+		/// average price can be queried for.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The minimal number of blocks between two price snapshots of a pool.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -206,6 +241,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The config of the pools created with [`Pallet::create_pool_with_config`]. The other pools
+	/// are constant product pools with the [`Config::LPFee`].
+	#[pallet::storage]
+	pub type PoolConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolConfig, OptionQuery>;
+
+	/// The time-weighted average price oracle of each pool.
+	#[pallet::storage]
+	pub type PriceOracles<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		PriceOracle<BlockNumberFor<T>, T::MaxPriceObservations>,
+		ValueQuery,
+	>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -224,6 +275,17 @@ pub mod pallet {
 			lp_token: T::PoolAssetId,
 		},
 
+		/// A successful call of the `CreatePoolWithConfig` extrinsic will create this event, after
+		/// the `PoolCreated` event.
+		PoolConfigured {
+			/// The pool id of the created pool.
+			pool_id: T::PoolId,
+			/// The invariant of the pool.
+			kind: PoolKind,
+			/// The fee the liquidity providers take of every swap through the pool.
+			lp_fee: Permill,
+		},
+
 		/// A successful call of the `AddLiquidity` extrinsic will create this event.
 		LiquidityAdded {
 			/// The account that the liquidity was taken from.
@@ -346,6 +408,12 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The price history of the pool doesn't cover the requested window.
+		InsufficientPriceHistory,
+		/// The fee isn't one of the supported fee tiers.
+		InvalidLPFee,
+		/// The amplification coefficient is zero or too high.
+		InvalidAmplification,
 	}
 
 	#[pallet::hooks]
@@ -355,6 +423,14 @@ pub mod pallet {
 				T::MaxSwapPathLength::get() > 1,
 				"the `MaxSwapPathLength` should be greater than 1",
 			);
+			assert!(
+				T::MaxPriceObservations::get() > 1,
+				"the `MaxPriceObservations` should be greater than 1",
+			);
+			assert!(
+				T::LPFeeTiers::get().iter().all(|fee| !fee.is_one()),
+				"the `LPFeeTiers` should be less than 100%",
+			);
 		}
 	}
 
@@ -373,50 +449,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
-				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
-			};
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
+			Self::do_create_pool(sender, asset1, asset2)?;
 			Ok(())
 		}
 
@@ -502,8 +535,9 @@ pub mod pallet {
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
-			T::Assets::transfer(*asset1, &sender, &pool_account, amount1, Preserve)?;
-			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
+			T::Assets::transfer(*asset1.clone(), &sender, &pool_account, amount1, Preserve)?;
+			T::Assets::transfer(*asset2.clone(), &sender, &pool_account, amount2, Preserve)?;
+			Self::update_price_oracle(&asset1, &asset2);
 
 			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
 
@@ -605,8 +639,9 @@ pub mod pallet {
 				Polite,
 			)?;
 
-			T::Assets::transfer(*asset1, &pool_account, &withdraw_to, amount1, Expendable)?;
-			T::Assets::transfer(*asset2, &pool_account, &withdraw_to, amount2, Expendable)?;
+			T::Assets::transfer(*asset1.clone(), &pool_account, &withdraw_to, amount1, Expendable)?;
+			T::Assets::transfer(*asset2.clone(), &pool_account, &withdraw_to, amount2, Expendable)?;
+			Self::update_price_oracle(&asset1, &asset2);
 
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: sender,
@@ -720,9 +755,106 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Creates an empty liquidity pool like [`Pallet::create_pool`], with the given invariant
+		/// and fee.
+		///
+		/// The invariant only determines the price of swaps, liquidity is added and removed in
+		/// proportion to the reserves for any kind of pool.
+		///
+		/// The origin must be Signed.
+		///
+		/// - `kind`: The invariant of the pool.
+		/// - `lp_fee`: The fee the liquidity providers take of every swap through the pool. Must be
+		///   the [`Config::LPFee`] or one of the [`Config::LPFeeTiers`].
+		///
+		/// Emits `PoolCreated` and `PoolConfigured` events when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_pool_with_config())]
+		pub fn create_pool_with_config(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			kind: PoolKind,
+			lp_fee: Permill,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				lp_fee == Self::default_lp_fee() || T::LPFeeTiers::get().contains(&lp_fee),
+				Error::<T>::InvalidLPFee
+			);
+			if let PoolKind::StableSwap { amplification } = kind {
+				ensure!(
+					amplification > 0 && amplification <= T::MaxAmplification::get(),
+					Error::<T>::InvalidAmplification
+				);
+			}
+
+			let pool_id = Self::do_create_pool(sender, asset1, asset2)?;
+			PoolConfigs::<T>::insert(&pool_id, PoolConfig { kind, lp_fee });
+			Self::deposit_event(Event::PoolConfigured { pool_id, kind, lp_fee });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create an empty liquidity pool of `asset1` and `asset2`, paying the setup fee from
+		/// `sender`.
+		///
+		/// Returns the id of the created pool.
+		fn do_create_pool(
+			sender: T::AccountId,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+		) -> Result<T::PoolId, DispatchError> {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
+				T::Assets::touch(*asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
+				T::Assets::touch(*asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
+				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
+			};
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id: pool_id.clone(),
+				pool_account,
+				lp_token,
+			});
+
+			Ok(pool_id)
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -997,6 +1129,12 @@ pub mod pallet {
 			T::Assets::resolve(&pool_to, credit_in)
 				.map_err(|c| (c, Error::<T>::BelowMinimum.into()))?;
 
+			for assets in path.windows(2) {
+				if let [(asset1, _), (asset2, _)] = assets {
+					Self::update_price_oracle(asset1, asset2);
+				}
+			}
+
 			Ok(credit_out)
 		}
 
@@ -1022,7 +1160,7 @@ pub mod pallet {
 
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
-		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
+		pub(crate) fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
@@ -1063,8 +1201,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let config = Self::get_pool_config(asset1, &asset2)?;
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in =
+					Self::get_pool_amount_in(&config, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1089,8 +1229,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let config = Self::get_pool_config(&asset1, asset2)?;
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_pool_amount_out(&config, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
@@ -1103,15 +1245,23 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let config = Self::get_pool_config(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+				match (include_fee, config.kind) {
+					(true, _) => Self::get_pool_amount_out(&config, &amount, &balance1, &balance2),
+					(false, PoolKind::ConstantProduct) =>
+						Self::quote(&amount, &balance1, &balance2),
+					(false, PoolKind::StableSwap { .. }) => Self::get_pool_amount_out(
+						&PoolConfig { lp_fee: Zero::zero(), ..config },
+						&amount,
+						&balance1,
+						&balance2,
+					),
 				}
+				.ok()
 			} else {
 				None
 			}
@@ -1125,15 +1275,23 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let config = Self::get_pool_config(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+				match (include_fee, config.kind) {
+					(true, _) => Self::get_pool_amount_in(&config, &amount, &balance1, &balance2),
+					(false, PoolKind::ConstantProduct) =>
+						Self::quote(&amount, &balance2, &balance1),
+					(false, PoolKind::StableSwap { .. }) => Self::get_pool_amount_in(
+						&PoolConfig { lp_fee: Zero::zero(), ..config },
+						&amount,
+						&balance1,
+						&balance2,
+					),
 				}
+				.ok()
 			} else {
 				None
 			}
@@ -1180,7 +1338,79 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
-		/// Calculates amount out.
+		/// The fee of the pools created with [`Pallet::create_pool`].
+		pub(crate) fn default_lp_fee() -> Permill {
+			Permill::from_perthousand(T::LPFee::get())
+		}
+
+		/// Returns the config of the pool of `asset1` and `asset2`.
+		pub fn get_pool_config(
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> Result<PoolConfig, Error<T>> {
+			let pool_id = T::PoolLocator::pool_id(asset1, asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			Ok(PoolConfigs::<T>::get(pool_id).unwrap_or(PoolConfig {
+				kind: PoolKind::ConstantProduct,
+				lp_fee: Self::default_lp_fee(),
+			}))
+		}
+
+		/// Calculates amount out of a pool with the given `config`.
+		///
+		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+		/// of the other asset.
+		pub fn get_pool_amount_out(
+			config: &PoolConfig,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match config.kind {
+				PoolKind::ConstantProduct => Self::get_constant_product_amount_out(
+					amount_in,
+					reserve_in,
+					reserve_out,
+					config.lp_fee,
+				),
+				PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_out(
+					amount_in,
+					reserve_in,
+					reserve_out,
+					amplification,
+					config.lp_fee,
+				),
+			}
+		}
+
+		/// Calculates amount in of a pool with the given `config`.
+		///
+		/// Given an output amount of an asset and pair reserves, returns a required input amount
+		/// of the other asset.
+		pub fn get_pool_amount_in(
+			config: &PoolConfig,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match config.kind {
+				PoolKind::ConstantProduct => Self::get_constant_product_amount_in(
+					amount_out,
+					reserve_in,
+					reserve_out,
+					config.lp_fee,
+				),
+				PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_in(
+					amount_out,
+					reserve_in,
+					reserve_out,
+					amplification,
+					config.lp_fee,
+				),
+			}
+		}
+
+		/// Calculates amount out of a constant product pool with the [`Config::LPFee`].
 		///
 		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
 		/// of the other asset.
@@ -1188,6 +1418,38 @@ pub mod pallet {
 			amount_in: &T::Balance,
 			reserve_in: &T::Balance,
 			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			Self::get_constant_product_amount_out(
+				amount_in,
+				reserve_in,
+				reserve_out,
+				Self::default_lp_fee(),
+			)
+		}
+
+		/// Calculates amount in of a constant product pool with the [`Config::LPFee`].
+		///
+		/// Given an output amount of an asset and pair reserves, returns a required input amount
+		/// of the other asset.
+		pub fn get_amount_in(
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			Self::get_constant_product_amount_in(
+				amount_out,
+				reserve_in,
+				reserve_out,
+				Self::default_lp_fee(),
+			)
+		}
+
+		/// Calculates amount out of a constant product pool.
+		fn get_constant_product_amount_out(
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			lp_fee: Permill,
 		) -> Result<T::Balance, Error<T>> {
 			let amount_in = T::HigherPrecisionBalance::from(*amount_in);
 			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
@@ -1198,14 +1460,14 @@ pub mod pallet {
 			}
 
 			let amount_in_with_fee = amount_in
-				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - (T::LPFee::get().into())))
+				.checked_mul(&Permill::one().saturating_sub(lp_fee).deconstruct().into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let numerator =
 				amount_in_with_fee.checked_mul(&reserve_out).ok_or(Error::<T>::Overflow)?;
 
 			let denominator = reserve_in
-				.checked_mul(&1000u32.into())
+				.checked_mul(&Permill::ACCURACY.into())
 				.ok_or(Error::<T>::Overflow)?
 				.checked_add(&amount_in_with_fee)
 				.ok_or(Error::<T>::Overflow)?;
@@ -1215,14 +1477,12 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
-		/// Calculates amount in.
-		///
-		/// Given an output amount of an asset and pair reserves, returns a required input amount
-		/// of the other asset.
-		pub fn get_amount_in(
+		/// Calculates amount in of a constant product pool.
+		fn get_constant_product_amount_in(
 			amount_out: &T::Balance,
 			reserve_in: &T::Balance,
 			reserve_out: &T::Balance,
+			lp_fee: Permill,
 		) -> Result<T::Balance, Error<T>> {
 			let amount_out = T::HigherPrecisionBalance::from(*amount_out);
 			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
@@ -1239,13 +1499,13 @@ pub mod pallet {
 			let numerator = reserve_in
 				.checked_mul(&amount_out)
 				.ok_or(Error::<T>::Overflow)?
-				.checked_mul(&1000u32.into())
+				.checked_mul(&Permill::ACCURACY.into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let denominator = reserve_out
 				.checked_sub(&amount_out)
 				.ok_or(Error::<T>::Overflow)?
-				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - T::LPFee::get().into()))
+				.checked_mul(&Permill::one().saturating_sub(lp_fee).deconstruct().into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let result = numerator
@@ -1320,6 +1580,18 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Provides a quote for `amount` of `asset1` in `asset2` at the time-weighted average
+		/// price over at least the last `window` blocks.
+		///
+		/// Unlike the other quotes, the price can't be moved by swaps within a single block.
+		#[api_version(2)]
+		fn quote_price_time_weighted(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			window: u32,
+		) -> Option<Balance>;
 	}
 }
 
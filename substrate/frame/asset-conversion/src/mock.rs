@@ -29,13 +29,14 @@ use frame_support::{
 			fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
 			imbalance::ResolveAssetTo,
 		},
-		AsEnsureOriginWithArg, ConstU128, ConstU32,
+		AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64,
 	},
-	PalletId,
+	BoundedVec, PalletId,
 };
 use frame_system::{EnsureSigned, EnsureSignedBy};
 use sp_arithmetic::Permill;
 use sp_runtime::{
+	bounded_vec,
 	traits::{AccountIdConversion, IdentityLookup},
 	BuildStorage,
 };
@@ -121,6 +122,8 @@ parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub LPFeeTiers: BoundedVec<Permill, ConstU32<4>> =
+		bounded_vec![Permill::from_parts(500), Permill::from_percent(1)];
 }
 
 ord_parameter_types! {
@@ -150,8 +153,13 @@ impl Config for Test {
 	type PalletId = AssetConversionPalletId;
 	type WeightInfo = ();
 	type LPFee = ConstU32<3>; // means 0.3%
+	type LPFeeTiers = LPFeeTiers;
+	type MaxLPFeeTiers = ConstU32<4>;
+	type MaxAmplification = ConstU32<10_000>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<4>;
+	type PriceObservationPeriod = ConstU64<10>;
 	type MintMinLiquidity = ConstU128<100>; // 100 is good enough when the main currency has 12 decimals.
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -0,0 +1,294 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time-weighted average price oracle of the liquidity pools.
+//!
+//! Each pool accumulates the prices of its assets, weighted by the number of blocks they held
+//! for, in the same way as [Uniswap V2](https://docs.uniswap.org/contracts/v2/concepts/core-concepts/oracles).
+//! The prices are the marginal prices of the invariant of the pool, see [`PoolKind`].
+//! The prices are only updated by the pallet itself after the reserves of the pool changed, so
+//! sending assets directly to the pool account or swapping back and forth within a single block
+//! does not affect the average.
+//!
+//! Snapshots of the cumulative prices are kept at least [`Config::PriceObservationPeriod`]
+//! blocks apart, up to [`Config::MaxPriceObservations`] of them, which bounds the longest window
+//! that can be queried.
+
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	ensure, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::AtLeast32BitUnsigned, FixedPointNumber, FixedU128, RuntimeDebug, SaturatedConversion,
+};
+
+/// Trait for providing time-weighted average prices of asset pairs.
+pub trait TwapOracle<AssetKind, BlockNumber> {
+	/// Returns the average price of `asset1` in `asset2`, i.e. the amount of `asset2` one unit of
+	/// `asset1` is worth, over at least the last `window` blocks.
+	///
+	/// Returns `None` if there is no pool for the asset pair or if its price history doesn't
+	/// cover the window.
+	fn time_weighted_average_price(
+		asset1: &AssetKind,
+		asset2: &AssetKind,
+		window: BlockNumber,
+	) -> Option<FixedU128>;
+}
+
+/// A snapshot of the cumulative prices of a pool.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PriceObservation<BlockNumber> {
+	/// The block of the snapshot.
+	pub block: BlockNumber,
+	/// The sum of the price of the first asset in the second asset over all blocks until
+	/// `block`. Wraps on overflow, only the difference between two snapshots is meaningful.
+	pub price1_cumulative: FixedU128,
+	/// The sum of the price of the second asset in the first asset over all blocks until
+	/// `block`. Wraps on overflow, only the difference between two snapshots is meaningful.
+	pub price2_cumulative: FixedU128,
+}
+
+/// The state of the price oracle of a pool.
+///
+/// The first asset of the pool is the one with the lower SCALE encoding.
+#[derive(
+	Decode,
+	Encode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxObservations))]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+pub struct PriceOracle<BlockNumber, MaxObservations: Get<u32>>
+where
+	BlockNumber: Clone + PartialEq + Eq + core::fmt::Debug,
+{
+	/// The price of the first asset in the second asset after the reserves last changed.
+	pub price1: FixedU128,
+	/// The price of the second asset in the first asset after the reserves last changed.
+	pub price2: FixedU128,
+	/// Snapshots of the cumulative prices, from the oldest to the newest. The last one is
+	/// updated whenever the reserves change.
+	pub observations: BoundedVec<PriceObservation<BlockNumber>, MaxObservations>,
+}
+
+impl<BlockNumber, MaxObservations: Get<u32>> Default for PriceOracle<BlockNumber, MaxObservations>
+where
+	BlockNumber: Clone + PartialEq + Eq + core::fmt::Debug,
+{
+	fn default() -> Self {
+		Self { price1: Zero::zero(), price2: Zero::zero(), observations: Default::default() }
+	}
+}
+
+impl<BlockNumber, MaxObservations: Get<u32>> PriceOracle<BlockNumber, MaxObservations>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy + core::fmt::Debug,
+{
+	/// The cumulative prices at `now`, assuming the prices didn't change since the last
+	/// snapshot.
+	fn cumulative_at(&self, now: BlockNumber) -> Option<PriceObservation<BlockNumber>> {
+		let latest = self.observations.last()?;
+		let elapsed = now.saturating_sub(latest.block).saturated_into::<u128>();
+		let accumulate = |cumulative: FixedU128, price: FixedU128| {
+			FixedU128::from_inner(
+				cumulative.into_inner().wrapping_add(price.into_inner().saturating_mul(elapsed)),
+			)
+		};
+
+		Some(PriceObservation {
+			block: now.max(latest.block),
+			price1_cumulative: accumulate(latest.price1_cumulative, self.price1),
+			price2_cumulative: accumulate(latest.price2_cumulative, self.price2),
+		})
+	}
+
+	/// Accumulate the prices since the last update, and set the new spot prices of the pool.
+	///
+	/// A new snapshot is added once the latest one is at least `period` blocks newer than the
+	/// one before it, dropping the oldest if there are too many.
+	pub(crate) fn update(
+		&mut self,
+		now: BlockNumber,
+		(price1, price2): (FixedU128, FixedU128),
+		period: BlockNumber,
+	) {
+		let current = self.cumulative_at(now).unwrap_or(PriceObservation {
+			block: now,
+			price1_cumulative: Zero::zero(),
+			price2_cumulative: Zero::zero(),
+		});
+
+		let len = self.observations.len();
+		let latest_is_due = match self.observations.get(len.saturating_sub(2)..) {
+			Some([previous, latest]) => latest.block >= previous.block.saturating_add(period),
+			_ => true,
+		};
+		match self.observations.last_mut() {
+			Some(latest) if latest.block == current.block || !latest_is_due => *latest = current,
+			_ => {
+				if self.observations.is_full() {
+					self.observations.remove(0);
+				}
+				// Can't fail, there is space left.
+				let _ = self.observations.try_push(current);
+			},
+		}
+
+		self.price1 = price1;
+		self.price2 = price2;
+	}
+
+	/// The average prices of the first and the second asset over at least the last `window`
+	/// blocks, at most `window` plus the period between snapshots.
+	pub(crate) fn average_prices(
+		&self,
+		now: BlockNumber,
+		window: BlockNumber,
+	) -> Option<(FixedU128, FixedU128)> {
+		let current = self.cumulative_at(now)?;
+		let target = now.checked_sub(&window)?;
+		let start = self.observations.iter().rev().find(|o| o.block <= target)?;
+		let elapsed = now.saturating_sub(start.block).saturated_into::<u128>();
+		if elapsed.is_zero() {
+			return None
+		}
+		let average = |current: FixedU128, start: FixedU128| {
+			FixedU128::from_inner(current.into_inner().wrapping_sub(start.into_inner()) / elapsed)
+		};
+
+		Some((
+			average(current.price1_cumulative, start.price1_cumulative),
+			average(current.price2_cumulative, start.price2_cumulative),
+		))
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `asset1` and `asset2` are in the order of the price oracle of their pool.
+	pub(crate) fn is_oracle_order(asset1: &T::AssetKind, asset2: &T::AssetKind) -> bool {
+		asset1.using_encoded(|asset1| asset2.using_encoded(|asset2| asset1 <= asset2))
+	}
+
+	/// Update the price oracle of the pool of `asset1` and `asset2` after its reserves changed.
+	///
+	/// Does nothing if the asset pair isn't supported, which is checked by the callers.
+	pub(crate) fn update_price_oracle(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+		let Ok(pool_id) = T::PoolLocator::pool_id(asset1, asset2) else { return };
+		let Ok(pool_account) = T::PoolLocator::address(&pool_id) else { return };
+		let reserve1 = Self::get_balance(&pool_account, asset1.clone());
+		let reserve2 = Self::get_balance(&pool_account, asset2.clone());
+		let (reserve1, reserve2) = if Self::is_oracle_order(asset1, asset2) {
+			(reserve1, reserve2)
+		} else {
+			(reserve2, reserve1)
+		};
+
+		let config = PoolConfigs::<T>::get(&pool_id).unwrap_or(PoolConfig {
+			kind: PoolKind::ConstantProduct,
+			lp_fee: Self::default_lp_fee(),
+		});
+		let prices = Self::spot_prices(&config.kind, reserve1, reserve2);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		PriceOracles::<T>::mutate(&pool_id, |oracle| {
+			oracle.update(now, prices, T::PriceObservationPeriod::get())
+		});
+	}
+
+	/// The marginal prices of a pool of `kind` with the given reserves, i.e. the price of the
+	/// first asset in the second one and the other way around, before any fee.
+	///
+	/// Zero if one of the reserves is empty.
+	pub(crate) fn spot_prices(
+		kind: &PoolKind,
+		reserve1: T::Balance,
+		reserve2: T::Balance,
+	) -> (FixedU128, FixedU128) {
+		if reserve1.is_zero() || reserve2.is_zero() {
+			return (Zero::zero(), Zero::zero())
+		}
+		let constant_product = || {
+			let (reserve1, reserve2) =
+				(reserve1.saturated_into::<u128>(), reserve2.saturated_into::<u128>());
+			(
+				FixedU128::saturating_from_rational(reserve2, reserve1),
+				FixedU128::saturating_from_rational(reserve1, reserve2),
+			)
+		};
+		match kind {
+			PoolKind::ConstantProduct => constant_product(),
+			// Falls back to the constant product price if the invariant can't be computed, which
+			// only happens for reserves close to the limits of the balance type.
+			PoolKind::StableSwap { amplification } =>
+				Self::stable_swap_spot_prices(&reserve1, &reserve2, *amplification)
+					.unwrap_or_else(|_| constant_product()),
+		}
+	}
+
+	/// Returns the average price of `asset1` in `asset2`, i.e. the amount of `asset2` one unit of
+	/// `asset1` is worth, over at least the last `window` blocks.
+	///
+	/// The average may cover up to [`Config::PriceObservationPeriod`] more blocks than `window`.
+	pub fn time_weighted_average_price(
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Result<FixedU128, DispatchError> {
+		ensure!(!window.is_zero(), Error::<T>::ZeroAmount);
+		let pool_id =
+			T::PoolLocator::pool_id(asset1, asset2).map_err(|_| Error::<T>::InvalidAssetPair)?;
+		ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let (price1, price2) = PriceOracles::<T>::get(&pool_id)
+			.average_prices(now, window)
+			.ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+		Ok(if Self::is_oracle_order(asset1, asset2) { price1 } else { price2 })
+	}
+
+	/// Used by the RPC service to provide a quote based on the time-weighted average price.
+	pub fn quote_price_time_weighted(
+		asset1: T::AssetKind,
+		asset2: T::AssetKind,
+		amount: T::Balance,
+		window: BlockNumberFor<T>,
+	) -> Option<T::Balance> {
+		let price = Self::time_weighted_average_price(&asset1, &asset2, window).ok()?;
+		let amount = price.saturating_mul_int(amount.saturated_into::<u128>());
+		amount.try_into().ok()
+	}
+}
+
+impl<T: Config> TwapOracle<T::AssetKind, BlockNumberFor<T>> for Pallet<T> {
+	fn time_weighted_average_price(
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Option<FixedU128> {
+		Self::time_weighted_average_price(asset1, asset2, window).ok()
+	}
+}
@@ -0,0 +1,276 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Swap amounts and prices of [`PoolKind::StableSwap`] pools.
+//!
+//! For the reserves `x` and `y` of a pool, the [stable swap](https://curve.fi/files/stableswap-paper.pdf)
+//! invariant `D` with the amplification coefficient `A` is
+//!
+//! `4A(x + y) + D = 4AD + D^3 / (4xy)`
+//!
+//! Both `D` and the reserve of one asset after a swap are found with Newton's method, like in
+//! the reference implementation of Curve.
+//!
+//! The marginal price of `x` in `y` is the ratio of the partial derivatives of the invariant,
+//! `(4A + D^3 / (4x^2y)) / (4A + D^3 / (4xy^2))`.
+
+use super::*;
+use sp_arithmetic::{PerThing, Permill};
+use sp_runtime::{FixedPointNumber, FixedU128, SaturatedConversion};
+
+/// The maximal number of iterations of Newton's method before giving up.
+const MAX_ITERATIONS: u32 = 255;
+
+impl<T: Config> Pallet<T> {
+	/// Calculates the amount out of a stable swap pool.
+	///
+	/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+	/// of the other asset.
+	pub(crate) fn get_stable_swap_amount_out(
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		amplification: u32,
+		lp_fee: Permill,
+	) -> Result<T::Balance, Error<T>> {
+		let amount_in = T::HigherPrecisionBalance::from(*amount_in);
+		let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+		let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			return Err(Error::<T>::ZeroLiquidity)
+		}
+
+		let amount_in_with_fee = amount_in
+			.checked_mul(&Permill::one().saturating_sub(lp_fee).deconstruct().into())
+			.ok_or(Error::<T>::Overflow)?
+			.checked_div(&Permill::ACCURACY.into())
+			.ok_or(Error::<T>::Overflow)?;
+
+		let d = Self::stable_swap_invariant(&reserve_in, &reserve_out, amplification)?;
+		let new_reserve_out = Self::stable_swap_reserve(
+			&reserve_in.checked_add(&amount_in_with_fee).ok_or(Error::<T>::Overflow)?,
+			&d,
+			amplification,
+		)?;
+
+		// round down in favour of the pool, Newton's method may be off by one.
+		let result = reserve_out
+			.checked_sub(&new_reserve_out)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_sub(&One::one())
+			.unwrap_or_else(Zero::zero);
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculates the amount in of a stable swap pool.
+	///
+	/// Given an output amount of an asset and pair reserves, returns a required input amount
+	/// of the other asset.
+	pub(crate) fn get_stable_swap_amount_in(
+		amount_out: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		amplification: u32,
+		lp_fee: Permill,
+	) -> Result<T::Balance, Error<T>> {
+		let amount_out = T::HigherPrecisionBalance::from(*amount_out);
+		let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+		let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			Err(Error::<T>::ZeroLiquidity)?
+		}
+
+		if amount_out >= reserve_out {
+			Err(Error::<T>::AmountOutTooHigh)?
+		}
+
+		let d = Self::stable_swap_invariant(&reserve_in, &reserve_out, amplification)?;
+		let new_reserve_in = Self::stable_swap_reserve(
+			&reserve_out.checked_sub(&amount_out).ok_or(Error::<T>::Overflow)?,
+			&d,
+			amplification,
+		)?;
+
+		// round up in favour of the pool, Newton's method may be off by one.
+		let amount_in_with_fee = new_reserve_in
+			.checked_sub(&reserve_in)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_add(&2u32.into())
+			.ok_or(Error::<T>::Overflow)?;
+
+		let result = amount_in_with_fee
+			.checked_mul(&Permill::ACCURACY.into())
+			.ok_or(Error::<T>::Overflow)?
+			.checked_div(&Permill::one().saturating_sub(lp_fee).deconstruct().into())
+			.ok_or(Error::<T>::Overflow)?
+			.checked_add(&One::one())
+			.ok_or(Error::<T>::Overflow)?;
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculates the marginal prices of a stable swap pool, i.e. the price of the first asset in
+	/// the second one and the other way around, before any fee.
+	pub(crate) fn stable_swap_spot_prices(
+		reserve1: &T::Balance,
+		reserve2: &T::Balance,
+		amplification: u32,
+	) -> Result<(FixedU128, FixedU128), Error<T>> {
+		let d = Self::stable_swap_invariant(
+			&T::HigherPrecisionBalance::from(*reserve1),
+			&T::HigherPrecisionBalance::from(*reserve2),
+			amplification,
+		)?;
+		let d: T::Balance = d.try_into().map_err(|_| Error::<T>::Overflow)?;
+		let ratio = |reserve: &T::Balance| {
+			FixedU128::checked_from_rational(
+				d.saturated_into::<u128>(),
+				(*reserve).saturated_into::<u128>(),
+			)
+			.ok_or(Error::<T>::Overflow)
+		};
+		// with `p = D / x` and `q = D / y`, the derivatives are `4A + p^2q / 4` and `4A + pq^2 /
+		// 4`.
+		let (p, q) = (ratio(reserve1)?, ratio(reserve2)?);
+		let ann = FixedU128::saturating_from_integer(amplification)
+			.saturating_mul(FixedU128::saturating_from_integer(4u32));
+		let pq = p
+			.saturating_mul(q)
+			.checked_div(&FixedU128::saturating_from_integer(4u32))
+			.ok_or(Error::<T>::Overflow)?;
+		let derivative1 = ann.saturating_add(pq.saturating_mul(p));
+		let derivative2 = ann.saturating_add(pq.saturating_mul(q));
+
+		Ok((
+			derivative1.checked_div(&derivative2).ok_or(Error::<T>::Overflow)?,
+			derivative2.checked_div(&derivative1).ok_or(Error::<T>::Overflow)?,
+		))
+	}
+
+	/// Calculates the invariant `D` of the reserves `x` and `y`.
+	fn stable_swap_invariant(
+		x: &T::HigherPrecisionBalance,
+		y: &T::HigherPrecisionBalance,
+		amplification: u32,
+	) -> Result<T::HigherPrecisionBalance, Error<T>> {
+		let two = T::HigherPrecisionBalance::from(2u32);
+		let three = T::HigherPrecisionBalance::from(3u32);
+		let ann = T::HigherPrecisionBalance::from(amplification)
+			.checked_mul(&4u32.into())
+			.ok_or(Error::<T>::Overflow)?;
+		let sum = x.checked_add(y).ok_or(Error::<T>::Overflow)?;
+
+		let mut d = sum.clone();
+		for _ in 0..MAX_ITERATIONS {
+			// d_p = d^3 / (4xy)
+			let d_p = d
+				.checked_mul(&d)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&x.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&d)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&y.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?;
+			// d = (4A * sum + 2 * d_p) * d / ((4A - 1) * d + 3 * d_p)
+			let numerator = ann
+				.checked_mul(&sum)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&d_p.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&d)
+				.ok_or(Error::<T>::Overflow)?;
+			let denominator = ann
+				.checked_sub(&One::one())
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&d)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&d_p.checked_mul(&three).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?;
+			let d_next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+
+			if Self::converged(&d_next, &d) {
+				return Ok(d_next)
+			}
+			d = d_next;
+		}
+
+		Err(Error::<T>::Overflow)
+	}
+
+	/// Calculates the reserve of one asset that keeps the invariant `d`, given the reserve `x` of
+	/// the other one.
+	fn stable_swap_reserve(
+		x: &T::HigherPrecisionBalance,
+		d: &T::HigherPrecisionBalance,
+		amplification: u32,
+	) -> Result<T::HigherPrecisionBalance, Error<T>> {
+		let two = T::HigherPrecisionBalance::from(2u32);
+		let ann = T::HigherPrecisionBalance::from(amplification)
+			.checked_mul(&4u32.into())
+			.ok_or(Error::<T>::Overflow)?;
+
+		// c = d^3 / (4x * 4A)
+		let c = d
+			.checked_mul(d)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_div(&x.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_mul(d)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_div(&ann.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+			.ok_or(Error::<T>::Overflow)?;
+		// b = x + d / 4A
+		let b = x
+			.checked_add(&d.checked_div(&ann).ok_or(Error::<T>::Overflow)?)
+			.ok_or(Error::<T>::Overflow)?;
+
+		let mut y = d.clone();
+		for _ in 0..MAX_ITERATIONS {
+			// y = (y^2 + c) / (2y + b - d)
+			let numerator = y
+				.checked_mul(&y)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&c)
+				.ok_or(Error::<T>::Overflow)?;
+			let denominator = y
+				.checked_mul(&two)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&b)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_sub(d)
+				.ok_or(Error::<T>::Overflow)?;
+			let y_next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+
+			if Self::converged(&y_next, &y) {
+				return Ok(y_next)
+			}
+			y = y_next;
+		}
+
+		Err(Error::<T>::Overflow)
+	}
+
+	/// Whether two successive approximations of Newton's method differ by at most one.
+	fn converged(next: &T::HigherPrecisionBalance, prev: &T::HigherPrecisionBalance) -> bool {
+		let diff = if next > prev { next.checked_sub(prev) } else { prev.checked_sub(next) };
+		diff.map_or(false, |diff| diff <= One::one())
+	}
+}
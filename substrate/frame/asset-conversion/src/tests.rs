@@ -28,7 +28,7 @@ use frame_support::{
 	},
 };
 use sp_arithmetic::Permill;
-use sp_runtime::{DispatchError, TokenError};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, TokenError};
 
 fn events() -> Vec<Event<Test>> {
 	let result = System::events()
@@ -2493,3 +2493,610 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn setup_twap_pool(user: u128) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000 + get_native_ed()));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		10000,
+		200,
+		1,
+		1,
+		user,
+	));
+
+	(token_1, token_2)
+}
+
+#[test]
+fn time_weighted_average_price_should_work() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_twap_pool(user);
+		let pool_id = <Test as Config>::PoolLocator::pool_id(&token_1, &token_2).unwrap();
+
+		// no history yet.
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 1),
+			Error::<Test>::InsufficientPriceHistory
+		);
+
+		System::set_block_number(11);
+		let price = FixedU128::saturating_from_rational(200, 10000);
+		assert_eq!(AssetConversion::time_weighted_average_price(&token_1, &token_2, 10), Ok(price));
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(&token_2, &token_1, 10),
+			Ok(FixedU128::saturating_from_integer(50))
+		);
+		assert_eq!(
+			AssetConversion::quote_price_time_weighted(token_1.clone(), token_2.clone(), 1000, 10),
+			Some(20)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_time_weighted(token_2.clone(), token_1.clone(), 10, 10),
+			Some(500)
+		);
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
+			1,
+			user,
+			false,
+		));
+		let (reserve1, reserve2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let new_price = FixedU128::saturating_from_rational(reserve2, reserve1);
+		assert_eq!(PriceOracles::<Test>::get(&pool_id).price1, new_price);
+
+		// the swap only affects the following blocks.
+		assert_eq!(AssetConversion::time_weighted_average_price(&token_1, &token_2, 10), Ok(price));
+
+		System::set_block_number(21);
+		let average = FixedU128::from_inner((price.into_inner() + new_price.into_inner()) / 2);
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 20),
+			Ok(average)
+		);
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 10),
+			Ok(new_price)
+		);
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 21),
+			Error::<Test>::InsufficientPriceHistory
+		);
+	});
+}
+
+#[test]
+fn time_weighted_average_price_cannot_be_manipulated() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_twap_pool(user);
+		let pool_id = <Test as Config>::PoolLocator::pool_id(&token_1, &token_2).unwrap();
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		let price = FixedU128::saturating_from_rational(200, 10000);
+
+		System::set_block_number(11);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			50000,
+			1,
+			user,
+			false,
+		));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone()],
+			balance(user, token_2.clone()) - 9800,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(AssetConversion::time_weighted_average_price(&token_1, &token_2, 10), Ok(price));
+		let spot_price = PriceOracles::<Test>::get(&pool_id).price1;
+
+		// sending assets to the pool account directly doesn't move the price.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), 2, pool_account, 5000));
+		System::set_block_number(21);
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 10),
+			Ok(spot_price)
+		);
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 20)
+				.unwrap()
+				.into_inner(),
+			(price.into_inner() + spot_price.into_inner()) / 2
+		);
+	});
+}
+
+#[test]
+fn time_weighted_average_price_errors() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_twap_pool(user);
+		System::set_block_number(11);
+
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_1, 10),
+			Error::<Test>::InvalidAssetPair
+		);
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &NativeOrWithId::WithId(3), 10),
+			Error::<Test>::PoolNotFound
+		);
+		assert_eq!(
+			AssetConversion::quote_price_time_weighted(token_1, NativeOrWithId::WithId(3), 10, 10),
+			None
+		);
+	});
+}
+
+#[test]
+fn price_observations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_twap_pool(user);
+		let pool_id = <Test as Config>::PoolLocator::pool_id(&token_1, &token_2).unwrap();
+		let max_observations: u32 = <Test as Config>::MaxPriceObservations::get();
+		let period: u64 = <Test as Config>::PriceObservationPeriod::get();
+
+		for i in 1..=max_observations as u64 + 1 {
+			// several updates within a period keep updating the latest snapshot.
+			System::set_block_number(1 + i * period - 1);
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				5000,
+				100,
+				1,
+				1,
+				user,
+			));
+			System::set_block_number(1 + i * period);
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				5000,
+				100,
+				1,
+				1,
+				user,
+			));
+		}
+
+		let observations = PriceOracles::<Test>::get(&pool_id).observations;
+		assert_eq!(observations.len() as u32, max_observations);
+		assert_eq!(
+			observations.iter().map(|o| o.block).collect::<Vec<_>>(),
+			vec![11, 21, 31, 41, 51].split_off(5 - max_observations as usize)
+		);
+
+		let now = 1 + (max_observations as u64 + 1) * period;
+		let oldest = observations.first().unwrap().block;
+		assert_ok!(AssetConversion::time_weighted_average_price(&token_1, &token_2, now - oldest));
+		assert_noop!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, now - oldest + 1),
+			Error::<Test>::InsufficientPriceHistory
+		);
+	});
+}
+
+#[test]
+fn time_weighted_average_price_of_stable_pool_matches_quotes() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let lp_fee = Permill::from_perthousand(<Test as Config>::LPFee::get());
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool_with_config(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			PoolKind::StableSwap { amplification: 10 },
+			lp_fee,
+		));
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			user,
+			4_000_000 + get_native_ed()
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 2_000_000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			3_000_000,
+			1_000_000,
+			1,
+			1,
+			user,
+		));
+
+		System::set_block_number(11);
+		let amount = 1000;
+		for (asset1, asset2) in [(&token_1, &token_2), (&token_2, &token_1)] {
+			let average = AssetConversion::quote_price_time_weighted(
+				asset1.clone(),
+				asset2.clone(),
+				amount,
+				10,
+			)
+			.unwrap();
+			let quoted = AssetConversion::quote_price_exact_tokens_for_tokens(
+				asset1.clone(),
+				asset2.clone(),
+				amount,
+				true,
+			)
+			.unwrap();
+			// a small swap gets the average price less the fee, up to the rounding.
+			let expected = (Permill::one() - lp_fee).mul_floor(average);
+			assert!(quoted.abs_diff(expected) <= 2, "{quoted} {expected}");
+		}
+
+		// unlike the ratio of the reserves of the pool.
+		assert_ne!(
+			AssetConversion::time_weighted_average_price(&token_1, &token_2, 10),
+			Ok(FixedU128::saturating_from_rational(1_000_000, 3_000_000))
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_config_should_work() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let kind = PoolKind::StableSwap { amplification: 100 };
+		let lp_fee = Permill::from_parts(500);
+		let max_amplification: u32 = <Test as Config>::MaxAmplification::get();
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 1000));
+
+		assert_noop!(
+			AssetConversion::create_pool_with_config(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				kind,
+				Permill::from_parts(700),
+			),
+			Error::<Test>::InvalidLPFee
+		);
+		assert_noop!(
+			AssetConversion::create_pool_with_config(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				PoolKind::StableSwap { amplification: 0 },
+				lp_fee,
+			),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			AssetConversion::create_pool_with_config(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				PoolKind::StableSwap { amplification: max_amplification + 1 },
+				lp_fee,
+			),
+			Error::<Test>::InvalidAmplification
+		);
+
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool_with_config(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			kind,
+			lp_fee,
+		));
+
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::PoolCreated {
+					creator: user,
+					pool_id: pool_id.clone(),
+					pool_account: <Test as Config>::PoolLocator::address(&pool_id).unwrap(),
+					lp_token
+				},
+				Event::<Test>::PoolConfigured { pool_id: pool_id.clone(), kind, lp_fee },
+			]
+		);
+		assert_eq!(pools(), vec![pool_id.clone()]);
+		assert_eq!(PoolConfigs::<Test>::get(&pool_id), Some(PoolConfig { kind, lp_fee }));
+		assert_eq!(
+			AssetConversion::get_pool_config(&token_1, &token_2).ok(),
+			Some(PoolConfig { kind, lp_fee })
+		);
+
+		assert_noop!(
+			AssetConversion::create_pool_with_config(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				kind,
+				lp_fee,
+			),
+			Error::<Test>::PoolExists
+		);
+
+		// pools created with `create_pool` are constant product pools with the default fee.
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone())
+		));
+		assert_eq!(
+			AssetConversion::get_pool_config(&token_1, &token_3).ok(),
+			Some(PoolConfig {
+				kind: PoolKind::ConstantProduct,
+				lp_fee: Permill::from_perthousand(<Test as Config>::LPFee::get()),
+			})
+		);
+	});
+}
+
+#[test]
+fn stable_swap_amounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let stable = PoolConfig {
+			kind: PoolKind::StableSwap { amplification: 100 },
+			lp_fee: Permill::from_parts(500),
+		};
+		let constant_product =
+			PoolConfig { kind: PoolKind::ConstantProduct, lp_fee: Permill::from_parts(500) };
+		let reserve = 1_000_000u128;
+
+		// balanced reserves give a price close to 1:1.
+		let out =
+			AssetConversion::get_pool_amount_out(&stable, &10_000, &reserve, &reserve).unwrap();
+		assert!(out < 10_000 && out > 9_980, "{}", out);
+		let cp_out =
+			AssetConversion::get_pool_amount_out(&constant_product, &10_000, &reserve, &reserve)
+				.unwrap();
+		assert!(out > cp_out);
+
+		// the higher the amplification, the lower the slippage.
+		let flatter = PoolConfig { kind: PoolKind::StableSwap { amplification: 1000 }, ..stable };
+		assert!(
+			AssetConversion::get_pool_amount_out(&flatter, &10_000, &reserve, &reserve).unwrap() >=
+				out
+		);
+
+		// the price moves away from 1:1 when the reserves get unbalanced.
+		let unbalanced_out =
+			AssetConversion::get_pool_amount_out(&stable, &10_000, &(reserve * 9), &reserve)
+				.unwrap();
+		assert!(unbalanced_out < out);
+
+		// the amount in is enough to get the amount out.
+		for (amount_out, reserve_in, reserve_out) in [
+			(1, reserve, reserve),
+			(10_000, reserve, reserve),
+			(500_000, reserve, reserve),
+			(10_000, reserve * 3, reserve),
+			(10_000, reserve, reserve * 3),
+		] {
+			let amount_in = AssetConversion::get_pool_amount_in(
+				&stable,
+				&amount_out,
+				&reserve_in,
+				&reserve_out,
+			)
+			.unwrap();
+			assert!(
+				AssetConversion::get_pool_amount_out(
+					&stable,
+					&amount_in,
+					&reserve_in,
+					&reserve_out
+				)
+				.unwrap() >= amount_out
+			);
+		}
+
+		assert!(matches!(
+			AssetConversion::get_pool_amount_in(&stable, &reserve, &reserve, &reserve),
+			Err(Error::<Test>::AmountOutTooHigh)
+		));
+		assert!(matches!(
+			AssetConversion::get_pool_amount_out(&stable, &10, &0, &reserve),
+			Err(Error::<Test>::ZeroLiquidity)
+		));
+	});
+}
+
+#[test]
+fn pool_fee_tier_is_applied() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let lp_fee = Permill::from_percent(1);
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+		assert_ok!(AssetConversion::create_pool_with_config(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			PoolKind::ConstantProduct,
+			lp_fee,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			1000,
+			1,
+			1,
+			user,
+		));
+
+		// (100 * 0.99 * 1000) / (10000 + 100 * 0.99)
+		let expected_out = 9;
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_1.clone(),
+				token_2.clone(),
+				100,
+				true
+			),
+			Some(expected_out)
+		);
+		assert!(AssetConversion::get_amount_out(&100, &10000, &1000).unwrap() >= expected_out);
+
+		let before = balance(user, token_2.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			100,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_2.clone()), before + expected_out);
+	});
+}
+
+#[test]
+fn swap_in_multi_hops_across_pool_kinds() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let stable = PoolConfig {
+			kind: PoolKind::StableSwap { amplification: 100 },
+			lp_fee: Permill::from_parts(500),
+		};
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_ok!(AssetConversion::create_pool_with_config(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			stable.kind,
+			stable.lp_fee,
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 200000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 300000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 200000));
+
+		let liquidity1 = 100000;
+		let liquidity2 = 100000;
+		let liquidity3 = 100000;
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			liquidity1,
+			liquidity2,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			liquidity2,
+			liquidity3,
+			1,
+			1,
+			user,
+		));
+
+		let input_amount = 1000;
+		let expect_out2 =
+			AssetConversion::get_amount_out(&input_amount, &liquidity1, &liquidity2).unwrap();
+		let expect_out3 =
+			AssetConversion::get_pool_amount_out(&stable, &expect_out2, &liquidity2, &liquidity3)
+				.unwrap();
+		assert!(
+			expect_out3 >
+				AssetConversion::get_amount_out(&expect_out2, &liquidity2, &liquidity3).unwrap()
+		);
+
+		let before = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone(), token_3.clone()],
+			input_amount,
+			1,
+			user,
+			true,
+		));
+		assert_eq!(balance(user, token_3.clone()), before + expect_out3);
+
+		// and back, for an exact amount out.
+		let (reserve3, reserve2) =
+			AssetConversion::get_reserves(token_3.clone(), token_2.clone()).unwrap();
+		let (reserve2_, reserve1) =
+			AssetConversion::get_reserves(token_2.clone(), token_1.clone()).unwrap();
+		let amount_out = 500;
+		let expect_in2 =
+			AssetConversion::get_amount_in(&amount_out, &reserve2_, &reserve1).unwrap();
+		let expect_in3 =
+			AssetConversion::get_pool_amount_in(&stable, &expect_in2, &reserve3, &reserve2)
+				.unwrap();
+
+		let before1 = balance(user, token_1.clone());
+		let before3 = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_2.clone(), token_1.clone()],
+			amount_out,
+			expect_in3,
+			user,
+			true,
+		));
+		assert_eq!(balance(user, token_1.clone()), before1 + amount_out);
+		assert_eq!(balance(user, token_3.clone()), before3 - expect_in3);
+	});
+}
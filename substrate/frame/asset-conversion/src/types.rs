@@ -19,7 +19,8 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_arithmetic::Permill;
+use sp_runtime::{traits::TryConvert, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +42,33 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// The invariant between the reserves of a pool, which determines the price of a swap.
+#[derive(Decode, Encode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum PoolKind {
+	/// The constant product `x * y = k` invariant.
+	#[codec(index = 0)]
+	ConstantProduct,
+	/// The stable swap invariant of Curve, suited for assets of (almost) the same value.
+	///
+	/// While the reserves are balanced, the price stays close to 1:1 and the slippage is low.
+	/// The higher the `amplification`, the longer it stays so, the lower it is the closer the
+	/// pool behaves to a constant product pool.
+	#[codec(index = 1)]
+	StableSwap {
+		/// The amplification coefficient of the invariant.
+		amplification: u32,
+	},
+}
+
+/// The parameters chosen at the creation of a pool.
+#[derive(Decode, Encode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PoolConfig {
+	/// The invariant of the pool.
+	pub kind: PoolKind,
+	/// The fee the liquidity providers take of every swap through the pool.
+	pub lp_fee: Permill,
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
@@ -66,7 +66,7 @@ fn account_vote<T: Config<I>, I: 'static>(b: BalanceOf<T, I>) -> AccountVote<Bal
 }
 
 benchmarks_instance_pallet! {
-	where_clause {  where T::MaxVotes: core::fmt::Debug }
+	where_clause {  where T::MaxVotes: core::fmt::Debug, T::MaxDelegations: core::fmt::Debug }
 
 	vote_new {
 		let caller = funded_account::<T, I>("caller", 0);
@@ -209,6 +209,46 @@ benchmarks_instance_pallet! {
 	}
 
 	undelegate {
+		let d in 1 .. T::MaxDelegations::get();
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// We need to create `d` delegations, each to a voter with `r` votes
+		for j in 0 .. d {
+			let voter = funded_account::<T, I>("voter", j);
+			ConvictionVoting::<T, I>::delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(voter.clone()),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+			for i in polls.iter().take(r as usize) {
+				ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+			}
+			assert_matches!(
+				VotingFor::<T, I>::get(&voter, &class),
+				Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+			);
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { targets, .. }) if targets.len() == d as usize
+		);
+	}: _(RawOrigin::Signed(caller.clone()), class.clone())
+	verify {
+		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
+	}
+
+	undelegate_from {
 		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
 
 		let all_polls = fill_voting::<T, I>().1;
@@ -222,10 +262,20 @@ benchmarks_instance_pallet! {
 		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
 		let delegate_vote = account_vote::<T, I>(delegated_balance);
 
+		// The delegation to `voter` is the last one of the maximum number of delegations
+		for j in 1 .. T::MaxDelegations::get() {
+			ConvictionVoting::<T, I>::delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(funded_account::<T, I>("voter", j)),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
 		ConvictionVoting::<T, I>::delegate(
 			RawOrigin::Signed(caller.clone()).into(),
 			class.clone(),
-			voter_lookup,
+			voter_lookup.clone(),
 			Conviction::Locked1x,
 			delegated_balance,
 		)?;
@@ -238,10 +288,13 @@ benchmarks_instance_pallet! {
 			VotingFor::<T, I>::get(&voter, &class),
 			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
 		);
-		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Delegating(_));
-	}: _(RawOrigin::Signed(caller.clone()), class.clone())
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup)
 	verify {
-		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
+		let remaining = T::MaxDelegations::get() as usize - 1;
+		match VotingFor::<T, I>::get(&caller, &class) {
+			Voting::Delegating(Delegating { targets, .. }) => assert_eq!(targets.len(), remaining),
+			Voting::Casting(_) => assert_eq!(remaining, 0),
+		}
 	}
 
 	unlock {
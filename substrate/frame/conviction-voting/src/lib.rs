@@ -38,11 +38,12 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
-	ArithmeticError, DispatchError, Perbill,
+	ArithmeticError, BoundedVec, DispatchError, Perbill,
 };
 use sp_std::prelude::*;
 
 mod conviction;
+pub mod migration;
 mod types;
 mod vote;
 pub mod weights;
@@ -51,7 +52,7 @@ pub use self::{
 	conviction::Conviction,
 	pallet::*,
 	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	vote::{AccountVote, Casting, Delegating, Delegation, Vote, Voting},
 	weights::WeightInfo,
 };
 
@@ -72,10 +73,16 @@ type VotingOf<T, I = ()> = Voting<
 	BlockNumberFor<T>,
 	PollIndexOf<T, I>,
 	<T as Config<I>>::MaxVotes,
+	<T as Config<I>>::MaxDelegations,
 >;
 #[allow(dead_code)]
-type DelegatingOf<T, I = ()> =
-	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+type DelegatingOf<T, I = ()> = Delegating<
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config<I>>::MaxDelegations,
+>;
+type DelegationOf<T, I = ()> = Delegation<BalanceOf<T, I>, <T as frame_system::Config>::AccountId>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
 pub type VotesOf<T, I = ()> = BalanceOf<T, I>;
 type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
@@ -88,15 +95,19 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{
-			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
+			DispatchResultWithPostInfo, Hooks, IsType, StorageDoubleMap, StorageMap,
+			StorageVersion, ValueQuery, Weight,
 		},
 		traits::ClassCountOf,
 		Twox64Concat,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::BoundedVec;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -130,6 +141,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotes: Get<u32>;
 
+		/// The maximum number of concurrent delegations an account may have in a single class.
+		///
+		/// Each delegation locks its own portion of the account's balance, so together they can
+		/// never delegate more than the account's total balance.
+		#[pallet::constant]
+		type MaxDelegations: Get<u32>;
+
 		/// The minimum period of vote locking.
 		///
 		/// It should be no shorter than enactment period to ensure that in the case of an approval,
@@ -170,6 +188,16 @@ pub mod pallet {
 		Delegated(T::AccountId, T::AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
 		Undelegated(T::AccountId),
+		/// An account has cancelled its delegation to one of its targets. \[who, target\]
+		UndelegatedFrom(T::AccountId, T::AccountId),
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::error]
@@ -182,14 +210,14 @@ pub mod pallet {
 		NoPermission,
 		/// The actor has no permission to conduct the action right now but will do in the future.
 		NoPermissionYet,
-		/// The account is already delegating.
+		/// The account is already delegating to this target.
 		AlreadyDelegating,
 		/// The account currently has votes attached to it and the operation cannot succeed until
 		/// these are removed through `remove_vote`.
 		AlreadyVoting,
 		/// Too high a balance was provided that the account cannot afford.
 		InsufficientFunds,
-		/// The account is not currently delegating.
+		/// The account is not currently delegating (to the given target).
 		NotDelegating,
 		/// Delegation to oneself makes no sense.
 		Nonsense,
@@ -199,6 +227,8 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// Maximum number of delegations in the class reached.
+		MaxDelegationsReached,
 	}
 
 	#[pallet::call]
@@ -229,8 +259,13 @@ pub mod pallet {
 		/// The balance delegated is locked for as long as it's delegated, and thereafter for the
 		/// time appropriate for the conviction's lock period.
 		///
+		/// An account may delegate to several targets within the same class, up to
+		/// `MaxDelegations`, each with its own conviction. Every delegation locks its own portion
+		/// of the balance, so the sum of all delegated balances in the class must not be more than
+		/// the account's current balance.
+		///
 		/// The dispatch origin of this call must be _Signed_, and the signing account must either:
-		///   - be delegating already; or
+		///   - be delegating already, but not to `to`; or
 		///   - have no voting activity (if there is, then it will need to be removed through
 		///     `remove_vote`).
 		///
@@ -239,8 +274,9 @@ pub mod pallet {
 		///   to this function are required.
 		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
 		///   account is undelegated, the funds will be locked for the corresponding period.
-		/// - `balance`: The amount of the account's balance to be used in delegating. This must not
-		///   be more than the account's current balance.
+		/// - `balance`: The amount of the account's balance to be used in this delegation. Together
+		///   with any other delegations in the class this must not be more than the account's
+		///   current balance.
 		///
 		/// Emits `Delegated`.
 		///
@@ -266,29 +302,35 @@ pub mod pallet {
 
 		/// Undelegate the voting power of the sending account for a particular class of polls.
 		///
-		/// Tokens may be unlocked following once an amount of time consistent with the lock period
-		/// of the conviction with which the delegation was issued has passed.
+		/// All of the account's delegations in the class are removed. Tokens may be unlocked
+		/// following once an amount of time consistent with the lock period of the conviction with
+		/// which each delegation was issued has passed.
 		///
 		/// The dispatch origin of this call must be _Signed_ and the signing account must be
 		/// currently delegating.
 		///
-		/// - `class`: The class of polls to remove the delegation from.
+		/// - `class`: The class of polls to remove the delegations from.
 		///
 		/// Emits `Undelegated`.
 		///
-		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
-		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		/// Weight: `O(D * R)` where D is the number of delegations of the voter and R is the
+		///   number of polls the voters delegating to have voted on. Weight is initially charged as
+		///   if maximum delegations and votes, but is refunded later.
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::undelegate(T::MaxVotes::get().into()))]
+		#[pallet::weight(T::WeightInfo::undelegate(
+			T::MaxDelegations::get(),
+			T::MaxVotes::get().into()
+		))]
 		pub fn undelegate(
 			origin: OriginFor<T>,
 			class: ClassOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let votes = Self::try_undelegate(who, class)?;
-			Ok(Some(T::WeightInfo::undelegate(votes)).into())
+			let max_votes = votes.iter().max().copied().unwrap_or_default();
+			Ok(Some(T::WeightInfo::undelegate(votes.len() as u32, max_votes)).into())
 		}
 
 		/// Remove the lock caused by prior voting/delegating which has expired within a particular
@@ -383,6 +425,35 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Undelegate the voting power the sending account delegated to `target` for a particular
+		/// class of polls, leaving any other delegations in the class in place.
+		///
+		/// Tokens may be unlocked following once an amount of time consistent with the lock period
+		/// of the conviction with which the delegation was issued has passed.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating to `target`.
+		///
+		/// - `class`: The class of polls to remove the delegation from.
+		/// - `target`: The account the delegation to be removed was made to.
+		///
+		/// Emits `UndelegatedFrom`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::undelegate_from(T::MaxVotes::get().into()))]
+		pub fn undelegate_from(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let votes = Self::try_undelegate_from(who, class, target)?;
+			Ok(Some(T::WeightInfo::undelegate_from(votes)).into())
+		}
 	}
 }
 
@@ -544,7 +615,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`.
+	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`,
+	/// in addition to any delegations `who` already has in `class`.
 	///
 	/// Return the upstream number of votes.
 	fn try_delegate(
@@ -559,76 +631,156 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
-				let old = sp_std::mem::replace(
-					voting,
-					Voting::Delegating(Delegating {
-						balance,
-						target: target.clone(),
-						conviction,
-						delegations: Default::default(),
-						prior: Default::default(),
-					}),
-				);
-				match old {
-					Voting::Delegating(Delegating { .. }) =>
-						return Err(Error::<T, I>::AlreadyDelegating.into()),
+				let delegation = Delegation { target: target.clone(), conviction, balance };
+				let total = match voting {
+					Voting::Delegating(delegating) => {
+						ensure!(
+							!delegating.targets.iter().any(|d| d.target == target),
+							Error::<T, I>::AlreadyDelegating
+						);
+						delegating
+							.targets
+							.try_push(delegation)
+							.map_err(|_| Error::<T, I>::MaxDelegationsReached)?;
+						delegating.balance()
+					},
 					Voting::Casting(Casting { votes, delegations, prior }) => {
 						// here we just ensure that we're currently idling with no votes recorded.
 						ensure!(votes.is_empty(), Error::<T, I>::AlreadyVoting);
-						voting.set_common(delegations, prior);
+						let targets = BoundedVec::try_from(vec![delegation])
+							.map_err(|_| Error::<T, I>::MaxDelegationsReached)?;
+						*voting = Voting::Delegating(Delegating {
+							targets,
+							delegations: *delegations,
+							prior: *prior,
+						});
+						balance
 					},
-				}
+				};
+				ensure!(
+					total <= T::Currency::total_balance(&who),
+					Error::<T, I>::InsufficientFunds
+				);
 
 				let votes =
 					Self::increase_upstream_delegation(&target, &class, conviction.votes(balance));
-				// Extend the lock to `balance` (rather than setting it) since we don't know what
+				// Extend the lock to `total` (rather than setting it) since we don't know what
 				// other votes are in place.
-				Self::extend_lock(&who, &class, balance);
+				Self::extend_lock(&who, &class, total);
 				Ok(votes)
 			})?;
 		Self::deposit_event(Event::<T, I>::Delegated(who, target));
 		Ok(votes)
 	}
 
-	/// Attempt to end the current delegation.
+	/// Attempt to end all of the current delegations.
 	///
-	/// Return the number of votes of upstream.
-	fn try_undelegate(who: T::AccountId, class: ClassOf<T, I>) -> Result<u32, DispatchError> {
-		let votes =
-			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
+	/// Return the number of votes of upstream, for each delegation removed.
+	fn try_undelegate(who: T::AccountId, class: ClassOf<T, I>) -> Result<Vec<u32>, DispatchError> {
+		let votes = VotingFor::<T, I>::try_mutate(
+			&who,
+			&class,
+			|voting| -> Result<Vec<u32>, DispatchError> {
 				match sp_std::mem::replace(voting, Voting::default()) {
-					Voting::Delegating(Delegating {
-						balance,
-						target,
-						conviction,
-						delegations,
-						mut prior,
-					}) => {
-						// remove any delegation votes to our current target.
-						let votes = Self::reduce_upstream_delegation(
-							&target,
-							&class,
-							conviction.votes(balance),
-						);
-						let now = frame_system::Pallet::<T>::block_number();
-						let lock_periods = conviction.lock_periods().into();
-						prior.accumulate(
-							now.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods),
-							),
-							balance,
-						);
+					Voting::Delegating(delegating) => {
+						let mut still_delegated = delegating.balance();
+						let Delegating { targets, delegations, mut prior } = delegating;
+						// Remove the delegations with the shortest lock periods first, so that the
+						// balance locked by each one covers the ones with longer lock periods.
+						let mut targets = targets.into_inner();
+						targets.sort_by_key(|d| d.conviction.lock_periods());
+						let votes = targets
+							.into_iter()
+							.map(|delegation| {
+								still_delegated = still_delegated.saturating_sub(delegation.balance);
+								Self::remove_delegation(
+									&class,
+									delegation,
+									still_delegated,
+									&mut prior,
+								)
+							})
+							.collect();
 						voting.set_common(delegations, prior);
 
 						Ok(votes)
 					},
 					Voting::Casting(_) => Err(Error::<T, I>::NotDelegating.into()),
 				}
-			})?;
+			},
+		)?;
 		Self::deposit_event(Event::<T, I>::Undelegated(who));
 		Ok(votes)
 	}
 
+	/// Attempt to end the current delegation to `target`, keeping any other delegations.
+	///
+	/// Return the number of votes of upstream.
+	fn try_undelegate_from(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+	) -> Result<u32, DispatchError> {
+		let votes =
+			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
+				let delegating = match voting {
+					Voting::Delegating(delegating) => delegating,
+					Voting::Casting(_) => return Err(Error::<T, I>::NotDelegating.into()),
+				};
+				let i = delegating
+					.targets
+					.iter()
+					.position(|d| d.target == target)
+					.ok_or(Error::<T, I>::NotDelegating)?;
+				let delegation = delegating.targets.remove(i);
+				let still_delegated = delegating.balance();
+				let votes = Self::remove_delegation(
+					&class,
+					delegation,
+					still_delegated,
+					&mut delegating.prior,
+				);
+				if delegating.targets.is_empty() {
+					let (delegations, prior) = (delegating.delegations, delegating.prior);
+					*voting = Voting::default();
+					voting.set_common(delegations, prior);
+				}
+
+				Ok(votes)
+			})?;
+		Self::deposit_event(Event::<T, I>::UndelegatedFrom(who, target));
+		Ok(votes)
+	}
+
+	/// Remove the voting power of `delegation` from its target and accumulate the lock it leaves
+	/// behind into `prior`.
+	///
+	/// The balance of `delegation` stays locked for its conviction period. Since `prior` is a
+	/// single lock which overlaps with the delegated balance, the balance `still_delegated` to
+	/// other targets is locked along with it; nothing is locked if the conviction has no lock
+	/// period.
+	///
+	/// Return the number of votes of upstream.
+	fn remove_delegation(
+		class: &ClassOf<T, I>,
+		delegation: DelegationOf<T, I>,
+		still_delegated: BalanceOf<T, I>,
+		prior: &mut vote::PriorLock<BlockNumberFor<T>, BalanceOf<T, I>>,
+	) -> u32 {
+		let Delegation { target, conviction, balance } = delegation;
+		// remove any delegation votes to the target.
+		let votes = Self::reduce_upstream_delegation(&target, class, conviction.votes(balance));
+		let lock_periods = conviction.lock_periods();
+		if lock_periods > 0 {
+			let now = frame_system::Pallet::<T>::block_number();
+			prior.accumulate(
+				now.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods.into())),
+				balance.saturating_add(still_delegated),
+			);
+		}
+		votes
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -683,4 +835,81 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			);
 		}
 	}
+
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// The following assertions must always apply.
+	///
+	/// Delegation assertions:
+	///
+	/// * Every delegating account has at least one delegation, to unique targets other than itself.
+	/// * The delegations recorded as received by every account equal the sum of the
+	///   conviction-weighted delegations made to it in the same class.
+	///
+	/// Tally assertions:
+	///
+	/// * The tally of every ongoing poll which has votes equals the sum of those votes, including
+	///   the delegations received by the voters.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::traits::VoteTally;
+		use sp_std::collections::btree_map::BTreeMap;
+
+		let mut received =
+			BTreeMap::<(T::AccountId, ClassOf<T, I>), Delegations<BalanceOf<T, I>>>::new();
+		let mut tallies = BTreeMap::<PollIndexOf<T, I>, TallyOf<T, I>>::new();
+		for (who, class, voting) in VotingFor::<T, I>::iter() {
+			match voting {
+				Voting::Delegating(Delegating { targets, .. }) => {
+					ensure!(!targets.is_empty(), "Delegating accounts must have a delegation");
+					for (i, delegation) in targets.iter().enumerate() {
+						ensure!(delegation.target != who, "Accounts cannot delegate to themselves");
+						ensure!(
+							targets.iter().skip(i + 1).all(|d| d.target != delegation.target),
+							"Delegation targets must be unique"
+						);
+						let entry =
+							received.entry((delegation.target.clone(), class.clone())).or_default();
+						*entry =
+							entry.saturating_add(delegation.conviction.votes(delegation.balance));
+					}
+				},
+				Voting::Casting(Casting { votes, delegations, .. }) =>
+					for &(poll_index, vote) in votes.iter() {
+						if T::Polls::as_ongoing(poll_index).is_none() {
+							continue
+						}
+						let tally = tallies
+							.entry(poll_index)
+							.or_insert_with(|| TallyOf::<T, I>::new(class.clone()));
+						tally.add(vote).ok_or("Tally of ongoing poll overflows")?;
+						if let Some(approve) = vote.as_standard() {
+							tally.increase(approve, delegations);
+						}
+					},
+			}
+		}
+
+		for (who, class, voting) in VotingFor::<T, I>::iter() {
+			let delegations = match voting {
+				Voting::Casting(Casting { delegations, .. }) => delegations,
+				Voting::Delegating(Delegating { delegations, .. }) => delegations,
+			};
+			ensure!(
+				received.remove(&(who, class)).unwrap_or_default() == delegations,
+				"Received delegations must equal the delegations made to the account"
+			);
+		}
+		ensure!(received.is_empty(), "Delegations must be made to accounts with voting records");
+
+		for (poll_index, expected) in tallies {
+			let tally = T::Polls::as_ongoing(poll_index).map(|(tally, _)| tally);
+			ensure!(
+				tally == Some(expected),
+				"Tally of ongoing poll must equal the sum of its votes"
+			);
+		}
+
+		Ok(())
+	}
 }
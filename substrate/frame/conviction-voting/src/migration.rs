@@ -0,0 +1,144 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the conviction-voting pallet.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{DefensiveTruncateFrom, UncheckedOnRuntimeUpgrade},
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrate `Voting::Delegating` from a single delegation to a list of delegations.
+pub mod v1 {
+	use super::*;
+
+	mod v0 {
+		use super::*;
+		use crate::vote::PriorLock;
+
+		/// V0 type for [`crate::Delegating`].
+		#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+		pub struct Delegating<Balance, AccountId, BlockNumber> {
+			pub balance: Balance,
+			pub target: AccountId,
+			pub conviction: Conviction,
+			pub delegations: Delegations<Balance>,
+			pub prior: PriorLock<BlockNumber, Balance>,
+		}
+
+		/// V0 type for [`crate::Voting`].
+		#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+		pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+		where
+			MaxVotes: Get<u32>,
+		{
+			Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
+			Delegating(Delegating<Balance, AccountId, BlockNumber>),
+		}
+
+		pub type VotingOf<T, I> = Voting<
+			BalanceOf<T, I>,
+			<T as frame_system::Config>::AccountId,
+			BlockNumberFor<T>,
+			PollIndexOf<T, I>,
+			<T as Config<I>>::MaxVotes,
+		>;
+
+		/// V0 type for [`crate::VotingFor`].
+		#[frame_support::storage_alias]
+		pub type VotingFor<T: Config<I>, I: 'static> = StorageDoubleMap<
+			Pallet<T, I>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			Twox64Concat,
+			ClassOf<T, I>,
+			VotingOf<T, I>,
+			OptionQuery,
+		>;
+	}
+
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for MigrateToV1<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(
+				T::MaxDelegations::get() >= 1,
+				"pallet-conviction-voting: existing delegations must fit the new bound"
+			);
+			let count = VotingFor::<T, I>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let (mut reads, mut writes) = (0u64, 0u64);
+			// The encoding of `Voting::Casting` is unchanged, so only the delegating accounts are
+			// rewritten.
+			for (who, class, old) in v0::VotingFor::<T, I>::iter() {
+				reads.saturating_inc();
+				if let v0::Voting::Delegating(v0::Delegating {
+					balance,
+					target,
+					conviction,
+					delegations,
+					prior,
+				}) = old
+				{
+					let targets = BoundedVec::defensive_truncate_from(vec![Delegation {
+						target,
+						conviction,
+						balance,
+					}]);
+					VotingFor::<T, I>::insert(
+						who,
+						class,
+						Voting::Delegating(Delegating { targets, delegations, prior }),
+					);
+					writes.saturating_inc();
+				}
+			}
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count: u32 = Decode::decode(&mut &state[..]).expect("pre_upgrade provides a count");
+			ensure!(
+				VotingFor::<T, I>::iter_keys().count() as u32 == count,
+				"pallet-conviction-voting: voting records lost during migration"
+			);
+			Pallet::<T, I>::do_try_state()
+		}
+	}
+
+	/// [`UncheckedOnRuntimeUpgrade`] implementation [`MigrateToV1`] wrapped in a
+	/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), which ensures that:
+	/// - The migration only runs once when the on-chain storage version is 0
+	/// - The on-chain storage version is updated to `1` after the migration executes
+	/// - Reads/Writes from checking/settings the on-chain storage version are accounted for
+	pub type MigrateV0ToV1<T, I = ()> = frame_support::migrations::VersionedMigration<
+		0, // The migration will only execute when the on-chain storage version is 0
+		1, // The on-chain storage version will be set to 1 after the migration is complete
+		MigrateToV1<T, I>,
+		crate::pallet::Pallet<T, I>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
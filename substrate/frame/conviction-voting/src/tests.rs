@@ -151,6 +151,7 @@ impl Config for Test {
 	type Currency = pallet_balances::Pallet<Self>;
 	type VoteLockingPeriod = ConstU64<3>;
 	type MaxVotes = ConstU32<3>;
+	type MaxDelegations = ConstU32<2>;
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = TestPolls;
@@ -846,3 +847,107 @@ fn errors_with_remove_vote_work() {
 		);
 	});
 }
+
+#[test]
+fn split_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 0)));
+
+		// 1 splits its balance between 2 and 3, each with its own conviction.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked2x, 6));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 4));
+		assert_eq!(tally(0), Tally::from_parts(13, 5, 16));
+		assert_eq!(Balances::usable_balance(1), 0);
+		assert_ok!(Voting::do_try_state());
+
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 1),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 4, Conviction::None, 1),
+			Error::<Test>::MaxDelegationsReached
+		);
+		assert_noop!(
+			Voting::vote(RuntimeOrigin::signed(1), 0, aye(1, 0)),
+			Error::<Test>::AlreadyDelegating
+		);
+
+		// Removing one delegation keeps the other one in place.
+		assert_ok!(Voting::undelegate_from(RuntimeOrigin::signed(1), 0, 3));
+		System::assert_last_event(RuntimeEvent::Voting(Event::UndelegatedFrom(1, 3)));
+		assert_eq!(tally(0), Tally::from_parts(13, 1, 16));
+		assert_noop!(
+			Voting::undelegate_from(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::NotDelegating
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 5),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_ok!(Voting::do_try_state());
+
+		// The removed balance stays locked for the conviction period of its delegation, along
+		// with the balance still delegated.
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 0);
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 4, Conviction::None, 2));
+		assert_eq!(Balances::usable_balance(1), 2);
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(RuntimeEvent::Voting(Event::Undelegated(1)));
+		assert_eq!(tally(0), Tally::from_parts(1, 1, 10));
+		assert_ok!(Voting::do_try_state());
+
+		// Only the balance delegated with `Locked2x` stays locked.
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+		run_to(10);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
+#[test]
+fn removed_delegations_without_lock_period_unlock_immediately() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 6));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 4));
+		assert_eq!(Balances::usable_balance(1), 0);
+
+		// Only the delegation with a lock period keeps its balance locked.
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 6));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 4));
+		assert_ok!(Voting::undelegate_from(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+		assert_ok!(Voting::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_wrong_tallies() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 5));
+		assert_ok!(Voting::do_try_state());
+
+		Polls::set(vec![(0, Ongoing(Tally::from_parts(7, 0, 15), 0))].into_iter().collect());
+		assert!(Voting::do_try_state().is_err());
+	});
+}
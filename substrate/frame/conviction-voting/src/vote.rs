@@ -149,22 +149,46 @@ impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<Block
 	}
 }
 
-/// Information concerning the delegation of some voting power.
+/// A single delegation of some portion of an account's voting power to a `target` account.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct Delegating<Balance, AccountId, BlockNumber> {
-	/// The amount of balance delegated.
-	pub balance: Balance,
+pub struct Delegation<Balance, AccountId> {
 	/// The account to which the voting power is delegated.
 	pub target: AccountId,
 	/// The conviction with which the voting power is delegated. When this gets undelegated, the
 	/// relevant lock begins.
 	pub conviction: Conviction,
+	/// The amount of balance delegated.
+	pub balance: Balance,
+}
+
+/// Information concerning the delegation of some voting power.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxDelegations))]
+#[codec(mel_bound(Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct Delegating<Balance, AccountId, BlockNumber, MaxDelegations>
+where
+	MaxDelegations: Get<u32>,
+{
+	/// The delegations currently in place. Each of them locks its own portion of the balance, so
+	/// the total amount locked is the sum of their balances. Targets are unique.
+	pub targets: BoundedVec<Delegation<Balance, AccountId>, MaxDelegations>,
 	/// The total amount of delegations that this account has received, post-conviction-weighting.
 	pub delegations: Delegations<Balance>,
 	/// Any pre-existing locks from past voting/delegating activity.
 	pub prior: PriorLock<BlockNumber, Balance>,
 }
 
+impl<Balance: Saturating + Zero + Copy, AccountId, BlockNumber, MaxDelegations>
+	Delegating<Balance, AccountId, BlockNumber, MaxDelegations>
+where
+	MaxDelegations: Get<u32>,
+{
+	/// The total balance delegated across all targets.
+	pub fn balance(&self) -> Balance {
+		self.targets.iter().fold(Zero::zero(), |a, d| a.saturating_add(d.balance))
+	}
+}
+
 /// Information concerning the direct vote-casting of some voting power.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxVotes))]
@@ -183,25 +207,28 @@ where
 
 /// An indicator for what an account is doing; it can either be delegating or voting.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-#[scale_info(skip_type_params(MaxVotes))]
+#[scale_info(skip_type_params(MaxVotes, MaxDelegations))]
 #[codec(mel_bound(
 	Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen,
 	PollIndex: MaxEncodedLen,
 ))]
-pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	/// The account is voting directly.
 	Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
-	/// The account is delegating `balance` of its balance to a `target` account with `conviction`.
-	Delegating(Delegating<Balance, AccountId, BlockNumber>),
+	/// The account is delegating portions of its balance to one or more `target` accounts, each
+	/// with its own `conviction`.
+	Delegating(Delegating<Balance, AccountId, BlockNumber, MaxDelegations>),
 }
 
-impl<Balance: Default, AccountId, BlockNumber: Zero, PollIndex, MaxVotes> Default
-	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+impl<Balance: Default, AccountId, BlockNumber: Zero, PollIndex, MaxVotes, MaxDelegations> Default
+	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	fn default() -> Self {
 		Voting::Casting(Casting {
@@ -212,10 +239,12 @@ where
 	}
 }
 
-impl<Balance, AccountId, BlockNumber, PollIndex, MaxVotes> AsMut<PriorLock<BlockNumber, Balance>>
-	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+impl<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
+	AsMut<PriorLock<BlockNumber, Balance>>
+	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	fn as_mut(&mut self) -> &mut PriorLock<BlockNumber, Balance> {
 		match self {
@@ -231,9 +260,11 @@ impl<
 		AccountId,
 		PollIndex,
 		MaxVotes,
-	> Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+		MaxDelegations,
+	> Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	pub fn rejig(&mut self, now: BlockNumber) {
 		AsMut::<PriorLock<BlockNumber, Balance>>::as_mut(self).rejig(now);
@@ -244,7 +275,7 @@ where
 		match self {
 			Voting::Casting(Casting { votes, prior, .. }) =>
 				votes.iter().map(|i| i.1.balance()).fold(prior.locked(), |a, i| a.max(i)),
-			Voting::Delegating(Delegating { balance, prior, .. }) => *balance.max(&prior.locked()),
+			Voting::Delegating(delegating) => delegating.balance().max(delegating.prior.locked()),
 		}
 	}
 
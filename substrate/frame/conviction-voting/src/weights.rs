@@ -56,7 +56,8 @@ pub trait WeightInfo {
 	fn remove_vote() -> Weight;
 	fn remove_other_vote() -> Weight;
 	fn delegate(r: u32, ) -> Weight;
-	fn undelegate(r: u32, ) -> Weight;
+	fn undelegate(d: u32, r: u32, ) -> Weight;
+	fn undelegate_from(r: u32, ) -> Weight;
 	fn unlock() -> Weight;
 }
 
@@ -168,6 +169,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:17 w:17)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 16]`.
+	/// The range of component `r` is `[0, 1]`.
+	fn undelegate(d: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + d * (184 ±0) + r * (1722 ±0)`
+		//  Estimated: `109992 + d * (29716 ±0) + r * (109992 ±0)`
+		// Minimum execution time: 59_450_000 picoseconds.
+		Weight::from_parts(19_081_441, 109992)
+			// Standard Error: 105_813
+			.saturating_add(Weight::from_parts(4_432_416, 0).saturating_mul(d.into()))
+			// Standard Error: 1_074_522
+			.saturating_add(Weight::from_parts(68_389_603, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -177,14 +207,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[0, 1]`.
-	fn undelegate(r: u32, ) -> Weight {
+	fn undelegate_from(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `472 + r * (1377 ±0)`
+		//  Measured:  `1476 + r * (1377 ±0)`
 		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 23_529_000 picoseconds.
-		Weight::from_parts(25_071_526, 109992)
-			// Standard Error: 138_190
-			.saturating_add(Weight::from_parts(40_350_973, 0).saturating_mul(r.into()))
+		// Minimum execution time: 28_690_000 picoseconds.
+		Weight::from_parts(36_111_385, 109992)
+			// Standard Error: 446_973
+			.saturating_add(Weight::from_parts(35_008_814, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
@@ -317,6 +347,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:17 w:17)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 16]`.
+	/// The range of component `r` is `[0, 1]`.
+	fn undelegate(d: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + d * (184 ±0) + r * (1722 ±0)`
+		//  Estimated: `109992 + d * (29716 ±0) + r * (109992 ±0)`
+		// Minimum execution time: 59_450_000 picoseconds.
+		Weight::from_parts(19_081_441, 109992)
+			// Standard Error: 105_813
+			.saturating_add(Weight::from_parts(4_432_416, 0).saturating_mul(d.into()))
+			// Standard Error: 1_074_522
+			.saturating_add(Weight::from_parts(68_389_603, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -326,14 +385,14 @@ impl WeightInfo for () {
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[0, 1]`.
-	fn undelegate(r: u32, ) -> Weight {
+	fn undelegate_from(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `472 + r * (1377 ±0)`
+		//  Measured:  `1476 + r * (1377 ±0)`
 		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 23_529_000 picoseconds.
-		Weight::from_parts(25_071_526, 109992)
-			// Standard Error: 138_190
-			.saturating_add(Weight::from_parts(40_350_973, 0).saturating_mul(r.into()))
+		// Minimum execution time: 28_690_000 picoseconds.
+		Weight::from_parts(36_111_385, 109992)
+			// Standard Error: 446_973
+			.saturating_add(Weight::from_parts(35_008_814, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
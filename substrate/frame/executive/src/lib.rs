@@ -178,13 +178,19 @@ use sp_std::{marker::PhantomData, prelude::*};
 
 #[cfg(feature = "try-runtime")]
 use ::{
+	codec::Decode,
 	frame_support::{
-		traits::{TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState},
+		traits::{
+			TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState,
+			STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+		},
 		StorageNoopGuard,
 	},
 	frame_try_runtime::{TryStateSelect, UpgradeCheckSelect},
 	log,
+	sp_metadata_ir::frame_metadata::RuntimeMetadataPrefixed,
 	sp_runtime::TryRuntimeError,
+	sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet},
 };
 
 #[allow(dead_code)]
@@ -465,6 +471,76 @@ where
 		Ok(before_all_weight.saturating_add(try_on_runtime_upgrade_weight))
 	}
 
+	/// Execute all Migrations of this runtime like [`Self::try_runtime_upgrade`], and check that
+	/// every storage item whose layout changed was migrated.
+	///
+	/// `old_metadata` and `new_metadata` are the SCALE encoded `RuntimeMetadataPrefixed` of the
+	/// runtime before the upgrade and of this runtime. A storage item that exists in both but
+	/// with incompatible hashers, key or value type is reported as unmigrated if the on-chain
+	/// `StorageVersion` of its pallet did not increase while the migrations ran.
+	pub fn try_runtime_upgrade_with_metadata(
+		checks: UpgradeCheckSelect,
+		old_metadata: &[u8],
+		new_metadata: &[u8],
+	) -> Result<Weight, TryRuntimeError> {
+		let decode_metadata = |mut metadata: &[u8]| {
+			RuntimeMetadataPrefixed::decode(&mut metadata).map_err(|_| {
+				log::error!(target: LOG_TARGET, "failed to decode the runtime metadata");
+				TryRuntimeError::Other("failed to decode the runtime metadata")
+			})
+		};
+		let old_metadata = decode_metadata(old_metadata)?;
+		let new_metadata = decode_metadata(new_metadata)?;
+
+		let changed_pallets = sp_metadata_ir::storage_layout_changes(&old_metadata, &new_metadata)
+			.ok_or(TryRuntimeError::Other("unsupported runtime metadata version"))?
+			.into_iter()
+			.map(|change| change.pallet)
+			.collect::<BTreeSet<_>>();
+		let versions_before = changed_pallets
+			.into_iter()
+			.map(|pallet| {
+				let version = Self::on_chain_storage_version(&pallet);
+				(pallet, version)
+			})
+			.collect::<BTreeMap<_, _>>();
+
+		let weight = Self::try_runtime_upgrade(checks)?;
+
+		let unmigrated =
+			sp_metadata_ir::unmigrated_storage_changes(&old_metadata, &new_metadata, |pallet| {
+				let before = versions_before.get(pallet).copied().unwrap_or_default();
+				(before, Self::on_chain_storage_version(pallet))
+			})
+			.ok_or(TryRuntimeError::Other("unsupported runtime metadata version"))?;
+
+		if unmigrated.is_empty() {
+			log::info!(target: LOG_TARGET, "✅ All storage layout changes are migrated.");
+			return Ok(weight)
+		}
+
+		for change in &unmigrated {
+			log::error!(
+				target: LOG_TARGET,
+				"❌ {:?} of {}::{} changed, but the storage version of {} did not increase.",
+				change.kind,
+				change.pallet,
+				change.entry,
+				change.pallet,
+			);
+		}
+		Err(TryRuntimeError::Other("storage layout changed without a migration"))
+	}
+
+	/// Reads the on-chain `StorageVersion` of the pallet with the given storage prefix.
+	fn on_chain_storage_version(pallet: &str) -> u16 {
+		let key = frame_support::storage::storage_prefix(
+			pallet.as_bytes(),
+			STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+		);
+		frame_support::storage::unhashed::get_or_default(&key)
+	}
+
 	/// Logs the result of trying to decode the entire state.
 	fn log_decode_result(
 		res: Result<usize, Vec<TryDecodeEntireStorageError>>,
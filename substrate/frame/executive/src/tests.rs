@@ -393,10 +393,19 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 
 		assert_eq!(0, System::last_runtime_upgrade_spec_version());
 
+		if MigrateBalances::get() {
+			StorageVersion::new(2).put::<Balances>();
+		}
+
 		Weight::from_parts(100, 0)
 	}
 }
 
+parameter_types! {
+	/// Whether [`CustomOnRuntimeUpgrade`] bumps the storage version of `Balances`.
+	pub static MigrateBalances: bool = false;
+}
+
 type Executive = super::Executive<
 	Runtime,
 	Block<TestXt>,
@@ -1160,6 +1169,81 @@ fn try_execute_block_works() {
 	});
 }
 
+/// Returns the metadata of the test runtime as if `Balances::TotalIssuance` was stored as `u32`
+/// before.
+#[cfg(feature = "try-runtime")]
+fn metadata_with_old_total_issuance() -> (Vec<u8>, Vec<u8>) {
+	use scale_info::form::PortableForm;
+	use sp_metadata_ir::frame_metadata::{
+		v14::StorageEntryType,
+		v15::{RuntimeMetadataV15, StorageEntryMetadata},
+		RuntimeMetadata, RuntimeMetadataPrefixed,
+	};
+
+	let new_metadata = Runtime::metadata_at_version(15).unwrap().to_vec();
+	let RuntimeMetadataPrefixed(prefix, RuntimeMetadata::V15(mut metadata)) =
+		RuntimeMetadataPrefixed::decode(&mut &new_metadata[..]).unwrap()
+	else {
+		panic!("requested metadata V15")
+	};
+
+	fn entry<'a>(
+		metadata: &'a mut RuntimeMetadataV15,
+		pallet: &str,
+		entry: &str,
+	) -> &'a mut StorageEntryMetadata<PortableForm> {
+		metadata
+			.pallets
+			.iter_mut()
+			.filter_map(|p| p.storage.as_mut())
+			.find(|s| s.prefix == pallet)
+			.and_then(|s| s.entries.iter_mut().find(|e| e.name == entry))
+			.unwrap()
+	}
+	let u32_ty = entry(&mut metadata, "System", "ExtrinsicCount").ty.clone();
+	assert!(matches!(u32_ty, StorageEntryType::Plain(_)));
+	entry(&mut metadata, "Balances", "TotalIssuance").ty = u32_ty;
+
+	let old_metadata = RuntimeMetadataPrefixed(prefix, RuntimeMetadata::V15(metadata)).encode();
+	(old_metadata, new_metadata)
+}
+
+#[test]
+#[cfg(feature = "try-runtime")]
+fn try_runtime_upgrade_with_metadata_detects_unmigrated_storage() {
+	let (old_metadata, new_metadata) = metadata_with_old_total_issuance();
+
+	new_test_ext(1).execute_with(|| {
+		assert_ok!(Executive::try_runtime_upgrade_with_metadata(
+			UpgradeCheckSelect::None,
+			&new_metadata,
+			&new_metadata,
+		));
+	});
+
+	new_test_ext(1).execute_with(|| {
+		assert_err!(
+			Executive::try_runtime_upgrade_with_metadata(
+				UpgradeCheckSelect::None,
+				&old_metadata,
+				&new_metadata,
+			),
+			TryRuntimeError::Other("storage layout changed without a migration"),
+		);
+	});
+
+	MigrateBalances::set(true);
+	new_test_ext(1).execute_with(|| {
+		assert_ok!(Executive::try_runtime_upgrade_with_metadata(
+			UpgradeCheckSelect::None,
+			&old_metadata,
+			&new_metadata,
+		));
+		assert_eq!(StorageVersion::get::<Balances>(), 2);
+	});
+	MigrateBalances::set(false);
+}
+
 /// Same as `extrinsic_while_exts_forbidden_errors` but using the try-runtime function.
 #[test]
 #[cfg(feature = "try-runtime")]
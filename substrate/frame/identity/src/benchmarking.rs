@@ -113,6 +113,78 @@ fn add_sub_accounts<T: Config>(
 	Ok(subs)
 }
 
+// Sets up the whitelisted caller as the registrar at index `r` with an attestation key, after `r`
+// other registrars, and the identity of `user` with a judgement requested from it. Returns a
+// reasonable judgement attestation of the registrar on the identity along with its signature.
+fn setup_attestation<T: Config>(
+	user: &T::AccountId,
+	r: u32,
+) -> Result<(AttestationOf<T>, T::OffchainSignature), &'static str>
+where
+	<T as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+	T::OffchainSignature: From<MultiSignature>,
+{
+	let user_origin =
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(user.clone()));
+	let _ = T::Currency::make_free_balance_be(user, BalanceOf::<T>::max_value());
+
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+	add_registrars::<T>(r)?;
+
+	let info = T::IdentityInformation::create_identity_info();
+	let info_hash = T::Hashing::hash_of(&info);
+	Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
+
+	let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+		.expect("RegistrarOrigin has no successful origin required for the benchmark");
+	Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+	Identity::<T>::request_judgement(user_origin, r, 10u32.into())?;
+
+	let public = sr25519_generate(0.into(), None);
+	let key: T::AccountId = MultiSigner::Sr25519(public).into_account().into();
+	Identity::<T>::set_attestation_key(RawOrigin::Signed(caller).into(), r, Some(key))?;
+
+	let attestation = Attestation {
+		registrar: r,
+		target: user.clone(),
+		identity: info_hash,
+		judgement: Judgement::Reasonable,
+		fields: T::IdentityInformation::all_fields(),
+		expires_at: 10u32.into(),
+	};
+	let payload = Identity::<T>::attestation_payload(&attestation);
+	let signature =
+		MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &payload).unwrap());
+
+	Ok((attestation, signature.into()))
+}
+
+// Records an attestation of each of the first `r` registrars on the identity of `who`, holding
+// their deposits.
+fn add_attestations<T: Config>(
+	who: &T::AccountId,
+	r: u32,
+	identity: T::Hash,
+) -> Result<(), &'static str> {
+	for i in 0..r {
+		let deposit = T::AttestationDeposit::get();
+		T::Currency::reserve(who, deposit)?;
+		let record = AttestationRecord {
+			attestation: identity,
+			identity,
+			judgement: Judgement::Reasonable,
+			fields: T::IdentityInformation::all_fields(),
+			expires_at: 10u32.into(),
+			deposit,
+		};
+		Attestations::<T>::insert(who, i, record);
+	}
+	Ok(())
+}
+
 fn bench_suffix() -> Vec<u8> {
 	b"bench".to_vec()
 }
@@ -267,12 +339,18 @@ mod benchmarks {
 			)?;
 		}
 
+		add_attestations::<T>(&caller, r, T::Hashing::hash_of(&info))?;
+
 		ensure!(IdentityOf::<T>::contains_key(&caller), "Identity does not exist.");
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
 		ensure!(!IdentityOf::<T>::contains_key(&caller), "Identity not cleared.");
+		ensure!(
+			Attestations::<T>::iter_prefix(&caller).next().is_none(),
+			"Attestations not cleared."
+		);
 		Ok(())
 	}
 
@@ -481,6 +559,8 @@ mod benchmarks {
 			)?;
 		}
 
+		add_attestations::<T>(&target, r, T::Hashing::hash_of(&info))?;
+
 		ensure!(IdentityOf::<T>::contains_key(&target), "Identity not set");
 
 		let origin =
@@ -490,6 +570,10 @@ mod benchmarks {
 		_(origin as T::RuntimeOrigin, target_lookup);
 
 		ensure!(!IdentityOf::<T>::contains_key(&target), "Identity not removed");
+		ensure!(
+			Attestations::<T>::iter_prefix(&target).next().is_none(),
+			"Attestations not removed"
+		);
 
 		Ok(())
 	}
@@ -723,5 +807,120 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_attestation_key(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		add_registrars::<T>(r)?;
+
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+
+		let key: T::AccountId = account("key", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), r, Some(key.clone()));
+
+		ensure!(AttestationKeys::<T>::get(r) == Some(key), "Attestation key not set.");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_attestation(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", r, SEED);
+		let (attestation, signature) = setup_attestation::<T>(&user, r)?;
+		let hash = T::Hashing::hash_of(&attestation);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(user.clone()), Box::new(attestation), signature);
+
+		assert_last_event::<T>(
+			Event::<T>::JudgementAttested { target: user, registrar_index: r, attestation: hash }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_attestation(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", r, SEED);
+		let (attestation, signature) = setup_attestation::<T>(&user, r)?;
+		let hash = T::Hashing::hash_of(&attestation);
+		Identity::<T>::submit_attestation(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(attestation.clone()),
+			signature,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(whitelisted_caller()), Box::new(attestation));
+
+		ensure!(Attestations::<T>::get(&user, r).is_none(), "Attestation not removed.");
+		assert_last_event::<T>(
+			Event::<T>::AttestationRevoked { registrar_index: r, attestation: hash }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_attestation(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", r, SEED);
+		let user_lookup = T::Lookup::unlookup(user.clone());
+		let (attestation, signature) = setup_attestation::<T>(&user, r)?;
+		let expires_at = attestation.expires_at;
+		Identity::<T>::submit_attestation(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(attestation),
+			signature,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), user_lookup, r);
+
+		assert_last_event::<T>(
+			Event::<T>::AttestationExpired { target: user, registrar_index: r }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_revocation() -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", 0, SEED);
+		let (attestation, _) = setup_attestation::<T>(&user, 0)?;
+		let hash = T::Hashing::hash_of(&attestation);
+		let expires_at = attestation.expires_at;
+		Identity::<T>::revoke_attestation(
+			RawOrigin::Signed(whitelisted_caller()).into(),
+			Box::new(attestation),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, hash);
+
+		ensure!(RevokedAttestations::<T>::get(0, hash).is_none(), "Revocation not removed.");
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -52,6 +52,19 @@
 //! Users can have multiple usernames that map to the same `AccountId`, however one `AccountId` can
 //! only map to a single username, known as the _primary_.
 //!
+//! ### Attestations
+//!
+//! Registrars can publish a key with which they sign attestations off-chain. An attestation
+//! carries the registrar's judgement on a specific version of an account's identity information,
+//! the identity fields the registrar has verified and an expiry. The account can submit it to have
+//! the judgement recorded in a single transaction, without waiting for `provide_judgement`. The
+//! registrar signs the attestation prefixed with a domain tag and the genesis hash of the chain, so
+//! that the signature is only valid as an attestation on this chain. Recording an attestation
+//! requires a deposit, which is returned once the attestation is removed.
+//!
+//! Registrars keep a revocation list of the attestations they no longer stand behind; revoking an
+//! attestation also removes the judgement recorded from it.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -66,6 +79,11 @@
 //! * `remove_expired_approval` - Remove a username that was issued but never accepted.
 //! * `set_primary_username` - Set a given username as an account's primary.
 //! * `remove_dangling_username` - Remove a username that maps to an account without an identity.
+//! * `submit_attestation` - Record a registrar's judgement from an attestation it signed.
+//! * `remove_expired_attestation` - Remove an expired attestation and the judgement recorded from
+//!   it.
+//! * `remove_expired_revocation` - Remove an expired attestation from a registrar's revocation
+//!   list.
 //!
 //! #### For General Users with Sub-Identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -78,6 +96,8 @@
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `set_attestation_key` - Set the key with which the registrar signs attestations.
+//! * `revoke_attestation` - Revoke an attestation given by the registrar.
 //!
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
@@ -109,13 +129,15 @@ use frame_support::{
 	traits::{BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency, StorageVersion},
 	BoundedVec,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use sp_runtime::traits::{
 	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
 };
 use sp_std::prelude::*;
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Attestation, AttestationOf, AttestationRecord, AttestationRecordOf, Data, FieldsIdentifierOf,
+	IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
 };
 pub use weights::WeightInfo;
 
@@ -125,6 +147,12 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type RegistrationOf<T> =
+	Registration<BalanceOf<T>, <T as Config>::MaxRegistrars, <T as Config>::IdentityInformation>;
+
+/// The domain tag prefixed to the attestations signed by registrars, along with the genesis hash,
+/// so that their signatures cannot be used for other payloads or on other chains.
+pub const ATTESTATION_DOMAIN: &[u8] = b"pallet-identity/attestation";
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -154,6 +182,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type SubAccountDeposit: Get<BalanceOf<Self>>;
 
+		/// The amount held on deposit for an attestation recorded for an account.
+		#[pallet::constant]
+		type AttestationDeposit: Get<BalanceOf<Self>>;
+
 		/// The maximum number of sub-accounts allowed per identified account.
 		#[pallet::constant]
 		type MaxSubAccounts: Get<u32>;
@@ -218,7 +250,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::AccountId,
-		(Registration<BalanceOf<T>, T::MaxRegistrars, T::IdentityInformation>, Option<Username<T>>),
+		(RegistrationOf<T>, Option<Username<T>>),
 		OptionQuery,
 	>;
 
@@ -297,6 +329,38 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The account whose key each registrar signs attestations with off-chain.
+	#[pallet::storage]
+	pub type AttestationKeys<T: Config> =
+		StorageMap<_, Twox64Concat, RegistrarIndex, T::AccountId, OptionQuery>;
+
+	/// The attestations accepted for an account, by the index of the registrar which gave them.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type Attestations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		AttestationRecordOf<T>,
+		OptionQuery,
+	>;
+
+	/// The revocation list of each registrar: the hashes of the attestations it revoked, along
+	/// with the block at which they expire and can be removed from the list.
+	#[pallet::storage]
+	pub type RevokedAttestations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RegistrarIndex,
+		Identity,
+		T::Hash,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -351,6 +415,18 @@ pub mod pallet {
 		NoUsername,
 		/// The username cannot be forcefully removed because it can still be accepted.
 		NotExpired,
+		/// The registrar has not set a key to sign attestations with.
+		NoAttestationKey,
+		/// The attestation has expired.
+		AttestationExpired,
+		/// The attestation was revoked by the registrar.
+		AttestationRevoked,
+		/// The attestation verifies fields which are not set in the identity.
+		InvalidAttestation,
+		/// No attestation of the registrar was found for the account.
+		NoAttestation,
+		/// The attestation or revocation cannot be removed because it has not expired yet.
+		AttestationNotExpired,
 	}
 
 	#[pallet::event]
@@ -392,6 +468,20 @@ pub mod pallet {
 		/// A dangling username (as in, a username corresponding to an account that has removed its
 		/// identity) has been removed.
 		DanglingUsernameRemoved { who: T::AccountId, username: Username<T> },
+		/// A registrar set the key it signs attestations with.
+		AttestationKeySet { registrar_index: RegistrarIndex },
+		/// A judgement was recorded from an attestation of a registrar.
+		JudgementAttested {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			attestation: T::Hash,
+		},
+		/// A registrar revoked an attestation.
+		AttestationRevoked { registrar_index: RegistrarIndex, attestation: T::Hash },
+		/// An expired attestation was removed along with the judgement recorded from it.
+		AttestationExpired { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// An expired attestation was removed from the revocation list of a registrar.
+		RevocationExpired { registrar_index: RegistrarIndex, attestation: T::Hash },
 	}
 
 	#[pallet::call]
@@ -550,7 +640,8 @@ pub mod pallet {
 			.into())
 		}
 
-		/// Clear an account's identity info and all sub-accounts and return all deposits.
+		/// Clear an account's identity info, all sub-accounts and attestations and return all
+		/// deposits.
 		///
 		/// Payment: All reserved balances on the account are returned.
 		///
@@ -569,7 +660,11 @@ pub mod pallet {
 			let (subs_deposit, sub_ids) = <SubsOf<T>>::take(&sender);
 			let (id, maybe_username) =
 				<IdentityOf<T>>::take(&sender).ok_or(Error::<T>::NoIdentity)?;
-			let deposit = id.total_deposit().saturating_add(subs_deposit);
+			let (attestations, attestations_deposit) = Self::remove_attestations(&sender);
+			let deposit = id
+				.total_deposit()
+				.saturating_add(subs_deposit)
+				.saturating_add(attestations_deposit);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
@@ -584,7 +679,7 @@ pub mod pallet {
 
 			#[allow(deprecated)]
 			Ok(Some(T::WeightInfo::clear_identity(
-				id.judgements.len() as u32,
+				(id.judgements.len() as u32).max(attestations),
 				sub_ids.len() as u32,
 			))
 			.into())
@@ -825,25 +920,7 @@ pub mod pallet {
 				return Err(Error::<T>::JudgementForDifferentIdentity.into())
 			}
 
-			let item = (reg_index, judgement);
-			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
-				Ok(position) => {
-					if let Judgement::FeePaid(fee) = id.judgements[position].1 {
-						T::Currency::repatriate_reserved(
-							&target,
-							&sender,
-							fee,
-							BalanceStatus::Free,
-						)
-						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
-					}
-					id.judgements[position] = item
-				},
-				Err(position) => id
-					.judgements
-					.try_insert(position, item)
-					.map_err(|_| Error::<T>::TooManyRegistrars)?,
-			}
+			Self::insert_judgement(&target, &sender, reg_index, judgement, &mut id)?;
 
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&target, (id, username));
@@ -852,11 +929,12 @@ pub mod pallet {
 			Ok(Some(T::WeightInfo::provide_judgement(judgements as u32)).into())
 		}
 
-		/// Remove an account's identity and sub-account information and slash the deposits.
+		/// Remove an account's identity, sub-account and attestation information and slash the
+		/// deposits.
 		///
-		/// Payment: Reserved balances from `set_subs` and `set_identity` are slashed and handled by
-		/// `Slash`. Verification request deposits are not returned; they should be cancelled
-		/// manually using `cancel_request`.
+		/// Payment: Reserved balances from `set_subs`, `set_identity` and `submit_attestation` are
+		/// slashed and handled by `Slash`. Verification request deposits are not returned; they
+		/// should be cancelled manually using `cancel_request`.
 		///
 		/// The dispatch origin for this call must match `T::ForceOrigin`.
 		///
@@ -881,7 +959,11 @@ pub mod pallet {
 			let (subs_deposit, sub_ids) = <SubsOf<T>>::take(&target);
 			let (id, maybe_username) =
 				<IdentityOf<T>>::take(&target).ok_or(Error::<T>::NoIdentity)?;
-			let deposit = id.total_deposit().saturating_add(subs_deposit);
+			let (attestations, attestations_deposit) = Self::remove_attestations(&target);
+			let deposit = id
+				.total_deposit()
+				.saturating_add(subs_deposit)
+				.saturating_add(attestations_deposit);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
@@ -894,8 +976,11 @@ pub mod pallet {
 			Self::deposit_event(Event::IdentityKilled { who: target, deposit });
 
 			#[allow(deprecated)]
-			Ok(Some(T::WeightInfo::kill_identity(id.judgements.len() as u32, sub_ids.len() as u32))
-				.into())
+			Ok(Some(T::WeightInfo::kill_identity(
+				(id.judgements.len() as u32).max(attestations),
+				sub_ids.len() as u32,
+			))
+			.into())
 		}
 
 		/// Add the given account to the sender's subs.
@@ -1193,6 +1278,222 @@ pub mod pallet {
 			Self::deposit_event(Event::DanglingUsernameRemoved { who: who.clone(), username });
 			Ok(Pays::No.into())
 		}
+
+		/// Set the key with which a registrar signs attestations off-chain.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `index`.
+		///
+		/// - `index`: the index of the registrar whose attestation key is to be set.
+		/// - `key`: the account whose key signs the attestations of the registrar, or `None` to
+		///   stop accepting them.
+		///
+		/// Emits `AttestationKeySet` if successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_attestation_key(T::MaxRegistrars::get()))]
+		pub fn set_attestation_key(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: RegistrarIndex,
+			key: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let registrars = <Registrars<T>>::get();
+			registrars
+				.get(index as usize)
+				.and_then(Option::as_ref)
+				.filter(|r| r.account == who)
+				.ok_or(Error::<T>::InvalidIndex)?;
+
+			AttestationKeys::<T>::set(index, key);
+			Self::deposit_event(Event::AttestationKeySet { registrar_index: index });
+
+			Ok(Some(T::WeightInfo::set_attestation_key(registrars.len() as u32)).into())
+		}
+
+		/// Record the judgement of a registrar on the sender's identity from an attestation that
+		/// the registrar signed off-chain.
+		///
+		/// Payment: `AttestationDeposit` will be reserved for the attestation recorded, replacing
+		/// the deposit of a previous attestation of the registrar. A fee previously reserved for a
+		/// judgement request to the registrar is transferred to the registrar.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the target of
+		/// the attestation.
+		///
+		/// - `attestation`: the attestation of the registrar. It must be upon the current identity
+		///   information of the sender, must neither have expired nor been revoked, and the fields
+		///   it verifies must be set in the identity.
+		/// - `signature`: the signature of the payload of `attestation`, as given by
+		///   `attestation_payload`, by the attestation key of the registrar.
+		///
+		/// Emits `JudgementAttested` if successful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::submit_attestation(T::MaxRegistrars::get()))]
+		pub fn submit_attestation(
+			origin: OriginFor<T>,
+			attestation: Box<AttestationOf<T>>,
+			signature: T::OffchainSignature,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(attestation.target == sender, Error::<T>::InvalidTarget);
+			ensure!(!attestation.judgement.has_deposit(), Error::<T>::InvalidJudgement);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < attestation.expires_at, Error::<T>::AttestationExpired);
+
+			let reg_index = attestation.registrar;
+			let registrar = <Registrars<T>>::get()
+				.get(reg_index as usize)
+				.and_then(Option::as_ref)
+				.map(|r| r.account.clone())
+				.ok_or(Error::<T>::InvalidIndex)?;
+			let key = AttestationKeys::<T>::get(reg_index).ok_or(Error::<T>::NoAttestationKey)?;
+			Self::validate_signature(&Self::attestation_payload(&attestation), &signature, &key)?;
+			let hash = T::Hashing::hash_of(&attestation);
+			ensure!(
+				!RevokedAttestations::<T>::contains_key(reg_index, hash),
+				Error::<T>::AttestationRevoked
+			);
+
+			let (mut id, username) = <IdentityOf<T>>::get(&sender).ok_or(Error::<T>::NoIdentity)?;
+			ensure!(
+				T::Hashing::hash_of(&id.info) == attestation.identity,
+				Error::<T>::JudgementForDifferentIdentity
+			);
+			ensure!(
+				id.info.has_identity(attestation.fields.clone()),
+				Error::<T>::InvalidAttestation
+			);
+
+			Self::insert_judgement(&sender, &registrar, reg_index, attestation.judgement, &mut id)?;
+
+			let old_deposit =
+				Attestations::<T>::get(&sender, reg_index).map_or_else(Zero::zero, |r| r.deposit);
+			let deposit = T::AttestationDeposit::get();
+			Self::rejig_deposit(&sender, old_deposit, deposit)?;
+
+			let judgements = id.judgements.len();
+			<IdentityOf<T>>::insert(&sender, (id, username));
+			let Attestation { identity, judgement, fields, expires_at, .. } = *attestation;
+			Attestations::<T>::insert(
+				&sender,
+				reg_index,
+				AttestationRecord {
+					attestation: hash,
+					identity,
+					judgement,
+					fields,
+					expires_at,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::JudgementAttested {
+				target: sender,
+				registrar_index: reg_index,
+				attestation: hash,
+			});
+
+			Ok(Some(T::WeightInfo::submit_attestation(judgements as u32)).into())
+		}
+
+		/// Revoke an attestation given by a registrar, adding it to the revocation list of the
+		/// registrar so that it can no longer be submitted. If the attestation was already
+		/// submitted, the judgement recorded from it is removed and its deposit returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar which gave the attestation.
+		///
+		/// - `attestation`: the attestation to be revoked.
+		///
+		/// Emits `AttestationRevoked` if successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::revoke_attestation(T::MaxRegistrars::get()))]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			attestation: Box<AttestationOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let reg_index = attestation.registrar;
+			<Registrars<T>>::get()
+				.get(reg_index as usize)
+				.and_then(Option::as_ref)
+				.filter(|r| r.account == sender)
+				.ok_or(Error::<T>::InvalidIndex)?;
+
+			let hash = T::Hashing::hash_of(&attestation);
+			RevokedAttestations::<T>::insert(reg_index, hash, attestation.expires_at);
+			let judgements = match Attestations::<T>::get(&attestation.target, reg_index) {
+				Some(record) if record.attestation == hash =>
+					Self::clear_attestation(&attestation.target, reg_index, &record),
+				_ => 0,
+			};
+			Self::deposit_event(Event::AttestationRevoked {
+				registrar_index: reg_index,
+				attestation: hash,
+			});
+
+			Ok(Some(T::WeightInfo::revoke_attestation(judgements)).into())
+		}
+
+		/// Remove an expired attestation of a registrar for `target`, along with the judgement
+		/// recorded from it.
+		///
+		/// Payment: The deposit of the attestation is returned to `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: the account the attestation was submitted for.
+		/// - `reg_index`: the index of the registrar which gave the attestation.
+		///
+		/// Emits `AttestationExpired` if successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::remove_expired_attestation(T::MaxRegistrars::get()))]
+		pub fn remove_expired_attestation(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let record =
+				Attestations::<T>::get(&target, reg_index).ok_or(Error::<T>::NoAttestation)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= record.expires_at, Error::<T>::AttestationNotExpired);
+
+			let judgements = Self::clear_attestation(&target, reg_index, &record);
+			Self::deposit_event(Event::AttestationExpired { target, registrar_index: reg_index });
+
+			Ok((Some(T::WeightInfo::remove_expired_attestation(judgements)), Pays::No).into())
+		}
+
+		/// Remove an expired attestation from the revocation list of a registrar.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `reg_index`: the index of the registrar which revoked the attestation.
+		/// - `attestation`: the hash of the revoked attestation.
+		///
+		/// Emits `RevocationExpired` if successful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::remove_expired_revocation())]
+		pub fn remove_expired_revocation(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			attestation: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let expires_at = RevokedAttestations::<T>::get(reg_index, attestation)
+				.ok_or(Error::<T>::NoAttestation)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= expires_at, Error::<T>::AttestationNotExpired);
+
+			RevokedAttestations::<T>::remove(reg_index, attestation);
+			Self::deposit_event(Event::RevocationExpired {
+				registrar_index: reg_index,
+				attestation,
+			});
+
+			Ok(Pays::No.into())
+		}
 	}
 }
 
@@ -1264,6 +1565,94 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Place the `judgement` of the registrar `reg_index`, whose account is `registrar`, on the
+	/// identity `id` of `target`. A fee reserved by `target` for the judgement is transferred to
+	/// the registrar.
+	fn insert_judgement(
+		target: &T::AccountId,
+		registrar: &T::AccountId,
+		reg_index: RegistrarIndex,
+		judgement: Judgement<BalanceOf<T>>,
+		id: &mut RegistrationOf<T>,
+	) -> DispatchResult {
+		let item = (reg_index, judgement);
+		match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+			Ok(position) => {
+				if let Judgement::FeePaid(fee) = id.judgements[position].1 {
+					T::Currency::repatriate_reserved(target, registrar, fee, BalanceStatus::Free)
+						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+				}
+				id.judgements[position] = item
+			},
+			Err(position) => id
+				.judgements
+				.try_insert(position, item)
+				.map_err(|_| Error::<T>::TooManyRegistrars)?,
+		}
+		Ok(())
+	}
+
+	/// Remove the attestation `record` of the registrar `reg_index` for `who` and return its
+	/// deposit, along with the judgement recorded from it if the attested identity information and
+	/// judgement are still in place.
+	///
+	/// Return the number of judgements on the identity of `who`.
+	fn clear_attestation(
+		who: &T::AccountId,
+		reg_index: RegistrarIndex,
+		record: &AttestationRecordOf<T>,
+	) -> u32 {
+		Attestations::<T>::remove(who, reg_index);
+		let err_amount = T::Currency::unreserve(who, record.deposit);
+		debug_assert!(err_amount.is_zero());
+		IdentityOf::<T>::mutate(who, |maybe_identity| {
+			let Some((id, _)) = maybe_identity else { return 0 };
+			if T::Hashing::hash_of(&id.info) == record.identity {
+				if let Ok(i) = id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+					if id.judgements[i].1 == record.judgement {
+						id.judgements.remove(i);
+					}
+				}
+			}
+			id.judgements.len() as u32
+		})
+	}
+
+	/// Remove all the attestations recorded for `who`.
+	///
+	/// Return the number of attestations removed and the total of their deposits, which are left
+	/// reserved for the caller to return or slash.
+	fn remove_attestations(who: &T::AccountId) -> (u32, BalanceOf<T>) {
+		Attestations::<T>::drain_prefix(who).fold((0, Zero::zero()), |(count, deposit), (_, r)| {
+			(count.saturating_add(1), deposit.saturating_add(r.deposit))
+		})
+	}
+
+	/// The payload which the attestation key of a registrar signs for `attestation`: the encoded
+	/// attestation prefixed with `ATTESTATION_DOMAIN` and the genesis hash of the chain.
+	pub fn attestation_payload(attestation: &AttestationOf<T>) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(ATTESTATION_DOMAIN, genesis_hash, attestation).encode()
+	}
+
+	/// The identity fields of `who` verified by the registrar `reg_index` through an attestation
+	/// which still stands, i.e. which has not expired and whose attested identity information and
+	/// judgement are still in place.
+	pub fn attested_fields(
+		who: &T::AccountId,
+		reg_index: RegistrarIndex,
+	) -> Option<FieldsIdentifierOf<T>> {
+		let record = Attestations::<T>::get(who, reg_index)?;
+		let (id, _) = IdentityOf::<T>::get(who)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let judged = id
+			.judgements
+			.binary_search_by_key(&reg_index, |x| x.0)
+			.map_or(false, |i| id.judgements[i].1 == record.judgement);
+		(now < record.expires_at && judged && T::Hashing::hash_of(&id.info) == record.identity)
+			.then_some(record.fields)
+	}
+
 	/// Validate a signature. Supports signatures on raw `data` or `data` wrapped in HTML `<Bytes>`.
 	pub fn validate_signature(
 		data: &[u8],
@@ -1358,8 +1747,14 @@ impl<T: Config> Pallet<T> {
 			<SuperOf<T>>::remove(sub);
 		}
 
+		// attestations
+		let (_, attestations_deposit) = Self::remove_attestations(&who);
+
 		// unreserve any deposits
-		let deposit = id.total_deposit().saturating_add(subs_deposit);
+		let deposit = id
+			.total_deposit()
+			.saturating_add(subs_deposit)
+			.saturating_add(attestations_deposit);
 		let err_amount = T::Currency::unreserve(&who, deposit);
 		debug_assert!(err_amount.is_zero());
 		Ok((registrars, encoded_byte_size, actual_subs))
@@ -78,6 +78,7 @@ impl pallet_identity::Config for Test {
 	type BasicDeposit = ConstU64<100>;
 	type ByteDeposit = ConstU64<10>;
 	type SubAccountDeposit = ConstU64<100>;
+	type AttestationDeposit = ConstU64<10>;
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
@@ -509,6 +510,384 @@ fn clearing_judgement_should_work() {
 	});
 }
 
+// Sets up `three` as registrar 0 with an attestation key and returns the key along with an
+// attestation of it on the identity of `ten` verifying the display and legal fields.
+fn setup_attestation() -> (sp_core::sr25519::Public, AttestationOf<Test>) {
+	let [_, _, three, _, ten, _, _, _] = accounts();
+	assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+	assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(infoof_ten())));
+
+	let public = sr25519_generate(0.into(), None);
+	let key: AccountIdOf<Test> = MultiSigner::Sr25519(public).into_account();
+	assert_ok!(Identity::set_attestation_key(RuntimeOrigin::signed(three), 0, Some(key)));
+	assert_eq!(
+		System::events().last().unwrap().event,
+		RuntimeEvent::Identity(Event::AttestationKeySet { registrar_index: 0 })
+	);
+
+	let attestation = Attestation {
+		registrar: 0,
+		target: ten,
+		identity: BlakeTwo256::hash_of(&infoof_ten()),
+		judgement: Judgement::Reasonable,
+		fields: (IdentityField::Display | IdentityField::Legal).bits(),
+		expires_at: 10,
+	};
+	(public, attestation)
+}
+
+fn sign_attestation(
+	public: &sp_core::sr25519::Public,
+	attestation: &AttestationOf<Test>,
+) -> MultiSignature {
+	let payload = Identity::attestation_payload(attestation);
+	MultiSignature::Sr25519(sr25519_sign(0.into(), public, &payload).unwrap())
+}
+
+#[test]
+fn setting_attestation_key_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_noop!(
+			Identity::set_attestation_key(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				Some(ten.clone())
+			),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_noop!(
+			Identity::set_attestation_key(RuntimeOrigin::signed(ten.clone()), 0, Some(ten.clone())),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::set_attestation_key(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			Some(ten.clone())
+		));
+		assert_eq!(AttestationKeys::<Test>::get(0), Some(ten));
+		assert_ok!(Identity::set_attestation_key(RuntimeOrigin::signed(three), 0, None));
+		assert_eq!(AttestationKeys::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn submitting_attestation_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		let (public, attestation) = setup_attestation();
+		let id_deposit = id_deposit(&infoof_ten());
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+
+		let hash = BlakeTwo256::hash_of(&attestation);
+		let signature = sign_attestation(&public, &attestation);
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(attestation.clone()),
+			signature
+		));
+		System::assert_last_event(
+			Event::JudgementAttested { target: ten.clone(), registrar_index: 0, attestation: hash }
+				.into(),
+		);
+
+		// The fee reserved for the judgement request goes to the registrar, and the attestation
+		// deposit is reserved.
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10 - 10);
+		assert_eq!(Balances::reserved_balance(ten.clone()), id_deposit + 10);
+		assert_eq!(Balances::free_balance(three), 100 + 10);
+		assert_eq!(
+			Identity::identity(ten.clone()).unwrap().0.judgements,
+			vec![(0, Judgement::Reasonable)]
+		);
+		assert_eq!(
+			Attestations::<Test>::get(&ten, 0),
+			Some(AttestationRecord {
+				attestation: hash,
+				identity: attestation.identity,
+				judgement: Judgement::Reasonable,
+				fields: attestation.fields,
+				expires_at: 10,
+				deposit: 10,
+			})
+		);
+		assert_eq!(Identity::attested_fields(&ten, 0), Some(attestation.fields));
+
+		// The attestation no longer stands after expiry.
+		run_to_block(10);
+		assert_eq!(Identity::attested_fields(&ten, 0), None);
+	});
+}
+
+#[test]
+fn submitting_invalid_attestation_should_fail() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, twenty, _, _] = accounts();
+		let (public, attestation) = setup_attestation();
+		let signature = sign_attestation(&public, &attestation);
+
+		// Only the target can submit the attestation.
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(twenty.clone()),
+				Box::new(attestation.clone()),
+				signature.clone()
+			),
+			Error::<Test>::InvalidTarget
+		);
+
+		// Deposit judgements cannot be attested.
+		let fee_paid = Attestation { judgement: Judgement::FeePaid(1), ..attestation.clone() };
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(fee_paid.clone()),
+				sign_attestation(&public, &fee_paid)
+			),
+			Error::<Test>::InvalidJudgement
+		);
+
+		// The registrar must exist and have an attestation key.
+		let unknown = Attestation { registrar: 1, ..attestation.clone() };
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(unknown.clone()),
+				sign_attestation(&public, &unknown)
+			),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), one));
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(unknown.clone()),
+				sign_attestation(&public, &unknown)
+			),
+			Error::<Test>::NoAttestationKey
+		);
+
+		// The signature must be of the attestation by the attestation key.
+		let other = Attestation { judgement: Judgement::KnownGood, ..attestation.clone() };
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(other),
+				signature.clone()
+			),
+			Error::<Test>::InvalidSignature
+		);
+		let bare =
+			MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &attestation.encode()).unwrap());
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(attestation.clone()),
+				bare
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		// The attested fields must be set in the identity.
+		let email = Attestation { fields: IdentityField::Email as u64, ..attestation.clone() };
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(email.clone()),
+				sign_attestation(&public, &email)
+			),
+			Error::<Test>::InvalidAttestation
+		);
+
+		// The attestation is upon the previous identity information.
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_twenty())
+		));
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(attestation.clone()),
+				signature.clone()
+			),
+			Error::<Test>::JudgementForDifferentIdentity
+		);
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		// Revoked attestations cannot be submitted.
+		assert_ok!(Identity::revoke_attestation(
+			RuntimeOrigin::signed(three),
+			Box::new(attestation.clone())
+		));
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(attestation.clone()),
+				signature.clone()
+			),
+			Error::<Test>::AttestationRevoked
+		);
+
+		// Expired attestations cannot be submitted.
+		run_to_block(10);
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten),
+				Box::new(attestation),
+				signature
+			),
+			Error::<Test>::AttestationExpired
+		);
+	});
+}
+
+#[test]
+fn revoking_attestation_should_remove_judgement() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		let (public, attestation) = setup_attestation();
+		let hash = BlakeTwo256::hash_of(&attestation);
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(attestation.clone()),
+			sign_attestation(&public, &attestation)
+		));
+
+		assert_noop!(
+			Identity::revoke_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(attestation.clone())
+			),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::revoke_attestation(
+			RuntimeOrigin::signed(three),
+			Box::new(attestation)
+		));
+		System::assert_last_event(
+			Event::AttestationRevoked { registrar_index: 0, attestation: hash }.into(),
+		);
+		assert_eq!(RevokedAttestations::<Test>::get(0, hash), Some(10));
+		assert_eq!(Attestations::<Test>::get(&ten, 0), None);
+		assert_eq!(Balances::reserved_balance(ten.clone()), id_deposit(&infoof_ten()));
+		assert!(Identity::identity(ten.clone()).unwrap().0.judgements.is_empty());
+		assert_eq!(Identity::attested_fields(&ten, 0), None);
+	});
+}
+
+#[test]
+fn removing_expired_attestations_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		let (public, attestation) = setup_attestation();
+		let hash = BlakeTwo256::hash_of(&attestation);
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(attestation.clone()),
+			sign_attestation(&public, &attestation)
+		));
+		let other = Attestation { expires_at: 20, ..attestation.clone() };
+		let other_hash = BlakeTwo256::hash_of(&other);
+		assert_ok!(Identity::revoke_attestation(RuntimeOrigin::signed(three), Box::new(other)));
+
+		assert_noop!(
+			Identity::remove_expired_attestation(
+				RuntimeOrigin::signed(twenty.clone()),
+				ten.clone(),
+				0
+			),
+			Error::<Test>::AttestationNotExpired
+		);
+		assert_noop!(
+			Identity::remove_expired_revocation(
+				RuntimeOrigin::signed(twenty.clone()),
+				0,
+				other_hash
+			),
+			Error::<Test>::AttestationNotExpired
+		);
+		assert_noop!(
+			Identity::remove_expired_revocation(RuntimeOrigin::signed(twenty.clone()), 0, hash),
+			Error::<Test>::NoAttestation
+		);
+
+		run_to_block(10);
+		assert_ok!(Identity::remove_expired_attestation(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone(),
+			0
+		));
+		System::assert_last_event(
+			Event::AttestationExpired { target: ten.clone(), registrar_index: 0 }.into(),
+		);
+		assert_eq!(Attestations::<Test>::get(&ten, 0), None);
+		assert!(Identity::identity(ten.clone()).unwrap().0.judgements.is_empty());
+		assert_eq!(Balances::reserved_balance(ten.clone()), id_deposit(&infoof_ten()));
+		assert_noop!(
+			Identity::remove_expired_attestation(RuntimeOrigin::signed(twenty.clone()), ten, 0),
+			Error::<Test>::NoAttestation
+		);
+
+		run_to_block(20);
+		assert_ok!(Identity::remove_expired_revocation(
+			RuntimeOrigin::signed(twenty),
+			0,
+			other_hash
+		));
+		System::assert_last_event(
+			Event::RevocationExpired { registrar_index: 0, attestation: other_hash }.into(),
+		);
+		assert_eq!(RevokedAttestations::<Test>::get(0, other_hash), None);
+	});
+}
+
+#[test]
+fn clearing_identity_should_remove_attestations() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, _, _, _] = accounts();
+		let (public, attestation) = setup_attestation();
+		let id_deposit = id_deposit(&infoof_ten());
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(attestation.clone()),
+			sign_attestation(&public, &attestation)
+		));
+		assert_eq!(Balances::reserved_balance(ten.clone()), id_deposit + 10);
+
+		// Clearing the identity returns the attestation deposit.
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		System::assert_last_event(
+			Event::IdentityCleared { who: ten.clone(), deposit: id_deposit + 10 }.into(),
+		);
+		assert_eq!(Attestations::<Test>::get(&ten, 0), None);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000);
+
+		// Killing the identity slashes it.
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(attestation.clone()),
+			sign_attestation(&public, &attestation)
+		));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten.clone()));
+		System::assert_last_event(
+			Event::IdentityKilled { who: ten.clone(), deposit: id_deposit + 10 }.into(),
+		);
+		assert_eq!(Attestations::<Test>::get(&ten, 0), None);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+		assert_eq!(Balances::reserved_balance(ten), 0);
+	});
+}
+
 #[test]
 fn killing_slashing_should_work() {
 	new_test_ext().execute_with(|| {
@@ -21,6 +21,7 @@ use frame_support::{
 	traits::{ConstU32, Get},
 	BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::{
 	build::{Fields, Variants},
 	Path, Type, TypeInfo,
@@ -319,6 +320,90 @@ pub struct RegistrarInfo<
 	pub fields: IdField,
 }
 
+/// An attestation of a registrar over the identity of an account, given off-chain.
+///
+/// The registrar signs the payload of the attestation (see `Pallet::attestation_payload`) with the
+/// key it published through `set_attestation_key`, and the account submits it to have the
+/// judgement recorded without the registrar having to call `provide_judgement`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Attestation<
+	AccountId,
+	Hash,
+	BlockNumber,
+	Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq,
+	FieldsIdentifier,
+> {
+	/// The index of the registrar giving the attestation.
+	pub registrar: RegistrarIndex,
+
+	/// The account whose identity the attestation is upon.
+	pub target: AccountId,
+
+	/// The hash of the identity information the attestation is upon.
+	pub identity: Hash,
+
+	/// The judgement of the registrar about the identity.
+	pub judgement: Judgement<Balance>,
+
+	/// The identity fields (e.g. email, twitter, legal name) the registrar has verified. These
+	/// must be set in the identity information.
+	pub fields: FieldsIdentifier,
+
+	/// The block at which the attestation expires.
+	pub expires_at: BlockNumber,
+}
+
+/// An attestation accepted for an account.
+///
+/// It only stands as long as it has not expired, and the attested identity information and
+/// judgement are still in place.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AttestationRecord<
+	Hash,
+	BlockNumber,
+	Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq,
+	FieldsIdentifier,
+> {
+	/// The hash of the attestation, as it appears in the registrar's revocation list.
+	pub attestation: Hash,
+
+	/// The hash of the identity information attested.
+	pub identity: Hash,
+
+	/// The judgement recorded from the attestation.
+	pub judgement: Judgement<Balance>,
+
+	/// The identity fields the registrar has verified.
+	pub fields: FieldsIdentifier,
+
+	/// The block at which the attestation expires.
+	pub expires_at: BlockNumber,
+
+	/// The amount held on deposit for the record.
+	pub deposit: Balance,
+}
+
+/// The identity fields identifier for a given pallet configuration.
+pub type FieldsIdentifierOf<T> =
+	<<T as Config>::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier;
+
+/// Attestation for a given pallet configuration.
+pub type AttestationOf<T> = Attestation<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::Hash,
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+	FieldsIdentifierOf<T>,
+>;
+
+/// Attestation record for a given pallet configuration.
+pub type AttestationRecordOf<T> = AttestationRecord<
+	<T as frame_system::Config>::Hash,
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+	FieldsIdentifierOf<T>,
+>;
+
 /// Authority properties for a given pallet configuration.
 pub type AuthorityPropertiesOf<T> = AuthorityProperties<Suffix<T>>;
 
@@ -74,6 +74,11 @@ pub trait WeightInfo {
 	fn remove_expired_approval() -> Weight;
 	fn set_primary_username() -> Weight;
 	fn remove_dangling_username() -> Weight;
+	fn set_attestation_key(r: u32, ) -> Weight;
+	fn submit_attestation(r: u32, ) -> Weight;
+	fn revoke_attestation(r: u32, ) -> Weight;
+	fn remove_expired_attestation(r: u32, ) -> Weight;
+	fn remove_expired_revocation() -> Weight;
 }
 
 /// Weights for `pallet_identity` using the Substrate node and recommended hardware.
@@ -151,23 +156,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7070 + r * (5 ±0) + s * (32 ±0)`
-		//  Estimated: `11037`
-		// Minimum execution time: 55_387_000 picoseconds.
-		Weight::from_parts(52_575_769, 11037)
-			// Standard Error: 17_705
-			.saturating_add(Weight::from_parts(268_160, 0).saturating_mul(r.into()))
-			// Standard Error: 3_454
-			.saturating_add(Weight::from_parts(1_576_194, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Measured:  `7134 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `11037 + r * (2636 ±0)`
+		// Minimum execution time: 117_554_000 picoseconds.
+		Weight::from_parts(13_202_232, 11037)
+			// Standard Error: 216_897
+			.saturating_add(Weight::from_parts(6_040_517, 0).saturating_mul(r.into()))
+			// Standard Error: 42_321
+			.saturating_add(Weight::from_parts(1_911_022, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
@@ -261,7 +271,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
@@ -269,17 +281,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7277 + r * (5 ±0) + s * (32 ±0)`
-		//  Estimated: `11037`
-		// Minimum execution time: 73_785_000 picoseconds.
-		Weight::from_parts(73_606_063, 11037)
-			// Standard Error: 26_433
-			.saturating_add(Weight::from_parts(230_018, 0).saturating_mul(r.into()))
-			// Standard Error: 5_157
-			.saturating_add(Weight::from_parts(1_483_326, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `7273 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `11037 + r * (2636 ±0)`
+		// Minimum execution time: 128_246_000 picoseconds.
+		Weight::from_parts(124_327_590, 11037)
+			// Standard Error: 208_111
+			.saturating_add(Weight::from_parts(1_122_938, 0).saturating_mul(r.into()))
+			// Standard Error: 40_606
+			.saturating_add(Weight::from_parts(1_459_612, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
@@ -441,6 +456,97 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:0 w:1)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_attestation_key(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + r * (57 ±0)`
+		//  Estimated: `2626`
+		// Minimum execution time: 7_012_000 picoseconds.
+		Weight::from_parts(7_480_116, 2626)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(88_410, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:1 w:0)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:0)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn submit_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7258 + r * (60 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 119_674_000 picoseconds.
+		Weight::from_parts(134_839_337, 11037)
+			// Standard Error: 172_128
+			.saturating_add(Weight::from_parts(4_439_091, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:0 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7328 + r * (60 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 64_423_000 picoseconds.
+		Weight::from_parts(91_559_571, 11037)
+			// Standard Error: 108_671
+			.saturating_add(Weight::from_parts(615_726, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn remove_expired_attestation(_r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7280 + r * (3 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 55_010_000 picoseconds.
+		Weight::from_parts(77_658_294, 11037)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_expired_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_384_000 picoseconds.
+		Weight::from_parts(12_901_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -517,23 +623,28 @@ impl WeightInfo for () {
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7070 + r * (5 ±0) + s * (32 ±0)`
-		//  Estimated: `11037`
-		// Minimum execution time: 55_387_000 picoseconds.
-		Weight::from_parts(52_575_769, 11037)
-			// Standard Error: 17_705
-			.saturating_add(Weight::from_parts(268_160, 0).saturating_mul(r.into()))
-			// Standard Error: 3_454
-			.saturating_add(Weight::from_parts(1_576_194, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `7134 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `11037 + r * (2636 ±0)`
+		// Minimum execution time: 117_554_000 picoseconds.
+		Weight::from_parts(13_202_232, 11037)
+			// Standard Error: 216_897
+			.saturating_add(Weight::from_parts(6_040_517, 0).saturating_mul(r.into()))
+			// Standard Error: 42_321
+			.saturating_add(Weight::from_parts(1_911_022, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
@@ -627,7 +738,9 @@ impl WeightInfo for () {
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Identity::Attestations` (r:21 w:20)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
@@ -635,17 +748,20 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7277 + r * (5 ±0) + s * (32 ±0)`
-		//  Estimated: `11037`
-		// Minimum execution time: 73_785_000 picoseconds.
-		Weight::from_parts(73_606_063, 11037)
-			// Standard Error: 26_433
-			.saturating_add(Weight::from_parts(230_018, 0).saturating_mul(r.into()))
-			// Standard Error: 5_157
-			.saturating_add(Weight::from_parts(1_483_326, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `7273 + r * (117 ±0) + s * (32 ±0)`
+		//  Estimated: `11037 + r * (2636 ±0)`
+		// Minimum execution time: 128_246_000 picoseconds.
+		Weight::from_parts(124_327_590, 11037)
+			// Standard Error: 208_111
+			.saturating_add(Weight::from_parts(1_122_938, 0).saturating_mul(r.into()))
+			// Standard Error: 40_606
+			.saturating_add(Weight::from_parts(1_459_612, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(r.into()))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
@@ -807,4 +923,95 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:0 w:1)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_attestation_key(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + r * (57 ±0)`
+		//  Estimated: `2626`
+		// Minimum execution time: 7_012_000 picoseconds.
+		Weight::from_parts(7_480_116, 2626)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(88_410, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationKeys` (r:1 w:0)
+	/// Proof: `Identity::AttestationKeys` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:0)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn submit_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7258 + r * (60 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 119_674_000 picoseconds.
+		Weight::from_parts(134_839_337, 11037)
+			// Standard Error: 172_128
+			.saturating_add(Weight::from_parts(4_439_091, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RevokedAttestations` (r:0 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7328 + r * (60 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 64_423_000 picoseconds.
+		Weight::from_parts(91_559_571, 11037)
+			// Standard Error: 108_671
+			.saturating_add(Weight::from_parts(615_726, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::Attestations` (r:1 w:1)
+	/// Proof: `Identity::Attestations` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn remove_expired_attestation(_r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7280 + r * (3 ±0)`
+		//  Estimated: `11037`
+		// Minimum execution time: 55_010_000 picoseconds.
+		Weight::from_parts(77_658_294, 11037)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::RevokedAttestations` (r:1 w:1)
+	/// Proof: `Identity::RevokedAttestations` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_expired_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_384_000 picoseconds.
+		Weight::from_parts(12_901_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
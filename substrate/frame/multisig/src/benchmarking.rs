@@ -47,6 +47,25 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+/// Create a stateful multisig of `s` signatories with a threshold of `s` and return its account
+/// along with the signatories and a call of length `z`.
+fn setup_stateful_multi<T: Config>(
+	s: u32,
+	z: u32,
+) -> Result<(T::AccountId, Vec<T::AccountId>, Box<<T as Config>::RuntimeCall>), &'static str> {
+	let (signatories, call) = setup_multi::<T>(s, z)?;
+	let creator: T::AccountId = account("creator", 0, SEED);
+	T::Currency::make_free_balance_be(&creator, BalanceOf::<T>::max_value());
+	let multi_account_id =
+		Multisig::<T>::stateful_account_id(&creator, NextStatefulIndex::<T>::get());
+	Multisig::<T>::create_stateful_multisig(
+		RawOrigin::Signed(creator).into(),
+		signatories.clone(),
+		s as u16,
+	)?;
+	Ok((multi_account_id, signatories, call))
+}
+
 benchmarks! {
 	as_multi_threshold_1 {
 		// Transaction Length
@@ -210,5 +229,193 @@ benchmarks! {
 		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
 	}
 
+	create_stateful_multisig {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let multi_account_id = Multisig::<T>::stateful_account_id(&caller, 0);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), signatories, s as u16)
+	verify {
+		assert!(StatefulMultisigs::<T>::contains_key(multi_account_id));
+	}
+
+	set_signatories {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		Multisig::<T>::create_stateful_multisig(
+			RawOrigin::Signed(caller.clone()).into(),
+			signatories.clone(),
+			2,
+		)?;
+		let multi_account_id = Multisig::<T>::stateful_account_id(&caller, 0);
+		T::Currency::make_free_balance_be(&multi_account_id, BalanceOf::<T>::max_value());
+		// Whitelist multisig account from further DB operations.
+		let multi_account_key = frame_system::Account::<T>::hashed_key_for(&multi_account_id);
+		frame_benchmarking::benchmarking::add_to_whitelist(multi_account_key.into());
+	}: _(RawOrigin::Signed(multi_account_id.clone()), signatories, s as u16, PendingPolicy::Invalidate)
+	verify {
+		let set = StatefulMultisigs::<T>::get(&multi_account_id).ok_or("signatory set not found")?;
+		assert_eq!(set.threshold, s as u16);
+		assert_eq!(set.version, 1);
+	}
+
+	as_stateful_multi_create {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_stateful_multi(RawOrigin::Signed(caller), multi_account_id.clone(), None, call, Weight::zero())
+	verify {
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(StatefulOperations::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	as_stateful_multi_approve {
+		// Signatories, need at least 3 people (so we don't complete the multisig)
+		let s in 3 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::as_stateful_multi(o, multi_account_id.clone(), None, call.clone(), Weight::zero())?;
+		let caller2 = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_stateful_multi(RawOrigin::Signed(caller2), multi_account_id.clone(), Some(timepoint), call, Weight::zero())
+	verify {
+		let multisig = Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+	}
+
+	as_stateful_multi_complete {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::as_stateful_multi(o, multi_account_id.clone(), None, call.clone(), Weight::zero())?;
+		// Everyone except the first person approves
+		for i in 1 .. s - 1 {
+			let o = RawOrigin::Signed(signatories[i as usize].clone()).into();
+			Multisig::<T>::as_stateful_multi(o, multi_account_id.clone(), Some(timepoint), call.clone(), Weight::zero())?;
+		}
+		let caller2 = signatories[0].clone();
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_stateful_multi(RawOrigin::Signed(caller2), multi_account_id.clone(), Some(timepoint), call, Weight::MAX)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!StatefulOperations::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	approve_as_stateful_multi_create {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: approve_as_stateful_multi(RawOrigin::Signed(caller), multi_account_id.clone(), None, call_hash, Weight::zero())
+	verify {
+		assert!(Multisigs::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	approve_as_stateful_multi_approve {
+		// Signatories, need at least 3 people (so we don't complete the multisig)
+		let s in 3 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::as_stateful_multi(o, multi_account_id.clone(), None, call, Weight::zero())?;
+		let caller2 = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: approve_as_stateful_multi(RawOrigin::Signed(caller2), multi_account_id.clone(), Some(timepoint), call_hash, Weight::zero())
+	verify {
+		let multisig = Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+	}
+
+	cancel_as_stateful_multi {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (multi_account_id, signatories, call) = setup_stateful_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories.last().cloned().ok_or("signatories should have len 2 or more")?;
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::as_stateful_multi(o, multi_account_id.clone(), None, call, Weight::zero())?;
+		// Invalidate it, so that any signatory can clean it up.
+		T::Currency::make_free_balance_be(&multi_account_id, BalanceOf::<T>::max_value());
+		Multisig::<T>::set_signatories(
+			RawOrigin::Signed(multi_account_id.clone()).into(),
+			signatories.clone(),
+			s as u16,
+			PendingPolicy::Invalidate,
+		)?;
+		let caller2 = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller2), multi_account_id.clone(), timepoint, call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!StatefulOperations::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	dissolve_stateful_multisig {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		let (multi_account_id, _, _) = setup_stateful_multi::<T>(s, 0)?;
+		// Whitelist multisig account from further DB operations.
+		let multi_account_key = frame_system::Account::<T>::hashed_key_for(&multi_account_id);
+		frame_benchmarking::benchmarking::add_to_whitelist(multi_account_key.into());
+	}: _(RawOrigin::Signed(multi_account_id.clone()))
+	verify {
+		assert!(!StatefulMultisigs::<T>::contains_key(multi_account_id));
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
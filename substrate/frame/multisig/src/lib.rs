@@ -31,6 +31,12 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Alternatively, a stateful multisig can be created. Its account is stable and linked to a stored
+//! set of signatories and a threshold, which can be changed later on by a call approved by the
+//! multisig itself. When the set changes, pending operations are either kept or invalidated, as
+//! chosen by [`PendingPolicy`]. Dissolving the multisig, again by a call approved by itself,
+//! returns the deposit of its signatory set.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +45,13 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_stateful_multisig` - Create a stateful multisig with a set of signatories and a
+//!   threshold.
+//! * `set_signatories` - Change the signatories and the threshold of a stateful multisig.
+//! * `as_stateful_multi` - Approve and if possible dispatch a call from a stateful multisig.
+//! * `approve_as_stateful_multi` - Approve a call from a stateful multisig.
+//! * `cancel_as_stateful_multi` - Cancel a call from a stateful multisig.
+//! * `dissolve_stateful_multisig` - Dissolve a stateful multisig and return its deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -64,7 +77,7 @@ use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	traits::{Dispatchable, TrailingZeroInput, Zero},
-	DispatchError, RuntimeDebug,
+	ArithmeticError, DispatchError, RuntimeDebug,
 };
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
@@ -118,6 +131,37 @@ where
 	approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// What happens to the pending operations of a stateful multisig when its signatories or
+/// threshold change.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PendingPolicy {
+	/// Pending operations stay open. Only the approvals of the new signatories count, measured
+	/// against the new threshold.
+	Keep,
+	/// Pending operations can no longer be approved or executed. They can be cancelled by their
+	/// depositor or by any of the new signatories, returning the deposit to the depositor.
+	Invalidate,
+}
+
+/// The signatories and threshold of a stateful multisig.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct SignatorySet<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The accounts which can approve operations. Always sorted.
+	signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The number of approvals needed to execute an operation.
+	threshold: u16,
+	/// Bumped whenever pending operations are invalidated.
+	version: u32,
+	/// The account holding the deposit for the set.
+	depositor: AccountId,
+	/// The amount held in reserve of the `depositor`.
+	deposit: Balance,
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -186,6 +230,25 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The signatory sets of the stateful multisigs.
+	#[pallet::storage]
+	pub type StatefulMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		SignatorySet<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
+	/// The version of the signatory set under which each open operation of a stateful multisig
+	/// was created.
+	#[pallet::storage]
+	pub type StatefulOperations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Blake2_128Concat, [u8; 32], u32>;
+
+	/// The index used to derive the account of the next stateful multisig.
+	#[pallet::storage]
+	pub type NextStatefulIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -216,6 +279,12 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a stateful multisig.
+		NotStateful,
+		/// The sender is not a signatory of the stateful multisig.
+		NotSignatory,
+		/// The operation was invalidated by a change of the signatories.
+		StaleOperation,
 	}
 
 	#[pallet::event]
@@ -245,6 +314,12 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// A stateful multisig has been created.
+		StatefulMultisigCreated { creator: T::AccountId, multisig: T::AccountId, threshold: u16 },
+		/// The signatories of a stateful multisig have been changed.
+		SignatoriesChanged { multisig: T::AccountId, threshold: u16, policy: PendingPolicy },
+		/// A stateful multisig has been dissolved.
+		StatefulMultisigDissolved { multisig: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -493,6 +568,248 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Create a stateful multisig, whose account stays the same when its signatories or
+		/// threshold change.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` times the number of signatories will be
+		/// reserved for the signatory set.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `signatories`: The accounts who can approve dispatches. Must be sorted and contain at
+		/// least `threshold` accounts.
+		/// - `threshold`: The total number of approvals for a dispatch before it is executed.
+		///
+		/// The account of the multisig is found in the deposited `StatefulMultisigCreated` event.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::create_stateful_multisig(signatories.len() as u32))]
+		pub fn create_stateful_multisig(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_signatory_set(signatories, threshold)?;
+
+			let index = <NextStatefulIndex<T>>::get();
+			<NextStatefulIndex<T>>::put(index.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			let id = Self::stateful_account_id(&who, index);
+
+			let deposit = Self::signatory_set_deposit(signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+
+			<StatefulMultisigs<T>>::insert(
+				&id,
+				SignatorySet {
+					signatories,
+					threshold,
+					version: 0,
+					depositor: who.clone(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::StatefulMultisigCreated {
+				creator: who,
+				multisig: id,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// Change the signatories and the threshold of a stateful multisig.
+		///
+		/// Payment: The deposit for the signatory set is moved to the multisig account itself and
+		/// the deposit of the previous depositor is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stateful multisig, i.e. it
+		/// must be approved through `as_stateful_multi`.
+		///
+		/// - `signatories`: The new accounts who can approve dispatches. Must be sorted and contain
+		/// at least `threshold` accounts.
+		/// - `threshold`: The new total number of approvals for a dispatch before it is executed.
+		/// - `policy`: Whether the pending operations of the multisig are kept or invalidated.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_signatories(signatories.len() as u32))]
+		pub fn set_signatories(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+			policy: PendingPolicy,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let mut set = <StatefulMultisigs<T>>::get(&id).ok_or(Error::<T>::NotStateful)?;
+			let signatories = Self::ensure_valid_signatory_set(signatories, threshold)?;
+
+			let deposit = Self::signatory_set_deposit(signatories.len());
+			let err_amount = T::Currency::unreserve(&set.depositor, set.deposit);
+			debug_assert!(err_amount.is_zero());
+			T::Currency::reserve(&id, deposit)?;
+
+			if policy == PendingPolicy::Invalidate {
+				set.version = set.version.wrapping_add(1);
+			}
+			set.signatories = signatories;
+			set.threshold = threshold;
+			set.depositor = id.clone();
+			set.deposit = deposit;
+			<StatefulMultisigs<T>>::insert(&id, set);
+
+			Self::deposit_event(Event::SignatoriesChanged { multisig: id, threshold, policy });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a stateful multisig.
+		///
+		/// If there are enough approvals, then dispatch the call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories of the
+		/// multisig.
+		///
+		/// - `multisig`: The account of the stateful multisig.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// NOTE: Unless this is the final approval, you will generally want to use
+		/// `approve_as_stateful_multi` instead, since it only requires a hash of the call.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_stateful_multi_create(s, z)
+				.max(T::WeightInfo::as_stateful_multi_approve(s, z))
+				.max(T::WeightInfo::as_stateful_multi_complete(s, z))
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stateful(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a stateful multisig.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories of the
+		/// multisig.
+		///
+		/// - `multisig`: The account of the stateful multisig.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_stateful_multi` instead.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_stateful_multi_create(s)
+				.max(T::WeightInfo::approve_as_stateful_multi_approve(s))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stateful(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of a stateful multisig. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor of the operation
+		/// or, if the operation was invalidated, by any of the signatories of the multisig. Once
+		/// the multisig is dissolved, only the depositor can cancel its operations.
+		///
+		/// - `multisig`: The account of the stateful multisig.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::cancel_as_stateful_multi(T::MaxSignatories::get()))]
+		pub fn cancel_as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let version = <StatefulOperations<T>>::get(&multisig, call_hash);
+			let maybe_set = <StatefulMultisigs<T>>::get(&multisig);
+			ensure!(maybe_set.is_some() || version.is_some(), Error::<T>::NotStateful);
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			let can_clean_up = maybe_set.map_or(false, |set| {
+				version != Some(set.version) && set.signatories.binary_search(&who).is_ok()
+			});
+			ensure!(m.depositor == who || can_clean_up, Error::<T>::NotOwner);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+			<StatefulOperations<T>>::remove(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigCancelled {
+				cancelling: who,
+				timepoint,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Dissolve a stateful multisig, returning the deposit of its signatory set.
+		///
+		/// Pending operations can no longer be approved or executed afterwards. They can still
+		/// be cancelled by their depositor through `cancel_as_stateful_multi`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stateful multisig, i.e. it
+		/// must be approved through `as_stateful_multi`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::dissolve_stateful_multisig(T::MaxSignatories::get()))]
+		pub fn dissolve_stateful_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let set = <StatefulMultisigs<T>>::take(&id).ok_or(Error::<T>::NotStateful)?;
+
+			let err_amount = T::Currency::unreserve(&set.depositor, set.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			Self::deposit_event(Event::StatefulMultisigDissolved { multisig: id });
+			Ok(())
+		}
 	}
 }
 
@@ -507,6 +824,13 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Derive the account ID of the stateful multisig created by `who` with the given `index`.
+	pub fn stateful_account_id(who: &T::AccountId, index: u32) -> T::AccountId {
+		let entropy = (b"modlpy/statemuls", who, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -524,6 +848,63 @@ impl<T: Config> Pallet<T> {
 
 		let id = Self::multi_account_id(&signatories, threshold);
 
+		Self::do_operate(
+			who,
+			id,
+			threshold,
+			&signatories,
+			None,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	fn operate_stateful(
+		who: T::AccountId,
+		id: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let set = <StatefulMultisigs<T>>::get(&id).ok_or(Error::<T>::NotStateful)?;
+		ensure!(set.signatories.binary_search(&who).is_ok(), Error::<T>::NotSignatory);
+
+		Self::do_operate(
+			who,
+			id,
+			set.threshold,
+			&set.signatories,
+			Some(set.version),
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	/// Approve and if possible dispatch an operation of the multisig `id`.
+	///
+	/// Only the approvals of `signatories` count towards the `threshold`. For stateful multisigs,
+	/// `maybe_version` is the version of the signatory set that the operation must be created
+	/// under.
+	#[allow(clippy::too_many_arguments)]
+	fn do_operate(
+		who: T::AccountId,
+		id: T::AccountId,
+		threshold: u16,
+		signatories: &[T::AccountId],
+		maybe_version: Option<u32>,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let other_signatories_len = signatories.len().saturating_sub(1);
+		// Stateful multisigs are benchmarked with the size of their whole signatory set.
+		let (s, stateful) = match maybe_version {
+			Some(_) => (signatories.len() as u32, true),
+			None => (other_signatories_len as u32, false),
+		};
+
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
 			CallOrHash::Call(call) => {
@@ -538,6 +919,15 @@ impl<T: Config> Pallet<T> {
 			// Yes; ensure that the timepoint exists and agrees.
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			if let Some(version) = maybe_version {
+				ensure!(
+					<StatefulOperations<T>>::get(&id, call_hash) == Some(version),
+					Error::<T>::StaleOperation
+				);
+			}
+
+			// Approvals of accounts which were removed from a stateful multisig don't count.
+			m.approvals.retain(|a| signatories.binary_search(a).is_ok());
 
 			// Ensure that either we have not yet signed or that it is at threshold.
 			let mut approvals = m.approvals.len() as u16;
@@ -559,6 +949,9 @@ impl<T: Config> Pallet<T> {
 				// Clean up storage before executing call to avoid an possibility of reentrancy
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
+				if maybe_version.is_some() {
+					<StatefulOperations<T>>::remove(&id, call_hash);
+				}
 				T::Currency::unreserve(&m.depositor, m.deposit);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
@@ -571,10 +964,11 @@ impl<T: Config> Pallet<T> {
 				});
 				Ok(get_result_weight(result)
 					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
+						if stateful {
+							T::WeightInfo::as_stateful_multi_complete(s, call_len as u32)
+						} else {
+							T::WeightInfo::as_multi_complete(s, call_len as u32)
+						}
 						.saturating_add(actual_weight)
 					})
 					.into())
@@ -600,8 +994,11 @@ impl<T: Config> Pallet<T> {
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+				let final_weight = if stateful {
+					T::WeightInfo::as_stateful_multi_approve(s, call_len as u32)
+				} else {
+					T::WeightInfo::as_multi_approve(s, call_len as u32)
+				};
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -627,10 +1024,16 @@ impl<T: Config> Pallet<T> {
 					approvals: initial_approvals,
 				},
 			);
+			if let Some(version) = maybe_version {
+				<StatefulOperations<T>>::insert(&id, call_hash, version);
+			}
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
-			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			let final_weight = if stateful {
+				T::WeightInfo::as_stateful_multi_create(s, call_len as u32)
+			} else {
+				T::WeightInfo::as_multi_create(s, call_len as u32)
+			};
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
@@ -665,6 +1068,26 @@ impl<T: Config> Pallet<T> {
 		signatories.insert(index, who);
 		Ok(signatories)
 	}
+
+	/// Check that the signatories of a stateful multisig are sorted, without duplicates and
+	/// enough for `threshold`.
+	fn ensure_valid_signatory_set(
+		signatories: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<BoundedVec<T::AccountId, T::MaxSignatories>, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		ensure!(signatories.len() >= threshold as usize, Error::<T>::TooFewSignatories);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0] < pair[1]),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		Ok(signatories.try_into().map_err(|_| Error::<T>::TooManySignatories)?)
+	}
+
+	/// The deposit for storing a signatory set of `len` accounts.
+	fn signatory_set_deposit(len: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (len as u32).into()
+	}
 }
 
 /// Return the weight of a dispatch call result as an `Option`.
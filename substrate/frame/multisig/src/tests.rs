@@ -59,6 +59,8 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 			RuntimeCall::Balances(_) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+			RuntimeCall::Multisig(Call::set_signatories { .. }) => true,
+			RuntimeCall::Multisig(Call::dissolve_stateful_multisig { .. }) => true,
 			_ => false,
 		}
 	}
@@ -691,3 +693,344 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+fn call_set_signatories(
+	signatories: Vec<u64>,
+	threshold: u16,
+	policy: PendingPolicy,
+) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Multisig(Call::set_signatories { signatories, threshold, policy }))
+}
+
+#[test]
+fn stateful_multisig_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2));
+		let multi = Multisig::stateful_account_id(&1, 0);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::assert_last_event(
+			Event::StatefulMultisigCreated { creator: 1, multisig: multi, threshold: 2 }.into(),
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().weight;
+		assert_noop!(
+			Multisig::as_stateful_multi(
+				RuntimeOrigin::signed(4),
+				multi,
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::approve_as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			blake2_256(&call.encode()),
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 3);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(StatefulOperations::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn stateful_multisig_signatory_set_is_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1),
+			Error::<Test>::MinimumThreshold,
+		);
+		assert_noop!(
+			Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2], 3),
+			Error::<Test>::TooFewSignatories,
+		);
+		assert_noop!(
+			Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![2, 1], 2),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 1, 2], 2),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3, 4], 2),
+			Error::<Test>::TooManySignatories,
+		);
+		assert_noop!(
+			Multisig::set_signatories(RuntimeOrigin::signed(1), vec![1, 2], 2, PendingPolicy::Keep),
+			Error::<Test>::NotStateful,
+		);
+	});
+}
+
+#[test]
+fn stateful_multisig_keeps_account_when_signatories_change() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2));
+		let multi = Multisig::stateful_account_id(&1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let change = call_set_signatories(vec![2, 3, 4], 3, PendingPolicy::Keep);
+		let change_weight = change.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			change.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			change,
+			change_weight
+		));
+
+		// The creator got the deposit of the set back, which is now held by the multisig itself.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(multi), 4);
+		assert_eq!(Multisig::stateful_account_id(&1, 0), multi);
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().weight;
+		assert_noop!(
+			Multisig::as_stateful_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		// The new threshold of 3 is not reached yet.
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn stateful_multisig_keeps_pending_operations() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2));
+		let multi = Multisig::stateful_account_id(&1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+
+		let change = call_set_signatories(vec![2, 3, 4], 2, PendingPolicy::Keep);
+		let change_weight = change.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			change.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			change,
+			change_weight
+		));
+
+		// The approval of the removed signatory doesn't count anymore.
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn stateful_multisig_invalidates_pending_operations() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2));
+		let multi = Multisig::stateful_account_id(&1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 7);
+
+		let change = call_set_signatories(vec![2, 3, 4], 2, PendingPolicy::Invalidate);
+		let change_weight = change.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			change.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			change,
+			change_weight
+		));
+
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(2),
+				multi,
+				Some(now()),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::StaleOperation,
+		);
+		assert_noop!(
+			Multisig::cancel_as_stateful_multi(RuntimeOrigin::signed(5), multi, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		// Any of the new signatories can clean up the invalidated operation.
+		assert_ok!(Multisig::cancel_as_stateful_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!StatefulOperations::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn stateful_multisig_can_be_dissolved() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_stateful_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2));
+		let multi = Multisig::stateful_account_id(&1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		let call = call_transfer(6, 5);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			call,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(3), 3);
+
+		assert_noop!(
+			Multisig::dissolve_stateful_multisig(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotStateful,
+		);
+		let dissolve = Box::new(RuntimeCall::Multisig(Call::dissolve_stateful_multisig {}));
+		let dissolve_weight = dissolve.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			dissolve.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			dissolve,
+			dissolve_weight
+		));
+		System::assert_has_event(Event::StatefulMultisigDissolved { multisig: multi }.into());
+		assert!(!StatefulMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// The pending operation can't go through anymore, but its depositor can cancel it.
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(2),
+				multi,
+				Some(now()),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotStateful,
+		);
+		assert_noop!(
+			Multisig::cancel_as_stateful_multi(RuntimeOrigin::signed(2), multi, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(!StatefulOperations::<Test>::contains_key(multi, hash));
+	});
+}
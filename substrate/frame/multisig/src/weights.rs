@@ -18,9 +18,9 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-04-09, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-anb7yjbi-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
 // Executed Command:
@@ -58,6 +58,15 @@ pub trait WeightInfo {
 	fn approve_as_multi_create(s: u32, ) -> Weight;
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
+	fn create_stateful_multisig(s: u32, ) -> Weight;
+	fn set_signatories(s: u32, ) -> Weight;
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight;
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight;
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight;
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight;
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight;
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight;
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -68,14 +77,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_threshold_1(z: u32, ) -> Weight {
+	fn as_multi_threshold_1(_z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `145`
+		//  Measured:  `9`
 		//  Estimated: `3997`
-		// Minimum execution time: 20_302_000 picoseconds.
-		Weight::from_parts(21_362_808, 3997)
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(432, 0).saturating_mul(z.into()))
+		// Minimum execution time: 13_780_000 picoseconds.
+		Weight::from_parts(18_467_286, 3997)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -84,14 +91,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `301 + s * (2 ±0)`
+		//  Measured:  `191 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_140_000 picoseconds.
-		Weight::from_parts(31_518_927, 6811)
-			// Standard Error: 754
-			.saturating_add(Weight::from_parts(115_804, 0).saturating_mul(s.into()))
-			// Standard Error: 7
-			.saturating_add(Weight::from_parts(1_442, 0).saturating_mul(z.into()))
+		// Minimum execution time: 24_326_000 picoseconds.
+		Weight::from_parts(17_338_620, 6811)
+			// Standard Error: 5_410
+			.saturating_add(Weight::from_parts(138_369, 0).saturating_mul(s.into()))
+			// Standard Error: 53
+			.saturating_add(Weight::from_parts(988, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -101,14 +108,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `320`
+		//  Measured:  `210`
 		//  Estimated: `6811`
-		// Minimum execution time: 27_375_000 picoseconds.
-		Weight::from_parts(17_806_361, 6811)
-			// Standard Error: 501
-			.saturating_add(Weight::from_parts(107_042, 0).saturating_mul(s.into()))
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(1_491, 0).saturating_mul(z.into()))
+		// Minimum execution time: 19_921_000 picoseconds.
+		Weight::from_parts(7_926_290, 6811)
+			// Standard Error: 6_270
+			.saturating_add(Weight::from_parts(179_861, 0).saturating_mul(s.into()))
+			// Standard Error: 61
+			.saturating_add(Weight::from_parts(1_150, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -124,14 +131,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `571 + s * (33 ±0)`
+		//  Measured:  `323 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 54_427_000 picoseconds.
-		Weight::from_parts(43_677_970, 6811)
-			// Standard Error: 1_342
-			.saturating_add(Weight::from_parts(154_697, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_534, 0).saturating_mul(z.into()))
+		// Minimum execution time: 34_482_000 picoseconds.
+		Weight::from_parts(32_301_885, 6811)
+			// Standard Error: 11_459
+			.saturating_add(Weight::from_parts(339_546, 0).saturating_mul(s.into()))
+			// Standard Error: 112
+			.saturating_add(Weight::from_parts(1_070, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -140,12 +147,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `301 + s * (2 ±0)`
+		//  Measured:  `192 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_102_000 picoseconds.
-		Weight::from_parts(30_317_105, 6811)
-			// Standard Error: 903
-			.saturating_add(Weight::from_parts(109_792, 0).saturating_mul(s.into()))
+		// Minimum execution time: 15_178_000 picoseconds.
+		Weight::from_parts(17_962_115, 6811)
+			// Standard Error: 7_057
+			.saturating_add(Weight::from_parts(179_518, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -154,12 +161,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `320`
+		//  Measured:  `210`
 		//  Estimated: `6811`
-		// Minimum execution time: 16_300_000 picoseconds.
-		Weight::from_parts(17_358_877, 6811)
-			// Standard Error: 522
-			.saturating_add(Weight::from_parts(99_194, 0).saturating_mul(s.into()))
+		// Minimum execution time: 10_161_000 picoseconds.
+		Weight::from_parts(11_639_140, 6811)
+			// Standard Error: 6_579
+			.saturating_add(Weight::from_parts(129_039, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -168,15 +175,188 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `492 + s * (1 ±0)`
+		//  Measured:  `382 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_147_000 picoseconds.
-		Weight::from_parts(32_003_421, 6811)
-			// Standard Error: 1_077
-			.saturating_add(Weight::from_parts(108_567, 0).saturating_mul(s.into()))
+		// Minimum execution time: 17_652_000 picoseconds.
+		Weight::from_parts(20_809_240, 6811)
+			// Standard Error: 5_297
+			.saturating_add(Weight::from_parts(153_480, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158`
+		//  Estimated: `1489`
+		// Minimum execution time: 11_847_000 picoseconds.
+		Weight::from_parts(12_045_936, 1489)
+			// Standard Error: 7_167
+			.saturating_add(Weight::from_parts(229_550, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 22_273_000 picoseconds.
+		Weight::from_parts(30_797_995, 6761)
+			// Standard Error: 15_403
+			.saturating_add(Weight::from_parts(221_134, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 31_277_000 picoseconds.
+		Weight::from_parts(42_294_345, 6811)
+			// Standard Error: 10_743
+			.saturating_add(Weight::from_parts(14_800, 0).saturating_mul(s.into()))
+			// Standard Error: 105
+			.saturating_add(Weight::from_parts(892, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 25_486_000 picoseconds.
+		Weight::from_parts(29_170_040, 6811)
+			// Standard Error: 8_464
+			.saturating_add(Weight::from_parts(152_122, 0).saturating_mul(s.into()))
+			// Standard Error: 82
+			.saturating_add(Weight::from_parts(539, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
+	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
+	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `581 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 42_744_000 picoseconds.
+		Weight::from_parts(19_515_011, 6811)
+			// Standard Error: 16_054
+			.saturating_add(Weight::from_parts(492_898, 0).saturating_mul(s.into()))
+			// Standard Error: 157
+			.saturating_add(Weight::from_parts(2_702, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 17_242_000 picoseconds.
+		Weight::from_parts(18_077_703, 6811)
+			// Standard Error: 6_913
+			.saturating_add(Weight::from_parts(205_599, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_777_000 picoseconds.
+		Weight::from_parts(17_899_228, 6811)
+			// Standard Error: 10_127
+			.saturating_add(Weight::from_parts(145_219, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `638 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 25_613_000 picoseconds.
+		Weight::from_parts(29_840_004, 6811)
+			// Standard Error: 8_577
+			.saturating_add(Weight::from_parts(116_841, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `249 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 15_823_000 picoseconds.
+		Weight::from_parts(11_210_313, 6761)
+			// Standard Error: 9_252
+			.saturating_add(Weight::from_parts(310_731, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -186,14 +366,12 @@ impl WeightInfo for () {
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_threshold_1(z: u32, ) -> Weight {
+	fn as_multi_threshold_1(_z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `145`
+		//  Measured:  `9`
 		//  Estimated: `3997`
-		// Minimum execution time: 20_302_000 picoseconds.
-		Weight::from_parts(21_362_808, 3997)
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(432, 0).saturating_mul(z.into()))
+		// Minimum execution time: 13_780_000 picoseconds.
+		Weight::from_parts(18_467_286, 3997)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -202,14 +380,14 @@ impl WeightInfo for () {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `301 + s * (2 ±0)`
+		//  Measured:  `191 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_140_000 picoseconds.
-		Weight::from_parts(31_518_927, 6811)
-			// Standard Error: 754
-			.saturating_add(Weight::from_parts(115_804, 0).saturating_mul(s.into()))
-			// Standard Error: 7
-			.saturating_add(Weight::from_parts(1_442, 0).saturating_mul(z.into()))
+		// Minimum execution time: 24_326_000 picoseconds.
+		Weight::from_parts(17_338_620, 6811)
+			// Standard Error: 5_410
+			.saturating_add(Weight::from_parts(138_369, 0).saturating_mul(s.into()))
+			// Standard Error: 53
+			.saturating_add(Weight::from_parts(988, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -219,14 +397,14 @@ impl WeightInfo for () {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `320`
+		//  Measured:  `210`
 		//  Estimated: `6811`
-		// Minimum execution time: 27_375_000 picoseconds.
-		Weight::from_parts(17_806_361, 6811)
-			// Standard Error: 501
-			.saturating_add(Weight::from_parts(107_042, 0).saturating_mul(s.into()))
-			// Standard Error: 4
-			.saturating_add(Weight::from_parts(1_491, 0).saturating_mul(z.into()))
+		// Minimum execution time: 19_921_000 picoseconds.
+		Weight::from_parts(7_926_290, 6811)
+			// Standard Error: 6_270
+			.saturating_add(Weight::from_parts(179_861, 0).saturating_mul(s.into()))
+			// Standard Error: 61
+			.saturating_add(Weight::from_parts(1_150, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -242,14 +420,14 @@ impl WeightInfo for () {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `571 + s * (33 ±0)`
+		//  Measured:  `323 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 54_427_000 picoseconds.
-		Weight::from_parts(43_677_970, 6811)
-			// Standard Error: 1_342
-			.saturating_add(Weight::from_parts(154_697, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_534, 0).saturating_mul(z.into()))
+		// Minimum execution time: 34_482_000 picoseconds.
+		Weight::from_parts(32_301_885, 6811)
+			// Standard Error: 11_459
+			.saturating_add(Weight::from_parts(339_546, 0).saturating_mul(s.into()))
+			// Standard Error: 112
+			.saturating_add(Weight::from_parts(1_070, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -258,12 +436,12 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `301 + s * (2 ±0)`
+		//  Measured:  `192 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_102_000 picoseconds.
-		Weight::from_parts(30_317_105, 6811)
-			// Standard Error: 903
-			.saturating_add(Weight::from_parts(109_792, 0).saturating_mul(s.into()))
+		// Minimum execution time: 15_178_000 picoseconds.
+		Weight::from_parts(17_962_115, 6811)
+			// Standard Error: 7_057
+			.saturating_add(Weight::from_parts(179_518, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -272,12 +450,12 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `320`
+		//  Measured:  `210`
 		//  Estimated: `6811`
-		// Minimum execution time: 16_300_000 picoseconds.
-		Weight::from_parts(17_358_877, 6811)
-			// Standard Error: 522
-			.saturating_add(Weight::from_parts(99_194, 0).saturating_mul(s.into()))
+		// Minimum execution time: 10_161_000 picoseconds.
+		Weight::from_parts(11_639_140, 6811)
+			// Standard Error: 6_579
+			.saturating_add(Weight::from_parts(129_039, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -286,13 +464,186 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `492 + s * (1 ±0)`
+		//  Measured:  `382 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 30_147_000 picoseconds.
-		Weight::from_parts(32_003_421, 6811)
-			// Standard Error: 1_077
-			.saturating_add(Weight::from_parts(108_567, 0).saturating_mul(s.into()))
+		// Minimum execution time: 17_652_000 picoseconds.
+		Weight::from_parts(20_809_240, 6811)
+			// Standard Error: 5_297
+			.saturating_add(Weight::from_parts(153_480, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::NextStatefulIndex` (r:1 w:1)
+	/// Proof: `Multisig::NextStatefulIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:0 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158`
+		//  Estimated: `1489`
+		// Minimum execution time: 11_847_000 picoseconds.
+		Weight::from_parts(12_045_936, 1489)
+			// Standard Error: 7_167
+			.saturating_add(Weight::from_parts(229_550, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361 + s * (33 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 22_273_000 picoseconds.
+		Weight::from_parts(30_797_995, 6761)
+			// Standard Error: 15_403
+			.saturating_add(Weight::from_parts(221_134, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 31_277_000 picoseconds.
+		Weight::from_parts(42_294_345, 6811)
+			// Standard Error: 10_743
+			.saturating_add(Weight::from_parts(14_800, 0).saturating_mul(s.into()))
+			// Standard Error: 105
+			.saturating_add(Weight::from_parts(892, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 25_486_000 picoseconds.
+		Weight::from_parts(29_170_040, 6811)
+			// Standard Error: 8_464
+			.saturating_add(Weight::from_parts(152_122, 0).saturating_mul(s.into()))
+			// Standard Error: 82
+			.saturating_add(Weight::from_parts(539, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
+	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
+	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_stateful_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `581 + s * (65 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 42_744_000 picoseconds.
+		Weight::from_parts(19_515_011, 6811)
+			// Standard Error: 16_054
+			.saturating_add(Weight::from_parts(492_898, 0).saturating_mul(s.into()))
+			// Standard Error: 157
+			.saturating_add(Weight::from_parts(2_702, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:0 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_stateful_multi_create(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339 + s * (34 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 17_242_000 picoseconds.
+		Weight::from_parts(18_077_703, 6811)
+			// Standard Error: 6_913
+			.saturating_add(Weight::from_parts(205_599, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:0)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 100]`.
+	fn approve_as_stateful_multi_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 15_777_000 picoseconds.
+		Weight::from_parts(17_899_228, 6811)
+			// Standard Error: 10_127
+			.saturating_add(Weight::from_parts(145_219, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StatefulOperations` (r:1 w:1)
+	/// Proof: `Multisig::StatefulOperations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_stateful_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `638 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 25_613_000 picoseconds.
+		Weight::from_parts(29_840_004, 6811)
+			// Standard Error: 8_577
+			.saturating_add(Weight::from_parts(116_841, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(3296), added: 5771, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn dissolve_stateful_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `249 + s * (34 ±0)`
+		//  Estimated: `6761`
+		// Minimum execution time: 15_823_000 picoseconds.
+		Weight::from_parts(11_210_313, 6761)
+			// Standard Error: 9_252
+			.saturating_add(Weight::from_parts(310_731, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -102,6 +102,73 @@ fn insert_recovery_account<T: Config>(caller: &T::AccountId, account: &T::Accoun
 	<Recoverable<T>>::insert(&account, recovery_config);
 }
 
+fn insert_pending_change<T: Config>(account: &T::AccountId, num: u32) {
+	let friends = generate_friends::<T>(num);
+	let bounded_friends: FriendsOf<T> = friends.try_into().unwrap();
+
+	// Get deposit for recovery
+	let total_deposit = get_total_deposit::<T>(&bounded_friends).unwrap();
+
+	let recovery_config = RecoveryConfig {
+		delay_period: DEFAULT_DELAY.into(),
+		deposit: total_deposit,
+		friends: bounded_friends,
+		threshold: num as u16,
+	};
+
+	// Reserve deposit for the new configuration
+	T::Currency::reserve(&account, total_deposit).unwrap();
+
+	<PendingRecoveryChanges<T>>::insert(
+		&account,
+		PendingRecoveryChange { enactment: Zero::zero(), config: Some(recovery_config) },
+	);
+}
+
+fn insert_inheritor<T: Config>(account: &T::AccountId, heir: &T::AccountId) {
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
+	let deposit = T::InheritanceDeposit::get();
+	T::Currency::reserve(&account, deposit).unwrap();
+
+	<Inheritors<T>>::insert(
+		&account,
+		InheritanceConfig { heir: heir.clone(), inactivity_period: DEFAULT_DELAY.into(), deposit },
+	);
+}
+
+fn insert_pending_inheritor_change<T: Config>(account: &T::AccountId, heir: &T::AccountId) {
+	let deposit = T::InheritanceDeposit::get();
+	T::Currency::reserve(&account, deposit).unwrap();
+
+	let inheritance = InheritanceConfig {
+		heir: heir.clone(),
+		inactivity_period: T::MinInactivityPeriod::get(),
+		deposit,
+	};
+	<PendingInheritorChanges<T>>::insert(
+		&account,
+		PendingInheritorChange { enactment: Zero::zero(), config: Some(inheritance) },
+	);
+}
+
+fn insert_active_inheritance<T: Config>(account: &T::AccountId, heir: &T::AccountId) {
+	T::Currency::make_free_balance_be(&heir, BalanceOf::<T>::max_value());
+
+	let deposit = T::RecoveryDeposit::get();
+	T::Currency::reserve(&heir, deposit).unwrap();
+
+	<ActiveInheritances<T>>::insert(
+		&account,
+		ActiveInheritance {
+			heir: heir.clone(),
+			created: DEFAULT_DELAY.into(),
+			deposit,
+			nonce: frame_system::Pallet::<T>::account_nonce(&account),
+		},
+	);
+}
+
 benchmarks! {
 	as_recovered {
 		let caller: T::AccountId = whitelisted_caller();
@@ -352,6 +419,12 @@ benchmarks! {
 
 		// Reserve deposit for recovery
 		T::Currency::reserve(&caller, total_deposit).unwrap();
+
+		// Announce the removal
+		<PendingRecoveryChanges<T>>::insert(
+			&caller,
+			PendingRecoveryChange { enactment: Zero::zero(), config: None },
+		);
 	}: _(
 		RawOrigin::Signed(caller.clone())
 	) verify {
@@ -377,5 +450,159 @@ benchmarks! {
 		account_lookup
 	)
 
+	announce_recovery_change {
+		let n in 1 .. T::MaxFriends::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		insert_recovery_account::<T>(&caller, &caller);
+
+		// Create friends
+		let friends = generate_friends::<T>(n);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		friends,
+		n as u16,
+		DEFAULT_DELAY.into()
+	) verify {
+		assert!(<PendingRecoveryChanges<T>>::contains_key(&caller));
+	}
+
+	announce_recovery_removal {
+		let n in 1 .. T::MaxFriends::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		insert_recovery_account::<T>(&caller, &caller);
+		insert_pending_change::<T>(&caller, n);
+	}: _(
+		RawOrigin::Signed(caller.clone())
+	) verify {
+		assert_eq!(<PendingRecoveryChanges<T>>::get(&caller).unwrap().config, None);
+	}
+
+	apply_recovery_change {
+		let n in 1 .. T::MaxFriends::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_recovery_account::<T>(&account, &account);
+		insert_pending_change::<T>(&account, n);
+	}: _(
+		RawOrigin::Signed(caller),
+		account_lookup
+	) verify {
+		assert_last_event::<T>(Event::RecoveryChangeApplied { account }.into());
+	}
+
+	cancel_recovery_change {
+		let n in 1 .. T::MaxFriends::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		insert_pending_change::<T>(&caller, n);
+	}: _(
+		RawOrigin::Signed(caller.clone())
+	) verify {
+		assert_last_event::<T>(Event::RecoveryChangeCancelled { account: caller }.into());
+	}
+
+	set_inheritor {
+		let caller: T::AccountId = whitelisted_caller();
+		let heir: T::AccountId = account("heir", 0, SEED);
+		let heir_lookup = T::Lookup::unlookup(heir.clone());
+		// Replace the pending change of the current heir
+		insert_inheritor::<T>(&caller, &account("heir", 1, SEED));
+		insert_pending_inheritor_change::<T>(&caller, &account("heir", 2, SEED));
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		heir_lookup,
+		T::MinInactivityPeriod::get()
+	) verify {
+		let pending = <PendingInheritorChanges<T>>::get(&caller).and_then(|p| p.config);
+		let inheritance = pending.or_else(|| <Inheritors<T>>::get(&caller)).unwrap();
+		assert_eq!(inheritance.heir, heir);
+	}
+
+	remove_inheritor {
+		let caller: T::AccountId = whitelisted_caller();
+		let heir: T::AccountId = account("heir", 0, SEED);
+		// Replace the pending change of the current heir
+		insert_inheritor::<T>(&caller, &heir);
+		insert_pending_inheritor_change::<T>(&caller, &account("heir", 1, SEED));
+	}: _(
+		RawOrigin::Signed(caller.clone())
+	) verify {
+		let pending = <PendingInheritorChanges<T>>::get(&caller);
+		assert!(pending.map_or(!<Inheritors<T>>::contains_key(&caller), |p| p.config.is_none()));
+	}
+
+	initiate_inheritance {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritor::<T>(&account, &caller);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		account_lookup
+	) verify {
+		assert_last_event::<T>(
+			Event::InheritanceInitiated { lost_account: account, heir: caller }.into()
+		);
+	}
+
+	claim_inheritance {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritor::<T>(&account, &caller);
+		insert_active_inheritance::<T>(&account, &caller);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		account_lookup
+	) verify {
+		assert_last_event::<T>(
+			Event::AccountRecovered { lost_account: account, rescuer_account: caller }.into()
+		);
+	}
+
+	close_inheritance {
+		let caller: T::AccountId = whitelisted_caller();
+		let heir: T::AccountId = account("heir", 0, SEED);
+		insert_inheritor::<T>(&caller, &heir);
+		insert_active_inheritance::<T>(&caller, &heir);
+	}: _(
+		RawOrigin::Signed(caller.clone())
+	) verify {
+		assert_last_event::<T>(
+			Event::InheritanceClosed { lost_account: caller, heir }.into()
+		);
+	}
+
+	apply_inheritor_change {
+		let caller: T::AccountId = whitelisted_caller();
+		let heir: T::AccountId = account("heir", 0, SEED);
+		let previous_heir: T::AccountId = account("heir", 1, SEED);
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritor::<T>(&account, &previous_heir);
+		insert_pending_inheritor_change::<T>(&account, &heir);
+	}: _(
+		RawOrigin::Signed(caller),
+		account_lookup
+	) verify {
+		assert_last_event::<T>(Event::InheritorSet { account, heir }.into());
+	}
+
+	cancel_inheritor_change {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		insert_pending_inheritor_change::<T>(&caller, &account("heir", 0, SEED));
+	}: _(
+		RawOrigin::Signed(caller.clone())
+	) verify {
+		assert_last_event::<T>(Event::InheritorChangeCancelled { account: caller }.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -45,6 +45,34 @@
 //! the number of friends chosen. This deposit is returned in full when the account
 //! owner removes their recovery configuration.
 //!
+//! ### Changing the Recovery Configuration
+//!
+//! An existing recovery configuration can not be changed instantly. Instead, the account owner
+//! announces the new configuration with `announce_recovery_change`, or its removal with
+//! `announce_recovery_removal`, and the change can only be applied after `ConfigChangeDelay`
+//! blocks. Until then, the previous configuration stays in effect, so that an attacker who
+//! compromised the key of an account can not get rid of its friends before they get a chance to
+//! recover it. A recovered account can cancel the announced change with `cancel_recovery_change`.
+//!
+//! The deposit for the new configuration is held as soon as the change is announced, and the
+//! deposit of the previous configuration is returned once the change is applied.
+//!
+//! ### Inheritance
+//!
+//! Besides its friends, an account can name an `heir` with `set_inheritor`, together with an
+//! `inactivity_period` of at least `MinInactivityPeriod`. Like a change of the recovery
+//! configuration, naming or removing the heir only takes effect `ConfigChangeDelay` blocks later,
+//! with `apply_inheritor_change`, and can be cancelled with `cancel_inheritor_change` until then.
+//! If `ConfigChangeDelay` is zero, it takes effect right away. The heir can start an inheritance
+//! with `initiate_inheritance`, placing the same deposit as for a recovery attempt. If the account
+//! does not send any transaction during the following `inactivity_period` blocks (i.e. its nonce
+//! does not change), the heir can call `claim_inheritance` and gains access to the account just
+//! like a successful rescuer, without any friend having to vouch for it.
+//!
+//! An account which is still in use can close the inheritance with `close_inheritance` and claim
+//! the deposit of the heir. Naming an heir requires an `InheritanceDeposit`, which is returned when
+//! the heir is removed with `remove_inheritor`.
+//!
 //! ### Recovery Life Cycle
 //!
 //! The intended life cycle of a successful recovery takes the following steps:
@@ -140,7 +168,26 @@
 //! * `close_recovery` - Close an active recovery process for your account and reclaim the recovery
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
-//!   un-recoverable.
+//!   un-recoverable. Unless `ConfigChangeDelay` is zero, the removal must have been announced.
+//! * `announce_recovery_change` - Announce a new recovery configuration for the account.
+//! * `announce_recovery_removal` - Announce the removal of the recovery configuration.
+//! * `cancel_recovery_change` - Cancel an announced change of the recovery configuration.
+//! * `set_inheritor` - Announce the heir of the account and how long the account must be inactive.
+//! * `remove_inheritor` - Announce the removal of the heir of the account.
+//! * `cancel_inheritor_change` - Cancel an announced change of the heir.
+//! * `close_inheritance` - Close an inheritance started by the heir and claim their deposit.
+//!
+//! #### For Any Account
+//!
+//! * `apply_recovery_change` - Apply an announced change of a recovery configuration once its delay
+//!   has passed.
+//! * `apply_inheritor_change` - Apply an announced change of the heir of an account once its delay
+//!   has passed.
+//!
+//! #### For the Heir of an Account
+//!
+//! * `initiate_inheritance` - Start inheriting the account.
+//! * `claim_inheritance` - Claim access to the account once it has been inactive for long enough.
 //!
 //! #### For Super Users
 //!
@@ -153,14 +200,16 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, StaticLookup},
-	RuntimeDebug,
+	traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, StaticLookup, Zero},
+	ArithmeticError, DispatchError, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
 use frame_support::{
-	dispatch::{GetDispatchInfo, PostDispatchInfo},
-	traits::{BalanceStatus, Currency, ReservableCurrency},
+	dispatch::{DispatchResult, GetDispatchInfo, PostDispatchInfo},
+	ensure,
+	traits::{BalanceStatus, Currency, Get, ReservableCurrency},
+	weights::Weight,
 	BoundedVec,
 };
 
@@ -180,6 +229,13 @@ type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 type FriendsOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFriends>;
+type RecoveryConfigOf<T> =
+	RecoveryConfig<frame_system::pallet_prelude::BlockNumberFor<T>, BalanceOf<T>, FriendsOf<T>>;
+type InheritanceConfigOf<T> = InheritanceConfig<
+	<T as frame_system::Config>::AccountId,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+	BalanceOf<T>,
+>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 /// An active recovery process.
@@ -209,12 +265,61 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	threshold: u16,
 }
 
+/// An announced change of the recovery configuration of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingRecoveryChange<BlockNumber, Balance, Friends> {
+	/// The block number from which the change can be applied.
+	enactment: BlockNumber,
+	/// The new recovery configuration, or `None` if the configuration is to be removed.
+	///
+	/// The deposit of the new configuration is held from the moment the change is announced.
+	config: Option<RecoveryConfig<BlockNumber, Balance, Friends>>,
+}
+
+/// Configuration for inheriting an inactive account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceConfig<AccountId, BlockNumber, Balance> {
+	/// The account which can inherit the account.
+	heir: AccountId,
+	/// The number of blocks since the start of the inheritance during which the account must stay
+	/// inactive before it can be inherited.
+	inactivity_period: BlockNumber,
+	/// The amount held in reserve of the account,
+	/// to be returned once this configuration is removed.
+	deposit: Balance,
+}
+
+/// An announced change of the heir of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingInheritorChange<AccountId, BlockNumber, Balance> {
+	/// The block number from which the change can be applied.
+	enactment: BlockNumber,
+	/// The new inheritance configuration, or `None` if the heir is to be removed.
+	///
+	/// The deposit of the new configuration is held from the moment the change is announced.
+	config: Option<InheritanceConfig<AccountId, BlockNumber, Balance>>,
+}
+
+/// An inheritance started by the heir of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ActiveInheritance<AccountId, BlockNumber, Balance, Nonce> {
+	/// The heir who started the inheritance.
+	heir: AccountId,
+	/// The block number when the inheritance started.
+	created: BlockNumber,
+	/// The amount held in reserve of the `heir`,
+	/// to be returned once the account is inherited.
+	deposit: Balance,
+	/// The nonce of the account when the inheritance started. The account is considered active
+	/// as soon as it changes.
+	nonce: Nonce,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -270,6 +375,25 @@ pub mod pallet {
 		/// threshold.
 		#[pallet::constant]
 		type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks that need to pass after a change of a recovery configuration is
+		/// announced before it can be applied.
+		///
+		/// If zero, a recovery configuration can also be removed directly with `remove_recovery`.
+		#[pallet::constant]
+		type ConfigChangeDelay: Get<BlockNumberFor<Self>>;
+
+		/// The amount of currency needed to reserve for naming the heir of an account.
+		///
+		/// This is held for an additional storage item whose value size is
+		/// `sizeof(AccountId, BlockNumber, Balance)` bytes.
+		#[pallet::constant]
+		type InheritanceDeposit: Get<BalanceOf<Self>>;
+
+		/// The minimum number of blocks an account must stay inactive before its heir can inherit
+		/// it.
+		#[pallet::constant]
+		type MinInactivityPeriod: Get<BlockNumberFor<Self>>;
 	}
 
 	/// Events type.
@@ -292,6 +416,24 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// A change of the recovery configuration of an account has been announced.
+		RecoveryChangeAnnounced { account: T::AccountId, enactment: BlockNumberFor<T> },
+		/// The new recovery configuration of an account has been applied.
+		RecoveryChangeApplied { account: T::AccountId },
+		/// An announced change of the recovery configuration of an account has been cancelled.
+		RecoveryChangeCancelled { account: T::AccountId },
+		/// An account has named its heir.
+		InheritorSet { account: T::AccountId, heir: T::AccountId },
+		/// The heir of an account has been removed.
+		InheritorRemoved { account: T::AccountId },
+		/// A change of the heir of an account has been announced.
+		InheritorChangeAnnounced { account: T::AccountId, enactment: BlockNumberFor<T> },
+		/// An announced change of the heir of an account has been cancelled.
+		InheritorChangeCancelled { account: T::AccountId },
+		/// The heir of lost account has started to inherit it.
+		InheritanceInitiated { lost_account: T::AccountId, heir: T::AccountId },
+		/// The inheritance of lost account by heir has been closed.
+		InheritanceClosed { lost_account: T::AccountId, heir: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -328,6 +470,22 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// There is no announced change of the recovery configuration
+		NoPendingChange,
+		/// The announced change can not be applied before its enactment
+		ChangeNotDue,
+		/// The removal of the recovery configuration must be announced first
+		ChangeNotAnnounced,
+		/// This account has no heir
+		NoInheritor,
+		/// This account is not the heir of the account
+		NotHeir,
+		/// The account has been active since the inheritance started
+		AccountActive,
+		/// The heir must wait until the inactivity period to inherit this account
+		InactivityPeriod,
+		/// The inactivity period is shorter than `MinInactivityPeriod`
+		InactivityPeriodTooShort,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -362,6 +520,42 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// Announced changes of recovery configurations, waiting for their enactment.
+	#[pallet::storage]
+	pub type PendingRecoveryChanges<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		PendingRecoveryChange<BlockNumberFor<T>, BalanceOf<T>, FriendsOf<T>>,
+	>;
+
+	/// The heirs of accounts, with the period of inactivity after which they can inherit them.
+	#[pallet::storage]
+	pub type Inheritors<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		InheritanceConfig<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+	>;
+
+	/// Announced changes of heirs, waiting for their enactment.
+	#[pallet::storage]
+	pub type PendingInheritorChanges<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		PendingInheritorChange<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+	>;
+
+	/// Inheritances started by heirs, keyed by the account to be inherited.
+	#[pallet::storage]
+	pub type ActiveInheritances<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		ActiveInheritance<T::AccountId, BlockNumberFor<T>, BalanceOf<T>, T::Nonce>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -448,29 +642,10 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			// Check account is not already set up for recovery
 			ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
-			// Check user input is valid
-			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
-			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
-			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
-			let bounded_friends: FriendsOf<T> =
-				friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
-			ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
-			// Total deposit is base fee + number of friends * factor fee
-			let friend_deposit = T::FriendDepositFactor::get()
-				.checked_mul(&bounded_friends.len().saturated_into())
-				.ok_or(ArithmeticError::Overflow)?;
-			let total_deposit = T::ConfigDepositBase::get()
-				.checked_add(&friend_deposit)
-				.ok_or(ArithmeticError::Overflow)?;
-			// Reserve the deposit
-			T::Currency::reserve(&who, total_deposit)?;
 			// Create the recovery configuration
-			let recovery_config = RecoveryConfig {
-				delay_period,
-				deposit: total_deposit,
-				friends: bounded_friends,
-				threshold,
-			};
+			let recovery_config = Self::new_recovery_config(friends, threshold, delay_period)?;
+			// Reserve the deposit
+			T::Currency::reserve(&who, recovery_config.deposit)?;
 			// Create the recovery configuration storage item
 			<Recoverable<T>>::insert(&who, recovery_config);
 
@@ -668,16 +843,17 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::remove_recovery(T::MaxFriends::get()))]
 		pub fn remove_recovery(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// Check there are no active recoveries
-			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
-			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
-			// Take the recovery configuration for this account.
-			let recovery_config = <Recoverable<T>>::take(&who).ok_or(Error::<T>::NotRecoverable)?;
-
-			// Unreserve the initial deposit for the recovery configuration.
-			T::Currency::unreserve(&who, recovery_config.deposit);
-			Self::deposit_event(Event::<T>::RecoveryRemoved { lost_account: who });
-			Ok(())
+			// Unless changes are immediate, the removal must have been announced and be due.
+			if !T::ConfigChangeDelay::get().is_zero() {
+				let pending = <PendingRecoveryChanges<T>>::get(&who)
+					.filter(|pending| pending.config.is_none())
+					.ok_or(Error::<T>::ChangeNotAnnounced)?;
+				ensure!(
+					pending.enactment <= <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::ChangeNotDue
+				);
+			}
+			Self::do_remove_recovery(who)
 		}
 
 		/// Cancel the ability to use `as_recovered` for `account`.
@@ -702,6 +878,334 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Announce a new recovery configuration for your account.
+		///
+		/// The new configuration can be applied with `apply_recovery_change` once
+		/// `ConfigChangeDelay` blocks have passed. Until then, the current configuration stays in
+		/// effect. Announcing another change replaces the pending one.
+		///
+		/// Payment: The deposit of the new configuration is reserved right away. The deposit of
+		/// the current configuration is returned once the change is applied.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `friends`: A list of friends you trust to vouch for recovery attempts. Should be
+		///   ordered and contain no duplicate values.
+		/// - `threshold`: The number of friends that must vouch for a recovery attempt before the
+		///   account can be recovered. Should be less than or equal to the length of the list of
+		///   friends.
+		/// - `delay_period`: The number of blocks after a recovery attempt is initialized that
+		///   needs to pass before the account can be recovered.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::announce_recovery_change(friends.len() as u32))]
+		pub fn announce_recovery_change(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u16,
+			delay_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Recoverable<T>>::contains_key(&who), Error::<T>::NotRecoverable);
+			let recovery_config = Self::new_recovery_config(friends, threshold, delay_period)?;
+			T::Currency::reserve(&who, recovery_config.deposit)?;
+			Self::announce_change(who, Some(recovery_config))
+		}
+
+		/// Announce the removal of the recovery configuration of your account.
+		///
+		/// Once `ConfigChangeDelay` blocks have passed, the configuration can be removed with
+		/// `remove_recovery` or `apply_recovery_change`. Announcing another change replaces the
+		/// pending one.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::announce_recovery_removal(T::MaxFriends::get()))]
+		pub fn announce_recovery_removal(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Recoverable<T>>::contains_key(&who), Error::<T>::NotRecoverable);
+			Self::announce_change(who, None)
+		}
+
+		/// Apply the announced change of the recovery configuration of `account`.
+		///
+		/// The dispatch origin for this call must be _Signed_. Anyone can apply a change once its
+		/// enactment has been reached, as long as there are no active recovery attempts for the
+		/// account.
+		///
+		/// Parameters:
+		/// - `account`: The account whose recovery configuration is changed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::apply_recovery_change(T::MaxFriends::get()))]
+		pub fn apply_recovery_change(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let pending =
+				<PendingRecoveryChanges<T>>::get(&account).ok_or(Error::<T>::NoPendingChange)?;
+			ensure!(
+				pending.enactment <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ChangeNotDue
+			);
+
+			let Some(recovery_config) = pending.config else {
+				return Self::do_remove_recovery(account)
+			};
+			// Vouches of the active recoveries might not be valid for the new configuration.
+			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&account);
+			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
+			let previous_config =
+				<Recoverable<T>>::get(&account).ok_or(Error::<T>::NotRecoverable)?;
+
+			// The deposit of the new configuration is already reserved.
+			T::Currency::unreserve(&account, previous_config.deposit);
+			<Recoverable<T>>::insert(&account, recovery_config);
+			<PendingRecoveryChanges<T>>::remove(&account);
+			Self::deposit_event(Event::<T>::RecoveryChangeApplied { account });
+			Ok(())
+		}
+
+		/// Cancel the announced change of the recovery configuration of your account.
+		///
+		/// Payment: The deposit reserved for the new configuration is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the account with the
+		/// announced change. A rescuer can cancel a malicious change through `as_recovered`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery_change(T::MaxFriends::get()))]
+		pub fn cancel_recovery_change(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pending =
+				<PendingRecoveryChanges<T>>::take(&who).ok_or(Error::<T>::NoPendingChange)?;
+			if let Some(recovery_config) = pending.config {
+				T::Currency::unreserve(&who, recovery_config.deposit);
+			}
+			Self::deposit_event(Event::<T>::RecoveryChangeCancelled { account: who });
+			Ok(())
+		}
+
+		/// Name the heir of your account, who can inherit it once it became inactive.
+		///
+		/// The heir can be applied with `apply_inheritor_change` once `ConfigChangeDelay` blocks
+		/// have passed, or right away if `ConfigChangeDelay` is zero. Until then, the current heir
+		/// stays in effect. Announcing another change replaces the pending one.
+		///
+		/// Payment: `InheritanceDeposit` balance will be reserved for storing the inheritance
+		/// configuration. This deposit is returned in full when the heir is removed again with
+		/// `remove_inheritor`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `heir`: The account which can inherit your account.
+		/// - `inactivity_period`: The number of blocks after the heir initiated the inheritance
+		///   during which your account must not send any transaction before it can be inherited.
+		///   Must be at least `MinInactivityPeriod`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::set_inheritor()
+				.saturating_add(Pallet::<T>::immediate_inheritor_change_weight())
+		)]
+		pub fn set_inheritor(
+			origin: OriginFor<T>,
+			heir: AccountIdLookupOf<T>,
+			inactivity_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let heir = T::Lookup::lookup(heir)?;
+			ensure!(
+				inactivity_period >= T::MinInactivityPeriod::get(),
+				Error::<T>::InactivityPeriodTooShort
+			);
+			let deposit = T::InheritanceDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Self::announce_inheritor_change(
+				who,
+				Some(InheritanceConfig { heir, inactivity_period, deposit }),
+			)
+		}
+
+		/// Remove the heir of your account.
+		///
+		/// The removal can be applied with `apply_inheritor_change` once `ConfigChangeDelay`
+		/// blocks have passed, or right away if `ConfigChangeDelay` is zero. Announcing another
+		/// change replaces the pending one.
+		///
+		/// NOTE: The user must make sure to call `close_inheritance` on an active inheritance
+		/// before the removal is applied else it will fail.
+		///
+		/// Payment: Once the removal is applied, the account will unreserve the
+		/// `InheritanceDeposit`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an account with an heir.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::remove_inheritor()
+				.saturating_add(Pallet::<T>::immediate_inheritor_change_weight())
+		)]
+		pub fn remove_inheritor(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Inheritors<T>>::contains_key(&who), Error::<T>::NoInheritor);
+			Self::announce_inheritor_change(who, None)
+		}
+
+		/// As the heir of an account, start inheriting it.
+		///
+		/// Payment: `RecoveryDeposit` balance will be reserved for initiating the inheritance.
+		/// It is returned once the account is inherited, or repatriated to the account if it
+		/// closes the inheritance. See `close_inheritance`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the heir of `account`.
+		///
+		/// Parameters:
+		/// - `account`: The account that you want to inherit.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::initiate_inheritance())]
+		pub fn initiate_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let inheritance = <Inheritors<T>>::get(&account).ok_or(Error::<T>::NoInheritor)?;
+			ensure!(inheritance.heir == who, Error::<T>::NotHeir);
+			ensure!(!<ActiveInheritances<T>>::contains_key(&account), Error::<T>::AlreadyStarted);
+			// Take the same deposit as for a recovery attempt
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			let active_inheritance = ActiveInheritance {
+				heir: who.clone(),
+				created: <frame_system::Pallet<T>>::block_number(),
+				deposit,
+				nonce: <frame_system::Pallet<T>>::account_nonce(&account),
+			};
+			<ActiveInheritances<T>>::insert(&account, active_inheritance);
+			Self::deposit_event(Event::<T>::InheritanceInitiated {
+				lost_account: account,
+				heir: who,
+			});
+			Ok(())
+		}
+
+		/// As the heir of an account, claim access to it once it stayed inactive during the
+		/// inactivity period.
+		///
+		/// On success, the heir can make calls on behalf of the account with `as_recovered`,
+		/// exactly like a successful rescuer, and the deposit of the inheritance is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the heir who initiated
+		/// the inheritance.
+		///
+		/// Parameters:
+		/// - `account`: The account that you want to inherit.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::claim_inheritance())]
+		pub fn claim_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let inheritance = <Inheritors<T>>::get(&account).ok_or(Error::<T>::NoInheritor)?;
+			ensure!(inheritance.heir == who, Error::<T>::NotHeir);
+			let active_inheritance = <ActiveInheritances<T>>::get(&account)
+				.filter(|active_inheritance| active_inheritance.heir == who)
+				.ok_or(Error::<T>::NotStarted)?;
+			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			// Make sure the account did not send any transaction in the meantime
+			ensure!(
+				active_inheritance.nonce == <frame_system::Pallet<T>>::account_nonce(&account),
+				Error::<T>::AccountActive
+			);
+			// Make sure the inactivity period has passed
+			let inheritable_block_number = active_inheritance
+				.created
+				.checked_add(&inheritance.inactivity_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				inheritable_block_number <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InactivityPeriod
+			);
+			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
+			<ActiveInheritances<T>>::remove(&account);
+			T::Currency::unreserve(&who, active_inheritance.deposit);
+			// Create the recovery storage item
+			Proxy::<T>::insert(&who, &account);
+			Self::deposit_event(Event::<T>::AccountRecovered {
+				lost_account: account,
+				rescuer_account: who,
+			});
+			Ok(())
+		}
+
+		/// Close the inheritance of your account started by your heir.
+		///
+		/// Payment: By calling this function, the account will receive the deposit
+		/// `RecoveryDeposit` placed by the heir.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an account with an
+		/// active inheritance.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::close_inheritance())]
+		pub fn close_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let active_inheritance =
+				<ActiveInheritances<T>>::take(&who).ok_or(Error::<T>::NotStarted)?;
+			let res = T::Currency::repatriate_reserved(
+				&active_inheritance.heir,
+				&who,
+				active_inheritance.deposit,
+				BalanceStatus::Free,
+			);
+			debug_assert!(res.is_ok());
+			Self::deposit_event(Event::<T>::InheritanceClosed {
+				lost_account: who,
+				heir: active_inheritance.heir,
+			});
+			Ok(())
+		}
+
+		/// Apply the announced change of the heir of `account`.
+		///
+		/// The dispatch origin for this call must be _Signed_. Anyone can apply a change once its
+		/// enactment has been reached, as long as there is no active inheritance of the account.
+		///
+		/// Parameters:
+		/// - `account`: The account whose heir is changed.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::apply_inheritor_change())]
+		pub fn apply_inheritor_change(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			Self::do_apply_inheritor_change(account)
+		}
+
+		/// Cancel the announced change of the heir of your account.
+		///
+		/// Payment: The deposit reserved for the new heir is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the account with the
+		/// announced change. A rescuer can cancel a malicious change through `as_recovered`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::cancel_inheritor_change())]
+		pub fn cancel_inheritor_change(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pending =
+				<PendingInheritorChanges<T>>::take(&who).ok_or(Error::<T>::NoPendingChange)?;
+			if let Some(inheritance) = pending.config {
+				T::Currency::unreserve(&who, inheritance.deposit);
+			}
+			Self::deposit_event(Event::<T>::InheritorChangeCancelled { account: who });
+			Ok(())
+		}
 	}
 }
 
@@ -715,4 +1219,134 @@ impl<T: Config> Pallet<T> {
 	fn is_friend(friends: &Vec<T::AccountId>, friend: &T::AccountId) -> bool {
 		friends.binary_search(&friend).is_ok()
 	}
+
+	/// Check the user input for a recovery configuration and compute its deposit.
+	fn new_recovery_config(
+		friends: Vec<T::AccountId>,
+		threshold: u16,
+		delay_period: frame_system::pallet_prelude::BlockNumberFor<T>,
+	) -> Result<RecoveryConfigOf<T>, DispatchError> {
+		ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+		ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+		ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
+		let bounded_friends: FriendsOf<T> =
+			friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+		ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
+		// Total deposit is base fee + number of friends * factor fee
+		let friend_deposit = T::FriendDepositFactor::get()
+			.checked_mul(&bounded_friends.len().saturated_into())
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_deposit = T::ConfigDepositBase::get()
+			.checked_add(&friend_deposit)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(RecoveryConfig {
+			delay_period,
+			deposit: total_deposit,
+			friends: bounded_friends,
+			threshold,
+		})
+	}
+
+	/// Store a change of the recovery configuration of `who`, replacing any pending one.
+	///
+	/// The deposit of `recovery_config` must already be reserved.
+	fn announce_change(
+		who: T::AccountId,
+		recovery_config: Option<RecoveryConfigOf<T>>,
+	) -> DispatchResult {
+		let enactment = <frame_system::Pallet<T>>::block_number()
+			.checked_add(&T::ConfigChangeDelay::get())
+			.ok_or(ArithmeticError::Overflow)?;
+		if let Some(previous) = <PendingRecoveryChanges<T>>::take(&who).and_then(|p| p.config) {
+			T::Currency::unreserve(&who, previous.deposit);
+		}
+		<PendingRecoveryChanges<T>>::insert(
+			&who,
+			PendingRecoveryChange { enactment, config: recovery_config },
+		);
+		Self::deposit_event(Event::<T>::RecoveryChangeAnnounced { account: who, enactment });
+		Ok(())
+	}
+
+	/// Remove the recovery configuration of `who`, along with any announced change of it.
+	fn do_remove_recovery(who: T::AccountId) -> DispatchResult {
+		// Check there are no active recoveries
+		let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
+		ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
+		// Take the recovery configuration for this account.
+		let recovery_config = <Recoverable<T>>::take(&who).ok_or(Error::<T>::NotRecoverable)?;
+
+		// Unreserve the initial deposit for the recovery configuration.
+		T::Currency::unreserve(&who, recovery_config.deposit);
+		if let Some(pending) = <PendingRecoveryChanges<T>>::take(&who).and_then(|p| p.config) {
+			T::Currency::unreserve(&who, pending.deposit);
+		}
+		Self::deposit_event(Event::<T>::RecoveryRemoved { lost_account: who });
+		Ok(())
+	}
+
+	/// Store a change of the heir of `who`, replacing any pending one, and apply it right away if
+	/// `ConfigChangeDelay` is zero.
+	///
+	/// The deposit of `inheritance` must already be reserved.
+	fn announce_inheritor_change(
+		who: T::AccountId,
+		inheritance: Option<InheritanceConfigOf<T>>,
+	) -> DispatchResult {
+		let enactment = <frame_system::Pallet<T>>::block_number()
+			.checked_add(&T::ConfigChangeDelay::get())
+			.ok_or(ArithmeticError::Overflow)?;
+		if let Some(previous) = <PendingInheritorChanges<T>>::take(&who).and_then(|p| p.config) {
+			T::Currency::unreserve(&who, previous.deposit);
+		}
+		<PendingInheritorChanges<T>>::insert(
+			&who,
+			PendingInheritorChange { enactment, config: inheritance },
+		);
+		Self::deposit_event(Event::<T>::InheritorChangeAnnounced {
+			account: who.clone(),
+			enactment,
+		});
+		if T::ConfigChangeDelay::get().is_zero() {
+			Self::do_apply_inheritor_change(who)?;
+		}
+		Ok(())
+	}
+
+	/// Apply the announced change of the heir of `account`, if it is due.
+	fn do_apply_inheritor_change(account: T::AccountId) -> DispatchResult {
+		let pending =
+			<PendingInheritorChanges<T>>::get(&account).ok_or(Error::<T>::NoPendingChange)?;
+		ensure!(
+			pending.enactment <= <frame_system::Pallet<T>>::block_number(),
+			Error::<T>::ChangeNotDue
+		);
+		// The active inheritance was started by the current heir.
+		ensure!(!<ActiveInheritances<T>>::contains_key(&account), Error::<T>::StillActive);
+
+		// The deposit of the new heir is already reserved.
+		if let Some(previous) = <Inheritors<T>>::take(&account) {
+			T::Currency::unreserve(&account, previous.deposit);
+		}
+		<PendingInheritorChanges<T>>::remove(&account);
+		match pending.config {
+			Some(inheritance) => {
+				let heir = inheritance.heir.clone();
+				<Inheritors<T>>::insert(&account, inheritance);
+				Self::deposit_event(Event::<T>::InheritorSet { account, heir });
+			},
+			None => Self::deposit_event(Event::<T>::InheritorRemoved { account }),
+		}
+		Ok(())
+	}
+
+	/// The weight of applying a change of the heir along with its announcement, which is only the
+	/// case if `ConfigChangeDelay` is zero.
+	fn immediate_inheritor_change_weight() -> Weight {
+		if T::ConfigChangeDelay::get().is_zero() {
+			T::WeightInfo::apply_inheritor_change()
+		} else {
+			Weight::zero()
+		}
+	}
 }
@@ -60,6 +60,9 @@ parameter_types! {
 	pub const RecoveryDeposit: u64 = 10;
 	// Large number of friends for benchmarking.
 	pub const MaxFriends: u32 = 128;
+	pub const InheritanceDeposit: u64 = 10;
+	pub static ConfigChangeDelay: u64 = 0;
+	pub const MinInactivityPeriod: u64 = 10;
 }
 
 impl Config for Test {
@@ -71,6 +74,9 @@ impl Config for Test {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type ConfigChangeDelay = ConfigChangeDelay;
+	type InheritanceDeposit = InheritanceDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
 }
 
 pub type BalancesCall = pallet_balances::Call<Test>;
@@ -20,8 +20,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use mock::{
-	new_test_ext, run_to_block, Balances, BalancesCall, MaxFriends, Recovery, RecoveryCall,
-	RuntimeCall, RuntimeOrigin, Test,
+	new_test_ext, run_to_block, Balances, BalancesCall, ConfigChangeDelay, MaxFriends, Recovery,
+	RecoveryCall, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Test,
 };
 use sp_runtime::{bounded_vec, traits::BadOrigin};
 
@@ -495,3 +495,285 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
 	});
 }
+
+#[test]
+fn recovery_config_change_is_delayed() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+		run_to_block(1);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		// Deposit is base + 3 friends
+		assert_eq!(Balances::reserved_balance(5), 13);
+
+		// Cannot change the configuration of an unrecoverable account
+		assert_noop!(
+			Recovery::announce_recovery_change(RuntimeOrigin::signed(1), vec![2, 3], 2, 0),
+			Error::<Test>::NotRecoverable
+		);
+		// New configuration is checked like in `create_recovery`
+		assert_noop!(
+			Recovery::announce_recovery_change(RuntimeOrigin::signed(5), vec![3, 2], 2, 0),
+			Error::<Test>::NotSorted
+		);
+		assert_ok!(Recovery::announce_recovery_change(RuntimeOrigin::signed(5), vec![2, 3], 2, 0));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::RecoveryChangeAnnounced {
+			account: 5,
+			enactment: 11,
+		}));
+		// Deposit of the new configuration is held right away
+		assert_eq!(Balances::reserved_balance(5), 25);
+
+		// Cannot apply the change before the delay passed, the old configuration stays in effect
+		assert_noop!(
+			Recovery::apply_recovery_change(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::ChangeNotDue
+		);
+		assert_eq!(<Recoverable<Test>>::get(&5).unwrap().friends.to_vec(), vec![2, 3, 4]);
+
+		run_to_block(11);
+		// An active recovery prevents the change
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_noop!(
+			Recovery::apply_recovery_change(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::StillActive
+		);
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+
+		// Anyone can apply the change
+		assert_ok!(Recovery::apply_recovery_change(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::RecoveryChangeApplied {
+			account: 5,
+		}));
+		let recovery_config = RecoveryConfig {
+			delay_period: 0,
+			deposit: 12,
+			friends: bounded_vec![2, 3],
+			threshold: 2,
+		};
+		assert_eq!(<Recoverable<Test>>::get(&5), Some(recovery_config));
+		assert_eq!(<PendingRecoveryChanges<Test>>::get(&5), None);
+		// Deposit of the old configuration is returned
+		assert_eq!(Balances::reserved_balance(5), 12);
+		assert_noop!(
+			Recovery::apply_recovery_change(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NoPendingChange
+		);
+	});
+}
+
+#[test]
+fn recovery_config_removal_is_delayed() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+		run_to_block(1);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+
+		// Removal must be announced
+		assert_noop!(
+			Recovery::remove_recovery(RuntimeOrigin::signed(5)),
+			Error::<Test>::ChangeNotAnnounced
+		);
+		// A pending change is not a removal
+		assert_ok!(Recovery::announce_recovery_change(RuntimeOrigin::signed(5), vec![2, 3], 2, 0));
+		assert_noop!(
+			Recovery::remove_recovery(RuntimeOrigin::signed(5)),
+			Error::<Test>::ChangeNotAnnounced
+		);
+		// Announcing the removal replaces the pending change and returns its deposit
+		assert_ok!(Recovery::announce_recovery_removal(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::reserved_balance(5), 13);
+		assert_noop!(
+			Recovery::remove_recovery(RuntimeOrigin::signed(5)),
+			Error::<Test>::ChangeNotDue
+		);
+
+		// A rescuer can cancel the removal through the recovered account
+		assert_ok!(Recovery::set_recovered(RuntimeOrigin::root(), 5, 1));
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::cancel_recovery_change {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::RecoveryChangeCancelled {
+			account: 5,
+		}));
+		assert_noop!(
+			Recovery::cancel_recovery_change(RuntimeOrigin::signed(5)),
+			Error::<Test>::NoPendingChange
+		);
+
+		assert_ok!(Recovery::announce_recovery_removal(RuntimeOrigin::signed(5)));
+		run_to_block(11);
+		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
+		assert_eq!(<Recoverable<Test>>::get(&5), None);
+		assert_eq!(<PendingRecoveryChanges<Test>>::get(&5), None);
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
+
+#[test]
+fn inheritance_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		// Account 5 names 1 as heir, who can inherit it after 10 blocks of inactivity
+		assert_ok!(Recovery::set_inheritor(RuntimeOrigin::signed(5), 1, 10));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritorSet {
+			account: 5,
+			heir: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(5), 10);
+
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 4),
+			Error::<Test>::NoInheritor
+		);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::NotHeir
+		);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotStarted
+		);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AlreadyStarted
+		);
+		// No friend needs to vouch, but the inactivity period must pass
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+
+		run_to_block(11);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::AccountRecovered {
+			lost_account: 5,
+			rescuer_account: 1,
+		}));
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+		assert_eq!(<ActiveInheritances<Test>>::get(&5), None);
+		// Deposit of the heir is returned
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// The heir can clean up the inherited account
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritor {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
+
+#[test]
+fn inheritance_of_active_account_fails() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Recovery::set_inheritor(RuntimeOrigin::signed(5), 1, 10));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+
+		// Account 5 sends a transaction
+		frame_system::Pallet::<Test>::inc_account_nonce(&5);
+		run_to_block(11);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AccountActive
+		);
+
+		// Cannot remove the heir while an inheritance is active
+		assert_noop!(
+			Recovery::remove_inheritor(RuntimeOrigin::signed(5)),
+			Error::<Test>::StillActive
+		);
+		// Account 5 closes the inheritance and takes the deposit of the heir
+		assert_ok!(Recovery::close_inheritance(RuntimeOrigin::signed(5)));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritanceClosed {
+			lost_account: 5,
+			heir: 1,
+		}));
+		assert_eq!(Balances::total_balance(&1), 90);
+		assert_eq!(Balances::free_balance(5), 100);
+		assert_noop!(
+			Recovery::close_inheritance(RuntimeOrigin::signed(5)),
+			Error::<Test>::NotStarted
+		);
+
+		assert_ok!(Recovery::remove_inheritor(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_noop!(
+			Recovery::remove_inheritor(RuntimeOrigin::signed(5)),
+			Error::<Test>::NoInheritor
+		);
+	});
+}
+
+#[test]
+fn inheritor_change_is_delayed() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+		run_to_block(1);
+		assert_noop!(
+			Recovery::set_inheritor(RuntimeOrigin::signed(5), 1, 9),
+			Error::<Test>::InactivityPeriodTooShort
+		);
+		assert_noop!(
+			Recovery::remove_inheritor(RuntimeOrigin::signed(5)),
+			Error::<Test>::NoInheritor
+		);
+
+		assert_ok!(Recovery::set_inheritor(RuntimeOrigin::signed(5), 1, 10));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritorChangeAnnounced {
+			account: 5,
+			enactment: 11,
+		}));
+		// Deposit of the new heir is held right away, but it is not the heir yet
+		assert_eq!(Balances::reserved_balance(5), 10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NoInheritor
+		);
+		assert_noop!(
+			Recovery::apply_inheritor_change(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::ChangeNotDue
+		);
+
+		run_to_block(11);
+		// Anyone can apply the change
+		assert_ok!(Recovery::apply_inheritor_change(RuntimeOrigin::signed(2), 5));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritorSet {
+			account: 5,
+			heir: 1,
+		}));
+		assert_eq!(<PendingInheritorChanges<Test>>::get(&5), None);
+		assert_noop!(
+			Recovery::apply_inheritor_change(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::NoPendingChange
+		);
+
+		// A compromised key can not replace the heir instantly
+		assert_ok!(Recovery::set_inheritor(RuntimeOrigin::signed(5), 2, 10));
+		assert_eq!(Balances::reserved_balance(5), 20);
+		assert_eq!(<Inheritors<Test>>::get(&5).unwrap().heir, 1);
+		// The heir can cancel the change through the inherited account
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		run_to_block(21);
+		assert_noop!(
+			Recovery::apply_inheritor_change(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::StillActive
+		);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::cancel_inheritor_change {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritorChangeCancelled {
+			account: 5,
+		}));
+		assert_eq!(Balances::reserved_balance(5), 10);
+
+		// Nor remove it instantly
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritor {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert!(<Inheritors<Test>>::contains_key(&5));
+		run_to_block(31);
+		assert_ok!(Recovery::apply_inheritor_change(RuntimeOrigin::signed(2), 5));
+		System::assert_last_event(RuntimeEvent::Recovery(Event::InheritorRemoved { account: 5 }));
+		assert_eq!(<Inheritors<Test>>::get(&5), None);
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
@@ -18,9 +18,9 @@
 //! Autogenerated weights for `pallet_recovery`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-04-09, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-anb7yjbi-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
 // Executed Command:
@@ -60,6 +60,17 @@ pub trait WeightInfo {
 	fn close_recovery(n: u32, ) -> Weight;
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
+	fn announce_recovery_change(n: u32, ) -> Weight;
+	fn announce_recovery_removal(n: u32, ) -> Weight;
+	fn apply_recovery_change(n: u32, ) -> Weight;
+	fn cancel_recovery_change(n: u32, ) -> Weight;
+	fn set_inheritor() -> Weight;
+	fn remove_inheritor() -> Weight;
+	fn initiate_inheritance() -> Weight;
+	fn claim_inheritance() -> Weight;
+	fn close_inheritance() -> Weight;
+	fn apply_inheritor_change() -> Weight;
+	fn cancel_inheritor_change() -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -73,10 +84,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `497`
+		//  Measured:  `120`
 		//  Estimated: `3997`
-		// Minimum execution time: 15_318_000 picoseconds.
-		Weight::from_parts(15_767_000, 3997)
+		// Minimum execution time: 8_218_000 picoseconds.
+		Weight::from_parts(8_639_000, 3997)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
@@ -85,8 +96,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 7_153_000 picoseconds.
-		Weight::from_parts(7_578_000, 0)
+		// Minimum execution time: 2_982_000 picoseconds.
+		Weight::from_parts(3_193_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -94,12 +105,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn create_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `246`
+		//  Measured:  `5`
 		//  Estimated: `3816`
-		// Minimum execution time: 23_303_000 picoseconds.
-		Weight::from_parts(24_725_158, 3816)
-			// Standard Error: 5_723
-			.saturating_add(Weight::from_parts(13_638, 0).saturating_mul(n.into()))
+		// Minimum execution time: 9_641_000 picoseconds.
+		Weight::from_parts(10_184_633, 3816)
+			// Standard Error: 5_082
+			.saturating_add(Weight::from_parts(100_701, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -109,10 +120,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	fn initiate_recovery() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `343`
+		//  Measured:  `101`
 		//  Estimated: `3854`
-		// Minimum execution time: 26_914_000 picoseconds.
-		Weight::from_parts(28_041_000, 3854)
+		// Minimum execution time: 12_412_000 picoseconds.
+		Weight::from_parts(13_224_000, 3854)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -123,12 +134,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn vouch_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `431 + n * (64 ±0)`
+		//  Measured:  `189 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 17_695_000 picoseconds.
-		Weight::from_parts(18_591_642, 3854)
-			// Standard Error: 5_582
-			.saturating_add(Weight::from_parts(188_668, 0).saturating_mul(n.into()))
+		// Minimum execution time: 8_808_000 picoseconds.
+		Weight::from_parts(9_655_633, 3854)
+			// Standard Error: 16_927
+			.saturating_add(Weight::from_parts(399_759, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -141,12 +152,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `463 + n * (64 ±0)`
+		//  Measured:  `221 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 22_580_000 picoseconds.
-		Weight::from_parts(23_526_020, 3854)
-			// Standard Error: 6_604
-			.saturating_add(Weight::from_parts(134_340, 0).saturating_mul(n.into()))
+		// Minimum execution time: 11_068_000 picoseconds.
+		Weight::from_parts(15_806_109, 3854)
+			// Standard Error: 46_365
+			.saturating_add(Weight::from_parts(985_293, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -155,17 +166,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn close_recovery(n: u32, ) -> Weight {
+	fn close_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `584 + n * (32 ±0)`
+		//  Measured:  `271 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 32_017_000 picoseconds.
-		Weight::from_parts(33_401_086, 3854)
-			// Standard Error: 6_498
-			.saturating_add(Weight::from_parts(95_507, 0).saturating_mul(n.into()))
+		// Minimum execution time: 17_038_000 picoseconds.
+		Weight::from_parts(29_290_431, 3854)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -173,23 +184,184 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `341 + n * (32 ±0)`
+		//  Measured:  `164 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 28_641_000 picoseconds.
-		Weight::from_parts(30_230_511, 3854)
-			// Standard Error: 7_058
-			.saturating_add(Weight::from_parts(61_004, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Minimum execution time: 16_681_000 picoseconds.
+		Weight::from_parts(18_130_762, 3854)
+			// Standard Error: 11_471
+			.saturating_add(Weight::from_parts(317_013, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `352`
+		//  Measured:  `110`
 		//  Estimated: `3545`
-		// Minimum execution time: 11_767_000 picoseconds.
-		Weight::from_parts(12_275_000, 3545)
+		// Minimum execution time: 5_555_000 picoseconds.
+		Weight::from_parts(5_858_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn announce_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `101`
+		//  Estimated: `3821`
+		// Minimum execution time: 11_907_000 picoseconds.
+		Weight::from_parts(13_136_111, 3821)
+			// Standard Error: 8_504
+			.saturating_add(Weight::from_parts(24_839, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn announce_recovery_removal(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (32 ±0)`
+		//  Estimated: `3821`
+		// Minimum execution time: 15_056_000 picoseconds.
+		Weight::from_parts(17_035_907, 3821)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn apply_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 20_795_000 picoseconds.
+		Weight::from_parts(22_199_006, 3854)
+			// Standard Error: 33_669
+			.saturating_add(Weight::from_parts(479_136, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `100 + n * (32 ±0)`
+		//  Estimated: `3821`
+		// Minimum execution time: 10_952_000 picoseconds.
+		Weight::from_parts(13_254_584, 3821)
+			// Standard Error: 27_631
+			.saturating_add(Weight::from_parts(49_026, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn set_inheritor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `3562`
+		// Minimum execution time: 17_538_000 picoseconds.
+		Weight::from_parts(23_039_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn remove_inheritor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `3562`
+		// Minimum execution time: 13_178_000 picoseconds.
+		Weight::from_parts(14_249_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn initiate_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3593`
+		// Minimum execution time: 14_856_000 picoseconds.
+		Weight::from_parts(15_962_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `345`
+		//  Estimated: `3593`
+		// Minimum execution time: 18_299_000 picoseconds.
+		Weight::from_parts(19_522_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn close_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `3593`
+		// Minimum execution time: 16_286_000 picoseconds.
+		Weight::from_parts(16_997_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:0)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritors` (r:1 w:1)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn apply_inheritor_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `3593`
+		// Minimum execution time: 16_924_000 picoseconds.
+		Weight::from_parts(17_700_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn cancel_inheritor_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `128`
+		//  Estimated: `3562`
+		// Minimum execution time: 11_807_000 picoseconds.
+		Weight::from_parts(13_776_000, 3562)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -205,10 +377,10 @@ impl WeightInfo for () {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `497`
+		//  Measured:  `120`
 		//  Estimated: `3997`
-		// Minimum execution time: 15_318_000 picoseconds.
-		Weight::from_parts(15_767_000, 3997)
+		// Minimum execution time: 8_218_000 picoseconds.
+		Weight::from_parts(8_639_000, 3997)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
@@ -217,8 +389,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 7_153_000 picoseconds.
-		Weight::from_parts(7_578_000, 0)
+		// Minimum execution time: 2_982_000 picoseconds.
+		Weight::from_parts(3_193_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -226,12 +398,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn create_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `246`
+		//  Measured:  `5`
 		//  Estimated: `3816`
-		// Minimum execution time: 23_303_000 picoseconds.
-		Weight::from_parts(24_725_158, 3816)
-			// Standard Error: 5_723
-			.saturating_add(Weight::from_parts(13_638, 0).saturating_mul(n.into()))
+		// Minimum execution time: 9_641_000 picoseconds.
+		Weight::from_parts(10_184_633, 3816)
+			// Standard Error: 5_082
+			.saturating_add(Weight::from_parts(100_701, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -241,10 +413,10 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	fn initiate_recovery() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `343`
+		//  Measured:  `101`
 		//  Estimated: `3854`
-		// Minimum execution time: 26_914_000 picoseconds.
-		Weight::from_parts(28_041_000, 3854)
+		// Minimum execution time: 12_412_000 picoseconds.
+		Weight::from_parts(13_224_000, 3854)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -255,12 +427,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn vouch_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `431 + n * (64 ±0)`
+		//  Measured:  `189 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 17_695_000 picoseconds.
-		Weight::from_parts(18_591_642, 3854)
-			// Standard Error: 5_582
-			.saturating_add(Weight::from_parts(188_668, 0).saturating_mul(n.into()))
+		// Minimum execution time: 8_808_000 picoseconds.
+		Weight::from_parts(9_655_633, 3854)
+			// Standard Error: 16_927
+			.saturating_add(Weight::from_parts(399_759, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -273,12 +445,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `463 + n * (64 ±0)`
+		//  Measured:  `221 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 22_580_000 picoseconds.
-		Weight::from_parts(23_526_020, 3854)
-			// Standard Error: 6_604
-			.saturating_add(Weight::from_parts(134_340, 0).saturating_mul(n.into()))
+		// Minimum execution time: 11_068_000 picoseconds.
+		Weight::from_parts(15_806_109, 3854)
+			// Standard Error: 46_365
+			.saturating_add(Weight::from_parts(985_293, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -287,17 +459,17 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn close_recovery(n: u32, ) -> Weight {
+	fn close_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `584 + n * (32 ±0)`
+		//  Measured:  `271 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 32_017_000 picoseconds.
-		Weight::from_parts(33_401_086, 3854)
-			// Standard Error: 6_498
-			.saturating_add(Weight::from_parts(95_507, 0).saturating_mul(n.into()))
+		// Minimum execution time: 17_038_000 picoseconds.
+		Weight::from_parts(29_290_431, 3854)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -305,23 +477,184 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `341 + n * (32 ±0)`
+		//  Measured:  `164 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 28_641_000 picoseconds.
-		Weight::from_parts(30_230_511, 3854)
-			// Standard Error: 7_058
-			.saturating_add(Weight::from_parts(61_004, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Minimum execution time: 16_681_000 picoseconds.
+		Weight::from_parts(18_130_762, 3854)
+			// Standard Error: 11_471
+			.saturating_add(Weight::from_parts(317_013, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `352`
+		//  Measured:  `110`
 		//  Estimated: `3545`
-		// Minimum execution time: 11_767_000 picoseconds.
-		Weight::from_parts(12_275_000, 3545)
+		// Minimum execution time: 5_555_000 picoseconds.
+		Weight::from_parts(5_858_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn announce_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `101`
+		//  Estimated: `3821`
+		// Minimum execution time: 11_907_000 picoseconds.
+		Weight::from_parts(13_136_111, 3821)
+			// Standard Error: 8_504
+			.saturating_add(Weight::from_parts(24_839, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn announce_recovery_removal(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (32 ±0)`
+		//  Estimated: `3821`
+		// Minimum execution time: 15_056_000 picoseconds.
+		Weight::from_parts(17_035_907, 3821)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn apply_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 20_795_000 picoseconds.
+		Weight::from_parts(22_199_006, 3854)
+			// Standard Error: 33_669
+			.saturating_add(Weight::from_parts(479_136, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::PendingRecoveryChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingRecoveryChanges` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_recovery_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `100 + n * (32 ±0)`
+		//  Estimated: `3821`
+		// Minimum execution time: 10_952_000 picoseconds.
+		Weight::from_parts(13_254_584, 3821)
+			// Standard Error: 27_631
+			.saturating_add(Weight::from_parts(49_026, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn set_inheritor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `3562`
+		// Minimum execution time: 17_538_000 picoseconds.
+		Weight::from_parts(23_039_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn remove_inheritor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `3562`
+		// Minimum execution time: 13_178_000 picoseconds.
+		Weight::from_parts(14_249_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn initiate_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3593`
+		// Minimum execution time: 14_856_000 picoseconds.
+		Weight::from_parts(15_962_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritors` (r:1 w:0)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `345`
+		//  Estimated: `3593`
+		// Minimum execution time: 18_299_000 picoseconds.
+		Weight::from_parts(19_522_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:1)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn close_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `3593`
+		// Minimum execution time: 16_286_000 picoseconds.
+		Weight::from_parts(16_997_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveInheritances` (r:1 w:0)
+	/// Proof: `Recovery::ActiveInheritances` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritors` (r:1 w:1)
+	/// Proof: `Recovery::Inheritors` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn apply_inheritor_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `3593`
+		// Minimum execution time: 16_924_000 picoseconds.
+		Weight::from_parts(17_700_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::PendingInheritorChanges` (r:1 w:1)
+	/// Proof: `Recovery::PendingInheritorChanges` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn cancel_inheritor_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `128`
+		//  Estimated: `3562`
+		// Minimum execution time: 11_807_000 picoseconds.
+		Weight::from_parts(13_776_000, 3562)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -0,0 +1,43 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the referenda pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_referenda::{ReferendumIndex, ReferendumPreview};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for previewing referenda.
+	pub trait ReferendaApi<Call, TrackId, PalletsOrigin, DryRunEffects>
+		where
+			Call: Codec,
+			TrackId: Codec,
+			PalletsOrigin: Codec,
+			DryRunEffects: Codec,
+	{
+		/// Returns a preview of the ongoing referendum `index`: its track, the origin its proposal
+		/// would be dispatched with, the call tree of the proposal with nested batches, proxies
+		/// and XCM sends, and the effects of dispatching it against the current state.
+		///
+		/// Returns `None` if the referendum is not ongoing.
+		fn referendum_preview(
+			index: ReferendumIndex,
+		) -> Option<ReferendumPreview<Call, TrackId, PalletsOrigin, DryRunEffects>>;
+	}
+}
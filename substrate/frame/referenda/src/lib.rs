@@ -68,6 +68,7 @@ use codec::{Codec, Encode};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
+	storage::transactional::with_transaction_opaque_err,
 	traits::{
 		schedule::{
 			v3::{Anon as ScheduleAnon, Named as ScheduleNamed},
@@ -82,7 +83,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Bounded, Dispatchable, One, Saturating, Zero},
-	DispatchError, Perbill,
+	DispatchError, Perbill, TransactionOutcome,
 };
 use sp_std::{fmt::Debug, prelude::*};
 
@@ -95,10 +96,11 @@ use self::branch::{BeginDecidingBranch, OneFewerDecidingBranch, ServiceBranch};
 pub use self::{
 	pallet::*,
 	types::{
-		BalanceOf, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit,
-		InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex, ReferendumInfo,
-		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
-		TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
+		BalanceOf, BoundedCallOf, CallKind, CallOf, CallPreview, Curve, DecidingStatus,
+		DecidingStatusOf, Deposit, InsertSorted, InspectCall, NegativeImbalanceOf, PalletsOriginOf,
+		PreimageStatus, ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumPreview,
+		ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf, TrackIdOf, TrackInfo,
+		TrackInfoOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -135,6 +137,39 @@ macro_rules! impl_tracksinfo_get {
 	};
 }
 
+/// Declare `$inspector`, an [`InspectCall`] decomposing the calls of the `Utility`, `Proxy` and
+/// `Whitelist` pallets of the runtime call `$call`, and the `send` call of the XCM pallet
+/// `$xcm_pallet` if given.
+#[macro_export]
+macro_rules! impl_inspect_call {
+	($inspector:ident, $call:ident $(, $xcm_pallet:ident)?) => {
+		/// Decomposes the proposals of referenda into the calls they dispatch.
+		pub struct $inspector;
+		impl $crate::InspectCall<$call> for $inspector {
+			fn inspect(call: &$call) -> ($crate::CallKind, $crate::Vec<$call>) {
+				match call {
+					$call::Utility(pallet_utility::Call::batch { calls }) |
+					$call::Utility(pallet_utility::Call::batch_all { calls }) |
+					$call::Utility(pallet_utility::Call::force_batch { calls }) =>
+						($crate::CallKind::Batch, calls.clone()),
+					$call::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+					$call::Utility(pallet_utility::Call::dispatch_as { call, .. }) |
+					$call::Utility(pallet_utility::Call::with_weight { call, .. }) |
+					$call::Whitelist(
+						pallet_whitelist::Call::dispatch_whitelisted_call_with_preimage { call },
+					) => ($crate::CallKind::Dispatch, $crate::Vec::from([(**call).clone()])),
+					$call::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+					$call::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) =>
+						($crate::CallKind::Proxy, $crate::Vec::from([(**call).clone()])),
+					$($call::$xcm_pallet(pallet_xcm::Call::send { .. }) =>
+						($crate::CallKind::XcmSend, $crate::Vec::new()),)?
+					_ => ($crate::CallKind::Plain, $crate::Vec::new()),
+				}
+			}
+		}
+	};
+}
+
 const ASSEMBLY_ID: LockIdentifier = *b"assembly";
 
 #[frame_support::pallet]
@@ -882,6 +917,63 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Preview the ongoing referendum `index`.
+	///
+	/// The preimage of the proposal is fetched from `T::Preimages`. If it is available, the
+	/// proposal is decomposed into its call tree with `Inspector`, and dispatched against the
+	/// current state with `dry_run`. The state changes of `dry_run` are rolled back.
+	///
+	/// Returns `None` if the referendum is not ongoing.
+	pub fn api_referendum_preview<Inspector, DryRunEffects>(
+		index: ReferendumIndex,
+		dry_run: impl FnOnce(PalletsOriginOf<T>, CallOf<T, I>) -> DryRunEffects,
+	) -> Option<ReferendumPreview<CallOf<T, I>, TrackIdOf<T, I>, PalletsOriginOf<T>, DryRunEffects>>
+	where
+		Inspector: InspectCall<CallOf<T, I>>,
+	{
+		let status = Self::ensure_ongoing(index).ok()?;
+		let mut preview = ReferendumPreview {
+			track: status.track,
+			origin: status.origin.clone(),
+			preimage: PreimageStatus::Available,
+			calls: Vec::new(),
+			dry_run: None,
+		};
+		match T::Preimages::peek(&status.proposal) {
+			Ok((call, _)) => {
+				Self::call_tree::<Inspector>(call.clone(), 0, &mut preview.calls);
+				preview.dry_run = with_transaction_opaque_err(|| {
+					TransactionOutcome::Rollback(Ok::<_, ()>(dry_run(status.origin, call)))
+				})
+				.ok()
+				.and_then(Result::ok);
+			},
+			Err(_) if T::Preimages::have(&status.proposal) =>
+				preview.preimage = PreimageStatus::Undecodable,
+			Err(_) => {
+				let requested = status
+					.proposal
+					.lookup_hash()
+					.map_or(false, |hash| T::Preimages::is_requested(&hash));
+				preview.preimage = PreimageStatus::Missing { requested };
+			},
+		}
+		Some(preview)
+	}
+
+	/// Append `call` and the calls it dispatches to `calls`, in depth-first order.
+	fn call_tree<Inspector: InspectCall<CallOf<T, I>>>(
+		call: CallOf<T, I>,
+		depth: u32,
+		calls: &mut Vec<CallPreview<CallOf<T, I>>>,
+	) {
+		let (kind, inner) = Inspector::inspect(&call);
+		calls.push(CallPreview { depth, kind, call });
+		for call in inner {
+			Self::call_tree::<Inspector>(call, depth.saturating_add(1), calls);
+		}
+	}
+
 	// Enqueue a proposal from a referendum which has presumably passed.
 	fn schedule_enactment(
 		index: ReferendumIndex,
@@ -682,3 +682,91 @@ fn detects_incorrect_len() {
 		);
 	});
 }
+
+#[test]
+fn referendum_preview_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		use frame_support::traits::Bounded;
+		use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash};
+
+		struct SchedulerInspector;
+		impl InspectCall<RuntimeCall> for SchedulerInspector {
+			fn inspect(call: &RuntimeCall) -> (CallKind, Vec<RuntimeCall>) {
+				match call {
+					RuntimeCall::Scheduler(pallet_scheduler::Call::schedule { call, .. }) =>
+						(CallKind::Dispatch, vec![*call.clone()]),
+					_ => (CallKind::Plain, Vec::new()),
+				}
+			}
+		}
+		let dry_run = |origin: OriginCaller, call: RuntimeCall| call.dispatch(origin.into());
+
+		let set_balance = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+			who: 42,
+			new_free: 5,
+		});
+		let schedule = RuntimeCall::Scheduler(pallet_scheduler::Call::schedule {
+			when: 10,
+			maybe_periodic: None,
+			priority: 0,
+			call: Box::new(set_balance.clone()),
+		});
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			<Preimage as StorePreimage>::bound(schedule.clone()).unwrap(),
+			DispatchTime::At(10),
+		));
+		let agenda = pallet_scheduler::Agenda::<Test>::get(10).len();
+		let preview =
+			Referenda::api_referendum_preview::<SchedulerInspector, _>(0, dry_run).unwrap();
+		assert_eq!(preview.track, 0);
+		assert_eq!(preview.origin, OriginCaller::system(RawOrigin::Root));
+		assert_eq!(preview.preimage, PreimageStatus::Available);
+		assert_eq!(
+			preview.calls,
+			vec![
+				CallPreview { depth: 0, kind: CallKind::Dispatch, call: schedule },
+				CallPreview { depth: 1, kind: CallKind::Plain, call: set_balance },
+			]
+		);
+		assert_ok!(preview.dry_run.unwrap());
+		// the state changes of the dry run were rolled back.
+		assert_eq!(pallet_scheduler::Agenda::<Test>::get(10).len(), agenda);
+
+		// the preimage of the proposal was never noted.
+		let hash = BlakeTwo256::hash(&[1u8; 40]);
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			Bounded::Lookup { hash, len: 40 },
+			DispatchTime::At(10),
+		));
+		let preview =
+			Referenda::api_referendum_preview::<SchedulerInspector, _>(1, dry_run).unwrap();
+		assert_eq!(preview.preimage, PreimageStatus::Missing { requested: false });
+		assert!(preview.calls.is_empty());
+		assert!(preview.dry_run.is_none());
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::root(), hash));
+		let preview =
+			Referenda::api_referendum_preview::<SchedulerInspector, _>(1, dry_run).unwrap();
+		assert_eq!(preview.preimage, PreimageStatus::Missing { requested: true });
+
+		// the preimage of the proposal is not a call.
+		let hash = note_preimage(1);
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			Bounded::Lookup { hash, len: 1 },
+			DispatchTime::At(10),
+		));
+		let preview =
+			Referenda::api_referendum_preview::<SchedulerInspector, _>(2, dry_run).unwrap();
+		assert_eq!(preview.preimage, PreimageStatus::Undecodable);
+		assert!(preview.dry_run.is_none());
+
+		// concluded referenda cannot be previewed.
+		assert_ok!(Referenda::kill(RuntimeOrigin::root(), 0));
+		assert!(Referenda::api_referendum_preview::<SchedulerInspector, _>(0, dry_run).is_none());
+	});
+}
@@ -288,6 +288,73 @@ impl<
 	}
 }
 
+/// The kind of a call within the call tree of a proposal.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CallKind {
+	/// A call which does not dispatch any other call.
+	Plain,
+	/// A call dispatching a batch of calls, e.g. `Utility::batch_all`.
+	Batch,
+	/// A call dispatching a call on behalf of a proxied account, e.g. `Proxy::proxy`.
+	Proxy,
+	/// A call dispatching a single call with another origin, e.g. `Utility::dispatch_as`.
+	Dispatch,
+	/// A call sending an XCM message to another consensus system, e.g. `XcmPallet::send`.
+	XcmSend,
+}
+
+/// Something which can decompose a call into the calls it dispatches.
+pub trait InspectCall<Call> {
+	/// Return the kind of `call` along with the calls it dispatches, in dispatch order.
+	fn inspect(call: &Call) -> (CallKind, Vec<Call>);
+}
+
+impl<Call> InspectCall<Call> for () {
+	fn inspect(_: &Call) -> (CallKind, Vec<Call>) {
+		(CallKind::Plain, Vec::new())
+	}
+}
+
+/// A call within the call tree of a proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CallPreview<Call> {
+	/// The depth of the call in the call tree. The proposal itself has a depth of `0`.
+	pub depth: u32,
+	/// The kind of the call.
+	pub kind: CallKind,
+	/// The call.
+	pub call: Call,
+}
+
+/// The availability of the preimage of a proposal.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PreimageStatus {
+	/// The proposal is inlined or its preimage is stored, and it decodes to a call.
+	Available,
+	/// The preimage of the proposal was not noted. `requested` is `true` if the preimage is
+	/// requested, in which case it may be noted without a deposit.
+	Missing { requested: bool },
+	/// The preimage of the proposal is stored but does not decode to a call.
+	Undecodable,
+}
+
+/// A preview of an ongoing referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumPreview<Call, TrackId, PalletsOrigin, DryRunEffects> {
+	/// The track of the referendum.
+	pub track: TrackId,
+	/// The origin the proposal would be dispatched with.
+	pub origin: PalletsOrigin,
+	/// The availability of the preimage of the proposal.
+	pub preimage: PreimageStatus,
+	/// The call tree of the proposal in depth-first order, starting with the proposal itself.
+	/// Empty unless the preimage is available.
+	pub calls: Vec<CallPreview<Call>>,
+	/// The effects of dispatching the proposal against the current state. `None` unless the
+	/// preimage is available.
+	pub dry_run: Option<DryRunEffects>,
+}
+
 /// Type for describing a curve over the 2-dimensional space of axes between 0-1, as represented
 /// by `(Perbill, Perbill)`.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
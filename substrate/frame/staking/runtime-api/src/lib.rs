@@ -20,8 +20,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -35,5 +37,10 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns the unlocking chunks of the stash `account`, with the estimated time at which
+		/// each of them can be withdrawn.
+		#[api_version(2)]
+		fn unlocking_chunks(account: AccountId) -> Vec<sp_staking::UnlockingChunkInfo<Balance>>;
 	}
 }
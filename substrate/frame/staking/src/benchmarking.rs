@@ -32,7 +32,7 @@ use sp_runtime::{
 	traits::{Bounded, One, StaticLookup, TrailingZeroInput, Zero},
 	Perbill, Percent, Saturating,
 };
-use sp_staking::{currency_to_vote::CurrencyToVote, SessionIndex};
+use sp_staking::{currency_to_vote::CurrencyToVote, PagedExposureMetadata, SessionIndex};
 use sp_std::prelude::*;
 
 pub use frame_benchmarking::v1::{
@@ -328,12 +328,16 @@ benchmarks! {
 		ledger.active = ed - One::one();
 		Ledger::<T>::insert(&controller, ledger);
 		CurrentEra::<T>::put(EraIndex::max_value());
+		// the opt-out deposit is returned when the stash is killed.
+		let _ = T::Currency::deposit_creating(&stash, T::AutoPayoutOptOutDeposit::get());
+		Staking::<T>::set_auto_payout(RawOrigin::Signed(controller.clone()).into(), false)?;
 
 		whitelist_account!(controller);
 	}: withdraw_unbonded(RawOrigin::Signed(controller.clone()), s)
 	verify {
 		assert!(!Ledger::<T>::contains_key(controller));
 		assert!(!T::VoterList::contains(&stash));
+		assert!(!AutoPayoutOptOut::<T>::contains_key(&stash));
 	}
 
 	validate {
@@ -473,6 +477,16 @@ benchmarks! {
 		assert_eq!(Payee::<T>::get(&stash), Some(RewardDestination::Account(controller)));
 	}
 
+	set_auto_payout {
+		let (stash, controller) = create_stash_controller::<T>(USER_SEED, 100, RewardDestination::Staked)?;
+		let _ = T::Currency::deposit_creating(&stash, T::AutoPayoutOptOutDeposit::get());
+		assert!(!AutoPayoutOptOut::<T>::contains_key(&stash));
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller), false)
+	verify {
+		assert!(AutoPayoutOptOut::<T>::contains_key(&stash));
+	}
+
 	update_payee {
 		let (stash, controller) = create_stash_controller::<T>(USER_SEED, 100, RewardDestination::Staked)?;
 		Payee::<T>::insert(&stash, {
@@ -634,6 +648,34 @@ benchmarks! {
 		}
 	}
 
+	auto_payout {
+		let v in 0 .. MaxValidators::<T>::get();
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		// validators of a finished era which are all paid out already, such that every one of
+		// them is looked up in full without paying anything out.
+		let era: EraIndex = 0;
+		for i in 0 .. v {
+			let (stash, _) = create_stash_controller::<T>(i, 100, RewardDestination::Staked)?;
+			ErasValidatorPrefs::<T>::insert(era, &stash, ValidatorPrefs::default());
+			ErasStakersOverview::<T>::insert(era, &stash, PagedExposureMetadata {
+				total: Zero::zero(),
+				own: Zero::zero(),
+				nominator_count: 0,
+				page_count: 1,
+			});
+			ClaimedRewards::<T>::insert(era, &stash, vec![0]);
+		}
+		CurrentEra::<T>::put(era + 1);
+		ActiveEra::<T>::put(ActiveEraInfo { index: era + 1, start: None });
+	}: {
+		Staking::<T>::do_auto_payout(Weight::MAX);
+	}
+	verify {
+		assert_eq!(AutoPayoutCursor::<T>::get(), Some((era + 1, None)));
+	}
+
 	rebond {
 		let l in 1 .. T::MaxUnlockingChunks::get() as u32;
 
@@ -682,6 +724,43 @@ benchmarks! {
 		assert!(original_bonded < new_bonded);
 	}
 
+	rebond_chunk {
+		let l in 1 .. T::MaxUnlockingChunks::get() as u32;
+
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = MinNominatorBond::<T>::get()
+			.max(T::Currency::minimum_balance())
+			// we use 100 to play friendly with the list threshold values in the mock
+			.max(100u32.into());
+
+		// setup a worst case list scenario.
+		let scenario = ListScenario::<T>::new(origin_weight, true)?;
+		let dest_weight = scenario.dest_weight;
+
+		// rebond an amount that will give the user dest_weight
+		let rebond_amount = dest_weight - origin_weight;
+
+		let controller = scenario.origin_controller1;
+		let mut staking_ledger = Ledger::<T>::get(controller.clone()).unwrap();
+
+		// the targeted chunk is the last one, so that the whole queue has to be searched.
+		for era in 0 .. l {
+			let value = if era == l - 1 { rebond_amount } else { One::one() };
+			staking_ledger.unlocking.try_push(UnlockChunk { value, era }).unwrap()
+		}
+		Ledger::<T>::insert(controller.clone(), staking_ledger.clone());
+		let original_bonded: BalanceOf<T> = staking_ledger.active;
+
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller.clone()), l - 1, rebond_amount)
+	verify {
+		let ledger = Ledger::<T>::get(&controller).ok_or("ledger not created after")?;
+		assert_eq!(ledger.active, original_bonded + rebond_amount);
+		assert_eq!(ledger.unlocking.len() as u32, l - 1);
+	}
+
 	reap_stash {
 		let s in 1 .. MAX_SPANS;
 		// clean up any existing state.
@@ -701,6 +780,9 @@ benchmarks! {
 			T::Currency::minimum_balance() - One::one(),
 		);
 		Ledger::<T>::insert(&controller, l);
+		// the opt-out deposit is returned when the stash is reaped.
+		let _ = T::Currency::deposit_creating(&stash, T::AutoPayoutOptOutDeposit::get());
+		Staking::<T>::set_auto_payout(RawOrigin::Signed(controller.clone()).into(), false)?;
 
 		assert!(Bonded::<T>::contains_key(&stash));
 		assert!(T::VoterList::contains(&stash));
@@ -710,6 +792,7 @@ benchmarks! {
 	verify {
 		assert!(!Bonded::<T>::contains_key(&stash));
 		assert!(!T::VoterList::contains(&stash));
+		assert!(!AutoPayoutOptOut::<T>::contains_key(&stash));
 	}
 
 	new_era {
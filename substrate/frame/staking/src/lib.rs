@@ -120,6 +120,10 @@
 //! nominators. To pay out all nominators, `payout_stakers` must be called once for each available
 //! page. Paging exists to limit the i/o cost to mutate storage for each nominator's account.
 //!
+//! Pending pages are also paid out automatically in `on_idle`, as long as the block has weight
+//! left. The eras closest to expiry are paid out first, and a validator can opt out of this with
+//! the `set_auto_payout` call.
+//!
 //! Slashing can occur at any point in time, once misbehavior is reported. Once slashing is
 //! determined, a value is deducted from the balance of the validator and all the nominators who
 //! voted for this validator (values are deducted from the _stash_ account of the slashed entity).
@@ -564,6 +568,24 @@ impl<T: Config> StakingLedger<T> {
 		(self, unlocking_balance)
 	}
 
+	/// Re-bond up to `value` of the unlocking chunk scheduled for `era`.
+	///
+	/// The chunk is removed once it is fully re-bonded. Returns the updated ledger and the amount
+	/// that was re-bonded, or `None` if there is no chunk for `era`.
+	fn rebond_chunk(mut self, era: EraIndex, value: BalanceOf<T>) -> Option<(Self, BalanceOf<T>)> {
+		let index = self.unlocking.iter().position(|chunk| chunk.era == era)?;
+		let chunk = &mut self.unlocking[index];
+		let rebonded = value.min(chunk.value);
+
+		chunk.value -= rebonded;
+		if chunk.value.is_zero() {
+			self.unlocking.remove(index);
+		}
+		self.active += rebonded;
+
+		Some((self, rebonded))
+	}
+
 	/// Slash the staker for a given amount of balance.
 	///
 	/// This implements a proportional slashing system, whereby we set our preference to slash as
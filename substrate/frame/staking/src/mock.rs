@@ -116,6 +116,7 @@ parameter_types! {
 	pub static Period: BlockNumber = 5;
 	pub static Offset: BlockNumber = 0;
 	pub static MaxControllersInDeprecationBatch: u32 = 5900;
+	pub static AutoPayoutOptOutDeposit: Balance = 10;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -283,6 +284,7 @@ impl crate::pallet::pallet::Config for Test {
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type HistoryDepth = HistoryDepth;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type AutoPayoutOptOutDeposit = AutoPayoutOptOutDeposit;
 	type EventListeners = EventListenerMock;
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy<DISABLING_LIMIT_FACTOR>;
 }
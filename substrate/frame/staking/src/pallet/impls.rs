@@ -26,9 +26,11 @@ use frame_support::{
 	defensive,
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		Currency, Defensive, DefensiveSaturating, EstimateNextNewSession, Get, Imbalance,
-		InspectLockableCurrency, Len, LockableCurrency, OnUnbalanced, TryCollect, UnixTime,
+		InspectLockableCurrency, Len, LockableCurrency, OnUnbalanced, ReservableCurrency,
+		TryCollect, UnixTime,
 	},
 	weights::Weight,
 };
@@ -46,7 +48,7 @@ use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
 	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, UnlockingChunkInfo,
 };
 use sp_std::prelude::*;
 
@@ -392,6 +394,102 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
+	/// Pay out pending reward pages of past eras, as far as `remaining_weight` allows.
+	///
+	/// Eras are processed from the oldest one still in history, i.e. the one closest to expiry,
+	/// up to the last finished era. Validators in [`AutoPayoutOptOut`] are skipped. The progress
+	/// is kept in [`AutoPayoutCursor`], such that every era is only iterated once.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn do_auto_payout(remaining_weight: Weight) -> Weight {
+		// Reading the eras and reading and writing `AutoPayoutCursor`.
+		let base_weight = T::WeightInfo::auto_payout(0);
+		if remaining_weight.any_lt(base_weight) {
+			return Weight::zero()
+		}
+		let mut consumed = base_weight;
+
+		let (Some(active_era), Some(current_era)) = (ActiveEra::<T>::get(), CurrentEra::<T>::get())
+		else {
+			return consumed
+		};
+		let oldest_era = current_era.saturating_sub(T::HistoryDepth::get());
+		let (mut era, mut last_validator) = match AutoPayoutCursor::<T>::get() {
+			Some((era, last_validator)) if era >= oldest_era => (era, last_validator),
+			_ => (oldest_era, None),
+		};
+
+		// Iterating a validator and looking up its next claimable page.
+		let validator_weight = T::WeightInfo::auto_payout(1).saturating_sub(base_weight);
+		let page_weight = T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+			// Looking up the next claimable page afterwards.
+			.saturating_add(validator_weight);
+		let fits = |consumed: Weight, extra: Weight| {
+			consumed.saturating_add(extra).all_lte(remaining_weight)
+		};
+
+		'eras: while era < active_era.index {
+			let validators = match &last_validator {
+				Some(last) => ErasValidatorPrefs::<T>::iter_key_prefix_from(
+					era,
+					ErasValidatorPrefs::<T>::hashed_key_for(era, last),
+				),
+				None => ErasValidatorPrefs::<T>::iter_key_prefix(era),
+			};
+
+			for validator in validators {
+				if !fits(consumed, validator_weight.saturating_add(page_weight)) {
+					break 'eras
+				}
+				consumed.saturating_accrue(validator_weight);
+
+				if !AutoPayoutOptOut::<T>::contains_key(&validator) {
+					while let Some(page) = Self::next_auto_payout_page(era, &validator) {
+						if !fits(consumed, page_weight) {
+							break 'eras
+						}
+
+						let result = with_storage_layer(|| {
+							Self::do_payout_stakers_by_page(validator.clone(), era, page)
+						});
+						let actual_weight = match &result {
+							Ok(post_info) => post_info.actual_weight,
+							Err(err) => err.post_info.actual_weight,
+						};
+						consumed.saturating_accrue(
+							actual_weight
+								.map_or(page_weight, |w| w.saturating_add(validator_weight)),
+						);
+
+						if result.is_err() {
+							// nothing more can be paid out for this validator in this era.
+							break
+						}
+						Self::deposit_event(Event::<T>::AutoPayoutProcessed {
+							era_index: era,
+							validator_stash: validator.clone(),
+							page,
+						});
+					}
+				}
+
+				last_validator = Some(validator);
+			}
+
+			era.saturating_inc();
+			last_validator = None;
+		}
+
+		AutoPayoutCursor::<T>::put((era, last_validator));
+		consumed
+	}
+
+	/// The next page of `validator` in `era` to pay out automatically, if any.
+	fn next_auto_payout_page(era: EraIndex, validator: &T::AccountId) -> Option<Page> {
+		let ledger = Self::ledger(Stash(validator.clone())).ok()?;
+		EraInfo::<T>::get_next_claimable_page(era, validator, &ledger)
+	}
+
 	/// Chill a stash account.
 	pub(crate) fn chill_stash(stash: &T::AccountId) {
 		let chilled_as_validator = Self::do_remove_validator(stash);
@@ -575,6 +673,7 @@ impl<T: Config> Pallet<T> {
 
 			let era_duration = (now_as_millis_u64.defensive_saturating_sub(active_era_start))
 				.saturated_into::<u64>();
+			LastEraDuration::<T>::put(era_duration);
 			let staked = Self::eras_total_stake(&active_era.index);
 			let issuance = T::Currency::total_issuance();
 
@@ -797,6 +896,10 @@ impl<T: Config> Pallet<T> {
 		Self::do_remove_validator(&stash);
 		Self::do_remove_nominator(&stash);
 
+		if let Some(deposit) = AutoPayoutOptOut::<T>::take(&stash) {
+			T::Currency::unreserve(&stash, deposit);
+		}
+
 		frame_system::Pallet::<T>::dec_consumers(&stash);
 
 		Ok(())
@@ -1185,6 +1288,42 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	/// The unlocking chunks of the stash `account`, with an estimate of when each of them can be
+	/// withdrawn.
+	///
+	/// A chunk unbonded in era `e` can be withdrawn once era `e + BondingDuration` is planned,
+	/// which is estimated from the start of the active era and the duration of the last era.
+	pub fn api_unlocking_chunks(account: T::AccountId) -> Vec<UnlockingChunkInfo<BalanceOf<T>>> {
+		let Ok(ledger) = Self::ledger(Stash(account)) else { return Vec::new() };
+
+		let current_era = CurrentEra::<T>::get().unwrap_or_default();
+		let now = T::UnixTime::now().as_millis().saturated_into::<u64>();
+		let active_era = ActiveEra::<T>::get()
+			.and_then(|active_era| Some((active_era.index, active_era.start?)));
+		// before the first era ends, the best guess is how long the active era lasted so far.
+		let era_duration = LastEraDuration::<T>::get()
+			.or_else(|| active_era.map(|(_, start)| now.saturating_sub(start)))
+			.filter(|duration| !duration.is_zero());
+
+		ledger
+			.unlocking
+			.iter()
+			.map(|chunk| {
+				let (unlock_at, withdrawable) = if chunk.era <= current_era {
+					(Some(now), chunk.value)
+				} else {
+					let unlock_at =
+						active_era.zip(era_duration).map(|((index, start), duration)| {
+							let eras_left = chunk.era.saturating_sub(index) as u64;
+							start.saturating_add(duration.saturating_mul(eras_left)).max(now)
+						});
+					(unlock_at, Zero::zero())
+				};
+				UnlockingChunkInfo { era: chunk.era, value: chunk.value, unlock_at, withdrawable }
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -25,7 +25,8 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		Currency, Defensive, DefensiveSaturating, EnsureOrigin, EstimateNextNewSession, Get,
-		InspectLockableCurrency, LockableCurrency, OnUnbalanced, UnixTime, WithdrawReasons,
+		InspectLockableCurrency, LockableCurrency, OnUnbalanced, ReservableCurrency, UnixTime,
+		WithdrawReasons,
 	},
 	weights::Weight,
 	BoundedVec,
@@ -94,7 +95,8 @@ pub mod pallet {
 				Self::AccountId,
 				Moment = BlockNumberFor<Self>,
 				Balance = Self::CurrencyBalance,
-			> + InspectLockableCurrency<Self::AccountId>;
+			> + InspectLockableCurrency<Self::AccountId>
+			+ ReservableCurrency<Self::AccountId>;
 		/// Just the `Currency::Balance` type; we have this item to allow us to constrain it to
 		/// `From<u64>`.
 		type CurrencyBalance: sp_runtime::traits::AtLeast32BitUnsigned
@@ -285,6 +287,12 @@ pub mod pallet {
 		/// The maximum amount of controller accounts that can be deprecated in one call.
 		type MaxControllersInDeprecationBatch: Get<u32>;
 
+		/// The deposit reserved from the stash of a validator which opts out of the automatic
+		/// payout of its rewards, for the [`AutoPayoutOptOut`] entry.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type AutoPayoutOptOutDeposit: Get<BalanceOf<Self>>;
+
 		/// Something that listens to staking updates and performs actions based on the data it
 		/// receives.
 		///
@@ -341,6 +349,7 @@ pub mod pallet {
 			type MaxExposurePageSize = ConstU32<64>;
 			type MaxUnlockingChunks = ConstU32<32>;
 			type MaxControllersInDeprecationBatch = ConstU32<100>;
+			type AutoPayoutOptOutDeposit = ();
 			type EventListeners = ();
 			type DisablingStrategy = crate::UpToLimitDisablingStrategy;
 			#[cfg(feature = "std")]
@@ -735,6 +744,25 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ChillThreshold<T: Config> = StorageValue<_, Percent, OptionQuery>;
 
+	/// The duration of the last era that ended, in milliseconds.
+	///
+	/// Used to estimate when unlocking chunks can be withdrawn.
+	#[pallet::storage]
+	pub(crate) type LastEraDuration<T> = StorageValue<_, u64, OptionQuery>;
+
+	/// Validators whose rewards are not paid out automatically in `on_idle`, along with the
+	/// deposit reserved from their stash.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AutoPayoutOptOut<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>>;
+
+	/// The progress of the automatic payout: the era being paid out and the last validator of
+	/// that era whose pending pages have all been paid out.
+	#[pallet::storage]
+	pub(crate) type AutoPayoutCursor<T: Config> =
+		StorageValue<_, (EraIndex, Option<T::AccountId>), OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -863,6 +891,10 @@ pub mod pallet {
 		ForceEra { mode: Forcing },
 		/// Report of a controller batch deprecation.
 		ControllerBatchDeprecated { failures: u32 },
+		/// A page of rewards has been paid out automatically.
+		AutoPayoutProcessed { era_index: EraIndex, validator_stash: T::AccountId, page: Page },
+		/// A validator has opted in or out of the automatic payout of its rewards.
+		AutoPayoutSet { stash: T::AccountId, enabled: bool },
 	}
 
 	#[pallet::error]
@@ -957,6 +989,10 @@ pub mod pallet {
 			// `on_finalize` weight is tracked in `on_initialize`
 		}
 
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_payout(remaining_weight)
+		}
+
 		fn integrity_test() {
 			// ensure that we funnel the correct value to the `DataProvider::MaxVotesPerVoter`;
 			assert_eq!(
@@ -2152,6 +2188,79 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Opt in or out of the automatic payout of rewards.
+		///
+		/// Unless opted out, the pending reward pages of a validator are paid out in `on_idle`,
+		/// starting with the eras closest to expiry. Rewards of opted out validators must be
+		/// claimed with `payout_stakers` or `payout_stakers_by_page`.
+		///
+		/// Opting out reserves [`Config::AutoPayoutOptOutDeposit`] from the stash, which is
+		/// returned when opting in again or when the stash is removed.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller.
+		///
+		/// Emits `AutoPayoutSet`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_auto_payout())]
+		pub fn set_auto_payout(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+
+			if enabled {
+				if let Some(deposit) = AutoPayoutOptOut::<T>::take(&ledger.stash) {
+					T::Currency::unreserve(&ledger.stash, deposit);
+				}
+			} else if !AutoPayoutOptOut::<T>::contains_key(&ledger.stash) {
+				let deposit = T::AutoPayoutOptOutDeposit::get();
+				T::Currency::reserve(&ledger.stash, deposit)?;
+				AutoPayoutOptOut::<T>::insert(&ledger.stash, deposit);
+			}
+
+			Self::deposit_event(Event::<T>::AutoPayoutSet { stash: ledger.stash, enabled });
+			Ok(())
+		}
+
+		/// Rebond a portion of the unlocking chunk scheduled for `era`.
+		///
+		/// Unlike `rebond`, which always starts with the chunks unlocking last, this targets a
+		/// single chunk. At most the value of that chunk is rebonded, and the chunk is removed once
+		/// nothing is left of it.
+		///
+		/// The dispatch origin must be signed by the controller.
+		///
+		/// ## Complexity
+		/// - Time complexity: O(L), where L is unlocking chunks
+		/// - Bounded by `MaxUnlockingChunks`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::rebond_chunk(T::MaxUnlockingChunks::get()))]
+		pub fn rebond_chunk(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+
+			let (ledger, rebonded_value) =
+				ledger.rebond_chunk(era, value).ok_or(Error::<T>::NoUnlockChunk)?;
+			// Last check: the new active amount of ledger must be more than ED.
+			ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+
+			Self::deposit_event(Event::<T>::Bonded {
+				stash: ledger.stash.clone(),
+				amount: rebonded_value,
+			});
+
+			let stash = ledger.stash.clone();
+			// NOTE: ledger must be updated prior to calling `Self::weight_of`.
+			ledger.update()?;
+			if T::VoterList::contains(&stash) {
+				let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
+			}
+
+			Ok(())
+		}
 	}
 }
 
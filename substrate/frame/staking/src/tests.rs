@@ -39,7 +39,7 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	SessionIndex, UnlockingChunkInfo,
 };
 use sp_std::prelude::*;
 use substrate_test_utils::assert_eq_uvec;
@@ -1768,6 +1768,125 @@ fn rebond_emits_right_value_in_event() {
 	});
 }
 
+#[test]
+fn rebond_chunk_targets_a_single_chunk() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// Give account 11 some large free balance greater than total
+		let _ = Balances::make_free_balance_be(&11, 1000000);
+
+		mock::start_active_era(2);
+		Staking::unbond(RuntimeOrigin::signed(11), 400).unwrap();
+		mock::start_active_era(3);
+		Staking::unbond(RuntimeOrigin::signed(11), 300).unwrap();
+		mock::start_active_era(4);
+		Staking::unbond(RuntimeOrigin::signed(11), 200).unwrap();
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 100);
+
+		// Re-bond part of the chunk in the middle of the queue.
+		assert_ok!(Staking::rebond_chunk(RuntimeOrigin::signed(11), 3 + 3, 100));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 200,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 400, era: 2 + 3 },
+					UnlockChunk { value: 200, era: 3 + 3 },
+					UnlockChunk { value: 200, era: 4 + 3 },
+				],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(*staking_events().last().unwrap(), Event::Bonded { stash: 11, amount: 100 });
+
+		// Re-bonding more than the chunk holds only re-bonds the chunk, and removes it.
+		assert_ok!(Staking::rebond_chunk(RuntimeOrigin::signed(11), 2 + 3, 1000));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 600,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 200, era: 3 + 3 },
+					UnlockChunk { value: 200, era: 4 + 3 },
+				],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(*staking_events().last().unwrap(), Event::Bonded { stash: 11, amount: 400 });
+
+		// There is no chunk for this era.
+		assert_noop!(
+			Staking::rebond_chunk(RuntimeOrigin::signed(11), 2 + 3, 100),
+			Error::<Test>::NoUnlockChunk
+		);
+		// Only the controller can rebond.
+		assert_noop!(
+			Staking::rebond_chunk(RuntimeOrigin::signed(10), 3 + 3, 100),
+			Error::<Test>::NotController
+		);
+	})
+}
+
+#[test]
+fn unlocking_chunks_api_estimates_unlock_time() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// no ledger, no chunks.
+		assert!(Staking::api_unlocking_chunks(1337).is_empty());
+
+		mock::start_active_era(1);
+		assert_eq!(LastEraDuration::<Test>::get(), Some(reward_time_per_era()));
+
+		Staking::unbond(RuntimeOrigin::signed(11), 400).unwrap();
+		mock::start_active_era(2);
+		Staking::unbond(RuntimeOrigin::signed(11), 300).unwrap();
+
+		// the start of the active era is not known until the first block of it is finalized.
+		assert!(Staking::api_unlocking_chunks(11).iter().all(|chunk| chunk.unlock_at.is_none()));
+		run_to_block(System::block_number() + 1);
+
+		let era_duration = LastEraDuration::<Test>::get().unwrap();
+		let active_era_start = ActiveEra::<Test>::get().unwrap().start.unwrap();
+		assert_eq!(
+			Staking::api_unlocking_chunks(11),
+			vec![
+				UnlockingChunkInfo {
+					era: 1 + 3,
+					value: 400,
+					unlock_at: Some(active_era_start + 2 * era_duration),
+					withdrawable: 0,
+				},
+				UnlockingChunkInfo {
+					era: 2 + 3,
+					value: 300,
+					unlock_at: Some(active_era_start + 3 * era_duration),
+					withdrawable: 0,
+				},
+			]
+		);
+
+		// once the bonding duration has passed, the first chunk can be withdrawn right away.
+		mock::start_active_era(4);
+		run_to_block(System::block_number() + 1);
+		let now = Timestamp::get();
+		let chunks = Staking::api_unlocking_chunks(11);
+		assert_eq!(
+			chunks[0],
+			UnlockingChunkInfo { era: 1 + 3, value: 400, unlock_at: Some(now), withdrawable: 400 },
+		);
+		assert_eq!(chunks[1].withdrawable, 0);
+		assert_eq!(
+			chunks[1].unlock_at,
+			Some(ActiveEra::<Test>::get().unwrap().start.unwrap() + era_duration)
+		);
+
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+		assert_eq!(Staking::api_unlocking_chunks(11).len(), 1);
+	})
+}
+
 #[test]
 fn max_staked_rewards_default_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -4831,6 +4950,162 @@ fn offences_weight_calculated_correctly() {
 	});
 }
 
+fn auto_payout_events() -> Vec<(EraIndex, AccountId, Page)> {
+	staking_events_since_last_call()
+		.into_iter()
+		.filter_map(|e| match e {
+			Event::AutoPayoutProcessed { era_index, validator_stash, page } =>
+				Some((era_index, validator_stash, page)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn auto_payout_pays_out_oldest_eras_first() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, balance);
+		for i in 0..100 {
+			bond_nominator(1000 + i, balance + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(3);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		let _ = staking_events_since_last_call();
+
+		let pre_payout_total_issuance = Balances::total_issuance();
+		Staking::on_idle(System::block_number(), Weight::MAX);
+
+		// both pages of the older era are paid out first.
+		assert_eq!(auto_payout_events(), vec![(1, 11, 0), (1, 11, 1), (2, 11, 0), (2, 11, 1)]);
+		assert!(Balances::total_issuance() > pre_payout_total_issuance);
+		assert_eq!(ClaimedRewards::<Test>::get(1, &11), vec![0, 1]);
+		assert_eq!(ClaimedRewards::<Test>::get(2, &11), vec![0, 1]);
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		// nothing left to pay out, until the active era is over.
+		Staking::on_idle(System::block_number(), Weight::MAX);
+		assert!(auto_payout_events().is_empty());
+
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(4);
+		Staking::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(auto_payout_events(), vec![(3, 11, 0), (3, 11, 1)]);
+	});
+}
+
+#[test]
+fn auto_payout_is_weight_bounded() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, balance);
+		for i in 0..100 {
+			bond_nominator(1000 + i, balance + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		// not even enough weight to read the cursor.
+		assert_eq!(Staking::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert!(auto_payout_events().is_empty());
+
+		// exactly enough weight for a single page, and looking up the validator before and after.
+		let base_weight = <Test as Config>::WeightInfo::auto_payout(0);
+		let validator_weight = <Test as Config>::WeightInfo::auto_payout(1) - base_weight;
+		let page_weight =
+			<Test as Config>::WeightInfo::payout_stakers_alive_staked(MaxExposurePageSize::get());
+		let one_page = base_weight + validator_weight * 2 + page_weight;
+
+		assert_eq!(Staking::on_idle(System::block_number(), one_page), one_page);
+		assert_eq!(auto_payout_events(), vec![(1, 11, 0)]);
+
+		assert!(Staking::on_idle(System::block_number(), one_page).all_lte(one_page));
+		assert_eq!(auto_payout_events(), vec![(1, 11, 1)]);
+
+		Staking::on_idle(System::block_number(), one_page);
+		assert!(auto_payout_events().is_empty());
+	});
+}
+
+#[test]
+fn auto_payout_skips_opted_out_validators() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			Staking::set_auto_payout(RuntimeOrigin::signed(1337), false),
+			Error::<Test>::NotController
+		);
+		// 11 has all of its balance bonded, give it some for the deposit.
+		let _ = Balances::deposit_creating(&11, AutoPayoutOptOutDeposit::get());
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(11), false));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::AutoPayoutSet { stash: 11, enabled: false }
+		);
+
+		mock::start_active_era(1);
+		reward_all_elected();
+		mock::start_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		Staking::on_idle(System::block_number(), Weight::MAX);
+		let paid_out = auto_payout_events();
+		assert!(paid_out.contains(&(1, 21, 0)));
+		assert!(paid_out.iter().all(|(_, validator, _)| *validator != 11));
+		assert!(ClaimedRewards::<Test>::get(1, &11).is_empty());
+
+		// opted out validators can still be paid out manually.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+
+		// opting in again pays out future eras automatically.
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(11), true));
+		assert!(!AutoPayoutOptOut::<Test>::contains_key(&11));
+		reward_all_elected();
+		mock::start_active_era(3);
+		let _ = staking_events_since_last_call();
+		Staking::on_idle(System::block_number(), Weight::MAX);
+		assert!(auto_payout_events().contains(&(2, 11, 0)));
+	});
+}
+
+#[test]
+fn auto_payout_opt_out_reserves_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		AutoPayoutOptOutDeposit::set(10);
+		assert_eq!(Balances::reserved_balance(&21), 0);
+
+		// opting out reserves the deposit once.
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(21), false));
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(21), false));
+		assert_eq!(Balances::reserved_balance(&21), 10);
+		assert_eq!(AutoPayoutOptOut::<Test>::get(&21), Some(10));
+
+		// opting in again returns it, even if the deposit changed in the meantime.
+		AutoPayoutOptOutDeposit::set(20);
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(21), true));
+		assert_eq!(Balances::reserved_balance(&21), 0);
+		assert!(!AutoPayoutOptOut::<Test>::contains_key(&21));
+
+		// the deposit is returned when the stash is killed.
+		assert_ok!(Staking::set_auto_payout(RuntimeOrigin::signed(21), false));
+		assert_eq!(Balances::reserved_balance(&21), 20);
+		assert_ok!(Staking::force_unstake(RuntimeOrigin::root(), 21, 0));
+		assert_eq!(Balances::reserved_balance(&21), 0);
+		assert!(!AutoPayoutOptOut::<Test>::contains_key(&21));
+	});
+}
+
 #[test]
 fn payout_to_any_account_works() {
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
@@ -7988,7 +8263,7 @@ mod ledger_recovery {
 			assert_eq!(Balances::balance_locked(crate::STAKING_ID, &333), lock_333_before); // OK
 			assert_eq!(Bonded::<Test>::get(&333), Some(444)); // OK
 			assert!(Payee::<Test>::get(&333).is_some()); // OK
-											 // however, ledger associated with its controller was killed.
+												// however, ledger associated with its controller was killed.
 			assert!(Ledger::<Test>::get(&444).is_none()); // NOK
 
 			// side effects on 444 - ledger, bonded, payee, lock should be completely removed.
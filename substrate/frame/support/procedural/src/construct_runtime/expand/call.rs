@@ -137,6 +137,17 @@ pub fn expand_outer_dispatch(
 			}
 		}
 
+		impl #scrate::dispatch::CheckFeatureFlags for RuntimeCall {
+			fn features_enabled(&self) -> bool {
+				match self {
+					#(
+						#pallet_attrs
+						#variant_patterns => call.features_enabled(),
+					)*
+				}
+			}
+		}
+
 		impl #scrate::traits::GetCallMetadata for RuntimeCall {
 			fn get_call_metadata(&self) -> #scrate::traits::CallMetadata {
 				use #scrate::traits::GetCallName;
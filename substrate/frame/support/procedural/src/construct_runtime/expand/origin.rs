@@ -145,10 +145,14 @@ pub fn expand_outer_origin(
 			}
 
 			fn reset_filter(&mut self) {
-				let filter = <
-					<#runtime as #system_path::Config>::BaseCallFilter
-					as #scrate::traits::Contains<<#runtime as #system_path::Config>::RuntimeCall>
-				>::contains;
+				// Calls gated by disabled feature flags are filtered in addition to the
+				// `BaseCallFilter`.
+				let filter = |call: &<#runtime as #system_path::Config>::RuntimeCall| {
+					<
+						<#runtime as #system_path::Config>::BaseCallFilter
+						as #scrate::traits::Contains<<#runtime as #system_path::Config>::RuntimeCall>
+					>::contains(call) && #scrate::dispatch::CheckFeatureFlags::features_enabled(call)
+				};
 
 				self.filter = #scrate::__private::sp_std::rc::Rc::new(Box::new(filter));
 			}
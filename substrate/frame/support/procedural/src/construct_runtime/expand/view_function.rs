@@ -0,0 +1,86 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::construct_runtime::Pallet;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use std::str::FromStr;
+
+/// Expands the dispatching of view functions to the pallets of the runtime.
+pub fn expand_outer_view_function(
+	runtime_name: &Ident,
+	pallet_decls: &[Pallet],
+	scrate: &TokenStream2,
+) -> TokenStream2 {
+	let prefix = quote!(#scrate::view_functions);
+
+	let pallet_names = pallet_decls.iter().map(|decl| &decl.name).collect::<Vec<_>>();
+	let pallet_attrs = pallet_decls
+		.iter()
+		.map(|decl| {
+			decl.cfg_pattern.iter().fold(TokenStream2::new(), |acc, pattern| {
+				let attr = TokenStream2::from_str(&format!("#[cfg({})]", pattern.original()))
+					.expect("was successfully parsed before; qed");
+				quote! {
+					#acc
+					#attr
+				}
+			})
+		})
+		.collect::<Vec<_>>();
+
+	quote! {
+		/// Dispatches view functions to the pallets of the runtime.
+		pub struct RuntimeViewFunction;
+
+		impl #prefix::DispatchViewFunction for RuntimeViewFunction {
+			fn dispatch_view_function<O: #scrate::__private::codec::Output>(
+				id: &#prefix::ViewFunctionId,
+				input: &mut &[u8],
+				output: &mut O,
+			) -> Result<(), #prefix::ViewFunctionDispatchError> {
+				#(
+					#pallet_attrs
+					if id.prefix == <#pallet_names as #prefix::ViewFunctionIdPrefix>::prefix() {
+						return <#pallet_names as #prefix::DispatchViewFunction>::dispatch_view_function(
+							id, input, output,
+						)
+					}
+				)*
+
+				Err(#prefix::ViewFunctionDispatchError::NotFound(id.clone()))
+			}
+		}
+
+		impl #runtime_name {
+			/// Execute a view function, to be used by the implementation of the
+			/// `RuntimeViewFunction` runtime API.
+			pub fn execute_view_function(
+				id: #prefix::ViewFunctionId,
+				input: #scrate::__private::sp_std::vec::Vec<u8>,
+			) -> Result<#scrate::__private::sp_std::vec::Vec<u8>, #prefix::ViewFunctionDispatchError> {
+				let mut output = #scrate::__private::sp_std::vec::Vec::new();
+				<RuntimeViewFunction as #prefix::DispatchViewFunction>::dispatch_view_function(
+					&id,
+					&mut &input[..],
+					&mut output,
+				)?;
+				Ok(output)
+			}
+		}
+	}
+}
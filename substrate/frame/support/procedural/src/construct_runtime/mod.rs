@@ -408,6 +408,7 @@ fn construct_runtime_final_expansion(
 		&system_pallet.path,
 	);
 	let outer_config = expand::expand_outer_config(&name, &pallets, &scrate);
+	let view_function = expand::expand_outer_view_function(&name, &pallets, &scrate);
 	let inherent =
 		expand::expand_outer_inherent(&name, &block, &unchecked_extrinsic, &pallets, &scrate);
 	let validate_unsigned = expand::expand_outer_validate_unsigned(&name, &pallets, &scrate);
@@ -495,6 +496,8 @@ fn construct_runtime_final_expansion(
 
 		#outer_config
 
+		#view_function
+
 		#inherent
 
 		#validate_unsigned
@@ -112,7 +112,27 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	}
 	debug_assert_eq!(fn_weight.len(), methods.len());
 
-	let fn_doc = methods.iter().map(|method| &method.docs).collect::<Vec<_>>();
+	let fn_doc = methods
+		.iter()
+		.map(|method| {
+			let mut docs = method.docs.clone();
+			if !method.feature_flags.is_empty() {
+				// Show the flags gating the call in the metadata, so that UIs can look them up in
+				// the dynamic parameters.
+				let flags = method
+					.feature_flags
+					.iter()
+					.map(|flag| format!("`{}`", flag.name))
+					.collect::<Vec<_>>();
+				let doc = format!(" Gated by the feature flags: {}.", flags.join(", "));
+				if !docs.is_empty() {
+					docs.push(syn::parse_quote!(""));
+				}
+				docs.push(syn::parse_quote!(#doc));
+			}
+			docs
+		})
+		.collect::<Vec<_>>();
 
 	let args_name = methods
 		.iter()
@@ -245,6 +265,33 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		});
 
+	// The filter reads each flag of a call from storage before it is dispatched.
+	let feature_flags_weight = methods.iter().map(|method| {
+		let reads = method.feature_flags.len() as u64;
+		if reads == 0 {
+			quote::quote!()
+		} else {
+			quote::quote!(
+				let __pallet_weight = __pallet_weight.saturating_add(
+					<
+						<T as #frame_system::Config>::DbWeight as #frame_support::traits::Get<_>
+					>::get().reads(#reads)
+				);
+			)
+		}
+	});
+
+	let features_enabled_result = methods.iter().map(|method| {
+		if method.feature_flags.is_empty() {
+			quote::quote!(true)
+		} else {
+			let flag_types = method.feature_flags.iter().map(|flag| &flag.type_);
+			quote::quote!(
+				#( <#flag_types as #frame_support::traits::Get<bool>>::get() )&&*
+			)
+		}
+	});
+
 	quote::quote_spanned!(span =>
 		#[doc(hidden)]
 		mod warnings {
@@ -333,6 +380,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 							let __pallet_weight = <
 								dyn #frame_support::dispatch::WeighData<( #( & #args_type, )* )>
 							>::weigh_data(&__pallet_base_weight, ( #( #args_name, )* ));
+							#feature_flags_weight
 
 							let __pallet_class = <
 								dyn #frame_support::dispatch::ClassifyDispatch<
@@ -356,6 +404,22 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		impl<#type_impl_gen> #frame_support::dispatch::CheckFeatureFlags for #call_ident<#type_use_gen>
+			#where_clause
+		{
+			fn features_enabled(&self) -> bool {
+				match *self {
+					#(
+						#cfg_attrs
+						Self::#fn_name { .. } => {
+							#features_enabled_result
+						},
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+		}
+
 		impl<#type_impl_gen> #frame_support::dispatch::CheckIfFeeless for #call_ident<#type_use_gen>
 			#where_clause
 		{
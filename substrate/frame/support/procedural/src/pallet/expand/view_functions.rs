@@ -0,0 +1,202 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::{parse::view_functions::ViewFunctionDef, Def};
+use proc_macro2::{Span, TokenStream};
+
+/// Expand the view functions of the pallet:
+/// * a struct per view function holding its arguments, implementing `ViewFunction`,
+/// * `ViewFunctionIdPrefix` and `DispatchViewFunction` for the pallet,
+/// * the `pallet_view_functions_metadata` function.
+pub fn expand_view_functions(def: &Def) -> TokenStream {
+	let frame_support = &def.frame_support;
+	let pallet_ident = &def.pallet_struct.pallet;
+	let type_impl_gen = &def.type_impl_generics(Span::call_site());
+	let type_use_gen = &def.type_use_generics(Span::call_site());
+
+	let (span, where_clause, view_fns) = match def.view_functions.as_ref() {
+		Some(view_fns) => (
+			view_fns.attr_span,
+			super::merge_where_clauses(&[&def.config.where_clause, &view_fns.where_clause]),
+			&view_fns.view_functions[..],
+		),
+		None => (Span::call_site(), def.config.where_clause.clone(), &[][..]),
+	};
+
+	let query_structs = view_fns.iter().map(|view_fn| expand_query_struct(def, view_fn, span));
+	let query_struct_idents =
+		view_fns.iter().map(|view_fn| view_fn.query_struct_ident()).collect::<Vec<_>>();
+
+	let metadata = view_fns.iter().map(|view_fn| {
+		let name = view_fn.name.to_string();
+		let query_struct_ident = view_fn.query_struct_ident();
+		let return_type = &view_fn.return_type;
+		let no_docs = vec![];
+		let docs = if cfg!(feature = "no-metadata-docs") { &no_docs } else { &view_fn.docs };
+		let inputs = view_fn.args.iter().map(|(name, ty)| {
+			let name = name.to_string();
+			quote::quote!(
+				#frame_support::__private::metadata_ir::PalletViewFunctionParamMetadataIR {
+					name: #name,
+					ty: #frame_support::__private::scale_info::meta_type::<#ty>(),
+				}
+			)
+		});
+
+		quote::quote!(
+			#frame_support::__private::metadata_ir::PalletViewFunctionMetadataIR {
+				name: #name,
+				id: <#query_struct_ident<#type_use_gen> as #frame_support::view_functions::ViewFunction>::id().into(),
+				inputs: #frame_support::__private::sp_std::vec![ #( #inputs ),* ],
+				output: #frame_support::__private::scale_info::meta_type::<#return_type>(),
+				docs: #frame_support::__private::sp_std::vec![ #( #docs ),* ],
+			}
+		)
+	});
+
+	quote::quote_spanned!(span =>
+		#( #query_structs )*
+
+		impl<#type_impl_gen> #frame_support::view_functions::ViewFunctionIdPrefix
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			fn prefix() -> [u8; 16] {
+				<Self as #frame_support::traits::PalletInfoAccess>::name_hash()
+			}
+		}
+
+		impl<#type_impl_gen> #frame_support::view_functions::DispatchViewFunction
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			#[allow(unused_variables)]
+			fn dispatch_view_function<O: #frame_support::__private::codec::Output>(
+				id: &#frame_support::view_functions::ViewFunctionId,
+				input: &mut &[u8],
+				output: &mut O,
+			) -> Result<(), #frame_support::view_functions::ViewFunctionDispatchError> {
+				#(
+					if id.suffix ==
+						<#query_struct_idents<#type_use_gen> as #frame_support::view_functions::ViewFunctionIdSuffix>::suffix()
+					{
+						return <#query_struct_idents<#type_use_gen> as #frame_support::view_functions::ViewFunction>::execute(
+							input, output,
+						)
+					}
+				)*
+
+				Err(#frame_support::view_functions::ViewFunctionDispatchError::NotFound(id.clone()))
+			}
+		}
+
+		impl<#type_impl_gen> #pallet_ident<#type_use_gen> #where_clause {
+			#[doc(hidden)]
+			pub fn pallet_view_functions_metadata()
+				-> #frame_support::__private::sp_std::vec::Vec<#frame_support::__private::metadata_ir::PalletViewFunctionMetadataIR>
+			{
+				#frame_support::__private::sp_std::vec![ #( #metadata ),* ]
+			}
+		}
+	)
+}
+
+/// Expand the struct holding the arguments of a single view function, e.g. `GetValue` for
+/// `get_value`.
+fn expand_query_struct(def: &Def, view_fn: &ViewFunctionDef, span: Span) -> TokenStream {
+	let frame_support = &def.frame_support;
+	let pallet_ident = &def.pallet_struct.pallet;
+	let type_impl_gen = &def.type_impl_generics(span);
+	let type_decl_bounded_gen = &def.type_decl_bounded_generics(span);
+	let type_use_gen = &def.type_use_generics(span);
+	let view_fns = def.view_functions.as_ref().expect("view functions are defined; qed");
+	let where_clause =
+		super::merge_where_clauses(&[&def.config.where_clause, &view_fns.where_clause]);
+
+	let fn_name = &view_fn.name;
+	let query_struct_ident = view_fn.query_struct_ident();
+	let return_type = &view_fn.return_type;
+	let arg_names = view_fn.args.iter().map(|(name, _)| name).collect::<Vec<_>>();
+	let arg_types = view_fn.args.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+
+	let fn_name_str = fn_name.to_string();
+
+	let doc = format!(
+		" The arguments of the view function [`Pallet::{fn_name}`].\n\n The encoding of \
+		this struct is the input of the view function."
+	);
+
+	quote::quote_spanned!(span =>
+		#[doc = #doc]
+		#[derive(
+			#frame_support::RuntimeDebugNoBound,
+			#frame_support::CloneNoBound,
+			#frame_support::EqNoBound,
+			#frame_support::PartialEqNoBound,
+			#frame_support::__private::codec::Encode,
+			#frame_support::__private::codec::Decode,
+			#frame_support::__private::scale_info::TypeInfo,
+		)]
+		#[codec(encode_bound())]
+		#[codec(decode_bound())]
+		#[scale_info(skip_type_params(#type_use_gen))]
+		pub struct #query_struct_ident<#type_decl_bounded_gen> #where_clause {
+			#(
+				#[allow(missing_docs)]
+				pub #arg_names: #arg_types,
+			)*
+			#[codec(skip)]
+			_marker: ::core::marker::PhantomData<(#type_use_gen,)>,
+		}
+
+		impl<#type_impl_gen> #query_struct_ident<#type_use_gen> #where_clause {
+			/// Create a new instance holding the given arguments.
+			#[allow(clippy::too_many_arguments)]
+			pub fn new(#( #arg_names: #arg_types, )*) -> Self {
+				Self { #( #arg_names, )* _marker: ::core::default::Default::default() }
+			}
+		}
+
+		impl<#type_impl_gen> #frame_support::view_functions::ViewFunctionIdSuffix
+			for #query_struct_ident<#type_use_gen> #where_clause
+		{
+			fn suffix() -> [u8; 16] {
+				#frame_support::view_functions::view_function_id_suffix(
+					#fn_name_str,
+					&[ #( #frame_support::__private::scale_info::meta_type::<#arg_types>() ),* ],
+					#frame_support::__private::scale_info::meta_type::<#return_type>(),
+				)
+			}
+		}
+
+		impl<#type_impl_gen> #frame_support::view_functions::ViewFunction
+			for #query_struct_ident<#type_use_gen> #where_clause
+		{
+			type ReturnType = #return_type;
+
+			fn id() -> #frame_support::view_functions::ViewFunctionId {
+				#frame_support::view_functions::ViewFunctionId {
+					prefix: <#pallet_ident<#type_use_gen> as #frame_support::view_functions::ViewFunctionIdPrefix>::prefix(),
+					suffix: <Self as #frame_support::view_functions::ViewFunctionIdSuffix>::suffix(),
+				}
+			}
+
+			fn invoke(self) -> Self::ReturnType {
+				let Self { #( #arg_names, )* _marker } = self;
+				#pallet_ident::<#type_use_gen>::#fn_name(#( #arg_names, )*)
+			}
+		}
+	)
+}
@@ -33,6 +33,7 @@ mod keyword {
 	syn::custom_keyword!(T);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(feeless_if);
+	syn::custom_keyword!(feature_flag);
 }
 
 /// Definition of dispatchables typically `impl<T: Config> Pallet<T> { ... }`
@@ -89,6 +90,34 @@ pub struct CallVariantDef {
 	pub cfg_attrs: Vec<syn::Attribute>,
 	/// The optional `feeless_if` attribute on the `pallet::call`.
 	pub feeless_check: Option<syn::ExprClosure>,
+	/// The `feature_flag` attributes on the `pallet::call`.
+	pub feature_flags: Vec<FeatureFlagDef>,
+}
+
+/// Definition of a feature flag gating a dispatchable: `#[pallet::feature_flag(T::MyFeature)]`.
+#[derive(Clone)]
+pub struct FeatureFlagDef {
+	/// The name of the flag, i.e. the last segment of the type path.
+	pub name: syn::Ident,
+	/// The type implementing `Get<bool>` that returns whether the flag is enabled.
+	pub type_: syn::Type,
+}
+
+impl FeatureFlagDef {
+	fn try_from(type_: syn::Type) -> syn::Result<Self> {
+		let name = match &type_ {
+			syn::Type::Path(syn::TypePath { path, .. }) =>
+				path.segments.last().map(|segment| segment.ident.clone()),
+			_ => None,
+		};
+		let Some(name) = name else {
+			let msg = "Invalid pallet::call, feature_flag must be a path to a type implementing \
+				`Get<bool>`, e.g. `T::MyFeature`";
+			return Err(syn::Error::new(type_.span(), msg))
+		};
+
+		Ok(Self { name, type_ })
+	}
 }
 
 /// Attributes for functions in call impl block.
@@ -99,6 +128,8 @@ pub enum FunctionAttr {
 	Weight(syn::Expr),
 	/// Parse for `#[pallet::feeless_if(expr)]`
 	FeelessIf(Span, syn::ExprClosure),
+	/// Parse for `#[pallet::feature_flag(type)]`
+	FeatureFlag(syn::Type),
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -138,6 +169,11 @@ impl syn::parse::Parse for FunctionAttr {
 					err
 				})?,
 			))
+		} else if lookahead.peek(keyword::feature_flag) {
+			content.parse::<keyword::feature_flag>()?;
+			let flag_content;
+			syn::parenthesized!(flag_content in content);
+			Ok(FunctionAttr::FeatureFlag(flag_content.parse::<syn::Type>()?))
 		} else {
 			Err(lookahead.error())
 		}
@@ -272,6 +308,7 @@ impl CallDef {
 				let mut call_idx_attrs = vec![];
 				let mut weight_attrs = vec![];
 				let mut feeless_attrs = vec![];
+				let mut feature_flags = vec![];
 				for attr in helper::take_item_pallet_attrs(&mut method.attrs)?.into_iter() {
 					match attr {
 						FunctionAttr::CallIndex(_) => {
@@ -283,6 +320,9 @@ impl CallDef {
 						FunctionAttr::FeelessIf(span, _) => {
 							feeless_attrs.push((span, attr));
 						},
+						FunctionAttr::FeatureFlag(flag) => {
+							feature_flags.push(FeatureFlagDef::try_from(flag)?);
+						},
 					}
 				}
 
@@ -447,6 +487,7 @@ impl CallDef {
 					attrs: method.attrs.clone(),
 					cfg_attrs,
 					feeless_check,
+					feature_flags,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
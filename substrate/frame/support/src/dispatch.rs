@@ -69,6 +69,18 @@ pub trait CheckIfFeeless {
 	fn is_feeless(&self, origin: &Self::Origin) -> bool;
 }
 
+/// Means to check if the feature flags gating a dispatchable are enabled.
+///
+/// This is automatically implemented for all dispatchables during pallet expansion.
+/// If a call is marked by one or more
+/// [`#[pallet::feature_flag]`](`macro@frame_support_procedural::feature_flag`) attributes, all
+/// of the flags must be enabled. `construct_runtime` combines this check with the
+/// `BaseCallFilter` of the runtime.
+pub trait CheckFeatureFlags {
+	/// Checks if all feature flags of the dispatchable are enabled.
+	fn features_enabled(&self) -> bool;
+}
+
 /// Origin for the System pallet.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum RawOrigin<AccountId> {
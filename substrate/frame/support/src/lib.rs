@@ -75,6 +75,7 @@ pub mod storage;
 #[cfg(test)]
 mod tests;
 pub mod traits;
+pub mod view_functions;
 pub mod weights;
 #[doc(hidden)]
 pub mod unsigned {
@@ -1847,6 +1848,74 @@ pub mod pallet_macros {
 	/// [`pallet_skip_feeless_payment::CheckIfFeeless`]: ../../pallet_skip_feeless_payment/struct.SkipCheckIfFeeless.html
 	pub use frame_support_procedural::feeless_if;
 
+	/// Allows gating a dispatchable behind a feature flag that can be toggled at runtime.
+	///
+	/// Each dispatchable may be annotated with one or more `#[pallet::feature_flag($type)]`
+	/// attributes. `$type` must implement [`Get<bool>`](crate::traits::Get), usually an
+	/// associated type of the pallet's `Config`. The last segment of the path is the name of
+	/// the flag. The dispatchable is only enabled if all of its flags return `true`.
+	///
+	/// ### Example
+	///
+	/// ```
+	/// #[frame_support::pallet(dev_mode)]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// # 	use frame_system::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[pallet::config]
+	/// 	pub trait Config: frame_system::Config {
+	/// 		/// Whether `something` can be called.
+	/// 		type SomethingEnabled: Get<bool>;
+	/// 	}
+	///
+	/// 	#[pallet::call]
+	/// 	impl<T: Config> Pallet<T> {
+	/// 		#[pallet::call_index(0)]
+	/// 		#[pallet::feature_flag(T::SomethingEnabled)]
+	/// 		pub fn something(_: OriginFor<T>) -> DispatchResult {
+	/// 			unimplemented!()
+	/// 		}
+	/// 	}
+	/// }
+	/// ```
+	///
+	/// The flags are meant to be stored by `pallet-parameters`, so that governance can toggle
+	/// them. The runtime binds the associated type to a `bool` parameter declared with
+	/// [`dynamic_params`](crate::dynamic_params):
+	///
+	/// ```ignore
+	/// #[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+	/// pub mod dynamic_params {
+	/// 	#[dynamic_pallet_params]
+	/// 	#[codec(index = 0)]
+	/// 	pub mod features {
+	/// 		#[codec(index = 0)]
+	/// 		pub static SomethingEnabled: bool = false;
+	/// 	}
+	/// }
+	///
+	/// impl pallet::Config for Runtime {
+	/// 	type SomethingEnabled = dynamic_params::features::SomethingEnabled;
+	/// }
+	/// ```
+	///
+	/// ### Macro expansion
+	///
+	/// The macro implements the [`CheckFeatureFlags`](crate::dispatch::CheckFeatureFlags)
+	/// trait on the `Call` enum of the pallet. `construct_runtime` combines it with the
+	/// `BaseCallFilter` of the runtime, so that calls with a disabled flag are filtered for all
+	/// origins except `Root`.
+	///
+	/// The filter reads every flag of a call from storage, so one DB read per flag is added to
+	/// the weight of the call. The flags are listed in the documentation of the call in the
+	/// metadata. Their current values are the entries of the `Parameters` storage of
+	/// `pallet-parameters`.
+	pub use frame_support_procedural::feature_flag;
+
 	/// Allows defining an error enum that will be returned from the dispatchable when an error
 	/// occurs.
 	///
@@ -2530,6 +2599,56 @@ pub mod pallet_macros {
 	/// Read more about origins at the [Origin Reference
 	/// Docs](../../polkadot_sdk_docs/reference_docs/frame_origin/index.html).
 	pub use frame_support_procedural::origin;
+
+	/// Allows defining view functions on a pallet.
+	///
+	/// View functions are read-only functions of a pallet that can be queried from outside the
+	/// runtime, without the need of a dedicated runtime API per pallet. The item must be an
+	/// inherent implementation of the pallet struct, and all of its functions must be public
+	/// and return a value:
+	///
+	/// ```
+	/// #[frame_support::pallet(dev_mode)]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[pallet::config]
+	/// 	pub trait Config: frame_system::Config {}
+	///
+	/// 	#[pallet::storage]
+	/// 	pub type SomeMap<T: Config> = StorageMap<_, Twox64Concat, u32, u32, OptionQuery>;
+	///
+	/// 	#[pallet::view_functions]
+	/// 	impl<T: Config> Pallet<T> {
+	/// 		/// Get a value from the map.
+	/// 		pub fn get_value(key: u32) -> Option<u32> {
+	/// 			SomeMap::<T>::get(key)
+	/// 		}
+	/// 	}
+	/// }
+	/// ```
+	///
+	/// ## Macro expansion
+	///
+	/// For each view function, the macro generates a struct holding its arguments, named after
+	/// the function in `PascalCase` (e.g. `GetValue`). The struct implements
+	/// [`ViewFunction`](crate::view_functions::ViewFunction), and its encoding is the input of
+	/// the view function.
+	///
+	/// A view function is identified by a [`ViewFunctionId`](crate::view_functions::ViewFunctionId),
+	/// whose prefix is the hash of the pallet name and whose suffix is the hash of the name and
+	/// of the resolved argument and return types of the function, see
+	/// [`view_function_id_suffix`](crate::view_functions::view_function_id_suffix). The pallet
+	/// implements
+	/// [`DispatchViewFunction`](crate::view_functions::DispatchViewFunction), and
+	/// `construct_runtime` implements `execute_view_function` on the runtime, dispatching to the
+	/// pallets. It is meant to be used by the implementation of the
+	/// [`RuntimeViewFunction`](crate::view_functions::runtime_api::RuntimeViewFunction) runtime
+	/// API. The view functions are also added to the pallet metadata.
+	pub use frame_support_procedural::view_functions;
 }
 
 #[deprecated(note = "Will be removed after July 2023; Use `sp_runtime::traits` directly instead.")]
@@ -0,0 +1,166 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for pallet view functions.
+//!
+//! View functions are read-only queries declared in a
+//! [`#[pallet::view_functions]`](crate::pallet_macros::view_functions) section. They are
+//! aggregated by `construct_runtime` and can be executed through the single
+//! [`RuntimeViewFunction`](runtime_api::RuntimeViewFunction) runtime API.
+
+use codec::{Decode, DecodeAll, Encode, Output};
+use scale_info::{MetaType, PortableRegistry, Registry, TypeInfo};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The unique identifier of a view function.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ViewFunctionId {
+	/// Identifies the pallet of the view function, see [`ViewFunctionIdPrefix`].
+	pub prefix: [u8; 16],
+	/// Identifies the view function within its pallet, see [`ViewFunctionIdSuffix`].
+	pub suffix: [u8; 16],
+}
+
+impl From<ViewFunctionId> for [u8; 32] {
+	fn from(value: ViewFunctionId) -> Self {
+		let mut output = [0u8; 32];
+		output[..16].copy_from_slice(&value.prefix);
+		output[16..].copy_from_slice(&value.suffix);
+		output
+	}
+}
+
+/// Error when dispatching a view function.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ViewFunctionDispatchError {
+	/// View functions are not implemented.
+	NotImplemented,
+	/// No view function with the given id exists.
+	NotFound(ViewFunctionId),
+	/// Failed to decode the arguments of the view function.
+	Codec,
+}
+
+impl From<codec::Error> for ViewFunctionDispatchError {
+	fn from(_: codec::Error) -> Self {
+		ViewFunctionDispatchError::Codec
+	}
+}
+
+/// Dispatches a view function by its id.
+///
+/// Implemented by each pallet for its view functions and by the runtime, which dispatches to
+/// the pallet matching the [`ViewFunctionId::prefix`].
+pub trait DispatchViewFunction {
+	/// Execute the view function `id` with the SCALE encoded arguments in `input` and write the
+	/// encoded result to `output`.
+	fn dispatch_view_function<O: Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError>;
+}
+
+impl DispatchViewFunction for () {
+	fn dispatch_view_function<O: Output>(
+		_id: &ViewFunctionId,
+		_input: &mut &[u8],
+		_output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		Err(ViewFunctionDispatchError::NotImplemented)
+	}
+}
+
+/// The prefix of the ids of all view functions of a pallet.
+///
+/// Automatically implemented for each pallet by the [`pallet`](crate::pallet) macro. It is the
+/// hash of the name of the pallet in the runtime.
+pub trait ViewFunctionIdPrefix {
+	/// The prefix of the view function ids.
+	fn prefix() -> [u8; 16];
+}
+
+/// The suffix of the id of a view function.
+///
+/// Automatically implemented for each view function by the [`pallet`](crate::pallet) macro, see
+/// [`view_function_id_suffix`].
+pub trait ViewFunctionIdSuffix {
+	/// The suffix of the view function id.
+	fn suffix() -> [u8; 16];
+}
+
+/// Returns the suffix of the id of the view function `name` with the arguments `args` and the
+/// return type `output`.
+///
+/// The suffix is the hash of the name and of the type information of all arguments and of the
+/// return type. Types are resolved first, so the suffix changes whenever their encoding does,
+/// but not when they are just spelled differently, e.g. through a type alias.
+pub fn view_function_id_suffix(name: &str, args: &[MetaType], output: MetaType) -> [u8; 16] {
+	let mut registry = Registry::new();
+	let args = args.iter().map(|ty| registry.register_type(ty).id).collect::<Vec<_>>();
+	let output = registry.register_type(&output).id;
+	let types = PortableRegistry::from(registry);
+
+	sp_io::hashing::twox_128(&(name, args, output, types).encode())
+}
+
+/// A view function together with its arguments.
+///
+/// Automatically implemented by the [`pallet`](crate::pallet) macro for a struct per view
+/// function, whose fields are the arguments of the view function. The encoding of this struct
+/// is the input of the view function.
+pub trait ViewFunction: DecodeAll {
+	/// The type returned by the view function.
+	type ReturnType: Encode;
+
+	/// The id of the view function.
+	fn id() -> ViewFunctionId;
+
+	/// Call the view function with the arguments in `self`.
+	fn invoke(self) -> Self::ReturnType;
+
+	/// Decode the arguments from `input`, call the view function and write the encoded result
+	/// to `output`.
+	fn execute<O: Output>(
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		let view_function = Self::decode_all(input)?;
+		let result = view_function.invoke();
+		Encode::encode_to(&result, output);
+		Ok(())
+	}
+}
+
+/// The runtime API to execute view functions.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// Runtime API to execute the view functions of all pallets.
+		pub trait RuntimeViewFunction {
+			/// Execute the view function `query_id` with the SCALE encoded arguments in `input`.
+			///
+			/// Returns the SCALE encoded result of the view function.
+			fn execute_view_function(
+				query_id: ViewFunctionId,
+				input: Vec<u8>,
+			) -> Result<Vec<u8>, ViewFunctionDispatchError>;
+		}
+	}
+}
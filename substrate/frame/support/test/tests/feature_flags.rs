@@ -0,0 +1,236 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `#[pallet::feature_flag]`.
+
+use frame_support::{
+	assert_noop, assert_ok, derive_impl,
+	dispatch::{CheckFeatureFlags, GetDispatchInfo},
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
+	traits::{AsEnsureOriginWithArg, Contains, OriginTrait, UnfilteredDispatchable},
+	weights::{RuntimeDbWeight, Weight},
+};
+use frame_system::{Call as SystemCall, EnsureRoot};
+use scale_info::TypeInfo;
+use sp_io::TestExternalities;
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+pub use self::pallet::*;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Enables `foo` and `foo_bar`.
+		type FooEnabled: Get<bool>;
+		/// Enables `foo_bar`.
+		type BarEnabled: Get<bool>;
+	}
+
+	#[pallet::storage]
+	pub type Value<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		pub fn always(_origin: OriginFor<T>, v: u32) -> DispatchResult {
+			Value::<T>::put(v);
+			Ok(())
+		}
+
+		/// Sets the value.
+		#[pallet::call_index(1)]
+		#[pallet::weight(Weight::from_parts(1_000, 0))]
+		#[pallet::feature_flag(T::FooEnabled)]
+		pub fn foo(_origin: OriginFor<T>, v: u32) -> DispatchResult {
+			Value::<T>::put(v);
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::from_parts(1_000, 0))]
+		#[pallet::feature_flag(T::FooEnabled)]
+		#[pallet::feature_flag(T::BarEnabled)]
+		pub fn foo_bar(_origin: OriginFor<T>, v: u32) -> DispatchResult {
+			Value::<T>::put(v);
+			Ok(())
+		}
+	}
+}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Parameters: pallet_parameters,
+		Example: pallet,
+	}
+);
+
+pub struct BaseFilter;
+impl Contains<RuntimeCall> for BaseFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::System(SystemCall::remark { .. }))
+	}
+}
+
+frame_support::parameter_types! {
+	pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 10, write: 100 };
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = BaseFilter;
+	type Block = frame_system::mocking::MockBlock<Self>;
+	type DbWeight = DbWeight;
+}
+
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod features {
+		#[codec(index = 0)]
+		pub static FooEnabled: bool = false;
+		#[codec(index = 1)]
+		pub static BarEnabled: bool = false;
+	}
+}
+
+#[derive_impl(pallet_parameters::config_preludes::TestDefaultConfig)]
+impl pallet_parameters::Config for Runtime {
+	type AdminOrigin = AsEnsureOriginWithArg<EnsureRoot<u64>>;
+}
+
+impl Config for Runtime {
+	type FooEnabled = dynamic_params::features::FooEnabled;
+	type BarEnabled = dynamic_params::features::BarEnabled;
+}
+
+fn enable(flag: dynamic_params::features::Parameters) {
+	assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), RuntimeParameters::Features(flag)));
+}
+
+fn enable_foo() {
+	enable(dynamic_params::features::Parameters::FooEnabled(
+		dynamic_params::features::FooEnabled,
+		Some(true),
+	));
+}
+
+fn enable_bar() {
+	enable(dynamic_params::features::Parameters::BarEnabled(
+		dynamic_params::features::BarEnabled,
+		Some(true),
+	));
+}
+
+fn foo(v: u32) -> RuntimeCall {
+	RuntimeCall::Example(Call::foo { v })
+}
+
+fn foo_bar(v: u32) -> RuntimeCall {
+	RuntimeCall::Example(Call::foo_bar { v })
+}
+
+#[test]
+fn features_enabled_requires_all_flags() {
+	TestExternalities::default().execute_with(|| {
+		assert!(RuntimeCall::Example(Call::always { v: 1 }).features_enabled());
+		assert!(RuntimeCall::System(SystemCall::remark { remark: vec![] }).features_enabled());
+		assert!(!foo(1).features_enabled());
+		assert!(!foo_bar(1).features_enabled());
+
+		enable_foo();
+		assert!(foo(1).features_enabled());
+		assert!(!foo_bar(1).features_enabled());
+
+		enable_bar();
+		assert!(foo(1).features_enabled());
+		assert!(foo_bar(1).features_enabled());
+	});
+}
+
+#[test]
+fn disabled_calls_are_filtered() {
+	TestExternalities::default().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert!(origin.filter_call(&RuntimeCall::Example(Call::always { v: 1 })));
+		assert!(!origin.filter_call(&foo(1)));
+		// The `BaseCallFilter` is still applied.
+		assert!(!origin.filter_call(&RuntimeCall::System(SystemCall::remark { remark: vec![] })));
+
+		assert_noop!(
+			foo(1).dispatch(RuntimeOrigin::signed(1)),
+			DispatchError::from(frame_system::Error::<Runtime>::CallFiltered),
+		);
+
+		enable_foo();
+		assert_ok!(foo(2).dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Value::<Runtime>::get(), 2);
+		assert_noop!(
+			foo_bar(3).dispatch(RuntimeOrigin::signed(1)),
+			DispatchError::from(frame_system::Error::<Runtime>::CallFiltered),
+		);
+	});
+}
+
+#[test]
+fn root_bypasses_feature_flags() {
+	TestExternalities::default().execute_with(|| {
+		assert!(RuntimeOrigin::root().filter_call(&foo_bar(1)));
+		assert_ok!(foo_bar(1).dispatch(RuntimeOrigin::root()));
+		assert_eq!(Value::<Runtime>::get(), 1);
+
+		// Bypassing the filter explicitly ignores the flags as well.
+		assert_ok!(Call::<Runtime>::foo { v: 2 }.dispatch_bypass_filter(RuntimeOrigin::signed(1)));
+		assert_eq!(Value::<Runtime>::get(), 2);
+	});
+}
+
+#[test]
+fn feature_flag_reads_are_weighed() {
+	let read = DbWeight::get().read;
+	assert_eq!(
+		RuntimeCall::Example(Call::always { v: 1 }).get_dispatch_info().weight,
+		Weight::zero()
+	);
+	assert_eq!(foo(1).get_dispatch_info().weight, Weight::from_parts(1_000 + read, 0));
+	assert_eq!(foo_bar(1).get_dispatch_info().weight, Weight::from_parts(1_000 + 2 * read, 0));
+}
+
+#[test]
+fn feature_flags_are_in_call_metadata() {
+	let scale_info::TypeDef::Variant(calls) = Call::<Runtime>::type_info().type_def else {
+		panic!("Call is an enum")
+	};
+	let docs = |name: &str| calls.variants.iter().find(|c| c.name == name).unwrap().docs.clone();
+
+	assert!(docs("always").is_empty());
+	assert_eq!(
+		docs("foo"),
+		vec!["Sets the value.", "", "Gated by the feature flags: `FooEnabled`."]
+	);
+	assert_eq!(docs("foo_bar"), vec!["Gated by the feature flags: `FooEnabled`, `BarEnabled`."],);
+}
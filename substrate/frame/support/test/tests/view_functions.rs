@@ -0,0 +1,322 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `#[pallet::view_functions]`.
+
+use codec::{Decode, Encode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use frame_support::{
+	derive_impl,
+	traits::PalletInfoAccess,
+	view_functions::{
+		view_function_id_suffix, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+		ViewFunctionIdPrefix, ViewFunctionIdSuffix,
+	},
+};
+use scale_info::{meta_type, TypeDef, TypeInfo};
+use sp_io::TestExternalities;
+use sp_metadata_ir::{
+	PalletViewFunctionParamMetadataIR, PalletViewFunctionsCustomMetadata,
+	VIEW_FUNCTIONS_CUSTOM_METADATA_KEY,
+};
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::storage]
+	pub type SomeValue<T: Config> = StorageValue<_, u32>;
+
+	#[pallet::storage]
+	pub type SomeMap<T: Config> = StorageMap<_, Twox64Concat, u32, u32, OptionQuery>;
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// Query a value.
+		pub fn get_value() -> Option<u32> {
+			SomeValue::<T>::get()
+		}
+
+		/// Query a value with arguments.
+		pub fn get_value_with_arg(key: u32) -> Option<u32> {
+			SomeMap::<T>::get(key)
+		}
+	}
+}
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet_with_instance {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {}
+
+	#[pallet::storage]
+	pub type SomeValue<T: Config<I>, I: 'static = ()> = StorageValue<_, u32>;
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Query a value, or the given default.
+		pub fn get_value_or(default: u32) -> u32 {
+			SomeValue::<T, I>::get().unwrap_or(default)
+		}
+	}
+}
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet_without_view_functions {
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Example: pallet,
+		ExampleInstance: pallet_with_instance,
+		ExampleInstance2: pallet_with_instance::<Instance2>,
+		NoViewFunctions: pallet_without_view_functions,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Self>;
+}
+
+impl pallet::Config for Runtime {}
+impl pallet_with_instance::Config for Runtime {}
+impl pallet_with_instance::Config<pallet_with_instance::Instance2> for Runtime {}
+impl pallet_without_view_functions::Config for Runtime {}
+
+fn execute<V: ViewFunction + Encode>(query: V) -> Result<V::ReturnType, ViewFunctionDispatchError>
+where
+	V::ReturnType: Decode,
+{
+	Runtime::execute_view_function(V::id(), query.encode())
+		.map(|output| V::ReturnType::decode(&mut &output[..]).unwrap())
+}
+
+#[test]
+fn view_functions_are_dispatched() {
+	TestExternalities::default().execute_with(|| {
+		assert_eq!(execute(pallet::GetValue::<Runtime>::new()), Ok(None));
+		assert_eq!(execute(pallet::GetValueWithArg::<Runtime>::new(1)), Ok(None));
+
+		pallet::SomeValue::<Runtime>::put(123);
+		pallet::SomeMap::<Runtime>::insert(1, 456);
+		assert_eq!(execute(pallet::GetValue::<Runtime>::new()), Ok(Some(123)));
+		assert_eq!(execute(pallet::GetValueWithArg::<Runtime>::new(1)), Ok(Some(456)));
+		assert_eq!(execute(pallet::GetValueWithArg::<Runtime>::new(2)), Ok(None));
+	});
+}
+
+#[test]
+fn view_functions_of_instances_are_dispatched() {
+	use pallet_with_instance::{GetValueOr, Instance2, SomeValue};
+
+	TestExternalities::default().execute_with(|| {
+		SomeValue::<Runtime>::put(1);
+		SomeValue::<Runtime, Instance2>::put(2);
+
+		assert_eq!(execute(GetValueOr::<Runtime>::new(0)), Ok(1));
+		assert_eq!(execute(GetValueOr::<Runtime, Instance2>::new(0)), Ok(2));
+
+		SomeValue::<Runtime, Instance2>::kill();
+		assert_eq!(execute(GetValueOr::<Runtime, Instance2>::new(7)), Ok(7));
+	});
+}
+
+#[test]
+fn view_function_ids_are_unique() {
+	use pallet_with_instance::{GetValueOr, Instance2};
+
+	let ids = [
+		pallet::GetValue::<Runtime>::id(),
+		pallet::GetValueWithArg::<Runtime>::id(),
+		GetValueOr::<Runtime>::id(),
+		GetValueOr::<Runtime, Instance2>::id(),
+	];
+	for (i, id) in ids.iter().enumerate() {
+		assert!(ids[i + 1..].iter().all(|other| other != id));
+	}
+
+	assert_eq!(pallet::GetValue::<Runtime>::id().prefix, Example::name_hash());
+	assert_eq!(GetValueOr::<Runtime, Instance2>::id().prefix, ExampleInstance2::name_hash());
+	assert_eq!(GetValueOr::<Runtime>::id().suffix, GetValueOr::<Runtime, Instance2>::id().suffix);
+}
+
+#[test]
+fn view_function_ids_hash_resolved_types() {
+	assert_eq!(
+		pallet::GetValueWithArg::<Runtime>::suffix(),
+		view_function_id_suffix(
+			"get_value_with_arg",
+			&[meta_type::<u32>()],
+			meta_type::<Option<u32>>()
+		),
+	);
+	assert_ne!(
+		pallet::GetValueWithArg::<Runtime>::suffix(),
+		view_function_id_suffix(
+			"get_value_with_arg",
+			&[meta_type::<u64>()],
+			meta_type::<Option<u32>>()
+		),
+	);
+
+	// The way a type is written does not matter, only the type it resolves to.
+	type Key = u32;
+	assert_eq!(
+		view_function_id_suffix(
+			"f",
+			&[meta_type::<Key>()],
+			meta_type::<core::option::Option<Key>>()
+		),
+		view_function_id_suffix("f", &[meta_type::<u32>()], meta_type::<Option<u32>>()),
+	);
+}
+
+#[test]
+fn unknown_view_functions_are_not_found() {
+	TestExternalities::default().execute_with(|| {
+		let unknown_suffix = ViewFunctionId { prefix: Example::name_hash(), suffix: [0u8; 16] };
+		assert_eq!(
+			Runtime::execute_view_function(unknown_suffix.clone(), vec![]),
+			Err(ViewFunctionDispatchError::NotFound(unknown_suffix)),
+		);
+
+		let unknown_prefix =
+			ViewFunctionId { prefix: [0u8; 16], suffix: pallet::GetValue::<Runtime>::id().suffix };
+		assert_eq!(
+			Runtime::execute_view_function(unknown_prefix.clone(), vec![]),
+			Err(ViewFunctionDispatchError::NotFound(unknown_prefix)),
+		);
+
+		let no_view_functions = ViewFunctionId {
+			prefix: <NoViewFunctions as ViewFunctionIdPrefix>::prefix(),
+			suffix: pallet::GetValue::<Runtime>::id().suffix,
+		};
+		assert_eq!(
+			Runtime::execute_view_function(no_view_functions.clone(), vec![]),
+			Err(ViewFunctionDispatchError::NotFound(no_view_functions)),
+		);
+	});
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+	TestExternalities::default().execute_with(|| {
+		let id = pallet::GetValueWithArg::<Runtime>::id();
+		// Too short.
+		assert_eq!(
+			Runtime::execute_view_function(id.clone(), vec![1]),
+			Err(ViewFunctionDispatchError::Codec),
+		);
+		// Too long.
+		assert_eq!(
+			Runtime::execute_view_function(id, 1u64.encode()),
+			Err(ViewFunctionDispatchError::Codec),
+		);
+	});
+}
+
+#[test]
+fn view_functions_are_in_metadata() {
+	let metadata = Runtime::metadata_ir();
+	let view_functions = |name: &str| {
+		metadata.pallets.iter().find(|p| p.name == name).unwrap().view_functions.clone()
+	};
+
+	let example = view_functions("Example");
+	assert_eq!(example.len(), 2);
+	assert_eq!(example[0].name, "get_value");
+	assert_eq!(example[0].id, <[u8; 32]>::from(pallet::GetValue::<Runtime>::id()));
+	assert!(example[0].inputs.is_empty());
+	assert_eq!(example[0].output, meta_type::<Option<u32>>());
+	assert_eq!(example[0].docs, vec![" Query a value."]);
+
+	assert_eq!(example[1].name, "get_value_with_arg");
+	assert_eq!(
+		example[1].inputs,
+		vec![PalletViewFunctionParamMetadataIR { name: "key", ty: meta_type::<u32>() }],
+	);
+
+	let instance = view_functions("ExampleInstance2");
+	assert_eq!(instance.len(), 1);
+	assert_eq!(
+		instance[0].id,
+		<[u8; 32]>::from(pallet_with_instance::GetValueOr::<
+			Runtime,
+			pallet_with_instance::Instance2,
+		>::id()),
+	);
+	assert_eq!(instance[0].output, meta_type::<u32>());
+
+	assert!(view_functions("NoViewFunctions").is_empty());
+}
+
+#[test]
+fn view_functions_are_in_v15_custom_metadata() {
+	let bytes = Runtime::metadata_at_version(15).expect("V15 is supported; qed");
+	let metadata = match RuntimeMetadataPrefixed::decode(&mut &bytes[..]).unwrap().1 {
+		RuntimeMetadata::V15(metadata) => metadata,
+		_ => panic!("metadata has been bumped, test needs to be updated"),
+	};
+
+	let custom = &metadata.custom.map[VIEW_FUNCTIONS_CUSTOM_METADATA_KEY];
+	let view_functions =
+		Vec::<PalletViewFunctionsCustomMetadata>::decode(&mut &custom.value[..]).unwrap();
+	let pallets = view_functions.iter().map(|p| p.pallet.as_str()).collect::<Vec<_>>();
+	assert_eq!(pallets, vec!["Example", "ExampleInstance", "ExampleInstance2"]);
+
+	let example = &view_functions[0].view_functions;
+	assert_eq!(example[1].name, "get_value_with_arg");
+	assert_eq!(example[1].id, <[u8; 32]>::from(pallet::GetValueWithArg::<Runtime>::id()));
+	assert_eq!(example[1].docs, vec![" Query a value with arguments."]);
+	assert_eq!(example[1].inputs[0].name, "key");
+
+	// The type ids refer to the type registry of the metadata.
+	let resolve = |id| &metadata.types.resolve(id).unwrap().type_def;
+	assert!(matches!(resolve(example[1].inputs[0].ty), TypeDef::Primitive(_)));
+	assert_eq!(metadata.types.resolve(example[1].output).unwrap().path.segments, vec!["Option"]);
+	assert!(matches!(resolve(custom.ty.id), TypeDef::Sequence(_)));
+}
+
+#[test]
+fn query_struct_type_info_only_contains_arguments() {
+	let type_info = pallet::GetValueWithArg::<Runtime>::type_info();
+	match type_info.type_def {
+		scale_info::TypeDef::Composite(composite) => {
+			assert_eq!(composite.fields.len(), 1);
+			assert_eq!(composite.fields[0].name, Some("key"));
+		},
+		_ => panic!("query struct must be a composite"),
+	}
+}
@@ -32,13 +32,14 @@ use frame_support::{
 		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Imbalance, OnUnbalanced,
 	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
-	PalletId,
+	BoundedVec, PalletId,
 };
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_asset_conversion::{Ascending, Chain, WithFirstAsset};
 use pallet_transaction_payment::FungibleAdapter;
 use sp_runtime::{
+	bounded_vec,
 	traits::{AccountIdConversion, IdentityLookup, SaturatedConversion},
 	Permill,
 };
@@ -208,6 +209,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub LPFeeTiers: BoundedVec<Permill, ConstU32<4>> =
+		bounded_vec![Permill::from_parts(500), Permill::from_percent(1)];
 	pub const MaxSwapPathLength: u32 = 4;
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 }
@@ -239,8 +242,13 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type PalletId = AssetConversionPalletId;
 	type LPFee = ConstU32<3>; // means 0.3%
+	type LPFeeTiers = LPFeeTiers;
+	type MaxLPFeeTiers = ConstU32<4>;
+	type MaxAmplification = ConstU32<10_000>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = MaxSwapPathLength;
+	type MaxPriceObservations = ConstU32<4>;
+	type PriceObservationPeriod = ConstU64<10>;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
 	type WeightInfo = ();
 	pallet_asset_conversion::runtime_benchmarks_enabled! {
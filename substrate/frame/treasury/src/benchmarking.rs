@@ -32,7 +32,7 @@ use frame_support::{
 		EnsureOrigin, OnInitialize,
 	},
 };
-use frame_system::RawOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_core::crypto::FromEntropy;
 
 /// Trait describing factory functions for dispatchables' parameters.
@@ -102,6 +102,35 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create the arguments for the `spend_in_tranches` dispatchable, with the amount of each of the
+// `n` tranches.
+fn create_tranche_spend_arguments<T: Config<I>, I: 'static>(
+	seed: u32,
+	n: u32,
+) -> (T::AssetKind, AssetBalanceOf<T, I>, T::Beneficiary, BeneficiaryLookupOf<T, I>) {
+	let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+		create_spend_arguments::<T, I>(seed);
+	(asset_kind, amount / n.into(), beneficiary, beneficiary_lookup)
+}
+
+// Create a spend of `n` tranches, all valid from `valid_from`.
+fn create_tranche_spend<T: Config<I>, I: 'static>(
+	n: u32,
+	valid_from: Option<BlockNumberFor<T>>,
+) -> Result<(T::AssetKind, AssetBalanceOf<T, I>, T::Beneficiary), BenchmarkError> {
+	let origin = T::SpendOrigin::try_successful_origin().map_err(|_| "No origin")?;
+	let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+		create_tranche_spend_arguments::<T, I>(SEED, n);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	Treasury::<T, I>::spend_in_tranches(
+		origin,
+		Box::new(asset_kind.clone()),
+		Box::new(beneficiary_lookup),
+		(0..n).map(|_| (amount, valid_from)).collect(),
+	)?;
+	Ok((asset_kind, amount, beneficiary))
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -274,6 +303,119 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn spend_in_tranches(n: Linear<1, { T::MaxTranches::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_tranche_spend_arguments::<T, _>(SEED, n);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let tranches = (0..n).map(|_| (amount, None)).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			Box::new(beneficiary_lookup),
+			tranches,
+		);
+
+		assert_last_event::<T, I>(
+			Event::TrancheSpendApproved {
+				index: 0,
+				asset_kind,
+				amount: amount * n.into(),
+				beneficiary,
+				tranches: n,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_milestone() -> Result<(), BenchmarkError> {
+		create_tranche_spend::<T, _>(1, None)?;
+		let origin =
+			T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0u32, 0u32);
+
+		let valid_from = frame_system::Pallet::<T>::block_number();
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		assert_last_event::<T, I>(
+			Event::MilestoneConfirmed { index: 0, tranche: 0, valid_from, expire_at }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn payout_tranche() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary) =
+			create_tranche_spend::<T, _>(1, Some(frame_system::Pallet::<T>::block_number()))?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0u32, 0u32);
+
+		let id = match SpendTranches::<T, I>::get(0, 0).unwrap().status {
+			PaymentState::Attempted { id, .. } => {
+				assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure);
+				id
+			},
+			_ => panic!("No payout attempt made"),
+		};
+		assert_last_event::<T, I>(
+			Event::TranchePaid { index: 0, tranche: 0, payment_id: id }.into(),
+		);
+		assert!(
+			Treasury::<T, _>::payout_tranche(RawOrigin::Signed(caller).into(), 0u32, 0u32).is_err()
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn check_tranche_status() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary) =
+			create_tranche_spend::<T, _>(1, Some(frame_system::Pallet::<T>::block_number()))?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+		Treasury::<T, _>::payout_tranche(RawOrigin::Signed(caller.clone()).into(), 0u32, 0u32)?;
+		match SpendTranches::<T, I>::get(0, 0).unwrap().status {
+			PaymentState::Attempted { id, .. } => {
+				T::Paymaster::ensure_concluded(id);
+			},
+			_ => panic!("No payout attempt made"),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0u32, 0u32);
+
+		if let Some(t) = SpendTranches::<T, I>::get(0, 0) {
+			assert!(!matches!(t.status, PaymentState::Attempted { .. }));
+		} else {
+			assert!(TrancheSpends::<T, I>::get(0).is_none());
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_tranche_spend(n: Linear<1, { T::MaxTranches::get() }>) -> Result<(), BenchmarkError> {
+		create_tranche_spend::<T, _>(n, None)?;
+		assert!(TrancheSpends::<T, I>::get(0).is_some());
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		void_spend(origin as T::RuntimeOrigin, 0u32);
+
+		assert!(TrancheSpends::<T, I>::get(0).is_none());
+		assert_eq!(SpendTranches::<T, I>::iter_prefix(0).count(), 0);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -69,6 +69,12 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! The `spend_in_tranches` dispatchable creates a spend of some asset kind which is paid out in
+//! several tranches. Each tranche becomes claimable either from a scheduled block number or once
+//! the [`pallet::Config::ApproveOrigin`] confirmed its milestone with `confirm_milestone`, and is
+//! then claimed with `payout_tranche` within one [`pallet::Config::PayoutPeriod`]. The tranches
+//! that were not paid out yet can be cancelled with `void_spend`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -86,12 +92,13 @@ use scale_info::TypeInfo;
 
 use sp_runtime::{
 	traits::{AccountIdConversion, CheckedAdd, Saturating, StaticLookup, Zero},
-	Permill, RuntimeDebug,
+	ArithmeticError, Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	dispatch_context::with_context,
 	ensure, print,
 	traits::{
 		tokens::Pay, Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
@@ -187,6 +194,40 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// Index of a tranche within a treasury spend.
+pub type TrancheIndex = u32;
+
+/// Info regarding an approved treasury spend paid out in tranches.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct TrancheSpendStatus<AssetKind, Beneficiary> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+}
+
+/// Info regarding a single tranche of a treasury spend.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct TrancheStatus<AssetBalance, BlockNumber, PaymentId> {
+	/// The asset amount of the tranche.
+	amount: AssetBalance,
+	/// The block number from which the tranche can be claimed, or `None` while its milestone
+	/// has not been confirmed.
+	valid_from: Option<BlockNumber>,
+	/// The block number by which the tranche has to be claimed, or `None` while its milestone
+	/// has not been confirmed.
+	expire_at: Option<BlockNumber>,
+	/// The status of the payout/claim.
+	status: PaymentState<PaymentId>,
+}
+
+#[derive(Default)]
+struct SpendContext<Balance> {
+	spend_in_context: BTreeMap<Balance, Balance>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -275,6 +316,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of tranches of a spend created with `spend_in_tranches`.
+		#[pallet::constant]
+		type MaxTranches: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -328,6 +373,32 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Spends paid out in tranches that have been approved and being processed.
+	///
+	/// Spends share their index with [`Spends`]. The tranches are kept in [`SpendTranches`].
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type TrancheSpends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		TrancheSpendStatus<T::AssetKind, T::Beneficiary>,
+		OptionQuery,
+	>;
+
+	/// The tranches of [`TrancheSpends`] which have not been processed yet.
+	// Hasher: Twox safe since `SpendIndex` and `TrancheIndex` are internal count based indices.
+	#[pallet::storage]
+	pub type SpendTranches<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		Twox64Concat,
+		TrancheIndex,
+		TrancheStatus<AssetBalanceOf<T, I>, BlockNumberFor<T>, <T::Paymaster as Pay>::Id>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -386,6 +457,36 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new asset spend paid out in tranches has been approved.
+		TrancheSpendApproved {
+			index: SpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			tranches: u32,
+		},
+		/// The milestone of a tranche was confirmed and the tranche can be claimed.
+		MilestoneConfirmed {
+			index: SpendIndex,
+			tranche: TrancheIndex,
+			valid_from: BlockNumberFor<T>,
+			expire_at: BlockNumberFor<T>,
+		},
+		/// A payment of a tranche happened.
+		TranchePaid {
+			index: SpendIndex,
+			tranche: TrancheIndex,
+			payment_id: <T::Paymaster as Pay>::Id,
+		},
+		/// A payment of a tranche failed and can be retried.
+		TranchePaymentFailed {
+			index: SpendIndex,
+			tranche: TrancheIndex,
+			payment_id: <T::Paymaster as Pay>::Id,
+		},
+		/// A tranche was processed and removed from the storage. It might have been successfully
+		/// paid or it may have expired.
+		TrancheProcessed { index: SpendIndex, tranche: TrancheIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -414,6 +515,12 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// A spend must have at least one tranche.
+		NoTranches,
+		/// The spend has more than [`Config::MaxTranches`] tranches.
+		TooManyTranches,
+		/// The tranche is not waiting for its milestone to be confirmed.
+		NotAwaitingMilestone,
 	}
 
 	#[pallet::hooks]
@@ -450,11 +557,6 @@ pub mod pallet {
 		}
 	}
 
-	#[derive(Default)]
-	struct SpendContext<Balance> {
-		spend_in_context: BTreeMap<Balance, Balance>,
-	}
-
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Propose and approve a spend of treasury funds.
@@ -610,24 +712,7 @@ pub mod pallet {
 					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
 
 			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(native_amount);
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::ensure_spend_allowance(max_amount, native_amount)?;
 
 			let index = SpendCount::<T, I>::get();
 			Spends::<T, I>::insert(
@@ -764,6 +849,10 @@ pub mod pallet {
 		///
 		/// A spend void is only possible if the payout has not been attempted yet.
 		///
+		/// For a spend paid out in tranches, all the tranches which payout has not been attempted
+		/// yet are voided. The tranches which payout has been attempted must still be processed
+		/// with `check_tranche_status`.
+		///
 		/// ### Parameters
 		/// - `index`: The spend index.
 		///
@@ -771,10 +860,15 @@ pub mod pallet {
 		///
 		/// Emits [`Event::AssetSpendVoided`] if successful.
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::void_spend())]
+		#[pallet::weight(
+			T::WeightInfo::void_spend()
+				.max(T::WeightInfo::void_tranche_spend(T::MaxTranches::get()))
+		)]
 		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
 			T::RejectOrigin::ensure_origin(origin)?;
-			let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let Some(spend) = Spends::<T, I>::get(index) else {
+				return Self::void_tranche_spend(index)
+			};
 			ensure!(
 				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
 				Error::<T, I>::AlreadyAttempted
@@ -784,6 +878,258 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds paid out in several tranches.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of all tranches of `asset_kind` in the native asset. The amount of `asset_kind`
+		/// is converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend for transferring specific amounts of `asset_kind` to a
+		/// designated beneficiary in tranches. Each tranche must be claimed using the
+		/// `payout_tranche` dispatchable within the [`Config::PayoutPeriod`] from the block it
+		/// becomes valid.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `tranches`: The amount of each tranche, with the block number from which it can be
+		///   claimed. If `None`, the tranche can be claimed once its milestone is confirmed with
+		///   `confirm_milestone`. At most [`Config::MaxTranches`] tranches are allowed.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::TrancheSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_in_tranches(tranches.len() as u32))]
+		pub fn spend_in_tranches(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			tranches: Vec<(AssetBalanceOf<T, I>, Option<BlockNumberFor<T>>)>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!tranches.is_empty(), Error::<T, I>::NoTranches);
+			ensure!(tranches.len() as u32 <= T::MaxTranches::get(), Error::<T, I>::TooManyTranches);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut amount = AssetBalanceOf::<T, I>::zero();
+			for (tranche_amount, valid_from) in tranches.iter() {
+				if let Some(valid_from) = valid_from {
+					let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+					ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+				}
+				amount = amount.checked_add(tranche_amount).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			let native_amount =
+				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
+					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			Self::ensure_spend_allowance(max_amount, native_amount)?;
+
+			let index = SpendCount::<T, I>::get();
+			let tranches_count = tranches.len() as u32;
+			for (tranche, (amount, valid_from)) in tranches.into_iter().enumerate() {
+				let expire_at =
+					valid_from.map(|valid_from| valid_from.saturating_add(T::PayoutPeriod::get()));
+				SpendTranches::<T, I>::insert(
+					index,
+					tranche as TrancheIndex,
+					TrancheStatus { amount, valid_from, expire_at, status: PaymentState::Pending },
+				);
+			}
+			TrancheSpends::<T, I>::insert(
+				index,
+				TrancheSpendStatus {
+					asset_kind: *asset_kind.clone(),
+					beneficiary: beneficiary.clone(),
+				},
+			);
+			SpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::TrancheSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				tranches: tranches_count,
+			});
+			Ok(())
+		}
+
+		/// Confirm the milestone of a tranche, making it claimable.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::ApproveOrigin`].
+		///
+		/// ## Details
+		///
+		/// The tranche can be claimed from the current block and within one
+		/// [`Config::PayoutPeriod`].
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		/// - `tranche`: The index of the tranche within the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneConfirmed`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::confirm_milestone())]
+		pub fn confirm_milestone(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+			tranche: TrancheIndex,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let expire_at = now.saturating_add(T::PayoutPeriod::get());
+			SpendTranches::<T, I>::try_mutate(index, tranche, |maybe_tranche| -> DispatchResult {
+				let tranche = maybe_tranche.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+				ensure!(tranche.valid_from.is_none(), Error::<T, I>::NotAwaitingMilestone);
+				tranche.valid_from = Some(now);
+				tranche.expire_at = Some(expire_at);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::MilestoneConfirmed {
+				index,
+				tranche,
+				valid_from: now,
+				expire_at,
+			});
+			Ok(())
+		}
+
+		/// Claim a tranche of a spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed
+		///
+		/// ## Details
+		///
+		/// Tranches must be claimed within one [`Config::PayoutPeriod`] from the block they became
+		/// valid. A tranche waiting for its milestone can not be claimed.
+		/// In case of a payout failure, the tranche status must be updated with the
+		/// `check_tranche_status` dispatchable before retrying with the current function.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		/// - `tranche`: The index of the tranche within the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::TranchePaid`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::payout_tranche())]
+		pub fn payout_tranche(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+			tranche: TrancheIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let spend = TrancheSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let mut tranche_status =
+				SpendTranches::<T, I>::get(index, tranche).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let (valid_from, expire_at) = tranche_status
+				.valid_from
+				.zip(tranche_status.expire_at)
+				.ok_or(Error::<T, I>::EarlyPayout)?;
+			ensure!(now >= valid_from, Error::<T, I>::EarlyPayout);
+			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+			ensure!(
+				matches!(tranche_status.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			let id = T::Paymaster::pay(&spend.beneficiary, spend.asset_kind, tranche_status.amount)
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			tranche_status.status = PaymentState::Attempted { id };
+			SpendTranches::<T, I>::insert(index, tranche, tranche_status);
+
+			Self::deposit_event(Event::<T, I>::TranchePaid { index, tranche, payment_id: id });
+
+			Ok(())
+		}
+
+		/// Check the status of a tranche and remove it from the storage if processed.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The status check is a prerequisite for retrying a failed payout of a tranche.
+		/// If a tranche has either succeeded or expired, it is removed from the storage by this
+		/// function, along with the spend once its last tranche is removed. In such instances,
+		/// transaction fees are refunded.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		/// - `tranche`: The index of the tranche within the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::TranchePaymentFailed`] if the tranche payout has failed.
+		/// Emits [`Event::TrancheProcessed`] if the tranche payout has succeed.
+		/// Emits [`Event::SpendProcessed`] if it was the last tranche of the spend.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::check_tranche_status())]
+		pub fn check_tranche_status(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+			tranche: TrancheIndex,
+		) -> DispatchResultWithPostInfo {
+			use PaymentState as State;
+			use PaymentStatus as Status;
+
+			ensure_signed(origin)?;
+			let mut tranche_status =
+				SpendTranches::<T, I>::get(index, tranche).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let expired = tranche_status.expire_at.map_or(false, |expire_at| now > expire_at);
+			if expired && !matches!(tranche_status.status, State::Attempted { .. }) {
+				// tranche has expired and no further status update is expected.
+				Self::remove_tranche(index, tranche);
+				return Ok(Pays::No.into())
+			}
+
+			let payment_id = match tranche_status.status {
+				State::Attempted { id } => id,
+				_ => return Err(Error::<T, I>::NotAttempted.into()),
+			};
+
+			match T::Paymaster::check_payment(payment_id) {
+				Status::Failure => {
+					tranche_status.status = PaymentState::Failed;
+					SpendTranches::<T, I>::insert(index, tranche, tranche_status);
+					Self::deposit_event(Event::<T, I>::TranchePaymentFailed {
+						index,
+						tranche,
+						payment_id,
+					});
+				},
+				Status::Success | Status::Unknown => {
+					Self::remove_tranche(index, tranche);
+					return Ok(Pays::No.into())
+				},
+				Status::InProgress => return Err(Error::<T, I>::Inconclusive.into()),
+			}
+			return Ok(Pays::Yes.into())
+		}
 	}
 }
 
@@ -798,6 +1144,32 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Ensure that an origin allowed to spend `max_amount` at a time can spend `native_amount`,
+	/// taking into account what it already spent within the same dispatch context.
+	fn ensure_spend_allowance(
+		max_amount: BalanceOf<T, I>,
+		native_amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+			let context = v.or_default();
+			// We group based on `max_amount`, to distinguish between different kind of
+			// origins. (assumes that all origins have different `max_amount`)
+			//
+			// Worst case is that we reject some "valid" request.
+			let spend = context.spend_in_context.entry(max_amount).or_default();
+
+			// Ensure that we don't overflow nor use more than `max_amount`
+			if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+				Err(Error::<T, I>::InsufficientPermission)
+			} else {
+				*spend = spend.saturating_add(native_amount);
+				Ok(())
+			}
+		})
+		.unwrap_or(Ok(()))
+		.map_err(Into::into)
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds() -> Weight {
 		let mut total_weight = Weight::zero();
@@ -879,6 +1251,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		total_weight
 	}
 
+	/// Void all the tranches of a spend which payout has not been attempted yet.
+	fn void_tranche_spend(index: SpendIndex) -> DispatchResult {
+		ensure!(TrancheSpends::<T, I>::contains_key(index), Error::<T, I>::InvalidIndex);
+		let voidable = SpendTranches::<T, I>::iter_prefix(index)
+			.filter(|(_, tranche)| {
+				matches!(tranche.status, PaymentState::Pending | PaymentState::Failed)
+			})
+			.map(|(tranche, _)| tranche)
+			.collect::<Vec<_>>();
+		ensure!(!voidable.is_empty(), Error::<T, I>::AlreadyAttempted);
+
+		for tranche in voidable {
+			SpendTranches::<T, I>::remove(index, tranche);
+		}
+		if SpendTranches::<T, I>::iter_key_prefix(index).next().is_none() {
+			TrancheSpends::<T, I>::remove(index);
+		}
+		Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
+		Ok(())
+	}
+
+	/// Remove a processed tranche, and its spend if it was the last tranche.
+	fn remove_tranche(index: SpendIndex, tranche: TrancheIndex) {
+		SpendTranches::<T, I>::remove(index, tranche);
+		Self::deposit_event(Event::<T, I>::TrancheProcessed { index, tranche });
+		if SpendTranches::<T, I>::iter_key_prefix(index).next().is_none() {
+			TrancheSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+		}
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
@@ -892,6 +1295,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_tranche_spends()?;
 
 		Ok(())
 	}
@@ -965,6 +1369,58 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// ## Invariants of tranche spend storage items
+	///
+	/// 1. Each entry in [`TrancheSpends`] should be saved under a key strictly less than current
+	/// [`SpendCount`], and not be used by [`Spends`].
+	/// 2. Each entry in [`TrancheSpends`] has at least one tranche in [`SpendTranches`], and each
+	/// tranche belongs to an entry in [`TrancheSpends`].
+	/// 3. For each tranche, `valid_from` and `expire_at` are either both known with
+	/// `expire_at` > `valid_from`, or both unknown.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_tranche_spends() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_spend_count = SpendCount::<T, I>::get();
+
+		TrancheSpends::<T, I>::iter_keys().try_for_each(|spend_index| -> DispatchResult {
+			ensure!(
+				current_spend_count > spend_index,
+				"`SpendCount` should by strictly greater than any SpendIndex used as a key for `TrancheSpends`."
+			);
+			ensure!(
+				!Spends::<T, I>::contains_key(spend_index),
+				"A SpendIndex cannot be used by both `Spends` and `TrancheSpends`."
+			);
+			ensure!(
+				SpendTranches::<T, I>::iter_key_prefix(spend_index).next().is_some(),
+				"Each entry in `TrancheSpends` must have at least one tranche."
+			);
+			Ok(())
+		})?;
+
+		SpendTranches::<T, I>::iter().try_for_each(
+			|(spend_index, _, tranche)| -> DispatchResult {
+				ensure!(
+					TrancheSpends::<T, I>::contains_key(spend_index),
+					"Each tranche in `SpendTranches` must belong to an entry in `TrancheSpends`."
+				);
+				match (tranche.valid_from, tranche.expire_at) {
+					(Some(valid_from), Some(expire_at)) => ensure!(
+						valid_from < expire_at,
+						"Tranche cannot expire before it becomes valid."
+					),
+					(None, None) => (),
+					_ =>
+						return Err(
+							"Tranche must know both when it becomes valid and expires.".into()
+						),
+				}
+				Ok(())
+			},
+		)?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Pallet<T, I> {
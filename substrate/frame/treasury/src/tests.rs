@@ -181,6 +181,7 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxTranches = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -217,6 +218,14 @@ fn get_payment_id(i: SpendIndex) -> Option<u64> {
 	}
 }
 
+fn get_tranche_payment_id(i: SpendIndex, t: TrancheIndex) -> Option<u64> {
+	let tranche = SpendTranches::<Test, _>::get(i, t).expect("no tranche");
+	match tranche.status {
+		PaymentState::Attempted { id } => Some(id),
+		_ => None,
+	}
+}
+
 #[test]
 fn genesis_config_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -768,6 +777,267 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn spend_in_tranches_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Treasury::spend_in_tranches(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				Box::new(6),
+				vec![]
+			),
+			Error::<Test, _>::NoTranches
+		);
+		assert_noop!(
+			Treasury::spend_in_tranches(
+				RuntimeOrigin::signed(14),
+				Box::new(1),
+				Box::new(6),
+				vec![(1, None); 11]
+			),
+			Error::<Test, _>::TooManyTranches
+		);
+		// the total amount of all tranches is checked against the origin allowance.
+		assert_noop!(
+			Treasury::spend_in_tranches(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				Box::new(6),
+				vec![(2, None), (1, None)]
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		// scheduled tranches cannot be already expired.
+		System::set_block_number(7);
+		assert_noop!(
+			Treasury::spend_in_tranches(
+				RuntimeOrigin::signed(12),
+				Box::new(1),
+				Box::new(6),
+				vec![(2, Some(1)), (1, None)]
+			),
+			Error::<Test, _>::SpendExpired
+		);
+
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, Some(10)), (3, None)]
+		));
+
+		assert_eq!(SpendCount::<Test, _>::get(), 1);
+		assert_eq!(Spends::<Test, _>::get(0), None);
+		assert_eq!(
+			TrancheSpends::<Test, _>::get(0).unwrap(),
+			TrancheSpendStatus { asset_kind: 1, beneficiary: 6 }
+		);
+		assert_eq!(
+			SpendTranches::<Test, _>::get(0, 0).unwrap(),
+			TrancheStatus {
+				amount: 2,
+				valid_from: Some(10),
+				expire_at: Some(15),
+				status: PaymentState::Pending,
+			}
+		);
+		assert_eq!(
+			SpendTranches::<Test, _>::get(0, 1).unwrap(),
+			TrancheStatus {
+				amount: 3,
+				valid_from: None,
+				expire_at: None,
+				status: PaymentState::Pending
+			}
+		);
+		System::assert_last_event(
+			Event::<Test, _>::TrancheSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 5,
+				beneficiary: 6,
+				tranches: 2,
+			}
+			.into(),
+		);
+
+		// tranche spends and spends share the same index space.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		assert!(Spends::<Test, _>::get(1).is_some());
+	});
+}
+
+#[test]
+fn tranche_milestone_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(<Test as Config>::PayoutPeriod::get(), 5);
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, Some(1)), (3, None)]
+		));
+
+		// the milestone tranche cannot be claimed before being confirmed.
+		assert_noop!(
+			Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test, _>::EarlyPayout
+		);
+		// a waiting tranche does not expire.
+		System::set_block_number(20);
+		assert_noop!(
+			Treasury::check_tranche_status(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test, _>::NotAttempted
+		);
+
+		assert_noop!(Treasury::confirm_milestone(RuntimeOrigin::signed(1), 0, 1), BadOrigin);
+		assert_noop!(
+			Treasury::confirm_milestone(RuntimeOrigin::root(), 0, 0),
+			Error::<Test, _>::NotAwaitingMilestone
+		);
+		assert_noop!(
+			Treasury::confirm_milestone(RuntimeOrigin::root(), 0, 2),
+			Error::<Test, _>::InvalidIndex
+		);
+		assert_ok!(Treasury::confirm_milestone(RuntimeOrigin::root(), 0, 1));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneConfirmed {
+				index: 0,
+				tranche: 1,
+				valid_from: 20,
+				expire_at: 25,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Treasury::confirm_milestone(RuntimeOrigin::root(), 0, 1),
+			Error::<Test, _>::NotAwaitingMilestone
+		);
+
+		// the scheduled tranche expired and the confirmed one can be claimed.
+		assert_noop!(
+			Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test, _>::SpendExpired
+		);
+		assert_ok!(Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(paid(6, 1), 3);
+	});
+}
+
+#[test]
+fn tranche_payout_and_check_status_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, Some(1)), (3, Some(3))]
+		));
+
+		// first tranche is paid, second one is not valid yet.
+		assert_ok!(Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 0));
+		assert_noop!(
+			Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test, _>::EarlyPayout
+		);
+		assert_eq!(paid(6, 1), 2);
+		let payment_id = get_tranche_payment_id(0, 0).expect("no payment attempt");
+		System::assert_last_event(
+			Event::<Test, _>::TranchePaid { index: 0, tranche: 0, payment_id }.into(),
+		);
+		assert_noop!(
+			Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+
+		// the payment failed and can be retried after the status check.
+		set_status(payment_id, PaymentStatus::InProgress);
+		assert_noop!(
+			Treasury::check_tranche_status(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test, _>::Inconclusive
+		);
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 2);
+		let info = Treasury::check_tranche_status(RuntimeOrigin::signed(1), 0, 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		System::assert_last_event(
+			Event::<Test, _>::TranchePaymentFailed { index: 0, tranche: 0, payment_id }.into(),
+		);
+		assert_ok!(Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 0));
+		assert_eq!(paid(6, 1), 2);
+
+		// the payment succeed and the tranche is removed.
+		let payment_id = get_tranche_payment_id(0, 0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		let info = Treasury::check_tranche_status(RuntimeOrigin::signed(1), 0, 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::<Test, _>::TrancheProcessed { index: 0, tranche: 0 }.into(),
+		);
+		assert_eq!(SpendTranches::<Test, _>::get(0, 0), None);
+		assert!(TrancheSpends::<Test, _>::get(0).is_some());
+
+		// the last tranche expired and its removal removes the spend.
+		System::set_block_number(9);
+		assert_noop!(
+			Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test, _>::SpendExpired
+		);
+		let info = Treasury::check_tranche_status(RuntimeOrigin::signed(1), 0, 1).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_has_event(
+			Event::<Test, _>::TrancheProcessed { index: 0, tranche: 1 }.into(),
+		);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+		assert_eq!(TrancheSpends::<Test, _>::get(0), None);
+		assert_eq!(SpendTranches::<Test, _>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn void_tranche_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, Some(1)), (3, None), (4, Some(5))]
+		));
+		assert_ok!(Treasury::payout_tranche(RuntimeOrigin::signed(1), 0, 0));
+
+		// the remaining tranches are voided, the attempted one is kept.
+		assert_noop!(Treasury::void_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::AssetSpendVoided { index: 0 }.into());
+		assert!(TrancheSpends::<Test, _>::get(0).is_some());
+		assert_eq!(SpendTranches::<Test, _>::iter_keys().collect::<Vec<_>>(), vec![(0, 0)]);
+		assert_noop!(
+			Treasury::void_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+
+		// the spend is removed when all its tranches are voided.
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, None), (3, None)]
+		));
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 1));
+		assert_eq!(TrancheSpends::<Test, _>::get(1), None);
+		assert_eq!(SpendTranches::<Test, _>::iter_prefix(1).count(), 0);
+		assert_noop!(
+			Treasury::void_spend(RuntimeOrigin::root(), 1),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -921,3 +1191,29 @@ fn try_state_spends_invariant_3_works() {
 		);
 	});
 }
+
+#[test]
+fn try_state_tranche_spends_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::pallet_prelude::DispatchError::Other;
+		assert_ok!(Treasury::spend_in_tranches(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			vec![(2, Some(1)), (3, None)]
+		));
+		assert_ok!(Treasury::do_try_state());
+		// Break the invariant by confirming the milestone without setting its expiry
+		SpendTranches::<Test>::mutate(0, 1, |t| t.as_mut().unwrap().valid_from = Some(1));
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other("Tranche must know both when it becomes valid and expires."))
+		);
+		// Break the invariant by removing all the tranches of the spend
+		let _ = SpendTranches::<Test>::clear_prefix(0, u32::MAX, None);
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other("Each entry in `TrancheSpends` must have at least one tranche."))
+		);
+	});
+}
@@ -58,6 +58,11 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn check_status() -> Weight;
 	fn void_spend() -> Weight;
+	fn spend_in_tranches(n: u32, ) -> Weight;
+	fn confirm_milestone() -> Weight;
+	fn payout_tranche() -> Weight;
+	fn check_tranche_status() -> Weight;
+	fn void_tranche_spend(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_treasury` using the Substrate node and recommended hardware.
@@ -168,6 +173,89 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:0 w:10)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:0 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn spend_in_tranches(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `107`
+		//  Estimated: `3501`
+		// Minimum execution time: 10_596_000 picoseconds.
+		Weight::from_parts(19_314_981, 3501)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Treasury::SpendTranches` (r:1 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	fn confirm_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `3516`
+		// Minimum execution time: 8_023_000 picoseconds.
+		Weight::from_parts(8_335_000, 3516)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Treasury::TrancheSpends` (r:1 w:0)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:1 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_tranche() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `615`
+		//  Estimated: `6208`
+		// Minimum execution time: 38_783_000 picoseconds.
+		Weight::from_parts(39_473_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Treasury::SpendTranches` (r:2 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:0 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn check_tranche_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `207`
+		//  Estimated: `6042`
+		// Minimum execution time: 14_356_000 picoseconds.
+		Weight::from_parts(14_838_000, 6042)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:1 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:11 w:10)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn void_tranche_spend(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + n * (35 ±0)`
+		//  Estimated: `3538 + n * (2526 ±0)`
+		// Minimum execution time: 16_736_000 picoseconds.
+		Weight::from_parts(20_537_887, 3538)
+			// Standard Error: 193_388
+			.saturating_add(Weight::from_parts(6_968_574, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2526).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -277,4 +365,87 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:0 w:10)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:0 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn spend_in_tranches(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `107`
+		//  Estimated: `3501`
+		// Minimum execution time: 10_596_000 picoseconds.
+		Weight::from_parts(19_314_981, 3501)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Treasury::SpendTranches` (r:1 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	fn confirm_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `3516`
+		// Minimum execution time: 8_023_000 picoseconds.
+		Weight::from_parts(8_335_000, 3516)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Treasury::TrancheSpends` (r:1 w:0)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:1 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_tranche() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `615`
+		//  Estimated: `6208`
+		// Minimum execution time: 38_783_000 picoseconds.
+		Weight::from_parts(39_473_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Treasury::SpendTranches` (r:2 w:1)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:0 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn check_tranche_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `207`
+		//  Estimated: `6042`
+		// Minimum execution time: 14_356_000 picoseconds.
+		Weight::from_parts(14_838_000, 6042)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::TrancheSpends` (r:1 w:1)
+	/// Proof: `Treasury::TrancheSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendTranches` (r:11 w:10)
+	/// Proof: `Treasury::SpendTranches` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn void_tranche_spend(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + n * (35 ±0)`
+		//  Estimated: `3538 + n * (2526 ±0)`
+		// Minimum execution time: 16_736_000 picoseconds.
+		Weight::from_parts(20_537_887, 3538)
+			// Standard Error: 193_388
+			.saturating_add(Weight::from_parts(6_968_574, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2526).saturating_mul(n.into()))
+	}
 }
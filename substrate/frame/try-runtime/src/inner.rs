@@ -19,6 +19,7 @@
 
 pub use frame_support::traits::{TryStateSelect, UpgradeCheckSelect};
 use frame_support::weights::Weight;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	/// Runtime api for testing the execution of a runtime upgrade.
@@ -36,6 +37,19 @@ sp_api::decl_runtime_apis! {
 		/// tracking is likely inaccurate.
 		fn on_runtime_upgrade(checks: UpgradeCheckSelect) -> (Weight, Weight);
 
+		/// dry-run runtime upgrades like [`Self::on_runtime_upgrade`], and check that every
+		/// storage item whose layout changed was migrated.
+		///
+		/// `old_metadata` is the metadata of the runtime before the upgrade, as returned by its
+		/// `Metadata_metadata_at_version` with version 14 or 15. The upgrade fails if the
+		/// hashers, the key type or the value type of a storage item changed while the on-chain
+		/// `StorageVersion` of its pallet did not increase.
+		#[api_version(2)]
+		fn on_runtime_upgrade_with_metadata(
+			checks: UpgradeCheckSelect,
+			old_metadata: Vec<u8>,
+		) -> (Weight, Weight);
+
 		/// Execute the given block, but optionally disable state-root and signature checks.
 		///
 		/// Optionally, a number of `try_state` hooks can also be executed after the block
@@ -0,0 +1,434 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detect changes of the storage layout between two versions of the runtime metadata.
+//!
+//! Changing the hashers, the key type or the value type of a storage item makes the data that is
+//! already stored undecodable, unless a migration translates it. Such a migration is expected to
+//! increase the on-chain `StorageVersion` of the pallet. [`unmigrated_storage_changes`] flags all
+//! changes for which this did not happen. It is meant to be used by tools like `try-runtime` that
+//! have access to the metadata of the old and the new runtime as well as to the chain state.
+
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use frame_metadata::{
+	v14::{PalletStorageMetadata, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef};
+
+/// A storage item whose layout is incompatible between two versions of the runtime metadata.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StorageLayoutChange {
+	/// Name of the pallet, which is also the prefix of its storage.
+	pub pallet: String,
+	/// Name of the storage item.
+	pub entry: String,
+	/// What changed.
+	pub kind: StorageLayoutChangeKind,
+}
+
+/// The kind of a [`StorageLayoutChange`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum StorageLayoutChangeKind {
+	/// The item changed from a plain value to a map or vice versa.
+	Kind,
+	/// The hashers of the map keys changed.
+	Hashers,
+	/// The type of the map key changed.
+	KeyType,
+	/// The type of the value changed.
+	ValueType,
+}
+
+/// Returns all storage items that exist in both `old` and `new` but have an incompatible layout.
+///
+/// Types are compared by their encoding and not by their names. A type is compatible if it can
+/// decode everything that the old type encoded. Adding variants to an enum is therefore allowed,
+/// while removing or changing one is not.
+///
+/// Returns `None` if one of the metadata versions is not supported. Only V14 and V15 are.
+pub fn storage_layout_changes(
+	old: &RuntimeMetadataPrefixed,
+	new: &RuntimeMetadataPrefixed,
+) -> Option<Vec<StorageLayoutChange>> {
+	let (old_types, old_pallets) = storage_metadata(old)?;
+	let (new_types, new_pallets) = storage_metadata(new)?;
+
+	let mut changes = Vec::new();
+	for old_storage in old_pallets {
+		let Some(new_storage) = new_pallets.iter().find(|s| s.prefix == old_storage.prefix) else {
+			continue
+		};
+
+		for old_entry in &old_storage.entries {
+			let Some(new_entry) = new_storage.entries.iter().find(|e| e.name == old_entry.name)
+			else {
+				continue
+			};
+
+			let compatible =
+				|old: u32, new: u32| TypeComparison::new(old_types, new_types).compatible(old, new);
+			let kind = match (&old_entry.ty, &new_entry.ty) {
+				(StorageEntryType::Plain(old), StorageEntryType::Plain(new)) =>
+					(!compatible(old.id, new.id)).then_some(StorageLayoutChangeKind::ValueType),
+				(
+					StorageEntryType::Map { hashers: old_hashers, key: old_key, value: old_value },
+					StorageEntryType::Map { hashers: new_hashers, key: new_key, value: new_value },
+				) =>
+					if old_hashers != new_hashers {
+						Some(StorageLayoutChangeKind::Hashers)
+					} else if !compatible(old_key.id, new_key.id) {
+						Some(StorageLayoutChangeKind::KeyType)
+					} else if !compatible(old_value.id, new_value.id) {
+						Some(StorageLayoutChangeKind::ValueType)
+					} else {
+						None
+					},
+				_ => Some(StorageLayoutChangeKind::Kind),
+			};
+
+			if let Some(kind) = kind {
+				changes.push(StorageLayoutChange {
+					pallet: old_storage.prefix.clone().into(),
+					entry: old_entry.name.clone().into(),
+					kind,
+				});
+			}
+		}
+	}
+
+	Some(changes)
+}
+
+/// Returns the storage layout changes of all pallets whose on-chain storage version did not
+/// increase.
+///
+/// `storage_version` is called with the name of a pallet and must return its on-chain storage
+/// version before and after the runtime upgrade, including its migrations.
+///
+/// Returns `None` if one of the metadata versions is not supported. Only V14 and V15 are.
+pub fn unmigrated_storage_changes(
+	old: &RuntimeMetadataPrefixed,
+	new: &RuntimeMetadataPrefixed,
+	mut storage_version: impl FnMut(&str) -> (u16, u16),
+) -> Option<Vec<StorageLayoutChange>> {
+	let mut changes = storage_layout_changes(old, new)?;
+	changes.retain(|change| {
+		let (before, after) = storage_version(&change.pallet);
+		after <= before
+	});
+	Some(changes)
+}
+
+/// Returns the type registry and the storage metadata of all pallets.
+fn storage_metadata(
+	metadata: &RuntimeMetadataPrefixed,
+) -> Option<(&PortableRegistry, Vec<&PalletStorageMetadata<PortableForm>>)> {
+	match &metadata.1 {
+		RuntimeMetadata::V14(metadata) => Some((
+			&metadata.types,
+			metadata.pallets.iter().filter_map(|p| p.storage.as_ref()).collect(),
+		)),
+		RuntimeMetadata::V15(metadata) => Some((
+			&metadata.types,
+			metadata.pallets.iter().filter_map(|p| p.storage.as_ref()).collect(),
+		)),
+		_ => None,
+	}
+}
+
+/// Compares types of two different registries by their encoding.
+struct TypeComparison<'a> {
+	old: &'a PortableRegistry,
+	new: &'a PortableRegistry,
+	/// Pairs of types that are currently being compared.
+	///
+	/// Recursive types would otherwise never terminate. Assuming that a pair is compatible while
+	/// it is compared is sound, since any incompatibility makes the whole comparison fail.
+	visiting: BTreeSet<(u32, u32)>,
+}
+
+impl<'a> TypeComparison<'a> {
+	fn new(old: &'a PortableRegistry, new: &'a PortableRegistry) -> Self {
+		Self { old, new, visiting: BTreeSet::new() }
+	}
+
+	/// Whether the `new` type can decode all values that were encoded with the `old` type.
+	fn compatible(&mut self, old: u32, new: u32) -> bool {
+		if !self.visiting.insert((old, new)) {
+			return true
+		}
+		let (Some(old_ty), Some(new_ty)) = (self.old.resolve(old), self.new.resolve(new)) else {
+			return false
+		};
+
+		match (&old_ty.type_def, &new_ty.type_def) {
+			(TypeDef::Composite(old), TypeDef::Composite(new)) =>
+				self.fields_compatible(&old.fields, &new.fields),
+			(TypeDef::Variant(old), TypeDef::Variant(new)) =>
+				old.variants.iter().all(|old_variant| {
+					new.variants.iter().find(|v| v.index == old_variant.index).map_or(
+						false,
+						|new_variant| {
+							self.fields_compatible(&old_variant.fields, &new_variant.fields)
+						},
+					)
+				}),
+			(TypeDef::Sequence(old), TypeDef::Sequence(new)) =>
+				self.compatible(old.type_param.id, new.type_param.id),
+			(TypeDef::Array(old), TypeDef::Array(new)) =>
+				old.len == new.len && self.compatible(old.type_param.id, new.type_param.id),
+			(TypeDef::Tuple(old), TypeDef::Tuple(new)) =>
+				old.fields.len() == new.fields.len() &&
+					old.fields.iter().zip(&new.fields).all(|(o, n)| self.compatible(o.id, n.id)),
+			(TypeDef::Primitive(old), TypeDef::Primitive(new)) => old == new,
+			(TypeDef::Compact(old), TypeDef::Compact(new)) =>
+				self.compatible(old.type_param.id, new.type_param.id),
+			(TypeDef::BitSequence(old), TypeDef::BitSequence(new)) =>
+				self.compatible(old.bit_store_type.id, new.bit_store_type.id) &&
+					self.compatible(old.bit_order_type.id, new.bit_order_type.id),
+			// A composite with a single field, like a new type wrapper, is encoded like its field.
+			(TypeDef::Composite(old), _) if old.fields.len() == 1 =>
+				self.compatible(old.fields[0].ty.id, new),
+			(_, TypeDef::Composite(new_composite)) if new_composite.fields.len() == 1 =>
+				self.compatible(old, new_composite.fields[0].ty.id),
+			_ => false,
+		}
+	}
+
+	fn fields_compatible(
+		&mut self,
+		old: &[Field<PortableForm>],
+		new: &[Field<PortableForm>],
+	) -> bool {
+		old.len() == new.len() &&
+			old.iter().zip(new).all(|(o, n)| self.compatible(o.ty.id, n.ty.id))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::*;
+	use scale_info::{meta_type, MetaType, TypeInfo};
+
+	#[derive(TypeInfo)]
+	struct Wrapper(#[allow(dead_code)] u32);
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum Old {
+		A(u32),
+		B,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum Extended {
+		A(u32),
+		B,
+		C(u64),
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum Changed {
+		A(u64),
+		B,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Node {
+		children: Vec<Node>,
+	}
+
+	fn plain(name: &'static str, ty: MetaType) -> StorageEntryMetadataIR {
+		StorageEntryMetadataIR {
+			name,
+			modifier: StorageEntryModifierIR::Optional,
+			ty: StorageEntryTypeIR::Plain(ty),
+			default: vec![],
+			docs: vec![],
+		}
+	}
+
+	fn map(
+		name: &'static str,
+		hasher: StorageHasherIR,
+		key: MetaType,
+		value: MetaType,
+	) -> StorageEntryMetadataIR {
+		StorageEntryMetadataIR {
+			name,
+			modifier: StorageEntryModifierIR::Optional,
+			ty: StorageEntryTypeIR::Map { hashers: vec![hasher], key, value },
+			default: vec![],
+			docs: vec![],
+		}
+	}
+
+	fn metadata(entries: Vec<StorageEntryMetadataIR>) -> MetadataIR {
+		MetadataIR {
+			pallets: vec![PalletMetadataIR {
+				name: "Test",
+				storage: Some(PalletStorageMetadataIR { prefix: "Test", entries }),
+				calls: None,
+				event: None,
+				constants: vec![],
+				view_functions: vec![],
+				error: None,
+				index: 0,
+				docs: vec![],
+			}],
+			extrinsic: ExtrinsicMetadataIR {
+				ty: meta_type::<()>(),
+				version: 0,
+				address_ty: meta_type::<()>(),
+				call_ty: meta_type::<()>(),
+				signature_ty: meta_type::<()>(),
+				extra_ty: meta_type::<()>(),
+				signed_extensions: vec![],
+			},
+			ty: meta_type::<()>(),
+			apis: vec![],
+			outer_enums: OuterEnumsIR {
+				call_enum_ty: meta_type::<()>(),
+				event_enum_ty: meta_type::<()>(),
+				error_enum_ty: meta_type::<()>(),
+			},
+		}
+	}
+
+	fn changes(
+		old: Vec<StorageEntryMetadataIR>,
+		new: Vec<StorageEntryMetadataIR>,
+	) -> Vec<StorageLayoutChangeKind> {
+		let old = into_v14(metadata(old));
+		let new = into_latest(metadata(new));
+		storage_layout_changes(&old, &new)
+			.unwrap()
+			.into_iter()
+			.map(|c| c.kind)
+			.collect()
+	}
+
+	#[test]
+	fn unchanged_layout_is_compatible() {
+		let entries = || {
+			vec![
+				plain("Value", meta_type::<Old>()),
+				map("Map", StorageHasherIR::Twox64Concat, meta_type::<u32>(), meta_type::<Node>()),
+			]
+		};
+		assert!(changes(entries(), entries()).is_empty());
+	}
+
+	#[test]
+	fn compatible_type_changes_are_allowed() {
+		assert!(changes(
+			vec![plain("Value", meta_type::<Old>()), plain("Number", meta_type::<u32>())],
+			vec![plain("Value", meta_type::<Extended>()), plain("Number", meta_type::<Wrapper>())],
+		)
+		.is_empty());
+	}
+
+	#[test]
+	fn incompatible_changes_are_detected() {
+		assert_eq!(
+			changes(
+				vec![
+					plain("Value", meta_type::<Old>()),
+					plain("Kind", meta_type::<u32>()),
+					map(
+						"Hashers",
+						StorageHasherIR::Twox64Concat,
+						meta_type::<u32>(),
+						meta_type::<u32>()
+					),
+					map(
+						"Key",
+						StorageHasherIR::Twox64Concat,
+						meta_type::<u32>(),
+						meta_type::<u32>()
+					),
+					map(
+						"Map",
+						StorageHasherIR::Twox64Concat,
+						meta_type::<u32>(),
+						meta_type::<u32>()
+					),
+				],
+				vec![
+					plain("Value", meta_type::<Changed>()),
+					map("Kind", StorageHasherIR::Identity, meta_type::<u32>(), meta_type::<u32>()),
+					map(
+						"Hashers",
+						StorageHasherIR::Blake2_128Concat,
+						meta_type::<u32>(),
+						meta_type::<u32>()
+					),
+					map(
+						"Key",
+						StorageHasherIR::Twox64Concat,
+						meta_type::<u64>(),
+						meta_type::<u32>()
+					),
+					map(
+						"Map",
+						StorageHasherIR::Twox64Concat,
+						meta_type::<u32>(),
+						meta_type::<u16>()
+					),
+				],
+			),
+			vec![
+				StorageLayoutChangeKind::ValueType,
+				StorageLayoutChangeKind::Kind,
+				StorageLayoutChangeKind::Hashers,
+				StorageLayoutChangeKind::KeyType,
+				StorageLayoutChangeKind::ValueType,
+			]
+		);
+	}
+
+	#[test]
+	fn removed_and_added_entries_are_ignored() {
+		assert!(changes(
+			vec![plain("Removed", meta_type::<u32>())],
+			vec![plain("Added", meta_type::<u64>())],
+		)
+		.is_empty());
+	}
+
+	#[test]
+	fn migrated_changes_are_not_reported() {
+		let old = into_latest(metadata(vec![plain("Value", meta_type::<u32>())]));
+		let new = into_latest(metadata(vec![plain("Value", meta_type::<u64>())]));
+
+		let unmigrated = unmigrated_storage_changes(&old, &new, |_| (1, 1)).unwrap();
+		assert_eq!(
+			unmigrated,
+			vec![StorageLayoutChange {
+				pallet: "Test".into(),
+				entry: "Value".into(),
+				kind: StorageLayoutChangeKind::ValueType,
+			}]
+		);
+		assert!(unmigrated_storage_changes(&old, &new, |_| (1, 2)).unwrap().is_empty());
+	}
+}
@@ -30,8 +30,18 @@ mod types;
 use frame_metadata::RuntimeMetadataPrefixed;
 pub use types::*;
 
+mod compat;
+pub use compat::{
+	storage_layout_changes, unmigrated_storage_changes, StorageLayoutChange,
+	StorageLayoutChangeKind,
+};
+
 mod v14;
 mod v15;
+pub use v15::{
+	PalletViewFunctionsCustomMetadata, ViewFunctionCustomMetadata, ViewFunctionParamCustomMetadata,
+	VIEW_FUNCTIONS_CUSTOM_METADATA_KEY,
+};
 
 /// Metadata V14.
 const V14: u32 = 14;
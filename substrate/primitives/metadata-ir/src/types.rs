@@ -125,6 +125,8 @@ pub struct PalletMetadataIR<T: Form = MetaForm> {
 	pub event: Option<PalletEventMetadataIR<T>>,
 	/// Pallet constants metadata.
 	pub constants: Vec<PalletConstantMetadataIR<T>>,
+	/// Pallet view functions metadata.
+	pub view_functions: Vec<PalletViewFunctionMetadataIR<T>>,
 	/// Pallet error metadata.
 	pub error: Option<PalletErrorMetadataIR<T>>,
 	/// Define the index of the pallet, this index will be used for the encoding of pallet event,
@@ -144,6 +146,7 @@ impl IntoPortable for PalletMetadataIR {
 			calls: self.calls.map(|calls| calls.into_portable(registry)),
 			event: self.event.map(|event| event.into_portable(registry)),
 			constants: registry.map_into_portable(self.constants),
+			view_functions: registry.map_into_portable(self.view_functions),
 			error: self.error.map(|error| error.into_portable(registry)),
 			index: self.index,
 			docs: registry.map_into_portable(self.docs),
@@ -394,6 +397,55 @@ impl IntoPortable for PalletConstantMetadataIR {
 	}
 }
 
+/// Metadata about one pallet view function.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub struct PalletViewFunctionMetadataIR<T: Form = MetaForm> {
+	/// Name of the view function.
+	pub name: T::String,
+	/// The id of the view function, used to execute it through the runtime API.
+	pub id: [u8; 32],
+	/// The arguments of the view function.
+	pub inputs: Vec<PalletViewFunctionParamMetadataIR<T>>,
+	/// The type returned by the view function.
+	pub output: T::Type,
+	/// Documentation of the view function.
+	pub docs: Vec<T::String>,
+}
+
+impl IntoPortable for PalletViewFunctionMetadataIR {
+	type Output = PalletViewFunctionMetadataIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		PalletViewFunctionMetadataIR {
+			name: self.name.into_portable(registry),
+			id: self.id,
+			inputs: registry.map_into_portable(self.inputs),
+			output: registry.register_type(&self.output),
+			docs: registry.map_into_portable(self.docs),
+		}
+	}
+}
+
+/// Metadata about one argument of a pallet view function.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub struct PalletViewFunctionParamMetadataIR<T: Form = MetaForm> {
+	/// Name of the argument.
+	pub name: T::String,
+	/// Type of the argument.
+	pub ty: T::Type,
+}
+
+impl IntoPortable for PalletViewFunctionParamMetadataIR {
+	type Output = PalletViewFunctionParamMetadataIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		PalletViewFunctionParamMetadataIR {
+			name: self.name.into_portable(registry),
+			ty: registry.register_type(&self.ty),
+		}
+	}
+}
+
 /// Metadata about a pallet error.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
 pub struct PalletErrorMetadataIR<T: Form = MetaForm> {
@@ -52,6 +52,7 @@ impl From<PalletMetadataIR> for PalletMetadata {
 			error: ir.error.map(Into::into),
 			index: ir.index,
 			// Note: ir.docs not part of v14.
+			// Note: ir.view_functions not part of v14.
 		}
 	}
 }
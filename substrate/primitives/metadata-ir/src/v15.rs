@@ -20,28 +20,124 @@
 use crate::OuterEnumsIR;
 
 use super::types::{
-	ExtrinsicMetadataIR, MetadataIR, PalletMetadataIR, RuntimeApiMetadataIR,
-	RuntimeApiMethodMetadataIR, RuntimeApiMethodParamMetadataIR, SignedExtensionMetadataIR,
+	ExtrinsicMetadataIR, MetadataIR, PalletMetadataIR, PalletViewFunctionMetadataIR,
+	RuntimeApiMetadataIR, RuntimeApiMethodMetadataIR, RuntimeApiMethodParamMetadataIR,
+	SignedExtensionMetadataIR,
 };
 
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use codec::{Decode, Encode};
 use frame_metadata::v15::{
-	CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata,
-	RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata, RuntimeMetadataV15,
-	SignedExtensionMetadata,
+	CustomMetadata, CustomValueMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata,
+	RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata,
+	RuntimeMetadataV15, SignedExtensionMetadata,
 };
+use scale_info::{form::PortableForm, meta_type, IntoPortable, Registry, TypeInfo};
+
+/// The key of the view functions in the custom metadata of V15.
+///
+/// V15 has no field for the view functions of the pallets, so they are added to the custom
+/// metadata as a `Vec<PalletViewFunctionsCustomMetadata>`. The key is only present if at least
+/// one pallet has view functions.
+pub const VIEW_FUNCTIONS_CUSTOM_METADATA_KEY: &str = "view_functions";
+
+/// The view functions of a pallet in the custom metadata of V15.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub struct PalletViewFunctionsCustomMetadata {
+	/// Name of the pallet.
+	pub pallet: String,
+	/// The view functions of the pallet.
+	pub view_functions: Vec<ViewFunctionCustomMetadata>,
+}
+
+/// A view function in the custom metadata of V15.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub struct ViewFunctionCustomMetadata {
+	/// Name of the view function.
+	pub name: String,
+	/// The id of the view function, used to execute it through the runtime API.
+	pub id: [u8; 32],
+	/// The arguments of the view function.
+	pub inputs: Vec<ViewFunctionParamCustomMetadata>,
+	/// The id of the type returned by the view function in the type registry of the metadata.
+	#[codec(compact)]
+	pub output: u32,
+	/// Documentation of the view function.
+	pub docs: Vec<String>,
+}
+
+/// An argument of a view function in the custom metadata of V15.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub struct ViewFunctionParamCustomMetadata {
+	/// Name of the argument.
+	pub name: String,
+	/// The id of the type of the argument in the type registry of the metadata.
+	#[codec(compact)]
+	pub ty: u32,
+}
+
+impl From<PalletViewFunctionMetadataIR<PortableForm>> for ViewFunctionCustomMetadata {
+	fn from(ir: PalletViewFunctionMetadataIR<PortableForm>) -> Self {
+		ViewFunctionCustomMetadata {
+			name: ir.name,
+			id: ir.id,
+			inputs: ir
+				.inputs
+				.into_iter()
+				.map(|input| ViewFunctionParamCustomMetadata { name: input.name, ty: input.ty.id })
+				.collect(),
+			output: ir.output.id,
+			docs: ir.docs,
+		}
+	}
+}
 
 impl From<MetadataIR> for RuntimeMetadataV15 {
 	fn from(ir: MetadataIR) -> Self {
-		RuntimeMetadataV15::new(
-			ir.pallets.into_iter().map(Into::into).collect(),
-			ir.extrinsic.into(),
-			ir.ty,
-			ir.apis.into_iter().map(Into::into).collect(),
-			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V15 easily.
-			CustomMetadata { map: Default::default() },
-		)
+		let view_functions = ir
+			.pallets
+			.iter()
+			.filter(|pallet| !pallet.view_functions.is_empty())
+			.map(|pallet| (pallet.name, pallet.view_functions.clone()))
+			.collect::<Vec<_>>();
+
+		// Same as `RuntimeMetadataV15::new`, but the registry is also needed for the types of
+		// the view functions in the custom metadata.
+		let mut registry = Registry::new();
+		let pallets = registry.map_into_portable(ir.pallets.into_iter().map(PalletMetadata::from));
+		let extrinsic = ExtrinsicMetadata::from(ir.extrinsic).into_portable(&mut registry);
+		let ty = registry.register_type(&ir.ty);
+		let apis = registry.map_into_portable(ir.apis.into_iter().map(RuntimeApiMetadata::from));
+		let outer_enums = OuterEnums::from(ir.outer_enums).into_portable(&mut registry);
+
+		let mut custom = BTreeMap::new();
+		if !view_functions.is_empty() {
+			let view_functions = view_functions
+				.into_iter()
+				.map(|(pallet, view_functions)| PalletViewFunctionsCustomMetadata {
+					pallet: pallet.into(),
+					view_functions: view_functions
+						.into_iter()
+						.map(|view_function| view_function.into_portable(&mut registry).into())
+						.collect(),
+				})
+				.collect::<Vec<_>>();
+			let ty = registry.register_type(&meta_type::<Vec<PalletViewFunctionsCustomMetadata>>());
+			custom.insert(
+				VIEW_FUNCTIONS_CUSTOM_METADATA_KEY.into(),
+				CustomValueMetadata { ty, value: view_functions.encode() },
+			);
+		}
+
+		RuntimeMetadataV15 {
+			types: registry.into(),
+			pallets,
+			extrinsic,
+			ty,
+			apis,
+			outer_enums,
+			custom: CustomMetadata { map: custom },
+		}
 	}
 }
 
@@ -83,6 +179,7 @@ impl From<PalletMetadataIR> for PalletMetadata {
 			error: ir.error.map(Into::into),
 			index: ir.index,
 			docs: ir.docs,
+			// Note: ir.view_functions are part of the custom metadata.
 		}
 	}
 }
@@ -463,6 +463,20 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// A chunk of balance that is being unbonded, together with an estimate of when it unlocks.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnlockingChunkInfo<Balance> {
+	/// Era at which the chunk can be withdrawn.
+	pub era: EraIndex,
+	/// Amount of the chunk.
+	pub value: Balance,
+	/// Estimated unix timestamp, in milliseconds, at which the chunk can be withdrawn. `None` if
+	/// no estimate can be made yet.
+	pub unlock_at: Option<u64>,
+	/// The part of `value` which can be withdrawn right away.
+	pub withdrawable: Balance,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can
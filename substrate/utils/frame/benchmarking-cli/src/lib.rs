@@ -22,6 +22,7 @@ mod extrinsic;
 mod machine;
 mod overhead;
 mod pallet;
+mod pov;
 mod shared;
 mod storage;
 
@@ -30,6 +31,7 @@ pub use extrinsic::{ExtrinsicBuilder, ExtrinsicCmd, ExtrinsicFactory};
 pub use machine::{MachineCmd, SUBSTRATE_REFERENCE_HARDWARE};
 pub use overhead::OverheadCmd;
 pub use pallet::PalletCmd;
+pub use pov::PovCmd;
 pub use sc_service::BasePath;
 pub use storage::StorageCmd;
 
@@ -46,6 +48,7 @@ pub enum BenchmarkCmd {
 	Block(BlockCmd),
 	Machine(MachineCmd),
 	Extrinsic(ExtrinsicCmd),
+	Pov(PovCmd),
 }
 
 /// Unwraps a [`BenchmarkCmd`] into its concrete sub-command.
@@ -62,6 +65,7 @@ macro_rules! unwrap_cmd {
 			BenchmarkCmd::Block($cmd) => $code,
 			BenchmarkCmd::Machine($cmd) => $code,
 			BenchmarkCmd::Extrinsic($cmd) => $code,
+			BenchmarkCmd::Pov($cmd) => $code,
 		}
 	}
 }
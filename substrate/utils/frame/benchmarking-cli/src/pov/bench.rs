@@ -0,0 +1,199 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the core PoV measuring logic.
+
+use codec::Encode;
+use sc_block_builder::{BlockBuilderApi, BlockBuilderBuilder};
+use sc_cli::{Error, Result};
+use sc_client_api::UsageProvider;
+use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_blockchain::{ApplyExtrinsicFailed::Validity, Error::ApplyExtrinsicFailed, HeaderBackend};
+use sp_runtime::{
+	traits::{Block as BlockT, HashingFor, Header as HeaderT},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	Digest, DigestItem, OpaqueExtrinsic,
+};
+
+use clap::Args;
+use log::info;
+use serde::Serialize;
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::extrinsic::ExtrinsicBuilder;
+
+/// Default value of `--max-pov-size`.
+///
+/// This is the `MAX_POV_SIZE` of the Polkadot relay chain.
+const DEFAULT_MAX_POV_SIZE: u32 = 5 * 1024 * 1024;
+
+/// Parameters to configure a *PoV* benchmark.
+#[derive(Debug, Default, Serialize, Clone, PartialEq, Args)]
+pub struct BenchmarkParams {
+	/// The maximal size of a PoV in bytes.
+	///
+	/// Should be set to the `max_pov_size` of the relay chain host configuration.
+	#[arg(long, default_value_t = DEFAULT_MAX_POV_SIZE)]
+	pub max_pov_size: u32,
+
+	/// Maximal number of extrinsics that should be put into a block.
+	///
+	/// Only useful for debugging.
+	#[arg(long)]
+	pub max_ext_per_block: Option<u32>,
+}
+
+/// The PoV size of a block and how much each extrinsic kind contributed to it.
+#[derive(Debug, Clone)]
+pub(crate) struct PovRecord {
+	/// Number of extrinsics per builder that were put into the block.
+	pub num_ext: Vec<u32>,
+	/// Estimated proof and body bytes that were added by the extrinsics of each builder.
+	pub ext_size: Vec<usize>,
+	/// Estimated size of the block with only its inherents, including their proof.
+	pub inherents_size: usize,
+	/// Encoded size of the raw storage proof.
+	pub proof_size: usize,
+	/// Encoded size of the compacted storage proof.
+	pub compact_proof_size: usize,
+	/// Encoded size of the PoV as it would be sent to the relay chain validators.
+	pub pov_size: usize,
+	/// Size of the PoV after compression.
+	pub compressed_pov_size: usize,
+}
+
+impl PovRecord {
+	/// Total number of non-inherent extrinsics in the block.
+	pub fn total_ext(&self) -> u32 {
+		self.num_ext.iter().sum()
+	}
+}
+
+/// Holds all objects needed to run the *PoV* benchmarks.
+pub(crate) struct Benchmark<Block, C> {
+	client: Arc<C>,
+	params: BenchmarkParams,
+	inherent_data: sp_inherents::InherentData,
+	digest_items: Vec<DigestItem>,
+	_p: PhantomData<Block>,
+}
+
+impl<Block, C> Benchmark<Block, C>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	C: ProvideRuntimeApi<Block> + CallApiAt<Block> + UsageProvider<Block> + HeaderBackend<Block>,
+	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+{
+	/// Create a new [`Self`] from the arguments.
+	pub fn new(
+		client: Arc<C>,
+		params: BenchmarkParams,
+		inherent_data: sp_inherents::InherentData,
+		digest_items: Vec<DigestItem>,
+	) -> Self {
+		Self { client, params, inherent_data, digest_items, _p: PhantomData }
+	}
+
+	/// The maximal PoV size that the blocks are compared against.
+	pub fn max_pov_size(&self) -> u32 {
+		self.params.max_pov_size
+	}
+
+	/// Builds a block with proof recording enabled and measures its PoV size.
+	///
+	/// The block is filled with extrinsics from the `ext_builders` in a round-robin fashion
+	/// until it is full. A single nonce is shared by all builders, so they must all use the
+	/// same signer. Passing no builders results in a block that only contains inherents.
+	pub fn measure_pov(&self, ext_builders: &[&dyn ExtrinsicBuilder]) -> Result<PovRecord> {
+		let chain = self.client.usage_info().chain;
+		let parent_header = self
+			.client
+			.header(chain.best_hash)?
+			.ok_or_else(|| Error::Input(format!("Header of {} not found", chain.best_hash)))?;
+		let mut builder = BlockBuilderBuilder::new(&*self.client)
+			.on_parent_block(chain.best_hash)
+			.with_parent_block_number(chain.best_number)
+			.with_inherent_digests(Digest { logs: self.digest_items.clone() })
+			.enable_proof_recording()
+			.build()?;
+
+		// Create and insert the inherents.
+		let inherents = builder.create_inherents(self.inherent_data.clone())?;
+		for inherent in inherents {
+			builder.push(inherent)?;
+		}
+		let inherents_size = builder.estimate_block_size(true);
+
+		let mut num_ext = vec![0u32; ext_builders.len()];
+		let mut ext_size = vec![0usize; ext_builders.len()];
+		if !ext_builders.is_empty() {
+			info!("Building block, this takes some time...");
+		}
+		// Put as many extrinsics into the block as possible and attribute the growth of the
+		// estimated block size to the builder that created each of them.
+		for nonce in 0..self.max_ext_per_block() {
+			let index = nonce as usize % ext_builders.len().max(1);
+			let Some(ext_builder) = ext_builders.get(index) else { break };
+			let ext = ext_builder.build(nonce)?;
+
+			let before = builder.estimate_block_size(true);
+			match builder.push(ext) {
+				Ok(()) => {},
+				Err(ApplyExtrinsicFailed(Validity(TransactionValidityError::Invalid(
+					InvalidTransaction::ExhaustsResources,
+				)))) => break, // Block is full
+				Err(e) => return Err(Error::Client(e)),
+			}
+			num_ext[index] += 1;
+			ext_size[index] += builder.estimate_block_size(true).saturating_sub(before);
+		}
+		if !ext_builders.is_empty() && num_ext.iter().all(|n| *n == 0) {
+			return Err("A Block must hold at least one extrinsic".into())
+		}
+
+		let built = builder.build()?;
+		let proof = built.proof.ok_or("Proof recording must be enabled")?;
+		let proof_size = proof.encoded_size();
+		let compact_proof = proof
+			.into_compact_proof::<HashingFor<Block>>(*parent_header.state_root())
+			.map_err(|e| format!("Failed to compact proof: {:?}", e))?;
+
+		// This is the same encoding as the `ParachainBlockData` that a collator sends to the
+		// relay chain validators.
+		let (header, extrinsics) = built.block.deconstruct();
+		let pov = (header, extrinsics, &compact_proof).encode();
+		// A PoV is only compressed when it is below the bomb limit, which is four times the
+		// maximal PoV size.
+		let bomb_limit = self.params.max_pov_size as usize * 4;
+		let compressed_pov_size = sp_maybe_compressed_blob::compress(&pov, bomb_limit)
+			.map_or(pov.len(), |compressed| compressed.len());
+
+		Ok(PovRecord {
+			num_ext,
+			ext_size,
+			inherents_size,
+			proof_size,
+			compact_proof_size: compact_proof.encoded_size(),
+			pov_size: pov.len(),
+			compressed_pov_size,
+		})
+	}
+
+	fn max_ext_per_block(&self) -> u32 {
+		self.params.max_ext_per_block.unwrap_or(u32::MAX)
+	}
+}
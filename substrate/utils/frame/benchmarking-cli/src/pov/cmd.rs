@@ -0,0 +1,308 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`PovCmd`] as entry point for the CLI to execute
+//! the *PoV* benchmark.
+
+use sc_block_builder::BlockBuilderApi;
+use sc_cli::{CliConfiguration, Error, ImportParams, Result, SharedParams};
+use sc_client_api::UsageProvider;
+use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_runtime::{traits::Block as BlockT, DigestItem, OpaqueExtrinsic};
+
+use clap::{Args, Parser};
+use comfy_table::Table;
+use log::{info, warn};
+use serde::Serialize;
+use std::{fmt::Debug, sync::Arc};
+use thousands::Separable;
+
+use super::bench::{Benchmark, BenchmarkParams, PovRecord};
+use crate::extrinsic::{ExtrinsicBuilder, ExtrinsicFactory};
+
+/// Log target for printing PoV size info.
+const LOG_TARGET: &'static str = "benchmark::pov";
+
+/// Marks the blocks whose compressed PoV is larger than the `max_pov_size`.
+const OVER_LIMIT: &'static str = "OVER LIMIT!";
+
+/// Benchmark the proof-of-validity (PoV) size of blocks.
+///
+/// Builds full blocks from the selected extrinsics while recording the storage proof, and
+/// reports the compressed PoV size as a validator would receive it from a collator. The result
+/// is compared to the `--max-pov-size` of the parachain. Example:
+///
+/// $ substrate benchmark pov --dev --extrinsic system::remark
+///   --extrinsic balances::transfer_keep_alive
+///
+/// One block is built for each extrinsic, and one more block that mixes all of them.
+/// The mixed block shows which of the extrinsics dominate the PoV usage. Without any
+/// `--extrinsic`, only the block with the inherents is measured.
+#[derive(Debug, Parser)]
+pub struct PovCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub params: PovParams,
+}
+
+/// The params for the [`PovCmd`].
+#[derive(Debug, Default, Serialize, Clone, PartialEq, Args)]
+pub struct PovParams {
+	#[clap(flatten)]
+	pub bench: BenchmarkParams,
+
+	/// List all available pallets and extrinsics.
+	///
+	/// The format is CSV with header `pallet, extrinsic`.
+	#[arg(long)]
+	pub list: bool,
+
+	/// Extrinsic to put into the blocks, in the form `pallet::extrinsic`.
+	///
+	/// Can be passed multiple times. All extrinsics must be signed by the same account. If none
+	/// is passed, only the block with the inherents is measured.
+	#[arg(long = "extrinsic", value_name = "PALLET::EXTRINSIC")]
+	pub extrinsics: Vec<String>,
+
+	/// Enable the Trie cache.
+	///
+	/// This should only be used for performance analysis and not for final results.
+	#[arg(long)]
+	pub enable_trie_cache: bool,
+}
+
+impl PovCmd {
+	/// Measure the PoV size of blocks that are filled with the selected extrinsics.
+	///
+	/// The output will be printed to console.
+	pub fn run<Block, C>(
+		&self,
+		client: Arc<C>,
+		inherent_data: sp_inherents::InherentData,
+		digest_items: Vec<DigestItem>,
+		ext_factory: &ExtrinsicFactory,
+	) -> Result<()>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		C: ProvideRuntimeApi<Block>
+			+ CallApiAt<Block>
+			+ UsageProvider<Block>
+			+ sp_blockchain::HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+	{
+		// Short circuit if --list was specified.
+		if self.params.list {
+			let list: Vec<String> = ext_factory.0.iter().map(|b| b.name()).collect();
+			info!(
+				"Listing available extrinsics ({}):\npallet, extrinsic\n{}",
+				list.len(),
+				list.join("\n")
+			);
+			return Ok(())
+		}
+
+		let ext_builders = self
+			.params
+			.extrinsics
+			.iter()
+			.map(|name| {
+				let (pallet, extrinsic) = name.split_once("::").unwrap_or((name.as_str(), ""));
+				ext_factory.try_get(pallet, extrinsic).ok_or_else(|| {
+					Error::Input(format!(
+						"Unknown extrinsic '{}'. Use --list for a complete list.",
+						name
+					))
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		let bench = Benchmark::new(client, self.params.bench.clone(), inherent_data, digest_items);
+		let max_pov_size = bench.max_pov_size() as usize;
+
+		let mut table = Table::new();
+		table.set_header([
+			"Block",
+			"Extrinsics",
+			"Storage proof",
+			"Compact proof",
+			"PoV",
+			"Compressed PoV",
+			"Of max_pov_size",
+		]);
+
+		let inherents = bench.measure_pov(&[])?;
+		table.add_row(Self::to_row("inherents only", &inherents, max_pov_size));
+
+		let mut over_limit = Vec::new();
+		if inherents.compressed_pov_size > max_pov_size {
+			over_limit.push("inherents only".into());
+		}
+		for ext_builder in &ext_builders {
+			let record = bench.measure_pov(&[*ext_builder])?;
+			let name = format!("{}::{}", ext_builder.pallet(), ext_builder.extrinsic());
+			if record.compressed_pov_size > max_pov_size {
+				over_limit.push(name.clone());
+			}
+			table.add_row(Self::to_row(&name, &record, max_pov_size));
+		}
+
+		let mixed =
+			(ext_builders.len() > 1).then(|| bench.measure_pov(&ext_builders)).transpose()?;
+		if let Some(ref mixed) = mixed {
+			if mixed.compressed_pov_size > max_pov_size {
+				over_limit.push("mixed".into());
+			}
+			table.add_row(Self::to_row("mixed", mixed, max_pov_size));
+		}
+
+		info!(
+			target: LOG_TARGET,
+			"PoV sizes in bytes with a max_pov_size of {} bytes:\n{}",
+			max_pov_size.separate_with_commas(),
+			table
+		);
+		if let Some(mixed) = mixed {
+			info!(
+				target: LOG_TARGET,
+				"PoV usage per extrinsic in the mixed block:\n{}",
+				Self::usage_table(&ext_builders, &mixed)
+			);
+		}
+		if !over_limit.is_empty() {
+			warn!(
+				target: LOG_TARGET,
+				"The compressed PoV of these blocks exceeds the max_pov_size: {}",
+				over_limit.join(", ")
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Formats a [`PovRecord`] as row of the summary table.
+	fn to_row(name: &str, record: &PovRecord, max_pov_size: usize) -> Vec<String> {
+		let percent = (record.compressed_pov_size as f64 / max_pov_size as f64) * 100.0;
+		let mut usage = format!("{:.2}%", percent);
+		if record.compressed_pov_size > max_pov_size {
+			usage = format!("{} {}", usage, OVER_LIMIT);
+		}
+
+		vec![
+			name.into(),
+			record.total_ext().separate_with_commas(),
+			record.proof_size.separate_with_commas(),
+			record.compact_proof_size.separate_with_commas(),
+			record.pov_size.separate_with_commas(),
+			record.compressed_pov_size.separate_with_commas(),
+			usage,
+		]
+	}
+
+	/// Breaks down how many bytes each extrinsic added to the uncompressed PoV of a block.
+	fn usage_table(ext_builders: &[&dyn ExtrinsicBuilder], record: &PovRecord) -> Table {
+		let total = record.inherents_size + record.ext_size.iter().sum::<usize>();
+
+		let mut rows = vec![(
+			"inherents".to_string(),
+			String::from("-"),
+			String::from("-"),
+			record.inherents_size,
+		)];
+		for (i, ext_builder) in ext_builders.iter().enumerate() {
+			let (count, size) = (record.num_ext[i], record.ext_size[i]);
+			let per_ext = size.checked_div(count as usize).unwrap_or_default();
+			rows.push((
+				format!("{}::{}", ext_builder.pallet(), ext_builder.extrinsic()),
+				count.separate_with_commas(),
+				per_ext.separate_with_commas(),
+				size,
+			));
+		}
+		// Show the extrinsics with the highest PoV usage first.
+		rows.sort_by(|a, b| b.3.cmp(&a.3));
+
+		let mut table = Table::new();
+		table.set_header(["Extrinsic", "Count", "Bytes per extrinsic", "Bytes", "Share"]);
+		for (name, count, per_ext, size) in rows {
+			let share = (size as f64 / total.max(1) as f64) * 100.0;
+			table.add_row(vec![
+				name,
+				count,
+				per_ext,
+				size.separate_with_commas(),
+				format!("{:.2}%", share),
+			]);
+		}
+		table
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for PovCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn trie_cache_maximum_size(&self) -> Result<Option<usize>> {
+		if self.params.enable_trie_cache {
+			Ok(self.import_params().map(|x| x.trie_cache_maximum_size()).unwrap_or_default())
+		} else {
+			Ok(None)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn record(compressed_pov_size: usize) -> PovRecord {
+		PovRecord {
+			num_ext: vec![10],
+			ext_size: vec![1000],
+			inherents_size: 100,
+			proof_size: 2000,
+			compact_proof_size: 1500,
+			pov_size: 2500,
+			compressed_pov_size,
+		}
+	}
+
+	#[test]
+	fn to_row_marks_blocks_over_the_limit() {
+		let row = PovCmd::to_row("system::remark", &record(1000), 2000);
+		assert_eq!(row.last().unwrap(), "50.00%");
+
+		let row = PovCmd::to_row("system::remark", &record(2000), 2000);
+		assert_eq!(row.last().unwrap(), "100.00%");
+
+		let row = PovCmd::to_row("system::remark", &record(3000), 2000);
+		assert_eq!(row.last().unwrap(), "150.00% OVER LIMIT!");
+	}
+}
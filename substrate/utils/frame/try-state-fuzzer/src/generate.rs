@@ -0,0 +1,240 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generation of random SCALE encoded values from their type information.
+
+use codec::{Compact, Encode};
+use rand::Rng;
+use scale_info::{MetaType, PortableRegistry, Registry, TypeDef, TypeDefPrimitive};
+use std::collections::BTreeMap;
+
+/// Generates random encoded values of a type, following its `scale-info` type information.
+///
+/// Types registered with [`Self::substitute`] are not generated, but picked from a list of
+/// pre-encoded values instead. This is used to only generate accounts that are known to the
+/// fuzzer.
+pub struct ValueGenerator {
+	registry: PortableRegistry,
+	root: u32,
+	substitutes: BTreeMap<u32, Vec<Vec<u8>>>,
+	max_depth: u32,
+	max_len: u32,
+}
+
+impl ValueGenerator {
+	/// Create a new generator for the type `root` together with the types that will be
+	/// substituted.
+	///
+	/// Returns the generator and the ids of the `substitutes` within its registry.
+	pub fn new(root: MetaType, substitutes: &[MetaType]) -> (Self, Vec<u32>) {
+		let mut registry = Registry::new();
+		let root = registry.register_type(&root).id;
+		let substitute_ids =
+			substitutes.iter().map(|ty| registry.register_type(ty).id).collect::<Vec<_>>();
+
+		let generator = Self {
+			registry: registry.into(),
+			root,
+			substitutes: Default::default(),
+			max_depth: 32,
+			max_len: 4,
+		};
+		(generator, substitute_ids)
+	}
+
+	/// Always generate one of `values` for the type with id `ty`.
+	///
+	/// Primitive types are never substituted, since they are usually used for more than one
+	/// purpose.
+	pub fn substitute(&mut self, ty: u32, values: Vec<Vec<u8>>) {
+		let is_primitive = self
+			.registry
+			.resolve(ty)
+			.map_or(true, |ty| matches!(ty.type_def, TypeDef::Primitive(_) | TypeDef::Compact(_)));
+		if !is_primitive && !values.is_empty() {
+			self.substitutes.insert(ty, values);
+		}
+	}
+
+	/// Set the maximal nesting depth of generated types.
+	pub fn set_max_depth(&mut self, max_depth: u32) {
+		self.max_depth = max_depth;
+	}
+
+	/// Set the maximal length of generated sequences and strings.
+	pub fn set_max_len(&mut self, max_len: u32) {
+		self.max_len = max_len;
+	}
+
+	/// Generate the encoding of a random value of the root type.
+	///
+	/// Returns `None` if no value could be generated within the maximal depth.
+	pub fn generate(&self, rng: &mut impl Rng) -> Option<Vec<u8>> {
+		let mut out = Vec::new();
+		self.generate_type(self.root, 0, rng, &mut out)?;
+		Some(out)
+	}
+
+	fn generate_type(
+		&self,
+		id: u32,
+		depth: u32,
+		rng: &mut impl Rng,
+		out: &mut Vec<u8>,
+	) -> Option<()> {
+		if depth > self.max_depth {
+			return None
+		}
+		if let Some(values) = self.substitutes.get(&id) {
+			out.extend_from_slice(&values[rng.gen_range(0..values.len())]);
+			return Some(())
+		}
+
+		let ty = self.registry.resolve(id)?;
+		match &ty.type_def {
+			TypeDef::Composite(composite) =>
+				for field in &composite.fields {
+					self.generate_type(field.ty.id, depth + 1, rng, out)?;
+				},
+			TypeDef::Variant(variant) => {
+				if variant.variants.is_empty() {
+					return None
+				}
+				let start = rng.gen_range(0..variant.variants.len());
+				// Try the other variants if the chosen one is too deep, e.g. nested calls.
+				let generated = (0..variant.variants.len()).find_map(|offset| {
+					let variant = &variant.variants[(start + offset) % variant.variants.len()];
+					let mut encoded = vec![variant.index];
+					for field in &variant.fields {
+						self.generate_type(field.ty.id, depth + 1, rng, &mut encoded)?;
+					}
+					Some(encoded)
+				})?;
+				out.extend(generated);
+			},
+			TypeDef::Sequence(sequence) => {
+				let len = self.generate_len(depth, rng);
+				Compact(len).encode_to(out);
+				for _ in 0..len {
+					self.generate_type(sequence.type_param.id, depth + 1, rng, out)?;
+				}
+			},
+			TypeDef::Array(array) =>
+				for _ in 0..array.len {
+					self.generate_type(array.type_param.id, depth + 1, rng, out)?;
+				},
+			TypeDef::Tuple(tuple) =>
+				for field in &tuple.fields {
+					self.generate_type(field.id, depth + 1, rng, out)?;
+				},
+			TypeDef::Primitive(primitive) => self.generate_primitive(primitive, depth, rng, out),
+			TypeDef::Compact(compact) => self.generate_compact(compact.type_param.id, rng, out)?,
+			// An empty bit sequence, since its encoding depends on the store and order types.
+			TypeDef::BitSequence(_) => Compact(0u32).encode_to(out),
+		}
+
+		Some(())
+	}
+
+	fn generate_primitive(
+		&self,
+		primitive: &TypeDefPrimitive,
+		depth: u32,
+		rng: &mut impl Rng,
+		out: &mut Vec<u8>,
+	) {
+		match primitive {
+			TypeDefPrimitive::Bool => rng.gen::<bool>().encode_to(out),
+			TypeDefPrimitive::Char => (rng.gen_range(b'a'..=b'z') as u32).encode_to(out),
+			TypeDefPrimitive::Str => {
+				let len = self.generate_len(depth, rng);
+				let string = (0..len).map(|_| rng.gen_range('a'..='z')).collect::<String>();
+				string.encode_to(out);
+			},
+			TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => out.extend(generate_int(1, rng)),
+			TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => out.extend(generate_int(2, rng)),
+			TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => out.extend(generate_int(4, rng)),
+			TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => out.extend(generate_int(8, rng)),
+			TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => out.extend(generate_int(16, rng)),
+			TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => out.extend(generate_int(32, rng)),
+		}
+	}
+
+	fn generate_compact(&self, id: u32, rng: &mut impl Rng, out: &mut Vec<u8>) -> Option<()> {
+		let ty = self.registry.resolve(id)?;
+		match &ty.type_def {
+			TypeDef::Primitive(primitive) => {
+				let width = match primitive {
+					TypeDefPrimitive::U8 => 1,
+					TypeDefPrimitive::U16 => 2,
+					TypeDefPrimitive::U32 => 4,
+					TypeDefPrimitive::U64 => 8,
+					TypeDefPrimitive::U128 => 16,
+					_ => return None,
+				};
+				let mut bytes = [0u8; 16];
+				bytes[..width].copy_from_slice(&generate_int(width, rng));
+				Compact(u128::from_le_bytes(bytes)).encode_to(out);
+			},
+			// Compact wrappers like `Perbill` are encoded as their single field.
+			TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+				self.generate_compact(composite.fields[0].ty.id, rng, out)?,
+			TypeDef::Composite(composite) if composite.fields.is_empty() => {},
+			TypeDef::Tuple(tuple) if tuple.fields.is_empty() => {},
+			_ => return None,
+		}
+
+		Some(())
+	}
+
+	/// Generate the length of a sequence, which shrinks with the depth to keep nested calls
+	/// small.
+	fn generate_len(&self, depth: u32, rng: &mut impl Rng) -> u32 {
+		let max_len = self.max_len.saturating_sub(depth / 4);
+		rng.gen_range(0..=max_len)
+	}
+}
+
+/// Generate the little endian bytes of an integer with `width` bytes.
+///
+/// The values are biased towards edge cases and small values, which are more likely to be
+/// meaningful than uniformly distributed ones.
+fn generate_int(width: usize, rng: &mut impl Rng) -> Vec<u8> {
+	let mut bytes = vec![0u8; width];
+	match rng.gen_range(0..8) {
+		// Zero.
+		0 => {},
+		// One.
+		1 => bytes[0] = 1,
+		// The maximal value.
+		2 => bytes.iter_mut().for_each(|b| *b = u8::MAX),
+		// A small value.
+		3 | 4 => bytes[0] = rng.gen_range(0..=16),
+		// A value of random magnitude.
+		_ => {
+			let bits = rng.gen_range(1..=width * 8);
+			rng.fill(&mut bytes[..]);
+			for (i, byte) in bytes.iter_mut().enumerate() {
+				let remaining = bits.saturating_sub(i * 8);
+				if remaining < 8 {
+					*byte &= ((1u16 << remaining) - 1) as u8;
+				}
+			}
+		},
+	}
+	bytes
+}
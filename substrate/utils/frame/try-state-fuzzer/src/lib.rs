@@ -0,0 +1,445 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Try-State Fuzzer
+//!
+//! A deterministic harness to fuzz the [`try_state`](frame_support::traits::TryState) invariants
+//! of the pallets of a mock runtime.
+//!
+//! The fuzzer generates random, well-typed `RuntimeCall`s from the `scale-info` type information
+//! of the runtime, which is the same information that ends up in the `#[pallet::call]` metadata.
+//! The calls are dispatched from random origins in a number of blocks. After each block, the
+//! `try_state` hooks of all pallets are executed. Any failing hook or panic is reported as a
+//! [`Failure`] together with a minimized sequence of calls that reproduces it.
+//!
+//! Calls are generated from a seed, so every run can be reproduced. Accounts in calls are only
+//! picked from [`Fuzzer::accounts`], such that they can be funded in the genesis of the mock
+//! runtime.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! #[test]
+//! fn fuzz_try_state() {
+//! 	let fuzzer = Fuzzer::<Runtime, AllPalletsWithSystem>::new(|| ExtBuilder::default().build())
+//! 		.accounts(vec![1, 2, 3])
+//! 		.call_filter(|call| {
+//! 			!matches!(call, RuntimeCall::System(frame_system::Call::set_code { .. }))
+//! 		})
+//! 		.blocks(10)
+//! 		.calls_per_block(20);
+//!
+//! 	if let Err(failure) = fuzzer.run_seeds(0..100) {
+//! 		panic!("{}", failure);
+//! 	}
+//! }
+//! ```
+//!
+//! Only `try_state` hooks are checked, so this crate, the pallets and the runtime must be
+//! compiled with the `try-runtime` feature.
+
+#![cfg(feature = "try-runtime")]
+
+mod generate;
+#[cfg(test)]
+mod tests;
+
+pub use generate::ValueGenerator;
+
+use codec::{Decode, DecodeLimit, Encode};
+use frame_support::{
+	traits::{
+		OnFinalize, OnIdle, OnInitialize, OriginTrait, PalletsInfoAccess, TryState, TryStateSelect,
+	},
+	weights::Weight,
+	CloneNoBound, DebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use scale_info::meta_type;
+use sp_io::TestExternalities;
+use sp_runtime::{
+	traits::{Dispatchable, StaticLookup, TrailingZeroInput},
+	TryRuntimeError,
+};
+use std::{
+	fmt,
+	marker::PhantomData,
+	panic::{catch_unwind, AssertUnwindSafe},
+};
+
+const LOG_TARGET: &str = "try-state-fuzzer";
+
+/// The maximal depth when decoding generated calls.
+const MAX_DECODE_DEPTH: u32 = 256;
+
+/// How often to try generating a call before giving up.
+const MAX_GENERATE_ATTEMPTS: u32 = 100;
+
+/// The origin of a fuzzed call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzOrigin<AccountId> {
+	/// The root origin.
+	Root,
+	/// The none origin.
+	None,
+	/// A signed origin of one of the accounts of the fuzzer.
+	Signed(AccountId),
+}
+
+/// A call together with the origin it is dispatched from.
+#[derive(CloneNoBound, DebugNoBound)]
+pub struct FuzzedCall<Runtime: frame_system::Config> {
+	/// The origin of the call.
+	pub origin: FuzzOrigin<Runtime::AccountId>,
+	/// The call itself.
+	pub call: Runtime::RuntimeCall,
+}
+
+/// The calls of each block.
+pub type Blocks<Runtime> = Vec<Vec<FuzzedCall<Runtime>>>;
+
+/// Why a sequence of blocks failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+	/// The `try_state` hook of `pallet` failed after the given block.
+	TryState { block: u32, pallet: &'static str, error: TryRuntimeError },
+	/// A call or hook panicked in the given block.
+	Panic { block: u32, message: String },
+}
+
+impl FailureKind {
+	/// Whether both failures are of the same kind, ignoring in which block they happened.
+	///
+	/// Panics are only of the same kind if their messages are equal, such that minimizing does
+	/// not turn one panic into another.
+	fn same_kind(&self, other: &Self) -> bool {
+		match (self, other) {
+			(
+				Self::TryState { pallet: pallet_a, error: error_a, .. },
+				Self::TryState { pallet: pallet_b, error: error_b, .. },
+			) => pallet_a == pallet_b && error_a == error_b,
+			(Self::Panic { message: message_a, .. }, Self::Panic { message: message_b, .. }) =>
+				message_a == message_b,
+			_ => false,
+		}
+	}
+}
+
+/// A failure found by the [`Fuzzer`].
+#[derive(CloneNoBound, DebugNoBound)]
+pub struct Failure<Runtime: frame_system::Config> {
+	/// The seed that found the failure.
+	pub seed: u64,
+	/// The minimized blocks that reproduce the failure with [`Fuzzer::replay`].
+	pub blocks: Blocks<Runtime>,
+	/// Why the blocks failed.
+	pub kind: FailureKind,
+}
+
+impl<Runtime: frame_system::Config> fmt::Display for Failure<Runtime> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.kind {
+			FailureKind::TryState { block, pallet, error } => writeln!(
+				f,
+				"try_state of {} failed after block #{} with {:?}",
+				pallet, block, error
+			)?,
+			FailureKind::Panic { block, message } =>
+				writeln!(f, "panicked in block #{} with {:?}", block, message)?,
+		}
+		writeln!(f, "Reproduce with seed {} or with the minimized blocks:", self.seed)?;
+		for (n, calls) in self.blocks.iter().enumerate() {
+			writeln!(f, "  block #{}:", n + 1)?;
+			for call in calls {
+				writeln!(f, "    {:?}: {:?}", call.origin, call.call)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Fuzzes the `try_state` invariants of `AllPallets` in a mock `Runtime`.
+///
+/// `AllPallets` is usually the `AllPalletsWithSystem` type generated by `construct_runtime`.
+pub struct Fuzzer<Runtime: frame_system::Config, AllPallets> {
+	ext: Box<dyn Fn() -> TestExternalities>,
+	call_filter: Box<dyn Fn(&Runtime::RuntimeCall) -> bool>,
+	generator: ValueGenerator,
+	account_ty: u32,
+	source_ty: u32,
+	accounts: Vec<Runtime::AccountId>,
+	blocks: u32,
+	calls_per_block: u32,
+	_phantom: PhantomData<AllPallets>,
+}
+
+impl<Runtime, AllPallets> Fuzzer<Runtime, AllPallets>
+where
+	Runtime: frame_system::Config,
+	AllPallets: OnInitialize<BlockNumberFor<Runtime>>
+		+ OnIdle<BlockNumberFor<Runtime>>
+		+ OnFinalize<BlockNumberFor<Runtime>>
+		+ TryState<BlockNumberFor<Runtime>>
+		+ PalletsInfoAccess,
+{
+	/// Create a new fuzzer that executes each sequence of blocks in a fresh instance of `ext`.
+	///
+	/// By default, calls are signed by one of 8 accounts that are decoded from the numbers
+	/// `0..8`.
+	pub fn new(ext: impl Fn() -> TestExternalities + 'static) -> Self {
+		let (generator, ids) = ValueGenerator::new(
+			meta_type::<Runtime::RuntimeCall>(),
+			&[
+				meta_type::<Runtime::AccountId>(),
+				meta_type::<<Runtime::Lookup as StaticLookup>::Source>(),
+			],
+		);
+		let accounts = (0u8..8)
+			.map(|i| {
+				Runtime::AccountId::decode(&mut TrailingZeroInput::new(&[i]))
+					.expect("infinite length input; no invalid inputs for type; qed")
+			})
+			.collect::<Vec<_>>();
+
+		Self {
+			ext: Box::new(ext),
+			call_filter: Box::new(|_| true),
+			generator,
+			account_ty: ids[0],
+			source_ty: ids[1],
+			accounts: Default::default(),
+			blocks: 10,
+			calls_per_block: 10,
+			_phantom: PhantomData,
+		}
+		.accounts(accounts)
+	}
+
+	/// Set the accounts that sign calls and that are used as arguments of calls.
+	pub fn accounts(mut self, accounts: Vec<Runtime::AccountId>) -> Self {
+		let encoded = accounts.iter().map(|a| a.encode()).collect();
+		let sources =
+			accounts.iter().map(|a| Runtime::Lookup::unlookup(a.clone()).encode()).collect();
+		self.generator.substitute(self.account_ty, encoded);
+		self.generator.substitute(self.source_ty, sources);
+		self.accounts = accounts;
+		self
+	}
+
+	/// Only generate calls for which `filter` returns `true`.
+	///
+	/// This can be used to exclude calls that cannot be executed in the mock runtime, e.g.
+	/// `frame_system::Call::set_code`.
+	pub fn call_filter(mut self, filter: impl Fn(&Runtime::RuntimeCall) -> bool + 'static) -> Self {
+		self.call_filter = Box::new(filter);
+		self
+	}
+
+	/// Set the number of blocks per run.
+	pub fn blocks(mut self, blocks: u32) -> Self {
+		self.blocks = blocks;
+		self
+	}
+
+	/// Set the number of calls in each block.
+	pub fn calls_per_block(mut self, calls_per_block: u32) -> Self {
+		self.calls_per_block = calls_per_block;
+		self
+	}
+
+	/// Set the maximal nesting depth of the types in generated calls.
+	pub fn max_depth(mut self, max_depth: u32) -> Self {
+		self.generator.set_max_depth(max_depth);
+		self
+	}
+
+	/// Set the maximal length of sequences in generated calls, e.g. the calls of a batch.
+	pub fn max_len(mut self, max_len: u32) -> Self {
+		self.generator.set_max_len(max_len);
+		self
+	}
+
+	/// Run the fuzzer with each of the `seeds`, stopping at the first failure.
+	pub fn run_seeds(&self, seeds: impl IntoIterator<Item = u64>) -> Result<(), Failure<Runtime>> {
+		seeds.into_iter().try_for_each(|seed| self.run(seed))
+	}
+
+	/// Run the fuzzer with a single `seed`.
+	///
+	/// The failing blocks are minimized before they are returned.
+	pub fn run(&self, seed: u64) -> Result<(), Failure<Runtime>> {
+		let blocks = self.generate_blocks(seed);
+		let Err(kind) = self.replay(&blocks) else { return Ok(()) };
+
+		log::info!(target: LOG_TARGET, "seed {} failed with {:?}, minimizing", seed, kind);
+		let (blocks, kind) = self.minimize(blocks, kind);
+		Err(Failure { seed, blocks, kind })
+	}
+
+	/// Generate the blocks of calls for `seed`.
+	pub fn generate_blocks(&self, seed: u64) -> Blocks<Runtime> {
+		let mut rng = ChaChaRng::seed_from_u64(seed);
+		(0..self.blocks)
+			.map(|_| {
+				(0..self.calls_per_block)
+					.filter_map(|_| {
+						let call = self.generate_call(&mut rng)?;
+						let origin = self.generate_origin(&mut rng);
+						Some(FuzzedCall { origin, call })
+					})
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Execute `blocks` in a fresh instance of the externalities.
+	///
+	/// Calls are dispatched with the call filter of the runtime and their results are ignored.
+	/// After each block, the `try_state` hooks of all pallets are executed.
+	pub fn replay(&self, blocks: &[Vec<FuzzedCall<Runtime>>]) -> Result<(), FailureKind> {
+		(self.ext)().execute_with(|| {
+			for (n, calls) in blocks.iter().enumerate() {
+				let block = n as u32 + 1;
+				let number: BlockNumberFor<Runtime> = block.into();
+				let panicked = |message| FailureKind::Panic { block, message };
+
+				catch_panic(|| {
+					frame_system::Pallet::<Runtime>::set_block_number(number);
+					AllPallets::on_initialize(number);
+				})
+				.map_err(panicked)?;
+
+				for call in calls {
+					let origin = match &call.origin {
+						FuzzOrigin::Root => Runtime::RuntimeOrigin::root(),
+						FuzzOrigin::None => Runtime::RuntimeOrigin::none(),
+						FuzzOrigin::Signed(who) => Runtime::RuntimeOrigin::signed(who.clone()),
+					};
+					catch_panic(|| {
+						let _ = call.call.clone().dispatch(origin);
+					})
+					.map_err(panicked)?;
+				}
+
+				catch_panic(|| {
+					AllPallets::on_idle(number, Weight::MAX);
+					AllPallets::on_finalize(number);
+				})
+				.map_err(panicked)?;
+
+				// Check the pallets one by one to know which of them failed.
+				for pallet in AllPallets::infos().into_iter().map(|info| info.name) {
+					let select = TryStateSelect::Only(vec![pallet.as_bytes().to_vec()]);
+					catch_panic(|| AllPallets::try_state(number, select))
+						.map_err(panicked)?
+						.map_err(|error| FailureKind::TryState { block, pallet, error })?;
+				}
+			}
+			Ok(())
+		})
+	}
+
+	fn generate_call(&self, rng: &mut ChaChaRng) -> Option<Runtime::RuntimeCall> {
+		(0..MAX_GENERATE_ATTEMPTS).find_map(|_| {
+			let encoded = self.generator.generate(rng)?;
+			Runtime::RuntimeCall::decode_all_with_depth_limit(MAX_DECODE_DEPTH, &mut &encoded[..])
+				.ok()
+				.filter(|call| (self.call_filter)(call))
+		})
+	}
+
+	fn generate_origin(&self, rng: &mut ChaChaRng) -> FuzzOrigin<Runtime::AccountId> {
+		match rng.gen_range(0..10) {
+			0 => FuzzOrigin::Root,
+			1 => FuzzOrigin::None,
+			_ if self.accounts.is_empty() => FuzzOrigin::None,
+			_ => FuzzOrigin::Signed(self.accounts[rng.gen_range(0..self.accounts.len())].clone()),
+		}
+	}
+
+	/// Remove blocks and calls from `blocks` as long as they still fail with the same kind of
+	/// failure.
+	fn minimize(
+		&self,
+		mut blocks: Blocks<Runtime>,
+		mut kind: FailureKind,
+	) -> (Blocks<Runtime>, FailureKind) {
+		let failing_block = match &kind {
+			FailureKind::TryState { block, .. } | FailureKind::Panic { block, .. } => *block,
+		};
+		blocks.truncate(failing_block as usize);
+
+		let still_fails =
+			|candidate: &Blocks<Runtime>, kind: &mut FailureKind| match self.replay(candidate) {
+				Err(new_kind) if new_kind.same_kind(kind) => {
+					*kind = new_kind;
+					true
+				},
+				_ => false,
+			};
+
+		let mut progress = true;
+		while progress {
+			progress = false;
+
+			// Remove whole blocks, then empty them, then remove single calls.
+			for n in (0..blocks.len()).rev() {
+				let mut candidate = blocks.clone();
+				candidate.remove(n);
+				if still_fails(&candidate, &mut kind) {
+					blocks = candidate;
+					progress = true;
+					continue
+				}
+
+				if !blocks[n].is_empty() {
+					let mut candidate = blocks.clone();
+					candidate[n].clear();
+					if still_fails(&candidate, &mut kind) {
+						blocks = candidate;
+						progress = true;
+						continue
+					}
+				}
+
+				for i in (0..blocks[n].len()).rev() {
+					let mut candidate = blocks.clone();
+					candidate[n].remove(i);
+					if still_fails(&candidate, &mut kind) {
+						blocks = candidate;
+						progress = true;
+					}
+				}
+			}
+		}
+
+		(blocks, kind)
+	}
+}
+
+/// Execute `f` and return the message of its panic, if any.
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+	catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+		payload
+			.downcast_ref::<&str>()
+			.map(|s| s.to_string())
+			.or_else(|| payload.downcast_ref::<String>().cloned())
+			.unwrap_or_else(|| "unknown panic".into())
+	})
+}
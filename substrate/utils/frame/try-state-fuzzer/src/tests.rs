@@ -0,0 +1,223 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{derive_impl, parameter_types};
+use sp_runtime::BuildStorage;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The maximal value of the counter.
+		type Limit: Get<u32>;
+		/// Whether `increment` forgets to check the limit of the counter.
+		type Buggy: Get<bool>;
+		/// Whether `explode` panics.
+		type Explosive: Get<bool>;
+	}
+
+	#[pallet::storage]
+	pub type Counter<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		LimitExceeded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(Counter::<T>::get() <= T::Limit::get(), "counter exceeds the limit");
+			Ok(())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		pub fn increment(origin: OriginFor<T>, by: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+			let limit = T::Limit::get();
+			let new = Counter::<T>::get().saturating_add(by);
+			if T::Buggy::get() {
+				ensure!(by <= limit, Error::<T>::LimitExceeded);
+			} else {
+				ensure!(new <= limit, Error::<T>::LimitExceeded);
+			}
+			Counter::<T>::put(new);
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		pub fn reset(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			Counter::<T>::kill();
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		pub fn explode(_origin: OriginFor<T>, fuse: u8) -> DispatchResult {
+			if T::Explosive::get() && fuse == u8::MAX {
+				panic!("boom");
+			}
+			Ok(())
+		}
+	}
+}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Counter: pallet,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Self>;
+}
+
+parameter_types! {
+	pub const Limit: u32 = 10;
+	pub static Buggy: bool = false;
+	pub static Explosive: bool = false;
+}
+
+impl pallet::Config for Runtime {
+	type Limit = Limit;
+	type Buggy = Buggy;
+	type Explosive = Explosive;
+}
+
+fn fuzzer() -> Fuzzer<Runtime, AllPalletsWithSystem> {
+	Fuzzer::new(|| {
+		frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into()
+	})
+	.call_filter(|call| !matches!(call, RuntimeCall::System(_)))
+	.blocks(5)
+	.calls_per_block(10)
+}
+
+fn total_calls(blocks: &Blocks<Runtime>) -> usize {
+	blocks.iter().map(|calls| calls.len()).sum()
+}
+
+#[test]
+fn generated_calls_are_deterministic() {
+	let fuzzer = fuzzer();
+	let encode = |blocks: Blocks<Runtime>| {
+		blocks
+			.into_iter()
+			.map(|calls| calls.into_iter().map(|c| (c.origin, c.call.encode())).collect::<Vec<_>>())
+			.collect::<Vec<_>>()
+	};
+
+	let blocks = fuzzer.generate_blocks(42);
+	assert_eq!(blocks.len(), 5);
+	assert_eq!(total_calls(&blocks), 50);
+	assert_eq!(encode(blocks), encode(fuzzer.generate_blocks(42)));
+	assert_ne!(encode(fuzzer.generate_blocks(42)), encode(fuzzer.generate_blocks(43)));
+}
+
+#[test]
+fn generated_calls_only_use_known_accounts() {
+	let fuzzer = fuzzer().accounts(vec![7, 8]);
+
+	for call in fuzzer.generate_blocks(0).into_iter().flatten() {
+		match call.origin {
+			FuzzOrigin::Signed(who) => assert!(who == 7 || who == 8),
+			FuzzOrigin::Root | FuzzOrigin::None => {},
+		}
+	}
+}
+
+#[test]
+fn correct_pallet_passes() {
+	Buggy::set(false);
+	Explosive::set(false);
+
+	assert!(fuzzer().run_seeds(0..10).is_ok());
+}
+
+#[test]
+fn broken_invariant_is_found_and_minimized() {
+	Buggy::set(true);
+	Explosive::set(false);
+
+	let fuzzer = fuzzer();
+	let failure = fuzzer.run_seeds(0..10).unwrap_err();
+	assert!(matches!(
+		failure.kind,
+		FailureKind::TryState {
+			pallet: "Counter",
+			error: sp_runtime::DispatchError::Other("counter exceeds the limit"),
+			..
+		}
+	));
+
+	// Only increments are left, and none of them can be removed without losing the failure.
+	for call in failure.blocks.iter().flatten() {
+		assert!(matches!(call.call, RuntimeCall::Counter(pallet::Call::increment { .. })));
+	}
+	assert!(total_calls(&failure.blocks) >= 2);
+	for n in 0..failure.blocks.len() {
+		for i in 0..failure.blocks[n].len() {
+			let mut blocks = failure.blocks.clone();
+			blocks[n].remove(i);
+			assert!(fuzzer.replay(&blocks).is_ok());
+		}
+	}
+
+	// The minimized blocks reproduce the failure.
+	assert_eq!(fuzzer.replay(&failure.blocks), Err(failure.kind.clone()));
+	// And so does the seed.
+	assert_eq!(fuzzer.run(failure.seed).unwrap_err().kind, failure.kind);
+}
+
+#[test]
+fn panics_are_found_and_minimized() {
+	Buggy::set(false);
+	Explosive::set(true);
+
+	let fuzzer = fuzzer();
+	let failure = fuzzer.run_seeds(0..10).unwrap_err();
+	assert_eq!(failure.kind, FailureKind::Panic { block: 1, message: "boom".into() });
+	assert_eq!(failure.blocks.len(), 1);
+	assert_eq!(total_calls(&failure.blocks), 1);
+	assert!(matches!(
+		failure.blocks[0][0].call,
+		RuntimeCall::Counter(pallet::Call::explode { fuse: u8::MAX })
+	));
+}
+
+#[test]
+fn panics_with_different_messages_are_different_failures() {
+	let panic = |block, message: &str| FailureKind::Panic { block, message: message.into() };
+
+	assert!(panic(1, "boom").same_kind(&panic(2, "boom")));
+	assert!(!panic(1, "boom").same_kind(&panic(1, "bang")));
+}
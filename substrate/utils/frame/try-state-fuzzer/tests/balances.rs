@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz the `try_state` invariants of `pallet-balances`.
+
+#![cfg(feature = "try-runtime")]
+
+use frame_support::{derive_impl, traits::VariantCountOf};
+use frame_try_state_fuzzer::Fuzzer;
+use sp_runtime::BuildStorage;
+
+type Balance = u64;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Self>;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type AccountStore = System;
+	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+}
+
+/// The accounts that sign and receive the fuzzed calls.
+const ACCOUNTS: [u64; 4] = [1, 2, 3, 4];
+
+fn ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: ACCOUNTS.iter().map(|who| (*who, 1_000)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
+}
+
+#[test]
+fn balances_try_state_holds() {
+	let fuzzer = Fuzzer::<Runtime, AllPalletsWithSystem>::new(ext)
+		.accounts(ACCOUNTS.to_vec())
+		.call_filter(|call| matches!(call, RuntimeCall::Balances(_)))
+		.blocks(5)
+		.calls_per_block(20);
+
+	if let Err(failure) = fuzzer.run_seeds(0..20) {
+		panic!("{}", failure);
+	}
+}
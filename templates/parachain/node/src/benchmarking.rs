@@ -0,0 +1,223 @@
+//! Setup code for [`super::command`] which would otherwise bloat that module.
+//!
+//! Should only be used for benchmarking as it may break in other contexts.
+
+use crate::service::ParachainClient;
+
+use codec::Encode;
+use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
+use cumulus_primitives_core::{relay_chain, ParaId};
+use parachain_template_runtime as runtime;
+use runtime::{AccountId, Balance};
+use sc_cli::Result;
+use sc_client_api::{BlockBackend, UsageProvider};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::{AuraApi, Slot, AURA_ENGINE_ID};
+use sp_core::{Get, Pair};
+use sp_inherents::{InherentData, InherentDataProvider};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{DigestItem, OpaqueExtrinsic, SaturatedConversion};
+
+use std::sync::Arc;
+
+/// Slot duration of the mocked relay chain, in milliseconds.
+const RELAY_CHAIN_SLOT_DURATION_MILLIS: u64 = 6000;
+
+/// Generates `System::Remark` extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub struct RemarkBuilder {
+	client: Arc<ParachainClient>,
+}
+
+impl RemarkBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<ParachainClient>) -> Self {
+		Self { client }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for RemarkBuilder {
+	fn pallet(&self) -> &str {
+		"system"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"remark"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			frame_system::Call::remark { remark: vec![] }.into(),
+			nonce,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Generates `Balances::TransferKeepAlive` extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub struct TransferKeepAliveBuilder {
+	client: Arc<ParachainClient>,
+	dest: AccountId,
+	value: Balance,
+}
+
+impl TransferKeepAliveBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<ParachainClient>, dest: AccountId, value: Balance) -> Self {
+		Self { client, dest, value }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder {
+	fn pallet(&self) -> &str {
+		"balances"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"transfer_keep_alive"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			pallet_balances::Call::transfer_keep_alive {
+				dest: self.dest.clone().into(),
+				value: self.value,
+			}
+			.into(),
+			nonce,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Create a transaction using the given `call`.
+///
+/// Note: Should only be used for benchmarking.
+pub fn create_benchmark_extrinsic(
+	client: &ParachainClient,
+	sender: sp_core::sr25519::Pair,
+	call: runtime::RuntimeCall,
+	nonce: u32,
+) -> runtime::UncheckedExtrinsic {
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let best_hash = client.chain_info().best_hash;
+	let best_block = client.chain_info().best_number;
+
+	let period = <runtime::Runtime as frame_system::Config>::BlockHashCount::get()
+		.checked_next_power_of_two()
+		.map(|c| c / 2)
+		.unwrap_or(2) as u64;
+	let extra: runtime::SignedExtra = (
+		frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+		frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+		frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+		frame_system::CheckGenesis::<runtime::Runtime>::new(),
+		frame_system::CheckEra::<runtime::Runtime>::from(sp_runtime::generic::Era::mortal(
+			period,
+			best_block.saturated_into(),
+		)),
+		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+		frame_system::CheckWeight::<runtime::Runtime>::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+		cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim::<runtime::Runtime>::new(),
+		frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+	);
+
+	let raw_payload = sp_runtime::generic::SignedPayload::from_raw(
+		call.clone(),
+		extra.clone(),
+		(
+			(),
+			runtime::VERSION.spec_version,
+			runtime::VERSION.transaction_version,
+			genesis_hash,
+			best_hash,
+			(),
+			(),
+			(),
+			(),
+			None,
+		),
+	);
+	let signature = raw_payload.using_encoded(|e| sender.sign(e));
+
+	runtime::UncheckedExtrinsic::new_signed(
+		call,
+		sp_runtime::AccountId32::from(sender.public()).into(),
+		runtime::Signature::Sr25519(signature),
+		extra,
+	)
+}
+
+/// Generates inherent data and digest items for the `benchmark pov` command.
+///
+/// The runtime requires the `set_validation_data` inherent, so the relay chain is mocked for the
+/// block on top of the best block. The block is authored at the start of the relay chain slot of
+/// the current time, and gets an Aura pre-runtime digest for the matching parachain slot.
+///
+/// Note: Should only be used for benchmarking.
+pub fn inherent_benchmark_data(
+	client: &ParachainClient,
+	para_id: ParaId,
+) -> Result<(InherentData, Vec<DigestItem>)> {
+	let best_hash = client.usage_info().chain.best_hash;
+	let parent_header = client
+		.header(best_hash)?
+		.ok_or_else(|| format!("Header of {} not found", best_hash))?;
+
+	let relay_slot = *sp_timestamp::Timestamp::current() / RELAY_CHAIN_SLOT_DURATION_MILLIS;
+	let timestamp = sp_timestamp::Timestamp::new(relay_slot * RELAY_CHAIN_SLOT_DURATION_MILLIS);
+
+	let slot_duration = client
+		.runtime_api()
+		.slot_duration(best_hash)
+		.map_err(|e| format!("Failed to fetch the Aura slot duration: {}", e))?;
+	let slot = Slot::from_timestamp(timestamp, slot_duration);
+	let digest_items = vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())];
+
+	let validation_data = MockValidationDataInherentDataProvider {
+		current_para_block: parent_header.number + 1,
+		para_id,
+		current_para_block_head: Some(parent_header.encode().into()),
+		// The relay parent number must be higher than the one of the best block. A real relay
+		// chain has far fewer blocks than slots, so the slot is used as block number as well.
+		relay_offset: relay_slot.saturated_into(),
+		relay_blocks_per_para_block: 0,
+		para_blocks_per_relay_epoch: 0,
+		relay_randomness_config: (),
+		xcm_config: MockXcmConfig::new(client, best_hash, Default::default()),
+		raw_downward_messages: Vec::new(),
+		raw_horizontal_messages: Vec::new(),
+		// Overwrite the slot of the mocked relay chain, which would be derived from the relay
+		// parent number otherwise.
+		additional_key_values: Some(vec![(
+			relay_chain::well_known_keys::CURRENT_SLOT.to_vec(),
+			Slot::from(relay_slot).encode(),
+		)]),
+	};
+
+	let mut inherent_data = InherentData::new();
+	futures::executor::block_on(async {
+		sp_timestamp::InherentDataProvider::new(timestamp)
+			.provide_inherent_data(&mut inherent_data)
+			.await?;
+		validation_data.provide_inherent_data(&mut inherent_data).await
+	})
+	.map_err(|e| format!("creating inherent data: {:?}", e))?;
+
+	Ok((inherent_data, digest_items))
+}
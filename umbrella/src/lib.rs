@@ -169,6 +169,10 @@ pub use cumulus_pallet_aura_ext;
 #[cfg(feature = "cumulus-pallet-dmp-queue")]
 pub use cumulus_pallet_dmp_queue;
 
+/// Manages the HRMP channels of a parachain with the relay chain.
+#[cfg(feature = "cumulus-pallet-hrmp-channels")]
+pub use cumulus_pallet_hrmp_channels;
+
 /// Base pallet for cumulus-based parachains.
 #[cfg(feature = "cumulus-pallet-parachain-system")]
 pub use cumulus_pallet_parachain_system;
@@ -616,6 +620,10 @@ pub use pallet_recovery;
 #[cfg(feature = "pallet-referenda")]
 pub use pallet_referenda;
 
+/// Runtime API for referenda FRAME pallet.
+#[cfg(feature = "pallet-referenda-runtime-api")]
+pub use pallet_referenda_runtime_api;
+
 /// Remark storage pallet.
 #[cfg(feature = "pallet-remark")]
 pub use pallet_remark;